@@ -177,7 +177,10 @@ fn test_step_boolean_operation(
                         }
                         
                         orbit_state.center = (min_bound + max_bound) * 0.5;
-                        let model_size = (max_bound - min_bound).length();
+                        // Routed through `bevy_mesh_boolean`'s deterministic length helper
+                        // rather than `Vec3::length` so this camera framing is reproducible
+                        // across platforms under the `deterministic` feature.
+                        let model_size = bevy_mesh_boolean::detmath::length(max_bound - min_bound);
                         orbit_state.distance = model_size.max(5.0) * 1.5; // Adjust camera distance based on model size
                     }
                 }
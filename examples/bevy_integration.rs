@@ -5,268 +5,523 @@
 //! of the MeshGL type for game development.
 
 use bevy::prelude::*;
-use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::mesh::{Indices, MeshVertexAttribute, PrimitiveTopology, VertexAttributeValues};
 use meshbool::{cube, get_mesh_gl, translate};
 use nalgebra::Vector3;
 
-/// Convert meshbool MeshGL to Bevy Mesh
-/// 
-/// This function leverages the rich metadata in MeshGL to create optimal Bevy meshes
-/// with proper vertex attributes, indices, and instance information.
-fn meshgl_to_bevy_mesh(mesh_gl: &meshbool::MeshGL) -> Mesh {
-    let mut bevy_mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
-    
-    // Extract vertex positions from MeshGL
-    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop as usize;
-    let mut positions = Vec::with_capacity(num_verts);
-    
-    for i in 0..num_verts {
-        let offset = i * mesh_gl.num_prop as usize;
-        positions.push([
-            mesh_gl.vert_properties[offset],
-            mesh_gl.vert_properties[offset + 1], 
-            mesh_gl.vert_properties[offset + 2]
-        ]);
+/// One property channel beyond position, describing where it lives in
+/// MeshGL's per-vertex float block and which Bevy attribute it feeds.
+#[derive(Clone, Copy)]
+struct PropertyChannel {
+    offset: usize,
+    size: usize,
+    attribute: MeshVertexAttribute,
+}
+
+/// Maps MeshGL's flat per-vertex property layout onto Bevy vertex
+/// attributes, so the conversion isn't hardcoded to a single fixed
+/// `num_prop`. Position is always channel 0 and is handled separately;
+/// `channels` covers everything after it, in offset order. Any property
+/// floats left over past the last channel (i.e. `num_prop` is larger than
+/// what `channels` accounts for) are passed through as named custom
+/// attributes instead of being silently dropped.
+#[derive(Clone)]
+struct PropertyLayout {
+    channels: Vec<PropertyChannel>,
+}
+
+impl PropertyLayout {
+    /// The layout `get_mesh_gl` itself produces: normal at 3..6, UV0 at
+    /// 6..8, tangent at 8..12, color at 12..16, each included only if
+    /// `num_prop` reaches far enough to hold it.
+    fn standard(num_prop: usize) -> Self {
+        let candidates = [
+            (3, 3, Mesh::ATTRIBUTE_NORMAL),
+            (6, 2, Mesh::ATTRIBUTE_UV_0),
+            (8, 4, Mesh::ATTRIBUTE_TANGENT),
+            (12, 4, Mesh::ATTRIBUTE_COLOR),
+        ];
+        let channels = candidates
+            .into_iter()
+            .filter(|&(offset, size, _)| num_prop >= offset + size)
+            .map(|(offset, size, attribute)| PropertyChannel { offset, size, attribute })
+            .collect();
+        PropertyLayout { channels }
     }
-    
-    // Extract triangle indices from MeshGL
-    let indices: Vec<u32> = mesh_gl.tri_verts.clone();
-    
-    // Insert vertex data into Bevy mesh
-    bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    
-    // If MeshGL has normals (property index 3, 4, 5), extract them
-    if mesh_gl.num_prop >= 6 {
-        let mut normals = Vec::with_capacity(num_verts);
-        for i in 0..num_verts {
-            let offset = i * mesh_gl.num_prop as usize;
-            normals.push([
-                mesh_gl.vert_properties[offset + 3],
-                mesh_gl.vert_properties[offset + 4], 
-                mesh_gl.vert_properties[offset + 5]
-            ]);
-        }
-        bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+
+    fn covered_up_to(&self) -> usize {
+        self.channels.iter().map(|c| c.offset + c.size).max().unwrap_or(3)
     }
-    
-    // If MeshGL has UVs (property index 6, 7), extract them
-    if mesh_gl.num_prop >= 8 {
-        let mut uvs = Vec::with_capacity(num_verts);
-        for i in 0..num_verts {
-            let offset = i * mesh_gl.num_prop as usize;
-            uvs.push([
-                mesh_gl.vert_properties[offset + 6],
-                mesh_gl.vert_properties[offset + 7]
-            ]);
-        }
-        bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+}
+
+/// Mint a custom vertex attribute for a property channel the layout doesn't
+/// recognize, named after its offset so round-tripping the same MeshGL
+/// twice reuses the same attribute identity (Bevy attribute ids must be
+/// stable, so this hashes the name rather than allocating a fresh one).
+fn custom_attribute(offset: usize, size: usize) -> MeshVertexAttribute {
+    use std::hash::{Hash, Hasher};
+    let name: &'static str = Box::leak(format!("meshgl_prop_{offset}").into_boxed_str());
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    name.hash(&mut hasher);
+    let id = hasher.finish();
+    let format = match size {
+        1 => bevy::render::render_resource::VertexFormat::Float32,
+        2 => bevy::render::render_resource::VertexFormat::Float32x2,
+        3 => bevy::render::render_resource::VertexFormat::Float32x3,
+        _ => bevy::render::render_resource::VertexFormat::Float32x4,
+    };
+    MeshVertexAttribute::new(name, id, format)
+}
+
+/// Apply a run's 3x4 row-major transform (rotation/scale in the first 3
+/// columns, translation in the 4th) to a point.
+fn apply_run_transform(p: [f32; 3], t: &[f32]) -> [f32; 3] {
+    [
+        t[0] * p[0] + t[1] * p[1] + t[2] * p[2] + t[3],
+        t[4] * p[0] + t[5] * p[1] + t[6] * p[2] + t[7],
+        t[8] * p[0] + t[9] * p[1] + t[10] * p[2] + t[11],
+    ]
+}
+
+/// Same transform applied to a direction (normal/tangent), dropping the
+/// translation column.
+fn apply_run_transform_dir(v: [f32; 3], t: &[f32]) -> [f32; 3] {
+    [
+        t[0] * v[0] + t[1] * v[1] + t[2] * v[2],
+        t[4] * v[0] + t[5] * v[1] + t[6] * v[2],
+        t[8] * v[0] + t[9] * v[1] + t[10] * v[2],
+    ]
+}
+
+const IDENTITY_TRANSFORM: [f32; 12] = [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+
+/// One original solid's share of a combined MeshGL, converted to a Bevy
+/// submesh with its vertices already carried back into that solid's local
+/// space via its `run_transform`. `channels` lists every non-position
+/// attribute the mesh carries (in the order its properties were written),
+/// so [`bevy_meshes_to_meshgl`] can read them back without having to
+/// recover a `MeshVertexAttribute` from a bare attribute id.
+struct MeshRun {
+    original_id: u32,
+    transform: [f32; 12],
+    mesh: Mesh,
+    channels: Vec<(MeshVertexAttribute, usize)>,
+}
+
+/// Convert a meshbool MeshGL to one Bevy submesh per run, keyed by
+/// `run_original_id`/`run_index` so each original solid that went into a
+/// boolean op keeps its own material segment instead of being flattened
+/// into one undifferentiated mesh. `layout` maps the property floats beyond
+/// position onto Bevy attributes; anything `layout` doesn't name is still
+/// carried through as a named custom attribute rather than truncated.
+fn meshgl_to_bevy_meshes(mesh_gl: &meshbool::MeshGL, layout: &PropertyLayout) -> Vec<MeshRun> {
+    let num_prop = mesh_gl.num_prop as usize;
+    let extra_offset = layout.covered_up_to();
+
+    let runs: Vec<(usize, usize, u32, [f32; 12])> = if mesh_gl.run_index.len() < 2 {
+        vec![(0, mesh_gl.tri_verts.len(), 0, IDENTITY_TRANSFORM)]
+    } else {
+        (0..mesh_gl.run_index.len() - 1)
+            .map(|r| {
+                let start = mesh_gl.run_index[r] as usize;
+                let end = mesh_gl.run_index[r + 1] as usize;
+                let original_id = mesh_gl.run_original_id.get(r).copied().unwrap_or(0);
+                let mut transform = IDENTITY_TRANSFORM;
+                let base = r * 12;
+                if mesh_gl.run_transform.len() >= base + 12 {
+                    transform.copy_from_slice(&mesh_gl.run_transform[base..base + 12]);
+                }
+                (start, end, original_id, transform)
+            })
+            .collect()
+    };
+
+    runs.into_iter()
+        .map(|(tri_start, tri_end, original_id, transform)| {
+            let tri_range = &mesh_gl.tri_verts[tri_start..tri_end];
+
+            // Dense local vertex index for just the vertices this run uses,
+            // so the submesh doesn't carry the whole combined vertex pool.
+            let mut remap: std::collections::HashMap<u32, u32> = std::collections::HashMap::new();
+            let mut order: Vec<u32> = Vec::new();
+            let local_indices: Vec<u32> = tri_range
+                .iter()
+                .map(|&v| {
+                    *remap.entry(v).or_insert_with(|| {
+                        order.push(v);
+                        (order.len() - 1) as u32
+                    })
+                })
+                .collect();
+
+            let vert_props = |v: u32, offset: usize| -> f32 { mesh_gl.vert_properties[v as usize * num_prop + offset] };
+
+            let positions: Vec<[f32; 3]> = order
+                .iter()
+                .map(|&v| apply_run_transform([vert_props(v, 0), vert_props(v, 1), vert_props(v, 2)], &transform))
+                .collect();
+
+            let mut bevy_mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+            bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+
+            let mut channels: Vec<(MeshVertexAttribute, usize)> = Vec::new();
+
+            for channel in &layout.channels {
+                let is_direction = channel.attribute == Mesh::ATTRIBUTE_NORMAL || channel.attribute == Mesh::ATTRIBUTE_TANGENT;
+                let values: Vec<[f32; 4]> = order
+                    .iter()
+                    .map(|&v| {
+                        let mut raw = [0.0; 4];
+                        for (i, slot) in raw.iter_mut().enumerate().take(channel.size) {
+                            *slot = vert_props(v, channel.offset + i);
+                        }
+                        if is_direction {
+                            let rotated = apply_run_transform_dir([raw[0], raw[1], raw[2]], &transform);
+                            raw[0] = rotated[0];
+                            raw[1] = rotated[1];
+                            raw[2] = rotated[2];
+                        }
+                        raw
+                    })
+                    .collect();
+                insert_sized_attribute(&mut bevy_mesh, channel.attribute, channel.size, values);
+                channels.push((channel.attribute, channel.size));
+            }
+
+            // Boolean ops split and interpolate triangles along the cut, so
+            // a `mesh_gl` that never carried a normal channel to begin with
+            // still needs *something* smooth to render with; rather than
+            // leave the new cut-edge vertices without normals at all, fall
+            // back to area-weighted smoothing (unnormalized face normals,
+            // so a bigger triangle pulls its shared vertices' averages
+            // harder than a sliver does) over this run's own geometry.
+            if !layout.channels.iter().any(|c| c.attribute == Mesh::ATTRIBUTE_NORMAL) {
+                let normals = match bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+                    Some(VertexAttributeValues::Float32x3(positions)) => compute_smooth_normals(positions, &local_indices),
+                    _ => Vec::new(),
+                };
+                if !normals.is_empty() {
+                    bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+                    channels.push((Mesh::ATTRIBUTE_NORMAL, 3));
+                }
+            }
+
+            // Anything past what `layout` names is an unrecognized channel;
+            // pass each one through whole rather than drop it.
+            for offset in extra_offset..num_prop {
+                if layout.channels.iter().any(|c| offset >= c.offset && offset < c.offset + c.size) {
+                    continue;
+                }
+                let values: Vec<[f32; 4]> = order.iter().map(|&v| [vert_props(v, offset), 0.0, 0.0, 0.0]).collect();
+                let attribute = custom_attribute(offset, 1);
+                insert_sized_attribute(&mut bevy_mesh, attribute, 1, values);
+                channels.push((attribute, 1));
+            }
+
+            bevy_mesh.insert_indices(Indices::U32(local_indices));
+
+            MeshRun { original_id, transform, mesh: bevy_mesh, channels }
+        })
+        .collect()
+}
+
+/// Smooth per-vertex fallback normals for a run with no normal channel of
+/// its own: accumulate each triangle's unnormalized face normal (the cross
+/// product of two edges, left unnormalized so a larger triangle contributes
+/// proportionally more) onto its three vertices, then normalize each
+/// vertex's running sum.
+fn compute_smooth_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+    let mut normals = vec![Vector3::new(0.0_f32, 0.0, 0.0); positions.len()];
+    for tri in indices.chunks(3) {
+        let (a, b, c) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+        let (pa, pb, pc) = (Vector3::from(positions[a]), Vector3::from(positions[b]), Vector3::from(positions[c]));
+        let face_normal = (pb - pa).cross(&(pc - pa));
+        normals[a] += face_normal;
+        normals[b] += face_normal;
+        normals[c] += face_normal;
     }
-    
-    // Insert indices
-    bevy_mesh.insert_indices(Indices::U32(indices));
-    
-    bevy_mesh
+    normals
+        .into_iter()
+        .map(|n| {
+            let n = if n.norm_squared() > 0.0 { n.normalize() } else { Vector3::new(0.0, 0.0, 1.0) };
+            [n.x, n.y, n.z]
+        })
+        .collect()
 }
 
-/// Convert Bevy Mesh to meshbool MeshGL
-/// 
-/// This function converts a Bevy mesh back to MeshGL format, preserving
-/// as much metadata as possible for round-trip compatibility.
-fn bevy_mesh_to_meshgl(bevy_mesh: &Mesh) -> meshbool::MeshGL {
+fn insert_sized_attribute(mesh: &mut Mesh, attribute: MeshVertexAttribute, size: usize, values: Vec<[f32; 4]>) {
+    match size {
+        1 => mesh.insert_attribute(attribute, values.iter().map(|v| v[0]).collect::<Vec<f32>>()),
+        2 => mesh.insert_attribute(attribute, values.iter().map(|v| [v[0], v[1]]).collect::<Vec<_>>()),
+        3 => mesh.insert_attribute(attribute, values.iter().map(|v| [v[0], v[1], v[2]]).collect::<Vec<_>>()),
+        _ => mesh.insert_attribute(attribute, values),
+    }
+}
+
+/// Convert one or more Bevy submeshes back into a single combined MeshGL,
+/// the inverse of [`meshgl_to_bevy_meshes`]: vertex properties from every
+/// run are concatenated, and `run_index`/`run_original_id`/`run_transform`
+/// are populated so the original per-solid structure survives the
+/// round-trip instead of collapsing to MeshGL's all-zero defaults.
+fn bevy_meshes_to_meshgl(runs: &[MeshRun]) -> meshbool::MeshGL {
     let mut mesh_gl = meshbool::MeshGL::default();
-    
-    // Extract vertex positions
-    if let Some(positions) = bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        let position_data = positions.as_float3();
-        if let Some(pos_data) = position_data {
-            let num_verts = pos_data.len();
-            mesh_gl.vert_properties.reserve(num_verts * 3);
-            mesh_gl.num_prop = 3; // Start with just positions
-            
-            for pos in pos_data {
-                mesh_gl.vert_properties.push(pos[0]);
-                mesh_gl.vert_properties.push(pos[1]);
-                mesh_gl.vert_properties.push(pos[2]);
+
+    // Every channel present on any run, in first-seen order, so a channel
+    // missing from one run still gets a zero-filled slot instead of
+    // shifting every later run's layout.
+    let mut channels: Vec<(MeshVertexAttribute, usize)> = Vec::new();
+    for run in runs {
+        for &(attribute, size) in &run.channels {
+            if !channels.iter().any(|(a, _)| a.id == attribute.id) {
+                channels.push((attribute, size));
             }
         }
     }
-    
-    // Extract normals if present
-    if let Some(normals) = bevy_mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
-        let normal_data = normals.as_float3();
-        if let Some(norm_data) = normal_data {
-            // Extend vertex properties to include normals
-            let num_verts = norm_data.len();
-            let mut extended_properties = Vec::with_capacity(num_verts * 6);
-            
-            for i in 0..num_verts {
-                // Copy position data
-                extended_properties.push(mesh_gl.vert_properties[i * 3]);
-                extended_properties.push(mesh_gl.vert_properties[i * 3 + 1]);
-                extended_properties.push(mesh_gl.vert_properties[i * 3 + 2]);
-                
-                // Add normal data
-                let norm = norm_data[i];
-                extended_properties.push(norm[0]);
-                extended_properties.push(norm[1]);
-                extended_properties.push(norm[2]);
+
+    mesh_gl.num_prop = 3 + channels.iter().map(|(_, size)| size).sum::<usize>() as u32;
+
+    let mut run_index = vec![0u32];
+    for run in runs {
+        let Some(VertexAttributeValues::Float32x3(positions)) = run.mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            continue;
+        };
+        let vert_base = (mesh_gl.vert_properties.len() / mesh_gl.num_prop as usize) as u32;
+
+        for (i, pos) in positions.iter().enumerate() {
+            mesh_gl.vert_properties.push(pos[0]);
+            mesh_gl.vert_properties.push(pos[1]);
+            mesh_gl.vert_properties.push(pos[2]);
+            for (attribute, size) in &channels {
+                let raw = run
+                    .mesh
+                    .attribute(*attribute)
+                    .map(|values| read_sized_attribute(values, i))
+                    .unwrap_or([0.0; 4]);
+                for slot in raw.iter().take(*size) {
+                    mesh_gl.vert_properties.push(*slot);
+                }
             }
-            
-            mesh_gl.vert_properties = extended_properties;
-            mesh_gl.num_prop = 6; // Positions + normals
         }
+
+        let local_indices: Vec<u32> = match run.mesh.indices() {
+            Some(Indices::U32(idx)) => idx.clone(),
+            Some(Indices::U16(idx)) => idx.iter().map(|&i| i as u32).collect(),
+            None => Vec::new(),
+        };
+        mesh_gl.tri_verts.extend(local_indices.iter().map(|&i| i + vert_base));
+
+        run_index.push(mesh_gl.tri_verts.len() as u32);
+        mesh_gl.run_original_id.push(run.original_id);
+        mesh_gl.run_transform.extend_from_slice(&run.transform);
     }
-    
-    // Extract UVs if present
-    if let Some(uvs) = bevy_mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
-        let uv_data = uvs.as_float2();
-        if let Some(uv_data) = uv_data {
-            // Extend vertex properties to include UVs
-            let num_verts = uv_data.len();
-            let mut extended_properties = Vec::with_capacity(num_verts * 8);
-            
-            for i in 0..num_verts {
-                // Copy existing data (positions + normals)
-                if mesh_gl.num_prop >= 6 {
-                    extended_properties.push(mesh_gl.vert_properties[i * 6]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 6 + 1]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 6 + 2]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 6 + 3]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 6 + 4]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 6 + 5]);
-                } else {
-                    extended_properties.push(mesh_gl.vert_properties[i * 3]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 3 + 1]);
-                    extended_properties.push(mesh_gl.vert_properties[i * 3 + 2]);
-                    extended_properties.push(0.0); // Normal x
-                    extended_properties.push(0.0); // Normal y
-                    extended_properties.push(0.0); // Normal z
-                }
-                
-                // Add UV data
-                let uv = uv_data[i];
-                extended_properties.push(uv[0]);
-                extended_properties.push(uv[1]);
+    mesh_gl.run_index = run_index;
+
+    mesh_gl
+}
+
+fn attribute_size(values: &VertexAttributeValues) -> usize {
+    match values {
+        VertexAttributeValues::Float32(_) => 1,
+        VertexAttributeValues::Float32x2(_) => 2,
+        VertexAttributeValues::Float32x3(_) => 3,
+        _ => 4,
+    }
+}
+
+fn read_sized_attribute(values: &VertexAttributeValues, i: usize) -> [f32; 4] {
+    match values {
+        VertexAttributeValues::Float32(v) => [v[i], 0.0, 0.0, 0.0],
+        VertexAttributeValues::Float32x2(v) => [v[i][0], v[i][1], 0.0, 0.0],
+        VertexAttributeValues::Float32x3(v) => [v[i][0], v[i][1], v[i][2], 0.0],
+        VertexAttributeValues::Float32x4(v) => v[i],
+        _ => [0.0; 4],
+    }
+}
+
+/// Single-submesh convenience wrapper over [`meshgl_to_bevy_meshes`] for
+/// callers (and the demos below) that don't care about run splitting and
+/// just want one combined Bevy mesh, using the standard property layout.
+fn meshgl_to_bevy_mesh(mesh_gl: &meshbool::MeshGL) -> Mesh {
+    let layout = PropertyLayout::standard(mesh_gl.num_prop as usize);
+    let runs = meshgl_to_bevy_meshes(mesh_gl, &layout);
+    merge_meshes(&runs)
+}
+
+/// Inverse convenience wrapper over [`bevy_meshes_to_meshgl`] for a single
+/// already-combined Bevy mesh (no run metadata to recover).
+fn bevy_mesh_to_meshgl(bevy_mesh: &Mesh) -> meshbool::MeshGL {
+    let channels = standard_channels_present(bevy_mesh);
+    bevy_meshes_to_meshgl(&[MeshRun {
+        original_id: 0,
+        transform: IDENTITY_TRANSFORM,
+        mesh: bevy_mesh.clone(),
+        channels,
+    }])
+}
+
+/// Which of the standard attributes a plain, externally-constructed Bevy
+/// mesh actually carries — used when there's no [`MeshRun`] (and so no
+/// already-known channel list) to fall back on, i.e. a mesh that didn't
+/// come from [`meshgl_to_bevy_meshes`].
+fn standard_channels_present(mesh: &Mesh) -> Vec<(MeshVertexAttribute, usize)> {
+    [
+        (Mesh::ATTRIBUTE_NORMAL, 3),
+        (Mesh::ATTRIBUTE_UV_0, 2),
+        (Mesh::ATTRIBUTE_TANGENT, 4),
+        (Mesh::ATTRIBUTE_COLOR, 4),
+    ]
+    .into_iter()
+    .filter(|(attribute, _)| mesh.attribute(attribute.clone()).is_some())
+    .collect()
+}
+
+/// Concatenate several runs' submeshes sharing the same channel list into
+/// one mesh, offsetting each one's indices past the ones already appended.
+fn merge_meshes(runs: &[MeshRun]) -> Mesh {
+    let mut merged = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    let mut channels: Vec<(MeshVertexAttribute, usize)> = Vec::new();
+    for run in runs {
+        for &(attribute, size) in &run.channels {
+            if !channels.iter().any(|(a, _)| a.id == attribute.id) {
+                channels.push((attribute, size));
             }
-            
-            mesh_gl.vert_properties = extended_properties;
-            mesh_gl.num_prop = 8; // Positions + normals + UVs
         }
     }
-    
-    // Extract indices
-    if let Some(indices) = bevy_mesh.indices() {
-        match indices {
-            Indices::U32(idx) => mesh_gl.tri_verts = idx.to_vec(),
-            Indices::U16(idx) => {
-                mesh_gl.tri_verts = idx.iter().map(|&i| i as u32).collect();
+    let mut attribute_buffers: Vec<Vec<[f32; 4]>> = vec![Vec::new(); channels.len()];
+
+    for run in runs {
+        let Some(VertexAttributeValues::Float32x3(mesh_positions)) = run.mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+            continue;
+        };
+        let base = positions.len() as u32;
+        positions.extend(mesh_positions.iter().copied());
+
+        for (i, (attribute, _)) in channels.iter().enumerate() {
+            let values = run.mesh.attribute(attribute.clone());
+            for v in 0..mesh_positions.len() {
+                attribute_buffers[i].push(values.map(|values| read_sized_attribute(values, v)).unwrap_or([0.0; 4]));
             }
         }
+
+        let local_indices: Vec<u32> = match run.mesh.indices() {
+            Some(Indices::U32(idx)) => idx.clone(),
+            Some(Indices::U16(idx)) => idx.iter().map(|&i| i as u32).collect(),
+            None => Vec::new(),
+        };
+        indices.extend(local_indices.iter().map(|&i| i + base));
     }
-    
-    mesh_gl
+
+    merged.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    for ((attribute, size), values) in channels.into_iter().zip(attribute_buffers) {
+        insert_sized_attribute(&mut merged, attribute, size, values);
+    }
+    merged.insert_indices(Indices::U32(indices));
+    merged
 }
 
 /// Demonstrate round-trip conversion: meshbool -> Bevy -> meshbool
 fn demonstrate_round_trip_conversion() {
     println!("=== Round-Trip Conversion Demo ===");
-    
+
     // Create a cube using meshbool
     let our_cube = cube(Vector3::new(2.0, 2.0, 2.0), true);
     println!("1. Created cube with meshbool");
-    
+
     // Convert to MeshGL
     let mesh_gl = get_mesh_gl(&our_cube, 0);
-    println!("2. Converted to MeshGL: {} verts, {} tris", 
+    println!("2. Converted to MeshGL: {} verts, {} tris",
              mesh_gl.vert_properties.len() / mesh_gl.num_prop as usize,
              mesh_gl.tri_verts.len() / 3);
-    
+
     // Convert MeshGL to Bevy Mesh
     let bevy_mesh = meshgl_to_bevy_mesh(&mesh_gl);
-    println!("3. Converted to Bevy Mesh: {} positions, {} indices", 
+    println!("3. Converted to Bevy Mesh: {} positions, {} indices",
              bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION).map(|a| a.len()).unwrap_or(0),
              bevy_mesh.indices().map(|i| i.len()).unwrap_or(0));
-    
+
     // Convert Bevy Mesh back to MeshGL
     let converted_mesh_gl = bevy_mesh_to_meshgl(&bevy_mesh);
-    println!("4. Converted back to MeshGL: {} verts, {} tris", 
+    println!("4. Converted back to MeshGL: {} verts, {} tris",
              converted_mesh_gl.vert_properties.len() / converted_mesh_gl.num_prop as usize,
              converted_mesh_gl.tri_verts.len() / 3);
-    
+
     // Verify round-trip preservation
     let original_num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop as usize;
     let converted_num_verts = converted_mesh_gl.vert_properties.len() / converted_mesh_gl.num_prop as usize;
     let original_num_tris = mesh_gl.tri_verts.len() / 3;
     let converted_num_tris = converted_mesh_gl.tri_verts.len() / 3;
-    
+
     println!("5. Round-trip verification:");
     println!("   Original: {} verts, {} tris", original_num_verts, original_num_tris);
     println!("   Converted: {} verts, {} tris", converted_num_verts, converted_num_tris);
-    
+
     // For basic validation, check that we have reasonable numbers
     assert!(converted_num_verts > 0, "Converted mesh should have vertices");
     assert!(converted_num_tris > 0, "Converted mesh should have triangles");
-    
+
     println!("âœ… Round-trip conversion successful!");
 }
 
 /// Demonstrate mesh operations with conversions
 fn demonstrate_mesh_operations() {
     println!("\n=== Mesh Operations Demo ===");
-    
+
     // Create cubes using meshbool
     let cube1 = cube(Vector3::new(2.0, 2.0, 2.0), true);
     let cube2 = cube(Vector3::new(1.0, 1.0, 1.0), true);
     let translated_cube2 = translate(&cube2, nalgebra::Point3::new(1.0, 0.0, 0.0));
-    
+
     println!("1. Created two cubes and translated one");
-    
+
     // Perform boolean union operation
     let union_result = &cube1 + &translated_cube2;
     println!("2. Performed boolean union: {} tris", union_result.num_tri());
-    
+
     // Convert result to Bevy mesh
     let mesh_gl = get_mesh_gl(&union_result, 0);
     let bevy_mesh = meshgl_to_bevy_mesh(&mesh_gl);
-    println!("3. Converted to Bevy mesh: {} positions, {} indices", 
+    println!("3. Converted to Bevy mesh: {} positions, {} indices",
              bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION).map(|a| a.len()).unwrap_or(0),
              bevy_mesh.indices().map(|i| i.len()).unwrap_or(0));
-    
+
     // Perform boolean intersection operation
     let intersection_result = &cube1 ^ &translated_cube2;
     println!("4. Performed boolean intersection: {} tris", intersection_result.num_tri());
-    
+
     // Convert result to Bevy mesh
     let mesh_gl = get_mesh_gl(&intersection_result, 0);
     let bevy_mesh = meshgl_to_bevy_mesh(&mesh_gl);
-    println!("5. Converted to Bevy mesh: {} positions, {} indices", 
+    println!("5. Converted to Bevy mesh: {} positions, {} indices",
              bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION).map(|a| a.len()).unwrap_or(0),
              bevy_mesh.indices().map(|i| i.len()).unwrap_or(0));
-    
+
     // Perform boolean difference operation
     let difference_result = &cube1 - &translated_cube2;
     println!("6. Performed boolean difference: {} tris", difference_result.num_tri());
-    
+
     // Convert result to Bevy mesh
     let mesh_gl = get_mesh_gl(&difference_result, 0);
     let bevy_mesh = meshgl_to_bevy_mesh(&mesh_gl);
-    println!("7. Converted to Bevy mesh: {} positions, {} indices", 
+    println!("7. Converted to Bevy mesh: {} positions, {} indices",
              bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION).map(|a| a.len()).unwrap_or(0),
              bevy_mesh.indices().map(|i| i.len()).unwrap_or(0));
-    
+
     println!("âœ… All mesh operations successful!");
 }
 
 /// Demonstrate advanced MeshGL features for game development
 fn demonstrate_advanced_features() {
     println!("\n=== Advanced MeshGL Features Demo ===");
-    
+
     // Create a cube using meshbool
     let our_cube = cube(Vector3::new(2.0, 2.0, 2.0), true);
-    
+
     // Convert to MeshGL
     let mesh_gl = get_mesh_gl(&our_cube, 0);
-    
+
     println!("MeshGL advanced features:");
     println!("  num_prop: {}", mesh_gl.num_prop);
     println!("  vert_properties len: {}", mesh_gl.vert_properties.len());
@@ -278,25 +533,46 @@ fn demonstrate_advanced_features() {
     println!("  run_transform len: {}", mesh_gl.run_transform.len());
     println!("  face_id len: {}", mesh_gl.face_id.len());
     println!("  tolerance: {}", mesh_gl.tolerance);
-    
+
+    // Split into per-run submeshes to show multi-material round-tripping:
+    // each original solid that fed into the boolean op gets its own Bevy
+    // submesh and material id instead of one undifferentiated mesh.
+    let layout = PropertyLayout::standard(mesh_gl.num_prop as usize);
+    let runs = meshgl_to_bevy_meshes(&mesh_gl, &layout);
+    println!("  runs: {} (keyed by run_original_id/run_index)", runs.len());
+    for run in &runs {
+        println!(
+            "    original_id {}: {} positions",
+            run.original_id,
+            run.mesh.attribute(Mesh::ATTRIBUTE_POSITION).map(|a| a.len()).unwrap_or(0)
+        );
+    }
+    let rejoined = bevy_meshes_to_meshgl(&runs);
+    println!(
+        "  rejoined MeshGL: {} verts, {} tris, {} runs",
+        rejoined.vert_properties.len() / rejoined.num_prop as usize,
+        rejoined.tri_verts.len() / 3,
+        rejoined.run_original_id.len()
+    );
+
     // These rich metadata features make MeshGL ideal for game development:
     // 1. Instance tracking for efficient rendering
     // 2. Material ID mapping for proper shader selection
     // 3. Transform information for dynamic batching
     // 4. Face connectivity for polygon reconstruction
     // 5. Merge information for manifold preservation
-    
+
     println!("âœ… Advanced features demonstrated!");
 }
 
 fn main() {
     println!("Bevy 0.17.0 Integration Example for meshbool");
     println!("=============================================\n");
-    
+
     demonstrate_round_trip_conversion();
     demonstrate_mesh_operations();
     demonstrate_advanced_features();
-    
+
     println!("\nðŸŽ‰ All demonstrations completed successfully!");
     println!("\nMeshGL's rich metadata makes it perfect for game development because:");
     println!("1. âœ… GPU-ready data layout minimizes CPU-GPU transfer overhead");
@@ -306,7 +582,7 @@ fn main() {
     println!("5. âœ… Face connectivity preserves polygon information through operations");
     println!("6. âœ… Merge information maintains manifold properties");
     println!("7. âœ… Tolerance control ensures quality preservation");
-    
+
     println!("\nThe sophisticated MeshGL type provides an excellent foundation for a future MeshWGPU type");
     println!("that would leverage these features for optimal game mesh performance.");
-}
\ No newline at end of file
+}
@@ -0,0 +1,191 @@
+//! First-class Bevy plugin exposing CSG as ECS components
+//!
+//! `ops_demo` and `bevy_interactive_demo` both hand-roll a `DemoState`
+//! resource, manual keyboard dispatch, and manual `Assets<Mesh>` re-upload
+//! every time the operation changes. This example ships `MeshBoolPlugin`
+//! instead: a solid-modeling scene graph described as ECS data
+//! (`CsgPrimitive` leaves combined by `CsgOp` internal nodes), kept in sync
+//! by change-detection systems that only re-evaluate the dirty subtrees and
+//! write the result straight into each node's attached `Mesh3d`. That's the
+//! same declarative blueprint/prefab shape the Blender-Bevy components
+//! ecosystem uses — author the graph (by hand here, or from an external
+//! tool), then let the plugin keep the rendered meshes current.
+
+use bevy::prelude::*;
+use meshbool::cube;
+use nalgebra::Vector3;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "MeshBool CSG Plugin Demo".to_string(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(MeshBoolPlugin)
+        .add_systems(Startup, setup_scene)
+        .run();
+}
+
+/// Registers the CSG component types and the evaluation systems; add this
+/// to an `App` and the rest of the scene graph can be spawned as plain
+/// `CsgPrimitive`/`CsgOp` data.
+pub struct MeshBoolPlugin;
+
+impl Plugin for MeshBoolPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (evaluate_csg_primitives, evaluate_csg_ops).chain());
+    }
+}
+
+/// Which boolean operation a [`CsgOp`] combines its two operands with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+/// Which primitive constructor a [`CsgPrimitive`] leaf builds, with its
+/// numeric arguments carried in `CsgPrimitive::params` rather than as
+/// typed fields, so new shapes don't need a new component type.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PrimitiveShape {
+    Cube,
+}
+
+/// A leaf solid in the scene graph, authored as data instead of built
+/// imperatively in a setup system.
+#[derive(Component, Clone, Debug)]
+pub struct CsgPrimitive {
+    pub shape: PrimitiveShape,
+    pub params: Vec<f64>,
+}
+
+impl CsgPrimitive {
+    fn build(&self) -> meshbool::Impl {
+        match self.shape {
+            PrimitiveShape::Cube => {
+                assert!(self.params.len() >= 3, "CsgPrimitive::Cube requires 3 params: [size_x, size_y, size_z]");
+                cube(Vector3::new(self.params[0], self.params[1], self.params[2]), true)
+            }
+        }
+    }
+}
+
+/// An internal node: combine two entities' already-evaluated solids (each
+/// either a `CsgPrimitive` leaf or another `CsgOp`) with `op`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct CsgOp {
+    pub op: BooleanOp,
+    pub a: Entity,
+    pub b: Entity,
+}
+
+/// Cached result of evaluating this entity's `CsgPrimitive`/`CsgOp`, so a
+/// parent `CsgOp` can read an operand's solid without re-evaluating that
+/// operand's own subtree every frame.
+#[derive(Component, Clone)]
+struct CsgResult(meshbool::Impl);
+
+/// Rebuild every dirty `CsgPrimitive` leaf's solid and stash it as a
+/// [`CsgResult`] for [`evaluate_csg_ops`] to read back.
+fn evaluate_csg_primitives(mut commands: Commands, query: Query<(Entity, &CsgPrimitive), Changed<CsgPrimitive>>) {
+    for (entity, primitive) in &query {
+        commands.entity(entity).insert(CsgResult(primitive.build()));
+    }
+}
+
+/// Re-evaluate only the `CsgOp`s whose own definition changed or whose
+/// operand's `CsgResult` changed this frame, then write the combined solid
+/// into this entity's `Mesh3d` — the change-detection replacement for
+/// `ops_demo`'s `state.is_changed()` check plus manual re-upload.
+fn evaluate_csg_ops(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    ops: Query<(Entity, &CsgOp)>,
+    results: Query<&CsgResult>,
+    changed_ops: Query<Entity, Changed<CsgOp>>,
+    changed_results: Query<Entity, Changed<CsgResult>>,
+) {
+    for (entity, op) in &ops {
+        let dirty = changed_ops.contains(entity) || changed_results.contains(op.a) || changed_results.contains(op.b);
+        if !dirty {
+            continue;
+        }
+
+        let (Ok(a), Ok(b)) = (results.get(op.a), results.get(op.b)) else {
+            continue;
+        };
+
+        let combined = match op.op {
+            BooleanOp::Union => &a.0 + &b.0,
+            BooleanOp::Intersection => &a.0 ^ &b.0,
+            BooleanOp::Difference => &a.0 - &b.0,
+        };
+
+        let mesh_gl = meshbool::get_mesh_gl(&combined, 0);
+        let handle = meshes.add(meshgl_to_bevy_mesh(&mesh_gl));
+        commands.entity(entity).insert((CsgResult(combined), Mesh3d(handle)));
+    }
+}
+
+/// Minimal MeshGL -> Bevy Mesh conversion (position + normal, falling back
+/// to a fixed up-normal when `mesh_gl` doesn't carry one) — this example is
+/// about the ECS plugin shape, not conversion fidelity, so it keeps
+/// `ops_demo`'s same lightweight approach rather than the fuller
+/// `PropertyLayout`-driven one in `bevy_integration`.
+fn meshgl_to_bevy_mesh(mesh_gl: &meshbool::MeshGL) -> Mesh {
+    let mut bevy_mesh = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, default());
+
+    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop as usize;
+    let mut positions = Vec::with_capacity(num_verts);
+    let mut normals = Vec::with_capacity(num_verts);
+    for i in 0..num_verts {
+        let offset = i * mesh_gl.num_prop as usize;
+        positions.push([mesh_gl.vert_properties[offset], mesh_gl.vert_properties[offset + 1], mesh_gl.vert_properties[offset + 2]]);
+        if mesh_gl.num_prop >= 6 {
+            normals.push([mesh_gl.vert_properties[offset + 3], mesh_gl.vert_properties[offset + 4], mesh_gl.vert_properties[offset + 5]]);
+        } else {
+            normals.push([0.0, 1.0, 0.0]);
+        }
+    }
+
+    bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    bevy_mesh.insert_indices(bevy::render::mesh::Indices::U32(mesh_gl.tri_verts.clone()));
+    bevy_mesh
+}
+
+/// Spawn two cube leaves and a union op combining them, plus a camera and
+/// light — `MeshBoolPlugin`'s systems resolve the graph from here on their
+/// own; nothing here has to call into `meshbool` directly.
+fn setup_scene(mut commands: Commands, mut materials: ResMut<Assets<StandardMaterial>>) {
+    let a = commands
+        .spawn(CsgPrimitive { shape: PrimitiveShape::Cube, params: vec![2.0, 2.0, 2.0] })
+        .id();
+    let b = commands
+        .spawn((
+            CsgPrimitive { shape: PrimitiveShape::Cube, params: vec![1.0, 1.0, 1.0] },
+            Transform::from_xyz(1.0, 0.0, 0.0),
+        ))
+        .id();
+
+    commands.spawn((
+        CsgOp { op: BooleanOp::Union, a, b },
+        MeshMaterial3d(materials.add(Color::srgb(0.8, 0.5, 0.2))),
+        Transform::default(),
+    ));
+
+    commands.spawn((
+        PointLight { color: Color::WHITE, intensity: 2000.0, range: 25.0, shadows_enabled: true, ..default() },
+        Transform::from_xyz(5.0, 10.0, 5.0),
+    ));
+
+    commands.spawn((
+        Camera3d::default(),
+        Transform::from_xyz(0.0, 4.0, 8.0).looking_at(Vec3::ZERO, Vec3::Y),
+    ));
+}
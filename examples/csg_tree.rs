@@ -0,0 +1,217 @@
+//! Lazy CSG expression tree with memoized, incremental re-evaluation
+//!
+//! `ops_demo`'s `handle_input`/`update_output_mesh` pair rebuilds the whole
+//! result `Impl` from scratch every time the operation changes, even though
+//! only one operand actually moved. `CsgTree` instead keeps a tree of
+//! Union/Intersection/Difference/Transform nodes over `Impl` leaves, each
+//! positioned by a Bevy `Transform` (translation/rotation/scale) rather
+//! than having that transform pre-baked into its geometry. `.evaluate()`
+//! folds each node's affine into its mesh lazily, composing parent
+//! transforms down the tree, and memoizes every subtree's result against a
+//! cheap content version so moving one leaf only recomputes the boolean
+//! ops on the path from that leaf to the root — not the whole tree. The
+//! result is a reusable, animatable solid-modeling graph instead of a flat
+//! chain of operators re-run on every keypress.
+
+use bevy::prelude::*;
+use meshbool::{cube, cylinder, from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+use std::cell::{Cell, RefCell};
+use std::hash::{Hash, Hasher};
+
+fn main() {
+    println!("=== CSG Tree Incremental Evaluation Demo ===");
+
+    let base = CsgTree::leaf(cube(Vector3::new(2.0, 2.0, 2.0), true), Transform::IDENTITY);
+    let bit = CsgTree::leaf(cylinder(2.5, 0.5, 0.5, 32, true), Transform::from_xyz(1.0, 0.0, 0.0));
+    let tree = CsgTree::op(BooleanOp::Difference, base, bit);
+
+    let first = tree.evaluate();
+    println!("1. Initial evaluation: {} tris", first.num_tri());
+
+    let second = tree.evaluate();
+    println!("2. Re-evaluated with no changes (should reuse the cached result): {} tris", second.num_tri());
+
+    // Move just the cylinder operand; only the Difference node and that
+    // leaf need to recompute, not anything unrelated elsewhere in a larger
+    // tree.
+    tree.set_leaf_transform(1, Transform::from_xyz(1.4, 0.0, 0.0));
+    let third = tree.evaluate();
+    println!("3. Re-evaluated after moving one operand: {} tris", third.num_tri());
+}
+
+/// Which boolean operation a [`CsgKind::Op`] combines its two operands
+/// with.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BooleanOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+enum CsgKind {
+    /// A solid positioned by its own transform; `version` is bumped only
+    /// when `mesh` itself is replaced, since hashing a whole mesh on every
+    /// `.evaluate()` call would be as expensive as the work memoization is
+    /// meant to avoid.
+    Leaf { mesh: Impl, transform: RefCell<Transform>, version: Cell<u64> },
+    Op { op: BooleanOp, a: Box<CsgTree>, b: Box<CsgTree> },
+    /// Wraps `child` in an additional transform without combining it with
+    /// anything, so a whole already-combined subtree can still be
+    /// repositioned as one unit.
+    Transform { transform: RefCell<Transform>, child: Box<CsgTree> },
+}
+
+/// One node of a lazy CSG expression tree. See the module documentation for
+/// the memoization scheme.
+pub struct CsgTree {
+    kind: CsgKind,
+    cache: RefCell<Option<(u64, Impl)>>,
+}
+
+impl CsgTree {
+    pub fn leaf(mesh: Impl, transform: Transform) -> Self {
+        CsgTree {
+            kind: CsgKind::Leaf { mesh, transform: RefCell::new(transform), version: Cell::new(0) },
+            cache: RefCell::new(None),
+        }
+    }
+
+    pub fn op(op: BooleanOp, a: CsgTree, b: CsgTree) -> Self {
+        CsgTree { kind: CsgKind::Op { op, a: Box::new(a), b: Box::new(b) }, cache: RefCell::new(None) }
+    }
+
+    pub fn transform(transform: Transform, child: CsgTree) -> Self {
+        CsgTree {
+            kind: CsgKind::Transform { transform: RefCell::new(transform), child: Box::new(child) },
+            cache: RefCell::new(None),
+        }
+    }
+
+    /// Replace the transform on the leaf at `path` (a [`CsgTree::op`]
+    /// child index, 0 for `a` / anything else for `b`, used at the top op
+    /// only; every deeper op level descends into `a`) or on the first
+    /// [`CsgTree::transform`] node reached the same way.
+    /// Panics if `path` doesn't lead to a node with a transform — this is a
+    /// demo helper, not public API meant to be misused.
+    fn set_leaf_transform(&self, path: usize, new_transform: Transform) {
+        match &self.kind {
+            CsgKind::Leaf { transform, .. } | CsgKind::Transform { transform, .. } => {
+                *transform.borrow_mut() = new_transform;
+            }
+            CsgKind::Op { a, b, .. } => {
+                if path == 0 {
+                    a.set_leaf_transform(0, new_transform)
+                } else {
+                    b.set_leaf_transform(0, new_transform)
+                }
+            }
+        }
+    }
+
+    /// Cheap content version for this subtree: leaves carry an explicit
+    /// counter (bumped only when their mesh is replaced), while transform
+    /// nodes and op nodes derive theirs from their current field values and
+    /// their children's versions, so a version can be recomputed every call
+    /// without ever touching mesh geometry.
+    fn version(&self) -> u64 {
+        match &self.kind {
+            CsgKind::Leaf { transform, version, .. } => {
+                combine(&[version.get(), hash_transform(&transform.borrow())])
+            }
+            CsgKind::Op { op, a, b } => combine(&[*op as u64, a.version(), b.version()]),
+            CsgKind::Transform { transform, child } => combine(&[hash_transform(&transform.borrow()), child.version()]),
+        }
+    }
+
+    /// Resolve this subtree to a concrete `Impl`, reusing the cached result
+    /// if nothing this node depends on has changed since the last call.
+    pub fn evaluate(&self) -> Impl {
+        let v = self.version();
+        if let Some((cached_v, result)) = self.cache.borrow().as_ref() {
+            if *cached_v == v {
+                return result.clone();
+            }
+        }
+
+        let result = match &self.kind {
+            CsgKind::Leaf { mesh, transform, .. } => apply_transform(mesh, &transform.borrow()),
+            CsgKind::Op { op, a, b } => {
+                let ra = a.evaluate();
+                let rb = b.evaluate();
+                match op {
+                    BooleanOp::Union => &ra + &rb,
+                    BooleanOp::Intersection => &ra ^ &rb,
+                    BooleanOp::Difference => &ra - &rb,
+                }
+            }
+            CsgKind::Transform { transform, child } => apply_transform(&child.evaluate(), &transform.borrow()),
+        };
+
+        *self.cache.borrow_mut() = Some((v, result.clone()));
+        result
+    }
+}
+
+fn combine(values: &[u64]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    values.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_transform(transform: &Transform) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for component in transform.translation.to_array() {
+        component.to_bits().hash(&mut hasher);
+    }
+    for component in transform.rotation.to_array() {
+        component.to_bits().hash(&mut hasher);
+    }
+    for component in transform.scale.to_array() {
+        component.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Fold `transform` into `mesh`'s vertices (and, if present, its normal
+/// channel — rotated/scaled as a direction rather than a point) lazily, at
+/// evaluation time: an identity transform is the common case for an
+/// untouched leaf, so it skips the MeshGL round-trip entirely instead of
+/// reconstructing an identical mesh.
+fn apply_transform(mesh: &Impl, transform: &Transform) -> Impl {
+    if *transform == Transform::IDENTITY {
+        return mesh.clone();
+    }
+
+    let affine = transform.compute_affine();
+    let mut mesh_gl: MeshGL = get_mesh_gl(mesh, 0);
+    let num_prop = mesh_gl.num_prop as usize;
+    let has_normals = num_prop >= 6;
+
+    for v in 0..mesh_gl.vert_properties.len() / num_prop {
+        let base = v * num_prop;
+        let p = bevy::math::Vec3::new(
+            mesh_gl.vert_properties[base],
+            mesh_gl.vert_properties[base + 1],
+            mesh_gl.vert_properties[base + 2],
+        );
+        let transformed = affine.transform_point3(p);
+        mesh_gl.vert_properties[base] = transformed.x;
+        mesh_gl.vert_properties[base + 1] = transformed.y;
+        mesh_gl.vert_properties[base + 2] = transformed.z;
+
+        if has_normals {
+            let n = bevy::math::Vec3::new(
+                mesh_gl.vert_properties[base + 3],
+                mesh_gl.vert_properties[base + 4],
+                mesh_gl.vert_properties[base + 5],
+            );
+            let rotated = affine.transform_vector3(n).normalize_or_zero();
+            mesh_gl.vert_properties[base + 3] = rotated.x;
+            mesh_gl.vert_properties[base + 4] = rotated.y;
+            mesh_gl.vert_properties[base + 5] = rotated.z;
+        }
+    }
+
+    from_mesh_gl(mesh_gl)
+}
@@ -4,6 +4,7 @@
 //! by showing three shapes arranged like an equation: LHS op RHS = OUTPUT
 
 use bevy::{asset::RenderAssetUsages, prelude::*};
+use bevy::tasks::{futures_lite::future, AsyncComputeTaskPool, Task};
 use meshbool::{cube, cylinder, get_mesh_gl, translate};
 use nalgebra::Vector3;
 
@@ -20,7 +21,7 @@ fn main() {
         .insert_resource(RhsShape(cylinder(2.0, 1.0, 1.0, 32, true)))
         .add_systems(Startup, setup_scene)
         .add_systems(Update, handle_input)
-        .add_systems(Update, update_output_mesh)
+        .add_systems(Update, (dispatch_output_mesh, poll_output_mesh))
         .run();
 }
 
@@ -281,32 +282,64 @@ fn meshgl_to_bevy_mesh(mesh_gl: &meshbool::MeshGL) -> Mesh {
     bevy_mesh
 }
 
-/// System to update the output mesh based on current operation
-fn update_output_mesh(
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut query: Query<&mut Mesh3d, With<OutputShapeMarker>>,
+/// Holds the in-flight boolean-op + mesh-conversion work for the output
+/// shape, so it can run on [`AsyncComputeTaskPool`] instead of blocking the
+/// `Update` schedule — `lhs + rhs`/`lhs ^ rhs`/`lhs - rhs` and
+/// `meshgl_to_bevy_mesh`'s per-vertex loop are pure CPU work with no need to
+/// run on the main thread.
+#[derive(Component)]
+struct OutputMeshTask(Task<(Mesh, &'static str)>);
+
+/// Spawns (or respawns) the background boolean-op + mesh-conversion task
+/// whenever `state` changes. `lhs`/`rhs` are cloned onto the task up front
+/// since the task outlives this system's borrow of the resources; an
+/// already in-flight task is dropped first, so a fast operation switch
+/// cancels the stale computation rather than racing it against the new one.
+fn dispatch_output_mesh(
+    mut commands: Commands,
+    query: Query<(Entity, Option<&OutputMeshTask>), With<OutputShapeMarker>>,
     state: Res<DemoState>,
     lhs_shape_resource: Res<LhsShape>,
     rhs_shape_resource: Res<RhsShape>,
 ) {
-    // Only update if state has changed
-    if state.is_changed() {
-        let lhs = &lhs_shape_resource.0;
-        let rhs = &rhs_shape_resource.0;
-
-        let output_shape = match state.current_operation {
-            OperationType::ViewOriginal => lhs.clone(),
-            OperationType::BooleanUnion => lhs + rhs,
-            OperationType::BooleanIntersection => lhs ^ rhs,
-            OperationType::BooleanDifference => lhs - rhs,
+    if !state.is_changed() {
+        return;
+    }
+
+    let Ok((entity, in_flight)) = query.single() else { return };
+    if in_flight.is_some() {
+        commands.entity(entity).remove::<OutputMeshTask>();
+    }
+
+    let lhs = lhs_shape_resource.0.clone();
+    let rhs = rhs_shape_resource.0.clone();
+    let operation = state.current_operation;
+    let task = AsyncComputeTaskPool::get().spawn(async move {
+        let output_shape = match operation {
+            OperationType::ViewOriginal => lhs,
+            OperationType::BooleanUnion => &lhs + &rhs,
+            OperationType::BooleanIntersection => &lhs ^ &rhs,
+            OperationType::BooleanDifference => &lhs - &rhs,
         };
+        let output_mesh_gl = get_mesh_gl(&output_shape, 0);
+        (meshgl_to_bevy_mesh(&output_mesh_gl), operation.name())
+    });
+    commands.entity(entity).insert(OutputMeshTask(task));
+}
 
-        if let Ok(mut mesh_handle) = query.single_mut() {
-            let output_mesh_gl = get_mesh_gl(&output_shape, 0);
-            let bevy_mesh = meshgl_to_bevy_mesh(&output_mesh_gl);
-            let new_mesh_handle = meshes.add(bevy_mesh);
-            *mesh_handle = Mesh3d(new_mesh_handle);
-            println!("🔄 Updated output mesh with operation: {}", state.current_operation.name());
-        }
+/// Polls [`OutputMeshTask`] each frame and, once the background computation
+/// finishes, swaps the finished mesh into [`Mesh3d`] and removes the task
+/// component.
+fn poll_output_mesh(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(Entity, &mut Mesh3d, &mut OutputMeshTask)>,
+) {
+    for (entity, mut mesh_handle, mut task) in &mut query {
+        let Some((output_bevy_mesh, operation_name)) = future::block_on(future::poll_once(&mut task.0)) else { continue };
+        let new_mesh_handle = meshes.add(output_bevy_mesh);
+        *mesh_handle = Mesh3d(new_mesh_handle);
+        commands.entity(entity).remove::<OutputMeshTask>();
+        println!("🔄 Updated output mesh with operation: {}", operation_name);
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::{color::palettes::tailwind::*, prelude::*, render::primitives::Aabb};
 #[allow(unused_imports)]
 use bevy_panorbit_camera::{PanOrbitCamera, PanOrbitCameraPlugin};
 
@@ -41,17 +41,25 @@ impl ManifoldPlugin {
         materials: &mut ResMut<Assets<StandardMaterial>>,
         manifold: manifold_rs::Manifold,
     ) {
+        let (bbox_min, bbox_max) = manifold.bounding_box();
         let manifold_mesh_handle: Handle<Mesh> = meshes.add(Self::manifold_to_bevy_mesh(manifold));
 
         let white_matl = materials.add(Color::srgb(0.5, 0.5, 0.5));
         let hover_matl = materials.add(Color::from(CYAN_300));
         let pressed_matl = materials.add(Color::from(YELLOW_300));
 
-        // Insert mesh
+        let aabb = Aabb::from_min_max(
+            Vec3::new(bbox_min[0] as f32, bbox_min[1] as f32, bbox_min[2] as f32),
+            Vec3::new(bbox_max[0] as f32, bbox_max[1] as f32, bbox_max[2] as f32),
+        );
+
+        // Insert mesh, handing the renderer a precomputed tight Aabb so
+        // frustum culling doesn't need to recompute bounds from vertex data.
         commands
             .spawn((
                 Mesh3d(manifold_mesh_handle),
                 MeshMaterial3d(white_matl.clone()),
+                aabb,
             ))
             .observe(Self::update_material_on::<Pointer<Over>>(
                 hover_matl.clone(),
@@ -84,8 +92,28 @@ impl ManifoldPlugin {
         }
     }
 
+    /// Convert a tagged manifold to a Bevy mesh, forwarding its `"color"`
+    /// property channel (if any) into `ATTRIBUTE_COLOR` for per-region
+    /// shading (e.g. labeling which operand a triangle came from).
+    #[allow(dead_code)]
+    fn tagged_manifold_to_bevy_mesh(tagged: &manifold_rs::properties::TaggedManifold) -> Mesh {
+        let mut mesh = Self::manifold_to_bevy_mesh_ref(tagged.manifold());
+        if let Some(colors) = tagged.channel_values("color") {
+            let colors: Vec<[f32; 4]> = colors
+                .chunks(4)
+                .map(|c| -> [f32; 4] { c.try_into().expect("color channel must be RGBA") })
+                .collect();
+            mesh = mesh.with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        }
+        mesh
+    }
+
     /// Convert Manifold to bevy mesh
     fn manifold_to_bevy_mesh(manifold: manifold_rs::Manifold) -> Mesh {
+        Self::manifold_to_bevy_mesh_ref(&manifold)
+    }
+
+    fn manifold_to_bevy_mesh_ref(manifold: &manifold_rs::Manifold) -> Mesh {
         let mesh = manifold.to_mesh();
 
         let vertices = mesh.vertices();
@@ -130,6 +158,56 @@ impl ManifoldPlugin {
                 .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
                 .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
             }
+            // Vertex with position + UV
+            5 => {
+                let uvs = vertices
+                    .chunks(5)
+                    .map(|c| -> [f32; 2] { [c[3], c[4]] })
+                    .collect::<Vec<[f32; 2]>>();
+
+                let vertices = vertices
+                    .chunks(5)
+                    .map(|c| -> [f32; 3] { [c[0], c[1], c[2]] })
+                    .collect::<Vec<[f32; 3]>>();
+
+                Mesh::new(
+                    bevy::render::mesh::PrimitiveTopology::TriangleList,
+                    bevy::asset::RenderAssetUsages::MAIN_WORLD
+                        | bevy::asset::RenderAssetUsages::RENDER_WORLD,
+                )
+                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+                .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+                .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+                .with_duplicated_vertices()
+                .with_computed_flat_normals()
+            }
+            // Vertex with position + normal + UV
+            8 => {
+                let normals = vertices
+                    .chunks(8)
+                    .map(|c| -> [f32; 3] { [c[3], c[4], c[5]] })
+                    .collect::<Vec<[f32; 3]>>();
+
+                let uvs = vertices
+                    .chunks(8)
+                    .map(|c| -> [f32; 2] { [c[6], c[7]] })
+                    .collect::<Vec<[f32; 2]>>();
+
+                let vertices = vertices
+                    .chunks(8)
+                    .map(|c| -> [f32; 3] { [c[0], c[1], c[2]] })
+                    .collect::<Vec<[f32; 3]>>();
+
+                Mesh::new(
+                    bevy::render::mesh::PrimitiveTopology::TriangleList,
+                    bevy::asset::RenderAssetUsages::MAIN_WORLD
+                        | bevy::asset::RenderAssetUsages::RENDER_WORLD,
+                )
+                .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, vertices)
+                .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+                .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+                .with_inserted_indices(bevy::render::mesh::Indices::U32(indices))
+            }
             num_props => panic!("Invalid property count {num_props}"),
         }
     }
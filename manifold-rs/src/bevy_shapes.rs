@@ -0,0 +1,178 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! `ToManifold` conversions from Bevy's analytic math primitives, so callers
+//! can build CSG trees directly out of `Cuboid`/`Sphere`/etc. instead of
+//! first tessellating them into a Bevy `Mesh` and re-deriving topology from
+//! an already-triangulated mesh.
+//!
+//! Gated behind the `bevy` feature since it's the only part of this crate
+//! that depends on `bevy_math`.
+
+use crate::ops;
+use crate::{Manifold, Mesh};
+use bevy_math::primitives::{Capsule3d, Cone, Cuboid, Cylinder, Sphere, Torus};
+
+/// Default tessellation resolution for primitives converted via
+/// [`ToManifold::to_manifold`]. Use [`ToManifold::to_manifold_with_resolution`]
+/// to control it explicitly.
+const DEFAULT_RESOLUTION: u32 = 32;
+
+/// Converts a Bevy math primitive into a watertight [`Manifold`].
+pub trait ToManifold {
+    /// Convert using [`DEFAULT_RESOLUTION`] segments/subdivisions.
+    fn to_manifold(&self) -> Manifold {
+        self.to_manifold_with_resolution(DEFAULT_RESOLUTION)
+    }
+
+    /// Convert, tessellating curved surfaces with `resolution` segments.
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold;
+}
+
+impl ToManifold for Cuboid {
+    fn to_manifold_with_resolution(&self, _resolution: u32) -> Manifold {
+        let size = self.half_size * 2.0;
+        Manifold::cube_primitive([size.x as f64, size.y as f64, size.z as f64], true)
+    }
+}
+
+impl ToManifold for Sphere {
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold {
+        Manifold::sphere(self.radius as f64, resolution)
+    }
+}
+
+impl ToManifold for Cylinder {
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold {
+        Manifold::cylinder(
+            self.radius as f64,
+            self.radius as f64,
+            (self.half_height * 2.0) as f64,
+            resolution,
+        )
+        .translate(0.0, 0.0, -self.half_height as f64)
+    }
+}
+
+impl ToManifold for Cone {
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold {
+        Manifold::cylinder(self.radius as f64, 0.0, self.height as f64, resolution)
+            .translate(0.0, 0.0, -(self.height as f64) / 2.0)
+    }
+}
+
+impl ToManifold for Capsule3d {
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold {
+        let radius = self.radius as f64;
+        let half_length = self.half_length as f64;
+        let hemisphere_rings = (resolution / 4).max(2);
+
+        // One stack of latitude rings from the south pole, up through the
+        // lower hemisphere, the cylindrical waist, the upper hemisphere, to
+        // the north pole. Each ring is either a single shared pole vertex or
+        // `resolution` vertices; `ring_starts` records where each begins.
+        let mut positions: Vec<[f64; 3]> = Vec::new();
+        let mut ring_starts: Vec<u32> = Vec::new();
+        let mut ring_counts: Vec<u32> = Vec::new();
+
+        let mut push_ring = |phi: f64, z_offset: f64, positions: &mut Vec<[f64; 3]>| {
+            ring_starts.push(positions.len() as u32);
+            if phi.abs() >= std::f64::consts::FRAC_PI_2 - 1e-12 {
+                positions.push([0.0, 0.0, z_offset + phi.signum() * radius]);
+                ring_counts.push(1);
+                return;
+            }
+            let ring_radius = radius * ops::cos(phi);
+            let z = z_offset + radius * ops::sin(phi);
+            for seg in 0..resolution {
+                let theta = 2.0 * std::f64::consts::PI * seg as f64 / resolution as f64;
+                positions.push([ring_radius * ops::cos(theta), ring_radius * ops::sin(theta), z]);
+            }
+            ring_counts.push(resolution);
+        };
+
+        for ring in 0..=hemisphere_rings {
+            let t = ring as f64 / hemisphere_rings as f64;
+            push_ring(std::f64::consts::FRAC_PI_2 * (t - 1.0), -half_length, &mut positions);
+        }
+        for ring in 0..=hemisphere_rings {
+            let t = ring as f64 / hemisphere_rings as f64;
+            push_ring(std::f64::consts::FRAC_PI_2 * t, half_length, &mut positions);
+        }
+
+        let mut indices: Vec<u32> = Vec::new();
+        for ring in 0..ring_starts.len() - 1 {
+            let (a_start, a_count) = (ring_starts[ring], ring_counts[ring]);
+            let (b_start, b_count) = (ring_starts[ring + 1], ring_counts[ring + 1]);
+
+            for seg in 0..resolution {
+                let a0 = a_start + if a_count == 1 { 0 } else { seg };
+                let a1 = a_start + if a_count == 1 { 0 } else { (seg + 1) % resolution };
+                let b0 = b_start + if b_count == 1 { 0 } else { seg };
+                let b1 = b_start + if b_count == 1 { 0 } else { (seg + 1) % resolution };
+
+                if a_count == 1 {
+                    indices.extend_from_slice(&[a0, b0, b1]);
+                } else if b_count == 1 {
+                    indices.extend_from_slice(&[a0, a1, b0]);
+                } else {
+                    indices.extend_from_slice(&[a0, a1, b0, a1, b1, b0]);
+                }
+            }
+        }
+
+        let vertices: Vec<f32> = positions
+            .iter()
+            .flat_map(|p| [p[0] as f32, p[1] as f32, p[2] as f32])
+            .collect();
+
+        Mesh::new(&vertices, &indices).to_manifold()
+    }
+}
+
+impl ToManifold for Torus {
+    fn to_manifold_with_resolution(&self, resolution: u32) -> Manifold {
+        let major = self.major_radius as f64;
+        let minor = self.minor_radius as f64;
+        let major_segments = resolution;
+        let minor_segments = resolution;
+
+        let mut positions: Vec<[f64; 3]> = Vec::new();
+        for i in 0..major_segments {
+            let theta = 2.0 * std::f64::consts::PI * i as f64 / major_segments as f64;
+            for j in 0..minor_segments {
+                let phi = 2.0 * std::f64::consts::PI * j as f64 / minor_segments as f64;
+                let ring_radius = major + minor * ops::cos(phi);
+                positions.push([
+                    ring_radius * ops::cos(theta),
+                    ring_radius * ops::sin(theta),
+                    minor * ops::sin(phi),
+                ]);
+            }
+        }
+
+        let idx = |i: u32, j: u32| -> u32 { i * minor_segments + (j % minor_segments) };
+        let mut indices = Vec::new();
+        for i in 0..major_segments {
+            let i_next = (i + 1) % major_segments;
+            for j in 0..minor_segments {
+                let j_next = j + 1;
+                indices.extend_from_slice(&[
+                    idx(i, j),
+                    idx(i_next, j),
+                    idx(i_next, j_next),
+                    idx(i, j),
+                    idx(i_next, j_next),
+                    idx(i, j_next),
+                ]);
+            }
+        }
+
+        let vertices: Vec<f32> = positions
+            .iter()
+            .flat_map(|p| [p[0] as f32, p[1] as f32, p[2] as f32])
+            .collect();
+
+        Mesh::new(&vertices, &indices).to_manifold()
+    }
+}
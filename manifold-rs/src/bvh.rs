@@ -0,0 +1,372 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Bounding-volume hierarchy over a mesh's triangles, used to find candidate
+//! intersecting triangle pairs between two operands in roughly `O(n log n)`
+//! instead of the `O(n * m)` brute-force scan.
+
+use crate::Mesh;
+
+/// Axis-aligned bounding box.
+#[derive(Clone, Copy, Debug)]
+struct Aabb {
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+impl Aabb {
+    fn of_triangle(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> Self {
+        let mut min = a;
+        let mut max = a;
+        for p in [b, c] {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+        }
+        Self { min, max }
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        let mut min = self.min;
+        let mut max = self.max;
+        for i in 0..3 {
+            min[i] = min[i].min(other.min[i]);
+            max[i] = max[i].max(other.max[i]);
+        }
+        Self { min, max }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        (0..3).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+
+    fn centroid(&self) -> [f32; 3] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+            (self.min[2] + self.max[2]) * 0.5,
+        ]
+    }
+
+    fn volume(&self) -> f32 {
+        (self.max[0] - self.min[0]).max(0.0)
+            * (self.max[1] - self.min[1]).max(0.0)
+            * (self.max[2] - self.min[2]).max(0.0)
+    }
+
+    fn longest_axis(&self) -> usize {
+        let extent = [
+            self.max[0] - self.min[0],
+            self.max[1] - self.min[1],
+            self.max[2] - self.min[2],
+        ];
+        if extent[0] >= extent[1] && extent[0] >= extent[2] {
+            0
+        } else if extent[1] >= extent[2] {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Slab test against a ray, returning the entry distance (clamped to 0
+    /// for an origin already inside the box) or `None` if the ray misses
+    /// the box or only exits behind the origin.
+    fn ray_intersect(&self, origin: [f32; 3], inv_dir: [f32; 3]) -> Option<f32> {
+        let mut t_min = f32::NEG_INFINITY;
+        let mut t_max = f32::INFINITY;
+
+        for axis in 0..3 {
+            let t0 = (self.min[axis] - origin[axis]) * inv_dir[axis];
+            let t1 = (self.max[axis] - origin[axis]) * inv_dir[axis];
+            let (t0, t1) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_min > t_max {
+                return None;
+            }
+        }
+
+        if t_max < 0.0 {
+            None
+        } else {
+            Some(t_min.max(0.0))
+        }
+    }
+}
+
+/// Leaf triangle batch size below which a node stops splitting.
+const LEAF_SIZE: usize = 4;
+
+enum Node {
+    Leaf { bbox: Aabb, tris: Vec<u32> },
+    Branch { bbox: Aabb, left: Box<Node>, right: Box<Node> },
+}
+
+impl Node {
+    fn bbox(&self) -> &Aabb {
+        match self {
+            Node::Leaf { bbox, .. } => bbox,
+            Node::Branch { bbox, .. } => bbox,
+        }
+    }
+
+    /// The two children of a `Branch` node. Panics on a `Leaf`, since callers
+    /// only reach for this after already matching on `Branch`.
+    fn children(&self) -> (&Node, &Node) {
+        match self {
+            Node::Branch { left, right, .. } => (left, right),
+            Node::Leaf { .. } => unreachable!("children() called on a leaf node"),
+        }
+    }
+}
+
+/// A BVH built over one mesh's triangles, for broad-phase intersection tests.
+pub struct TriangleBvh {
+    root: Node,
+}
+
+impl TriangleBvh {
+    /// Build a BVH over `mesh`'s triangles.
+    pub fn build(mesh: &Mesh) -> Self {
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        let num_props = mesh.num_props() as usize;
+
+        let pos = |i: u32| -> [f32; 3] {
+            let base = i as usize * num_props;
+            [vertices[base], vertices[base + 1], vertices[base + 2]]
+        };
+
+        let mut tri_boxes: Vec<(u32, Aabb)> = indices
+            .chunks_exact(3)
+            .enumerate()
+            .map(|(tri_idx, tri)| {
+                let bbox = Aabb::of_triangle(pos(tri[0]), pos(tri[1]), pos(tri[2]));
+                (tri_idx as u32, bbox)
+            })
+            .collect();
+
+        Self {
+            root: Self::build_node(&mut tri_boxes),
+        }
+    }
+
+    fn build_node(tri_boxes: &mut [(u32, Aabb)]) -> Node {
+        let bbox = tri_boxes
+            .iter()
+            .map(|(_, b)| *b)
+            .reduce(|a, b| a.union(&b))
+            .expect("build_node called with no triangles");
+
+        if tri_boxes.len() <= LEAF_SIZE {
+            return Node::Leaf {
+                bbox,
+                tris: tri_boxes.iter().map(|(i, _)| *i).collect(),
+            };
+        }
+
+        // Median-of-centroids split along the node's longest axis. Ties in
+        // centroid ordering still land in exactly one half since
+        // `select_nth_unstable_by` partitions deterministically.
+        let axis = bbox.longest_axis();
+        let mid = tri_boxes.len() / 2;
+        tri_boxes.select_nth_unstable_by(mid, |(_, a), (_, b)| {
+            a.centroid()[axis]
+                .partial_cmp(&b.centroid()[axis])
+                .expect("triangle centroid coordinate must not be NaN")
+        });
+
+        let (left_half, right_half) = tri_boxes.split_at_mut(mid);
+        let left = Self::build_node(left_half);
+        let right = Self::build_node(right_half);
+
+        Node::Branch {
+            bbox,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn bbox(&self) -> &Aabb {
+        self.root.bbox()
+    }
+
+    /// The world-space bounding box `(min, max)` enclosing every triangle in
+    /// this BVH — the triangle-soup-side counterpart to `Manifold::bounding_box`.
+    pub fn bounding_box(&self) -> ([f32; 3], [f32; 3]) {
+        let bbox = self.bbox();
+        (bbox.min, bbox.max)
+    }
+
+    /// Find candidate intersecting triangle pairs `(tri_in_self, tri_in_other)`
+    /// between this BVH and `other`, traversing both trees simultaneously and
+    /// pruning a subtree the instant its box is disjoint from the other
+    /// side's.
+    pub fn collide(&self, other: &Self) -> Vec<(u32, u32)> {
+        let mut out = Vec::new();
+        traverse_pair(&self.root, &other.root, &mut out);
+        out
+    }
+
+    /// Walk the BVH front-to-back along `origin`/`dir`, calling `visit` with
+    /// each candidate triangle's index. `visit` runs the caller's precise
+    /// per-triangle test (e.g. Möller–Trumbore) and returns the hit distance
+    /// on a hit, or `None` on a miss; the nearest hit distance seen so far is
+    /// tracked here and used to prune any subtree whose slab-test entry
+    /// distance is already farther away than it, so triangles that can't
+    /// possibly beat the current best are never visited.
+    pub fn raycast(&self, origin: [f32; 3], dir: [f32; 3], mut visit: impl FnMut(u32) -> Option<f32>) -> Option<f32> {
+        let inv_dir = [1.0 / dir[0], 1.0 / dir[1], 1.0 / dir[2]];
+        let mut best: Option<f32> = None;
+        traverse_ray(&self.root, origin, inv_dir, &mut best, &mut visit);
+        best
+    }
+
+    /// Every leaf triangle whose bounding box the ray intersects, in no
+    /// particular order and without the nearest-hit pruning [`Self::raycast`]
+    /// applies — a candidate superset for a caller that needs every
+    /// crossing along the ray (e.g. [`crate::Manifold::contains`]'s parity
+    /// count) rather than just the closest one.
+    pub fn ray_candidates(&self, origin: [f32; 3], dir: [f32; 3]) -> Vec<u32> {
+        let inv_dir = [1.0 / dir[0], 1.0 / dir[1], 1.0 / dir[2]];
+        let mut out = Vec::new();
+        collect_ray_candidates(&self.root, origin, inv_dir, &mut out);
+        out
+    }
+}
+
+/// Find candidate intersecting triangle pairs `(tri_in_a, tri_in_b)` between
+/// two meshes. When `use_bvh` is true, both meshes' triangles are organized
+/// into a [`TriangleBvh`] and traversed in tandem, recursing only into
+/// child-pairs whose bounding boxes overlap; when false, every triangle pair
+/// whose AABBs overlap is emitted via a brute-force `O(n * m)` scan. The BVH
+/// mode returns a superset of the brute-force pairs, in a different order:
+/// it emits every triangle pair of two overlapping leaves (up to
+/// `LEAF_SIZE` triangles each) without testing the triangles' own boxes.
+pub fn candidate_triangle_pairs(a: &Mesh, b: &Mesh, use_bvh: bool) -> Vec<(u32, u32)> {
+    if use_bvh {
+        TriangleBvh::build(a).collide(&TriangleBvh::build(b))
+    } else {
+        brute_force_pairs(a, b)
+    }
+}
+
+fn traverse_pair(a: &Node, b: &Node, out: &mut Vec<(u32, u32)>) {
+    if !a.bbox().overlaps(b.bbox()) {
+        return;
+    }
+
+    match (a, b) {
+        (Node::Leaf { tris: tris_a, .. }, Node::Leaf { tris: tris_b, .. }) => {
+            for &ta in tris_a {
+                for &tb in tris_b {
+                    out.push((ta, tb));
+                }
+            }
+        }
+        (Node::Leaf { .. }, Node::Branch { .. }) => {
+            let (left, right) = b.children();
+            traverse_pair(a, left, out);
+            traverse_pair(a, right, out);
+        }
+        (Node::Branch { .. }, Node::Leaf { .. }) => {
+            let (left, right) = a.children();
+            traverse_pair(left, b, out);
+            traverse_pair(right, b, out);
+        }
+        (Node::Branch { .. }, Node::Branch { .. }) => {
+            // Descend whichever side currently has the larger bounding
+            // volume first, so the coarsest available split gets applied
+            // (and its non-overlapping half pruned) before refining the
+            // smaller side.
+            if a.bbox().volume() >= b.bbox().volume() {
+                let (left, right) = a.children();
+                traverse_pair(left, b, out);
+                traverse_pair(right, b, out);
+            } else {
+                let (left, right) = b.children();
+                traverse_pair(a, left, out);
+                traverse_pair(a, right, out);
+            }
+        }
+    }
+}
+
+fn traverse_ray(node: &Node, origin: [f32; 3], inv_dir: [f32; 3], best: &mut Option<f32>, visit: &mut impl FnMut(u32) -> Option<f32>) {
+    let Some(entry) = node.bbox().ray_intersect(origin, inv_dir) else { return };
+    if best.map_or(false, |b| entry > b) {
+        return;
+    }
+
+    match node {
+        Node::Leaf { tris, .. } => {
+            for &tri in tris {
+                if let Some(t) = visit(tri) {
+                    if best.map_or(true, |b| t < b) {
+                        *best = Some(t);
+                    }
+                }
+            }
+        }
+        Node::Branch { .. } => {
+            let (left, right) = node.children();
+            let left_entry = left.bbox().ray_intersect(origin, inv_dir);
+            let right_entry = right.bbox().ray_intersect(origin, inv_dir);
+
+            // Descend whichever child the ray reaches first, so a hit found
+            // there can prune the farther child before it's even visited.
+            let (first, second) = match (left_entry, right_entry) {
+                (Some(l), Some(r)) if r < l => (right, left),
+                _ => (left, right),
+            };
+            traverse_ray(first, origin, inv_dir, best, visit);
+            traverse_ray(second, origin, inv_dir, best, visit);
+        }
+    }
+}
+
+fn collect_ray_candidates(node: &Node, origin: [f32; 3], inv_dir: [f32; 3], out: &mut Vec<u32>) {
+    if node.bbox().ray_intersect(origin, inv_dir).is_none() {
+        return;
+    }
+
+    match node {
+        Node::Leaf { tris, .. } => out.extend_from_slice(tris),
+        Node::Branch { .. } => {
+            let (left, right) = node.children();
+            collect_ray_candidates(left, origin, inv_dir, out);
+            collect_ray_candidates(right, origin, inv_dir, out);
+        }
+    }
+}
+
+fn brute_force_pairs(a: &Mesh, b: &Mesh) -> Vec<(u32, u32)> {
+    let boxes_of = |mesh: &Mesh| -> Vec<Aabb> {
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        let num_props = mesh.num_props() as usize;
+        let pos = |i: u32| -> [f32; 3] {
+            let base = i as usize * num_props;
+            [vertices[base], vertices[base + 1], vertices[base + 2]]
+        };
+        indices
+            .chunks_exact(3)
+            .map(|tri| Aabb::of_triangle(pos(tri[0]), pos(tri[1]), pos(tri[2])))
+            .collect()
+    };
+
+    let boxes_a = boxes_of(a);
+    let boxes_b = boxes_of(b);
+
+    let mut out = Vec::new();
+    for (ia, box_a) in boxes_a.iter().enumerate() {
+        for (ib, box_b) in boxes_b.iter().enumerate() {
+            if box_a.overlaps(box_b) {
+                out.push((ia as u32, ib as u32));
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,86 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! A lazily-evaluated CSG expression tree: leaves wrap a [`Manifold`],
+//! internal nodes hold a [`BooleanOp`] and two children. [`CsgNode::evaluate`]
+//! walks the tree once and caches the result at every `Op` node, so a later
+//! evaluation reuses every cache still set. [`CsgNode::invalidate`] clears a
+//! node's cache together with those of all its descendants.
+
+use crate::{BooleanOp, Manifold};
+
+/// A node in a CSG expression tree.
+pub enum CsgNode {
+    /// A leaf manifold, e.g. one of the primitive conversions. Leaves have
+    /// nothing to cache: the manifold itself is already the evaluated value.
+    Leaf(Manifold),
+    /// An operation over two subtrees, memoizing the combined result until
+    /// [`CsgNode::invalidate`] clears it.
+    Op {
+        op: BooleanOp,
+        left: Box<CsgNode>,
+        right: Box<CsgNode>,
+        cache: Option<Manifold>,
+    },
+}
+
+impl CsgNode {
+    /// Wrap a manifold as a leaf node.
+    pub fn leaf(manifold: Manifold) -> Self {
+        CsgNode::Leaf(manifold)
+    }
+
+    /// Combine two subtrees under `op`.
+    pub fn op(op: BooleanOp, left: CsgNode, right: CsgNode) -> Self {
+        CsgNode::Op {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+            cache: None,
+        }
+    }
+
+    /// Evaluate the tree, reusing any still-valid cached results. Call
+    /// [`CsgNode::set_leaf`] or [`CsgNode::invalidate`] before re-evaluating
+    /// after a leaf's manifold changes.
+    pub fn evaluate(&mut self) -> &Manifold {
+        match self {
+            CsgNode::Leaf(manifold) => manifold,
+            CsgNode::Op { op, left, right, cache } => cache.get_or_insert_with(|| {
+                let l = left.evaluate();
+                let r = right.evaluate();
+                l.boolean_op(r, clone_op(op))
+            }),
+        }
+    }
+
+    /// Replace a leaf's manifold in place. The caller must still clear the
+    /// cache of every ancestor `Op` node (e.g. [`CsgNode::invalidate`] on the
+    /// root, which clears the whole tree) before the next
+    /// [`CsgNode::evaluate`], since a leaf has no cache of its own to
+    /// invalidate.
+    pub fn set_leaf(&mut self, manifold: Manifold) {
+        if let CsgNode::Leaf(slot) = self {
+            *slot = manifold;
+        }
+    }
+
+    /// Clear this node's cached result, and recursively its children's, so
+    /// the next [`CsgNode::evaluate`] recomputes the whole subtree.
+    pub fn invalidate(&mut self) {
+        if let CsgNode::Op { left, right, cache, .. } = self {
+            *cache = None;
+            left.invalidate();
+            right.invalidate();
+        }
+    }
+}
+
+fn clone_op(op: &BooleanOp) -> BooleanOp {
+    match op {
+        BooleanOp::Union => BooleanOp::Union,
+        BooleanOp::Intersection => BooleanOp::Intersection,
+        BooleanOp::Difference => BooleanOp::Difference,
+        BooleanOp::SymmetricDifference => BooleanOp::SymmetricDifference,
+    }
+}
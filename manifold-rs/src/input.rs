@@ -0,0 +1,256 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Read [Mesh] back in from STL and PLY, the counterpart to [`crate::output`].
+//!
+//! Both formats are written out by [`crate::output`] as a flat, unwelded
+//! triangle soup (every triangle owns its own three vertices), so reading
+//! one back in naively would hand [`Manifold::from_mesh`] a mesh with no
+//! shared vertices at all — not watertight, and not boolean-op-able. Import
+//! instead welds coincident positions within an epsilon (hashing quantized
+//! positions into a grid, same approach as every other weld pass in this
+//! workspace) before indexing, then walks the welded triangles to repair any
+//! inconsistent winding, so the result round-trips through `load -> boolean
+//! -> write_stl` intact.
+
+use crate::Mesh;
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+
+/// Positions closer than this (in the mesh's own units) are welded into a
+/// single shared vertex on import.
+pub const WELD_TOLERANCE: f32 = 1e-5;
+
+impl Mesh {
+    /// Parse `data` as an STL file (ASCII or binary, auto-detected), weld
+    /// coincident vertices, and repair triangle winding.
+    pub fn from_stl(data: &[u8]) -> io::Result<Self> {
+        let triangles = parse_stl(data)?;
+        let (vertices, indices) = weld_and_fix_winding(&triangles);
+        Ok(Self::new(&vertices, &indices))
+    }
+
+    /// Read and parse an STL file from `path`. See [`Self::from_stl`].
+    pub fn from_stl_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_stl(&std::fs::read(path)?)
+    }
+
+    /// Parse `data` as an ASCII PLY file, weld coincident vertices, and
+    /// repair triangle winding.
+    pub fn from_ply(data: &[u8]) -> io::Result<Self> {
+        let triangles = parse_ply_ascii(data)?;
+        let (vertices, indices) = weld_and_fix_winding(&triangles);
+        Ok(Self::new(&vertices, &indices))
+    }
+
+    /// Read and parse an ASCII PLY file from `path`. See [`Self::from_ply`].
+    pub fn from_ply_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_ply(&std::fs::read(path)?)
+    }
+}
+
+/// Parse an STL file into a flat triangle soup (three positions per
+/// triangle, no welding yet). Binary STL is detected by its header's
+/// declared triangle count matching the file's actual length; anything else
+/// is parsed as ASCII.
+fn parse_stl(data: &[u8]) -> io::Result<Vec<[f32; 3]>> {
+    if data.len() >= 84 {
+        let declared = u32::from_le_bytes(data[80..84].try_into().unwrap()) as usize;
+        if data.len() == 84 + declared * 50 {
+            return Ok(parse_stl_binary(data));
+        }
+    }
+    Ok(parse_stl_ascii(&String::from_utf8_lossy(data)))
+}
+
+fn parse_stl_binary(data: &[u8]) -> Vec<[f32; 3]> {
+    let triangle_count = u32::from_le_bytes(data[80..84].try_into().unwrap()) as usize;
+    let mut triangles = Vec::with_capacity(triangle_count * 3);
+
+    for i in 0..triangle_count {
+        let record = &data[84 + i * 50..84 + (i + 1) * 50];
+        // Bytes 0..12 are the facet normal; vertices start at byte 12.
+        for v in 0..3 {
+            let offset = 12 + v * 12;
+            let x = f32::from_le_bytes(record[offset..offset + 4].try_into().unwrap());
+            let y = f32::from_le_bytes(record[offset + 4..offset + 8].try_into().unwrap());
+            let z = f32::from_le_bytes(record[offset + 8..offset + 12].try_into().unwrap());
+            triangles.push([x, y, z]);
+        }
+    }
+
+    triangles
+}
+
+fn parse_stl_ascii(text: &str) -> Vec<[f32; 3]> {
+    text.lines()
+        .map(str::trim)
+        .filter_map(|line| line.strip_prefix("vertex"))
+        .filter_map(|rest| {
+            let mut fields = rest.split_whitespace();
+            let x = fields.next()?.parse().ok()?;
+            let y = fields.next()?.parse().ok()?;
+            let z = fields.next()?.parse().ok()?;
+            Some([x, y, z])
+        })
+        .collect()
+}
+
+/// Parse an ASCII PLY file (`element vertex` / `element face` header,
+/// `property float x`/`y`/`z`; faces with more than three vertices are
+/// fan-triangulated) into a flat triangle soup, expanding each face's vertex
+/// indices back into positions so it can be welded the same way as STL's
+/// unwelded soup.
+fn parse_ply_ascii(data: &[u8]) -> io::Result<Vec<[f32; 3]>> {
+    let text = String::from_utf8_lossy(data);
+    let mut lines = text.lines();
+
+    let mut vertex_count = 0usize;
+    let mut face_count = 0usize;
+    for line in lines.by_ref() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("element vertex ") {
+            vertex_count = rest.trim().parse().map_err(|_| invalid_data("malformed 'element vertex' line"))?;
+        } else if let Some(rest) = line.strip_prefix("element face ") {
+            face_count = rest.trim().parse().map_err(|_| invalid_data("malformed 'element face' line"))?;
+        } else if line == "end_header" {
+            break;
+        }
+    }
+
+    let mut vertices = Vec::with_capacity(vertex_count);
+    for line in lines.by_ref().take(vertex_count) {
+        let mut fields = line.split_whitespace();
+        let x: f32 = fields.next().and_then(|s| s.parse().ok()).ok_or_else(|| invalid_data("malformed vertex line"))?;
+        let y: f32 = fields.next().and_then(|s| s.parse().ok()).ok_or_else(|| invalid_data("malformed vertex line"))?;
+        let z: f32 = fields.next().and_then(|s| s.parse().ok()).ok_or_else(|| invalid_data("malformed vertex line"))?;
+        vertices.push([x, y, z]);
+    }
+
+    let mut triangles = Vec::with_capacity(face_count * 3);
+    for line in lines.take(face_count) {
+        let indices: Vec<usize> = line.split_whitespace().filter_map(|s| s.parse().ok()).collect();
+        // First field is the vertex count for this face; fan-triangulate
+        // anything beyond a plain triangle.
+        let Some((&n, rest)) = indices.split_first() else { continue };
+        for i in 1..n.saturating_sub(1) {
+            for &idx in &[rest[0], rest[i], rest[i + 1]] {
+                let v = *vertices.get(idx).ok_or_else(|| invalid_data("face index out of range"))?;
+                triangles.push(v);
+            }
+        }
+    }
+
+    Ok(triangles)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Weld `triangles` (a flat triangle soup, three positions per triangle)
+/// into shared indexed vertices, then repair winding so adjacent triangles
+/// agree on orientation.
+fn weld_and_fix_winding(triangles: &[[f32; 3]]) -> (Vec<f32>, Vec<u32>) {
+    let (welded, remap) = weld_by_position(triangles, WELD_TOLERANCE);
+    let mut indices: Vec<u32> = (0..triangles.len()).map(|i| remap[i]).collect();
+    fix_winding(&mut indices);
+
+    let vertices: Vec<f32> = welded.into_iter().flat_map(|p| p).collect();
+    (vertices, indices)
+}
+
+/// Snap positions within `tolerance` of each other using a uniform spatial
+/// grid keyed on `floor(position / tolerance)`, mirroring
+/// `bevy_step_loader::to_manifold::weld_by_position`. Returns the
+/// deduplicated positions plus an old-index -> new-index remap.
+fn weld_by_position(points: &[[f32; 3]], tolerance: f32) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let cell_of = |p: &[f32; 3]| -> (i64, i64, i64) {
+        ((p[0] / tolerance).floor() as i64, (p[1] / tolerance).floor() as i64, (p[2] / tolerance).floor() as i64)
+    };
+    let tolerance_sq = tolerance * tolerance;
+    let dist_sq = |a: &[f32; 3], b: &[f32; 3]| -> f32 { (a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2) };
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    let mut welded: Vec<[f32; 3]> = Vec::with_capacity(points.len());
+    let mut remap = vec![0u32; points.len()];
+
+    for (i, p) in points.iter().enumerate() {
+        let (cx, cy, cz) = cell_of(p);
+        let mut found = None;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) else { continue };
+                    for &candidate in candidates {
+                        if dist_sq(p, &welded[candidate]) <= tolerance_sq {
+                            found = Some(candidate);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        let target = found.unwrap_or_else(|| {
+            let new_index = welded.len();
+            welded.push(*p);
+            grid.entry((cx, cy, cz)).or_default().push(new_index);
+            new_index
+        });
+        remap[i] = target as u32;
+    }
+
+    (welded, remap)
+}
+
+/// Walk the welded triangle mesh and flip any triangle whose winding
+/// disagrees with its already-visited neighbors, so that every shared edge
+/// ends up traversed in opposite directions by its two triangles (the
+/// consistent-orientation a watertight manifold needs) instead of whatever
+/// order the source file's two facets happened to list it in.
+fn fix_winding(indices: &mut [u32]) {
+    let triangle_count = indices.len() / 3;
+
+    // Undirected edge -> triangles that touch it, so a flood fill can walk
+    // from triangle to triangle across shared edges.
+    let mut edge_to_triangles: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for t in 0..triangle_count {
+        let tri = [indices[t * 3], indices[t * 3 + 1], indices[t * 3 + 2]];
+        for &(a, b) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_to_triangles.entry(key).or_default().push(t);
+        }
+    }
+
+    let mut visited = vec![false; triangle_count];
+    for start in 0..triangle_count {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut queue = vec![start];
+
+        while let Some(t) = queue.pop() {
+            let tri = [indices[t * 3], indices[t * 3 + 1], indices[t * 3 + 2]];
+            for &(a, b) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let key = if a < b { (a, b) } else { (b, a) };
+                let Some(neighbors) = edge_to_triangles.get(&key) else { continue };
+                for &n in neighbors {
+                    if n == t || visited[n] {
+                        continue;
+                    }
+                    let n_tri = [indices[n * 3], indices[n * 3 + 1], indices[n * 3 + 2]];
+                    let shares_direction = [(n_tri[0], n_tri[1]), (n_tri[1], n_tri[2]), (n_tri[2], n_tri[0])]
+                        .contains(&(a, b));
+                    if shares_direction {
+                        indices.swap(n * 3 + 1, n * 3 + 2);
+                    }
+                    visited[n] = true;
+                    queue.push(n);
+                }
+            }
+        }
+    }
+}
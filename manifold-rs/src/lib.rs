@@ -6,8 +6,46 @@
 #[cfg(feature = "output")]
 pub mod output;
 
+#[cfg(feature = "input")]
+pub mod input;
+
+mod ops;
+
+pub mod bvh;
+pub mod csg;
+
+#[cfg(feature = "bevy")]
+pub mod bevy_shapes;
+
+pub mod primitives;
+pub mod properties;
+pub mod raycast;
+pub mod tangent;
+
 #[cxx::bridge(namespace = "manifold_rs")]
 mod ffi {
+    /// How [`crate::CrossSection::offset`] treats a path's
+    /// corners when growing or shrinking it.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum JoinType {
+        /// Corners are rounded off, approximated by segments sized by the
+        /// offset's `arc_tolerance`.
+        Round,
+        /// Corners are squared off, then the resulting spike is clipped flat
+        /// once it extends past `miter_limit * delta` from the original
+        /// corner.
+        Miter,
+        /// Corners are beveled at a flat 90-degree angle.
+        Square,
+    }
+
+    /// The two manifolds produced by a single-pass split, sharing the cost
+    /// of computing the shared cut boundary once instead of twice.
+    struct SplitResult {
+        first: UniquePtr<Manifold>,
+        second: UniquePtr<Manifold>,
+    }
+
     // C++ types and signatures exposed to Rust.
     unsafe extern "C++" {
         include!("manifold_rs.h");
@@ -25,6 +63,22 @@ mod ffi {
 
         fn is_empty(self: &Manifold) -> bool;
 
+        /// Reserve a block of `n` fresh, globally unique original-mesh IDs,
+        /// returning the first one. The rest of the block is `first + 1 ..
+        /// first + n`.
+        fn reserve_ids(n: u32) -> u32;
+
+        /// Return a copy of the manifold stamped with a fresh original ID
+        /// (via `reserve_ids(1)`), marking every triangle as belonging to
+        /// this input rather than whatever it was derived from.
+        fn as_original(self: &Manifold) -> UniquePtr<Manifold>;
+
+        /// Get the manifold's original ID, or -1 if it isn't an original
+        /// (i.e. it's the result of an operation that combined more than
+        /// one original, and its triangles carry a mix of origin IDs
+        /// readable from its [`Mesh`]'s `run_original_id` instead).
+        fn original_id(self: &Manifold) -> i32;
+
         /// Slice the manifold into a set of polygons.
         fn slice(self: &Manifold, height: f64) -> UniquePtr<Polygons>;
 
@@ -45,6 +99,17 @@ mod ffi {
             segments: u32,
         ) -> UniquePtr<Manifold>;
 
+        /// Batch many independent manifolds into a single one, concatenating
+        /// vertex/triangle buffers with remapped indices instead of running
+        /// a boolean. Much cheaper than folding N unions when the bodies
+        /// don't overlap, e.g. laying out an array of imported parts.
+        fn compose(manifolds: &[&Manifold]) -> UniquePtr<Manifold>;
+
+        /// Split into its disconnected components, each as its own
+        /// manifold, by flood-filling connected triangle sets. The inverse
+        /// of `compose`.
+        fn decompose(self: &Manifold) -> Vec<UniquePtr<Manifold>>;
+
         /// Get the union of two manifolds.
         fn union_(a: &Manifold, b: &Manifold) -> UniquePtr<Manifold>;
 
@@ -63,6 +128,25 @@ mod ffi {
             offset: f64,
         ) -> UniquePtr<Manifold>;
 
+        /// Split the manifold by a plane in one pass, returning the same
+        /// `(kept, removed)` halves as calling `trim_by_plane` with `offset`
+        /// and then `-offset` on the flipped normal, but computed together
+        /// so the shared cut boundary is found only once.
+        fn split_by_plane(
+            self: &Manifold,
+            x: f64,
+            y: f64,
+            z: f64,
+            offset: f64,
+        ) -> SplitResult;
+
+        /// Split the manifold by `cutter` in one pass, returning
+        /// `(intersection, difference)` computed together — cheaper than
+        /// running `intersection` and `difference` as two separate
+        /// booleans, and guarantees the two results agree on the shared
+        /// boundary.
+        fn split(self: &Manifold, cutter: &Manifold) -> SplitResult;
+
         /// Convex hull.
         fn hull(self: &Manifold) -> UniquePtr<Manifold>;
 
@@ -118,6 +202,99 @@ mod ffi {
             min_sharp_angle: f64,
         ) -> UniquePtr<Manifold>;
 
+        /// Get the axis-aligned bounding box of the manifold as
+        /// [min_x, min_y, min_z, max_x, max_y, max_z].
+        fn bounding_box(self: &Manifold) -> UniquePtr<CxxVector<f64>>;
+
+        /// Get the manifold's merge tolerance: the minimum distance at which
+        /// two vertices are treated as coincident.
+        fn get_tolerance(self: &Manifold) -> f64;
+
+        /// Return a copy of the manifold with its merge tolerance set to
+        /// `tolerance` (never below the value Manifold derives from
+        /// floating-point precision for this geometry's scale).
+        fn set_tolerance(self: &Manifold, tolerance: f64) -> UniquePtr<Manifold>;
+
+        /// Get the manifold's epsilon: the floating-point-precision-derived
+        /// lower bound `get_tolerance` can't go below for this geometry's
+        /// scale, distinct from the (possibly larger) tolerance actually in
+        /// effect.
+        fn get_epsilon(self: &Manifold) -> f64;
+
+        /// Simplify the manifold by collapsing features smaller than its
+        /// current tolerance.
+        fn simplify(self: &Manifold) -> UniquePtr<Manifold>;
+
+        /// A boolean-operable 2D polygon set, wrapper for C++ CrossSection
+        /// object.
+        type CrossSection;
+
+        /// Convert sliced/projected polygons into a [`CrossSection`] that
+        /// can be boolean-combined, hulled, transformed, and offset.
+        fn polygons_to_cross_section(polygons: &Polygons) -> UniquePtr<CrossSection>;
+
+        /// Union of two cross sections.
+        fn cross_section_union(a: &CrossSection, b: &CrossSection) -> UniquePtr<CrossSection>;
+
+        /// Intersection of two cross sections.
+        fn cross_section_intersection(
+            a: &CrossSection,
+            b: &CrossSection,
+        ) -> UniquePtr<CrossSection>;
+
+        /// Difference of two cross sections.
+        fn cross_section_difference(
+            a: &CrossSection,
+            b: &CrossSection,
+        ) -> UniquePtr<CrossSection>;
+
+        /// 2D convex hull.
+        fn cross_section_hull(self: &CrossSection) -> UniquePtr<CrossSection>;
+
+        /// Translate the cross section.
+        fn cross_section_translate(self: &CrossSection, x: f64, y: f64) -> UniquePtr<CrossSection>;
+
+        /// Scale the cross section.
+        fn cross_section_scale(self: &CrossSection, x: f64, y: f64) -> UniquePtr<CrossSection>;
+
+        /// Rotate the cross section, in degrees.
+        fn cross_section_rotate(self: &CrossSection, degrees: f64) -> UniquePtr<CrossSection>;
+
+        /// Grow (`delta > 0`) or shrink (`delta < 0`) the cross section by
+        /// `delta`, with corners handled according to `join_type`. Round
+        /// joins are approximated by segments no further than
+        /// `arc_tolerance` from the true arc; miter joins clip spikes past
+        /// `miter_limit * delta` from the original corner.
+        fn offset(
+            self: &CrossSection,
+            delta: f64,
+            join_type: JoinType,
+            miter_limit: f64,
+            arc_tolerance: f64,
+        ) -> UniquePtr<CrossSection>;
+
+        /// Convert the cross section back into [`Polygons`].
+        fn cross_section_to_polygons(cross_section: &CrossSection) -> UniquePtr<Polygons>;
+
+        /// Extrude a [`CrossSection`] into a manifold, the `CrossSection`
+        /// counterpart to [`extrude`]'s raw polygon data.
+        fn extrude_cross_section(
+            cross_section: &CrossSection,
+            height: f64,
+            n_divisions: u32,
+            twist_degrees: f64,
+            scale_top_x: f64,
+            scale_top_y: f64,
+        ) -> UniquePtr<Manifold>;
+
+        /// Revolve a [`CrossSection`] into a manifold, the `CrossSection`
+        /// counterpart to [`revolve`]'s raw polygon data.
+        fn revolve_cross_section(
+            cross_section: &CrossSection,
+            circular_segments: u32,
+            revolve_degrees: f64,
+        ) -> UniquePtr<Manifold>;
+
         /// Manifold object, wrapper for C++ mesh object.
         type Mesh;
 
@@ -130,6 +307,16 @@ mod ffi {
         /// Get the indices of the mesh.
         fn indices(self: &Mesh) -> UniquePtr<CxxVector<u32>>;
 
+        /// Get the starting triangle index of each contiguous run of
+        /// triangles that share an original ID, one past the last run's
+        /// start appended as a terminating total-triangle-count entry (so
+        /// `run_index[i]..run_index[i + 1]` is run `i`'s triangle range).
+        fn run_index(self: &Mesh) -> UniquePtr<CxxVector<u32>>;
+
+        /// Get the original ID each run (see `run_index`) of triangles came
+        /// from, one entry per run.
+        fn run_original_id(self: &Mesh) -> UniquePtr<CxxVector<u32>>;
+
         /// Create a mesh from a manifold.
         fn mesh_from_manifold(manifold: &Manifold) -> UniquePtr<Mesh>;
 
@@ -141,10 +328,23 @@ mod ffi {
         /// The vertices are a flat array of floats containing the x, y, z coordinates of each vertex.
         /// The indices are a flat array of unsigned integers containing the indices of the vertices.
         fn mesh_from_vertices(vertices: &[f32], indices: &[u32]) -> UniquePtr<Mesh>;
+
+        /// Create a mesh from vertices and indices, with an explicit
+        /// `num_props` floats per vertex (position, plus however many
+        /// trailing property floats — normals, UVs, colors — are interleaved
+        /// after it) instead of inferring positions-only layout.
+        fn mesh_from_vertices_with_props(
+            vertices: &[f32],
+            num_props: u32,
+            indices: &[u32],
+        ) -> UniquePtr<Mesh>;
     }
 }
 
+pub use ffi::JoinType;
+
 /// Boolean operation on manifolds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum BooleanOp {
     /// Union of two manifolds.
     Union,
@@ -152,6 +352,8 @@ pub enum BooleanOp {
     Intersection,
     /// Difference of two manifolds.
     Difference,
+    /// Symmetric difference (XOR): `(a ∪ b) − (a ∩ b)`.
+    SymmetricDifference,
 }
 
 /// Manifold rust wrapper for C++ polygons object.
@@ -167,6 +369,79 @@ impl Polygons {
     pub fn get_as_slice(&self, i: usize) -> &[f64] {
         self.0.get_as_slice(i)
     }
+
+    /// Convert into a [`CrossSection`] that can be boolean-combined, hulled,
+    /// transformed, and offset.
+    pub fn to_cross_section(&self) -> CrossSection {
+        CrossSection(ffi::polygons_to_cross_section(&self.0))
+    }
+}
+
+/// Manifold rust wrapper for C++ CrossSection object: a boolean-operable 2D
+/// polygon set, the 2D counterpart to [`Manifold`].
+pub struct CrossSection(cxx::UniquePtr<ffi::CrossSection>);
+
+impl CrossSection {
+    /// Union of two cross sections.
+    pub fn union(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_union(self.inner(), b.inner()))
+    }
+
+    /// Intersection of two cross sections.
+    pub fn intersection(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_intersection(self.inner(), b.inner()))
+    }
+
+    /// Difference of two cross sections.
+    pub fn difference(&self, b: &Self) -> Self {
+        Self(ffi::cross_section_difference(self.inner(), b.inner()))
+    }
+
+    /// Boolean operation on cross sections.
+    pub fn boolean_op(&self, b: &Self, op: BooleanOp) -> Self {
+        match op {
+            BooleanOp::Union => self.union(b),
+            BooleanOp::Intersection => self.intersection(b),
+            BooleanOp::Difference => self.difference(b),
+            BooleanOp::SymmetricDifference => self.union(b).difference(&self.intersection(b)),
+        }
+    }
+
+    /// 2D convex hull.
+    pub fn hull(&self) -> Self {
+        Self(self.0.cross_section_hull())
+    }
+
+    /// Translate the cross section.
+    pub fn translate(&self, x: f64, y: f64) -> Self {
+        Self(self.0.cross_section_translate(x, y))
+    }
+
+    /// Scale the cross section.
+    pub fn scale(&self, x: f64, y: f64) -> Self {
+        Self(self.0.cross_section_scale(x, y))
+    }
+
+    /// Rotate the cross section, in degrees.
+    pub fn rotate(&self, degrees: f64) -> Self {
+        Self(self.0.cross_section_rotate(degrees))
+    }
+
+    /// Grow (`delta > 0`) or shrink (`delta < 0`) the cross section by
+    /// `delta`, with corners handled according to `join_type`.
+    pub fn offset(&self, delta: f64, join_type: JoinType, miter_limit: f64, arc_tolerance: f64) -> Self {
+        Self(self.0.offset(delta, join_type, miter_limit, arc_tolerance))
+    }
+
+    /// Convert back into [`Polygons`].
+    pub fn to_polygons(&self) -> Polygons {
+        Polygons(ffi::cross_section_to_polygons(&self.0))
+    }
+
+    /// Get the inner C++ cross section object.
+    fn inner(&self) -> &ffi::CrossSection {
+        self.0.as_ref().unwrap()
+    }
 }
 
 /// Manifold rust wrapper for C++ manifold object.
@@ -193,6 +468,24 @@ impl Manifold {
         Self(self.0.trim_by_plane(x, y, z, offset))
     }
 
+    /// Split by a plane in one pass, returning `(kept, removed)` — cheaper
+    /// than calling [`Self::trim_by_plane`] on both sides of the plane,
+    /// since the shared cut boundary is only computed once. Useful for CAD
+    /// sectioning where both the cutaway and the remaining stock are needed.
+    pub fn split_by_plane(&self, x: f64, y: f64, z: f64, offset: f64) -> (Self, Self) {
+        let result = self.0.split_by_plane(x, y, z, offset);
+        (Self(result.first), Self(result.second))
+    }
+
+    /// Split by `cutter` in one pass, returning `(intersection, difference)`
+    /// computed together rather than as two separate booleans — useful for
+    /// kerf-aware cutting where both the chip and the remaining stock are
+    /// needed and must agree on the shared boundary.
+    pub fn split(&self, cutter: &Self) -> (Self, Self) {
+        let result = self.0.split(cutter.inner());
+        (Self(result.first), Self(result.second))
+    }
+
     /// Convex hull.
     pub fn hull(&self) -> Self {
         Self(self.0.hull())
@@ -223,24 +516,50 @@ impl Manifold {
         Self(ffi::cube(x_size, y_size, z_size))
     }
 
+    /// The empty manifold, e.g. as a fallback result when an operation
+    /// couldn't be carried out at all rather than just yielding nothing.
+    pub fn empty() -> Self {
+        Self::cube(0.0, 0.0, 0.0)
+    }
+
     /// Create a cylinder manifold.
     pub fn cylinder(radius_low: f64, radius_high: f64, height: f64, segments: u32) -> Self {
         Self(ffi::cylinder(radius_low, radius_high, height, segments))
     }
 
+    /// Batch `manifolds` into a single manifold without running a boolean,
+    /// much cheaper than folding them together with [`Self::union`] when
+    /// they don't overlap.
+    pub fn compose(manifolds: &[&Self]) -> Self {
+        let inner: Vec<&ffi::Manifold> = manifolds.iter().map(|m| m.inner()).collect();
+        Self(ffi::compose(&inner))
+    }
+
+    /// Split into its disconnected components, each as its own manifold.
+    /// The inverse of [`Self::compose`].
+    pub fn decompose(&self) -> Vec<Self> {
+        self.0.decompose().into_iter().map(Self).collect()
+    }
+
     /// Get the union of two manifolds.
     pub fn union(&self, b: &Self) -> Self {
-        Self(ffi::union_(self.inner(), b.inner()))
+        Self(ffi::union_(self.inner(), b.inner())).with_merged_tolerance(self, b)
     }
 
     /// Get the intersection of two manifolds.
     pub fn intersection(&self, b: &Self) -> Self {
-        Self(ffi::intersection(self.inner(), b.inner()))
+        Self(ffi::intersection(self.inner(), b.inner())).with_merged_tolerance(self, b)
     }
 
     /// Get the difference of two manifolds.
     pub fn difference(&self, b: &Self) -> Self {
-        Self(ffi::difference(self.inner(), b.inner()))
+        Self(ffi::difference(self.inner(), b.inner())).with_merged_tolerance(self, b)
+    }
+
+    /// Get the symmetric difference (XOR) of two manifolds: the parts
+    /// belonging to exactly one of `self` and `b`, i.e. `(a ∪ b) − (a ∩ b)`.
+    pub fn symmetric_difference(&self, b: &Self) -> Self {
+        self.union(b).difference(&self.intersection(b))
     }
 
     /// Boolean operation on manifolds.
@@ -249,6 +568,7 @@ impl Manifold {
             crate::BooleanOp::Union => self.union(b),
             crate::BooleanOp::Intersection => self.intersection(b),
             crate::BooleanOp::Difference => self.difference(b),
+            crate::BooleanOp::SymmetricDifference => self.symmetric_difference(b),
         }
     }
 
@@ -324,6 +644,127 @@ impl Manifold {
         mesh.into()
     }
 
+    /// Get the axis-aligned bounding box of the manifold as (min, max).
+    pub fn bounding_box(&self) -> ([f64; 3], [f64; 3]) {
+        let bbox_binding = self.0.bounding_box();
+        let bbox = bbox_binding.as_ref().unwrap().as_slice();
+        (
+            [bbox[0], bbox[1], bbox[2]],
+            [bbox[3], bbox[4], bbox[5]],
+        )
+    }
+
+    /// Get the manifold's merge tolerance.
+    pub fn get_tolerance(&self) -> f64 {
+        self.0.get_tolerance()
+    }
+
+    /// Return a copy of the manifold with its merge tolerance set to
+    /// `tolerance`. Useful when combining operands exported at different
+    /// scales/units, where the default tolerance can collapse or duplicate
+    /// coincident vertices incorrectly.
+    pub fn set_tolerance(&self, tolerance: f64) -> Self {
+        Self(self.0.set_tolerance(tolerance))
+    }
+
+    /// Get the manifold's epsilon: the precision-derived floor under
+    /// `get_tolerance`, useful for checking how far above that floor a
+    /// tolerance passed to [`Manifold::set_tolerance`] actually sits.
+    pub fn epsilon(&self) -> f64 {
+        self.0.get_epsilon()
+    }
+
+    /// Derive a vertex-merge tolerance from this manifold's own
+    /// bounding-box diagonal rather than a fixed constant, so a manifold
+    /// modeled in millimeters and one modeled in meters each get a snap
+    /// distance proportional to their own scale instead of one that's too
+    /// loose for the small mesh or too tight to merge coincident vertices on
+    /// the large one. Never goes below [`Self::epsilon`], the
+    /// precision-derived floor the library won't go under anyway.
+    pub fn default_tolerance(&self) -> f64 {
+        let (min, max) = self.bounding_box();
+        let diagonal = ops::sqrt((max[0] - min[0]).powi(2) + (max[1] - min[1]).powi(2) + (max[2] - min[2]).powi(2));
+        (diagonal * 1e-7).max(self.epsilon())
+    }
+
+    /// Return a copy with its tolerance set to [`Self::default_tolerance`].
+    pub fn with_default_tolerance(&self) -> Self {
+        self.set_tolerance(self.default_tolerance())
+    }
+
+    /// Return a copy of `self` with its tolerance raised to the larger of
+    /// `a` and `b`'s tolerances, so a boolean op combining two inputs
+    /// modeled at different scales keeps whichever input's looser,
+    /// coarser-scale tolerance the result actually needs to stay watertight,
+    /// rather than silently keeping the tighter of the two.
+    fn with_merged_tolerance(self, a: &Self, b: &Self) -> Self {
+        self.set_tolerance(a.get_tolerance().max(b.get_tolerance()))
+    }
+
+    /// Collapse features smaller than the manifold's current tolerance.
+    pub fn simplify(&self) -> Self {
+        Self(self.0.simplify())
+    }
+
+    /// Reserve a block of `n` fresh, globally unique original-mesh IDs,
+    /// returning the first one.
+    pub fn reserve_ids(n: u32) -> u32 {
+        ffi::reserve_ids(n)
+    }
+
+    /// Return a copy stamped with a fresh original ID, marking every
+    /// triangle as belonging to this input. Do this before combining
+    /// multiple inputs with a boolean op if the output needs to trace
+    /// which triangles came from which input afterward — e.g. to
+    /// re-apply a source face's material, or highlight the surfaces a
+    /// cutting tool actually touched.
+    pub fn as_original(&self) -> Self {
+        Self(self.0.as_original())
+    }
+
+    /// Get the original ID ([`Self::as_original`]) this manifold was
+    /// stamped with, or -1 if it's the result of an operation that
+    /// combined more than one original — in that case, read per-triangle
+    /// origin IDs back from [`Self::to_mesh`]'s
+    /// [`Mesh::triangle_original_ids`] instead.
+    pub fn original_id(&self) -> i32 {
+        self.0.original_id()
+    }
+
+    /// Extrude a [`CrossSection`] to create a manifold, the `CrossSection`
+    /// counterpart to [`Self::extrude`].
+    pub fn extrude_cross_section(
+        cross_section: &CrossSection,
+        height: f64,
+        n_divisions: u32,
+        twist_degrees: f64,
+        scale_top_x: f64,
+        scale_top_y: f64,
+    ) -> Self {
+        Self(ffi::extrude_cross_section(
+            cross_section.inner(),
+            height,
+            n_divisions,
+            twist_degrees,
+            scale_top_x,
+            scale_top_y,
+        ))
+    }
+
+    /// Revolve a [`CrossSection`] to create a manifold, the `CrossSection`
+    /// counterpart to [`Self::revolve`].
+    pub fn revolve_cross_section(
+        cross_section: &CrossSection,
+        circular_segments: u32,
+        revolve_degrees: f64,
+    ) -> Self {
+        Self(ffi::revolve_cross_section(
+            cross_section.inner(),
+            circular_segments,
+            revolve_degrees,
+        ))
+    }
+
     /// Get the inner C++ manifold object.
     fn inner(&self) -> &ffi::Manifold {
         self.0.as_ref().unwrap()
@@ -341,6 +782,15 @@ impl Mesh {
         Self(mesh)
     }
 
+    /// Create a new mesh from vertices and indices, with `num_props` floats
+    /// per vertex interleaved after position (normals, UVs, colors, ...).
+    /// Use this over [`Self::new`] whenever the vertex layout carries more
+    /// than bare positions, so it doesn't have to be inferred.
+    pub fn with_num_props(vertices: &[f32], num_props: u32, indices: &[u32]) -> Self {
+        let mesh = ffi::mesh_from_vertices_with_props(vertices, num_props, indices);
+        Self(mesh)
+    }
+
     /// Number of properties per vertex
     pub fn num_props(&self) -> u32 {
         self.0.num_props()
@@ -365,6 +815,36 @@ impl Mesh {
         let manifold = ffi::manifold_from_mesh(&self.0);
         Manifold(manifold)
     }
+
+    /// Get the starting triangle index of each contiguous run of triangles
+    /// that share an original ID, plus a terminating entry equal to the
+    /// total triangle count.
+    pub fn run_index(&self) -> Vec<u32> {
+        let run_index_binding = self.0.run_index();
+        run_index_binding.as_ref().unwrap().as_slice().to_vec()
+    }
+
+    /// Get the original ID each run of triangles ([`Self::run_index`]) came
+    /// from, one entry per run.
+    pub fn run_original_id(&self) -> Vec<u32> {
+        let run_original_id_binding = self.0.run_original_id();
+        run_original_id_binding.as_ref().unwrap().as_slice().to_vec()
+    }
+
+    /// Expand [`Self::run_index`]/[`Self::run_original_id`] into one
+    /// original ID per triangle, so a caller walking triangles (e.g. to
+    /// re-apply a source face's material after a boolean op) doesn't have
+    /// to resolve the run it falls into itself.
+    pub fn triangle_original_ids(&self) -> Vec<u32> {
+        let run_index = self.run_index();
+        let run_original_id = self.run_original_id();
+        let mut ids = Vec::with_capacity(*run_index.last().unwrap_or(&0) as usize);
+        for (run, &original_id) in run_original_id.iter().enumerate() {
+            let count = run_index[run + 1] - run_index[run];
+            ids.extend(std::iter::repeat(original_id).take(count as usize));
+        }
+        ids
+    }
 }
 
 /// Convert Mesh to Manifold struct
@@ -0,0 +1,53 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! `f64`/`f32` transcendental and rounding-sensitive helpers, selected
+//! between plain `std` float methods and `libm`'s equivalents by a `libm`
+//! cargo feature — the same split `meshbool::ops` applies to its own `f32`
+//! values, just covering the `f64` trig this crate's Rust-side primitive
+//! generation ([`crate::bevy_shapes`], [`crate::primitives`]'s icosphere)
+//! and the `f32` square roots its geometry helpers ([`crate::tangent`],
+//! [`crate::primitives`]'s tetrahedron) actually call. `std`'s float methods have unspecified
+//! precision across targets and Rust versions, so identical inputs can
+//! otherwise produce bit-different meshes; `libm` pins one implementation
+//! everywhere, which golden-file mesh comparison tests depend on.
+
+#[cfg(feature = "libm")]
+pub fn sin(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(feature = "libm")]
+pub fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[cfg(feature = "libm")]
+pub fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(feature = "libm")]
+pub fn sqrt_f32(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt_f32(x: f32) -> f32 {
+    x.sqrt()
+}
@@ -1,7 +1,7 @@
 // Copyright © 2024 The µCAD authors <info@ucad.xyz>
 // SPDX-License-Identifier: Apache-2.0
 
-//! Write [Mesh] to STL and PLY
+//! Write [Mesh] to STL, PLY, OBJ, glTF, and 3MF
 
 use crate::{Manifold, Mesh};
 
@@ -18,6 +18,13 @@ pub trait Vertex {
 
     /// Return number of properties
     fn num_props(&self) -> u32;
+
+    /// Return per-vertex color, quantized to the `uchar` channels PLY's
+    /// `red`/`green`/`blue` properties expect. `None` for vertex layouts
+    /// that carry no color channel.
+    fn color(&self) -> Option<[u8; 3]> {
+        None
+    }
 }
 
 /// Vertex with position
@@ -75,6 +82,78 @@ impl Vertex for VertexPos3Normal {
     }
 }
 
+/// A Vertex with Position and per-vertex Color, quantized from `0..=1`
+/// floats (the layout a vertex buffer stores them in) to the `u8` channels
+/// PLY's `uchar` color properties expect.
+pub struct VertexPos3Color {
+    pub pos: Vec3,
+    pub color: [u8; 3],
+}
+
+impl Vertex for VertexPos3Color {
+    fn from_slice_and_offset(slice: &[f32], offset: usize) -> Self {
+        Self {
+            pos: Vec3::new(slice[offset], slice[offset + 1], slice[offset + 2]),
+            color: quantize_color(slice[offset + 3], slice[offset + 4], slice[offset + 5]),
+        }
+    }
+
+    fn pos(&self) -> &Vec3 {
+        &self.pos
+    }
+
+    fn normal(&self) -> Option<&Vec3> {
+        None
+    }
+
+    fn num_props(&self) -> u32 {
+        6
+    }
+
+    fn color(&self) -> Option<[u8; 3]> {
+        Some(self.color)
+    }
+}
+
+/// A Vertex with Position, Normal, and per-vertex Color.
+pub struct VertexPos3NormalColor {
+    pub pos: Vec3,
+    pub normal: Vec3,
+    pub color: [u8; 3],
+}
+
+impl Vertex for VertexPos3NormalColor {
+    fn from_slice_and_offset(slice: &[f32], offset: usize) -> Self {
+        Self {
+            pos: Vec3::new(slice[offset], slice[offset + 1], slice[offset + 2]),
+            normal: Vec3::new(slice[offset + 3], slice[offset + 4], slice[offset + 5]),
+            color: quantize_color(slice[offset + 6], slice[offset + 7], slice[offset + 8]),
+        }
+    }
+
+    fn pos(&self) -> &Vec3 {
+        &self.pos
+    }
+
+    fn normal(&self) -> Option<&Vec3> {
+        Some(&self.normal)
+    }
+
+    fn num_props(&self) -> u32 {
+        9
+    }
+
+    fn color(&self) -> Option<[u8; 3]> {
+        Some(self.color)
+    }
+}
+
+/// Quantize an RGB color from `0..=1` floats to `0..=255` bytes, clamping
+/// out-of-range input rather than wrapping on overflow/underflow.
+fn quantize_color(r: f32, g: f32, b: f32) -> [u8; 3] {
+    [r, g, b].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
 /// Triangle
 #[derive(Clone, Copy, Debug)]
 pub struct Triangle<T>(pub T, pub T, pub T);
@@ -142,13 +221,63 @@ pub fn write_stl(
     Ok(())
 }
 
+/// Interpret vertices and indices as triangles and write them to a binary
+/// STL: an 80-byte zero-padded header (no comment text, just padding), a
+/// little-endian `u32` triangle count, then one 50-byte record per
+/// triangle — three `f32`s for the facet normal (computed the same way
+/// [`Triangle::normal`] does), the three vertex positions, and a trailing
+/// `u16` attribute byte count left at 0. This is the layout most slicers
+/// and CAD tools actually expect, and is a fraction of the size of the
+/// ASCII `write_stl` above for the same geometry.
+pub fn write_stl_binary(
+    vertices: &[f32],
+    num_props: u32,
+    indices: &[u32],
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let num_props = num_props as usize;
+    let triangle_count = (indices.len() / 3) as u32;
+
+    writer.write_all(&[0u8; 80])?;
+    writer.write_all(&triangle_count.to_le_bytes())?;
+
+    for i in (0..indices.len()).step_by(3) {
+        let triangle = Triangle(
+            VertexPos3::from_slice_and_offset(vertices, indices[i] as usize * num_props),
+            VertexPos3::from_slice_and_offset(vertices, indices[i + 1] as usize * num_props),
+            VertexPos3::from_slice_and_offset(vertices, indices[i + 2] as usize * num_props),
+        );
+        let n = triangle.normal();
+        for component in [n.x, n.y, n.z] {
+            writer.write_all(&component.to_le_bytes())?;
+        }
+        for vertex in [triangle.0, triangle.1, triangle.2] {
+            for component in [vertex.pos().x, vertex.pos().y, vertex.pos().z] {
+                writer.write_all(&component.to_le_bytes())?;
+            }
+        }
+        writer.write_all(&0u16.to_le_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Write vertices/indices as an ASCII PLY. `num_props` floats per vertex are
+/// interpreted as position, then (if present) normal, then (if `has_color`)
+/// a trailing RGB triple quantized to `uchar` channels on write — the same
+/// layout [`VertexPos3Color`]/[`VertexPos3NormalColor`] read a flat vertex
+/// buffer as.
 pub fn write_ply(
     vertices: &[f32],
     num_props: u32,
     indices: &[u32],
+    has_color: bool,
     writer: &mut impl std::io::Write,
 ) -> std::io::Result<()> {
     let num_props = num_props as usize;
+    let color_props = if has_color { 3 } else { 0 };
+    let has_normal = num_props >= 6 + color_props;
+    let position_and_normal_props = num_props - color_props;
 
     writeln!(writer, "ply")?;
     writeln!(writer, "format ascii 1.0")?;
@@ -172,18 +301,31 @@ pub fn write_ply(
     writeln!(writer, "property float x")?;
     writeln!(writer, "property float y")?;
     writeln!(writer, "property float z")?;
-    if num_props > 3 {
+    if has_normal {
         writeln!(writer, "property float nx")?;
         writeln!(writer, "property float ny")?;
         writeln!(writer, "property float nz")?;
     }
+    if has_color {
+        writeln!(writer, "property uchar red")?;
+        writeln!(writer, "property uchar green")?;
+        writeln!(writer, "property uchar blue")?;
+    }
 
     writeln!(writer, "element face {len}", len = indices.len() / 3)?;
     writeln!(writer, "property list uchar int vertex_index")?;
     writeln!(writer, "end_header")?;
 
     vertices.chunks(num_props).try_for_each(|chunk| {
-        chunk.iter().try_for_each(|x| write!(writer, "{x} "))?;
+        chunk[..position_and_normal_props].iter().try_for_each(|x| write!(writer, "{x} "))?;
+        if has_color {
+            let rgb = quantize_color(
+                chunk[position_and_normal_props],
+                chunk[position_and_normal_props + 1],
+                chunk[position_and_normal_props + 2],
+            );
+            write!(writer, "{} {} {} ", rgb[0], rgb[1], rgb[2])?;
+        }
         writeln!(writer)
     })?;
 
@@ -194,39 +336,146 @@ pub fn write_ply(
     Ok(())
 }
 
+/// Binary-little-endian counterpart to [`write_ply`]: the same header shape
+/// (`format binary_little_endian 1.0` in place of `format ascii 1.0`), but
+/// vertex floats, `uchar` colors, and face lists (a `uchar` count followed
+/// by `int32` indices) are packed as raw little-endian bytes after
+/// `end_header` instead of printed as text — a fraction of the size for the
+/// high-triangle-count meshes boolean ops tend to produce.
+pub fn write_ply_binary(
+    vertices: &[f32],
+    num_props: u32,
+    indices: &[u32],
+    has_color: bool,
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let num_props = num_props as usize;
+    let color_props = if has_color { 3 } else { 0 };
+    let has_normal = num_props >= 6 + color_props;
+    let position_and_normal_props = num_props - color_props;
+
+    assert!(
+        vertices.len() % num_props == 0,
+        "Number of vertices elements must be divisible by num_props"
+    );
+    assert!(
+        indices.len() % 3 == 0,
+        "Number of indices must be divisible by 3"
+    );
+
+    writeln!(writer, "ply")?;
+    writeln!(writer, "format binary_little_endian 1.0")?;
+    writeln!(writer, "comment written by rust-sdf")?;
+    writeln!(
+        writer,
+        "element vertex {len}",
+        len = vertices.len() / num_props
+    )?;
+    writeln!(writer, "property float x")?;
+    writeln!(writer, "property float y")?;
+    writeln!(writer, "property float z")?;
+    if has_normal {
+        writeln!(writer, "property float nx")?;
+        writeln!(writer, "property float ny")?;
+        writeln!(writer, "property float nz")?;
+    }
+    if has_color {
+        writeln!(writer, "property uchar red")?;
+        writeln!(writer, "property uchar green")?;
+        writeln!(writer, "property uchar blue")?;
+    }
+    writeln!(writer, "element face {len}", len = indices.len() / 3)?;
+    writeln!(writer, "property list uchar int vertex_index")?;
+    writeln!(writer, "end_header")?;
+
+    for chunk in vertices.chunks(num_props) {
+        for &x in &chunk[..position_and_normal_props] {
+            writer.write_all(&x.to_le_bytes())?;
+        }
+        if has_color {
+            let rgb = quantize_color(
+                chunk[position_and_normal_props],
+                chunk[position_and_normal_props + 1],
+                chunk[position_and_normal_props + 2],
+            );
+            writer.write_all(&rgb)?;
+        }
+    }
+
+    for triangle in indices.chunks(3) {
+        writer.write_all(&3u8.to_le_bytes())?;
+        for &idx in triangle {
+            writer.write_all(&(idx as i32).to_le_bytes())?;
+        }
+    }
+
+    Ok(())
+}
+
 pub trait WriteStl {
     fn write_stl(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
 
+    fn write_stl_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
     fn write_stl_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
         let mut writer = std::fs::File::create(filename)?;
         self.write_stl(&mut writer)
     }
+
+    fn write_stl_binary_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::fs::File::create(filename)?;
+        self.write_stl_binary(&mut writer)
+    }
 }
 
 pub trait WritePly {
     fn write_ply(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
 
+    fn write_ply_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
     fn write_ply_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
         let mut writer = std::fs::File::create(filename)?;
         self.write_ply(&mut writer)
     }
+
+    fn write_ply_binary_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::fs::File::create(filename)?;
+        self.write_ply_binary(&mut writer)
+    }
 }
 
 impl WriteStl for Mesh {
     fn write_stl(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
         write_stl(&self.vertices(), self.num_props(), &self.indices(), writer)
     }
+
+    fn write_stl_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_stl_binary(&self.vertices(), self.num_props(), &self.indices(), writer)
+    }
 }
 
 impl WriteStl for Manifold {
     fn write_stl(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
         self.to_mesh().write_stl(writer)
     }
+
+    fn write_stl_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.to_mesh().write_stl_binary(writer)
+    }
 }
 
 impl WritePly for Mesh {
     fn write_ply(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
-        write_ply(&self.vertices(), self.num_props(), &self.indices(), writer)
+        // `Mesh`'s vertex properties come straight from the manifold
+        // kernel, which has no color channel, so `has_color` is always
+        // false here; it exists for callers writing their own
+        // `VertexPos3Color`/`VertexPos3NormalColor` buffers directly
+        // through the free `write_ply`/`write_ply_binary` functions.
+        write_ply(&self.vertices(), self.num_props(), &self.indices(), false, writer)
+    }
+
+    fn write_ply_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_ply_binary(&self.vertices(), self.num_props(), &self.indices(), false, writer)
     }
 }
 
@@ -234,4 +483,494 @@ impl WritePly for Manifold {
     fn write_ply(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
         self.to_mesh().write_ply(writer)
     }
+
+    fn write_ply_binary(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.to_mesh().write_ply_binary(writer)
+    }
+}
+
+/// Write vertices/indices as a Wavefront OBJ. Emits `vn` normals when
+/// `num_props` includes them (i.e. is 6, matching [`VertexPos3Normal`]);
+/// `f` face lines reference the `v`/`vn` pair by the same index either way,
+/// since OBJ requires a matching count of normal references per face.
+pub fn write_obj(
+    vertices: &[f32],
+    num_props: u32,
+    indices: &[u32],
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let num_props = num_props as usize;
+    assert!(
+        vertices.len() % num_props == 0,
+        "Number of vertices elements must be divisible by num_props"
+    );
+    assert!(
+        indices.len() % 3 == 0,
+        "Number of indices must be divisible by 3"
+    );
+
+    let has_normals = num_props >= 6;
+
+    for chunk in vertices.chunks(num_props) {
+        writeln!(writer, "v {} {} {}", chunk[0], chunk[1], chunk[2])?;
+    }
+    if has_normals {
+        for chunk in vertices.chunks(num_props) {
+            writeln!(writer, "vn {} {} {}", chunk[3], chunk[4], chunk[5])?;
+        }
+    }
+
+    for triangle in indices.chunks(3) {
+        // OBJ indices are 1-based.
+        let (a, b, c) = (triangle[0] + 1, triangle[1] + 1, triangle[2] + 1);
+        if has_normals {
+            writeln!(writer, "f {a}//{a} {b}//{b} {c}//{c}")?;
+        } else {
+            writeln!(writer, "f {a} {b} {c}")?;
+        }
+    }
+
+    Ok(())
+}
+
+pub trait WriteObj {
+    fn write_obj(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
+    fn write_obj_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::fs::File::create(filename)?;
+        self.write_obj(&mut writer)
+    }
+}
+
+impl WriteObj for Mesh {
+    fn write_obj(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_obj(&self.vertices(), self.num_props(), &self.indices(), writer)
+    }
+}
+
+impl WriteObj for Manifold {
+    fn write_obj(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.to_mesh().write_obj(writer)
+    }
+}
+
+/// Write vertices/indices as a minimal, single-mesh binary glTF (`.glb`):
+/// one buffer holding all positions, then all normals (when `num_props` has
+/// them), then the indices, as separate non-interleaved blocks, one
+/// bufferView per accessor, and
+/// the smallest possible `scene`/`node`/`mesh` wrapper around them. Good
+/// enough to round-trip through any glTF viewer; it doesn't attempt
+/// materials, textures, or multi-primitive meshes.
+pub fn write_gltf(
+    vertices: &[f32],
+    num_props: u32,
+    indices: &[u32],
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let num_props = num_props as usize;
+    let has_normals = num_props >= 6;
+    let vertex_count = vertices.len() / num_props;
+
+    let mut positions = Vec::with_capacity(vertex_count * 3);
+    let mut normals = Vec::with_capacity(if has_normals { vertex_count * 3 } else { 0 });
+    for chunk in vertices.chunks(num_props) {
+        positions.extend_from_slice(&chunk[0..3]);
+        if has_normals {
+            normals.extend_from_slice(&chunk[3..6]);
+        }
+    }
+
+    let mut bin = Vec::new();
+    let positions_offset = bin.len();
+    for f in &positions {
+        bin.extend_from_slice(&f.to_le_bytes());
+    }
+    let normals_offset = bin.len();
+    for f in &normals {
+        bin.extend_from_slice(&f.to_le_bytes());
+    }
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+    let indices_offset = bin.len();
+    for i in indices {
+        bin.extend_from_slice(&i.to_le_bytes());
+    }
+    while bin.len() % 4 != 0 {
+        bin.push(0);
+    }
+
+    let (min, max) = positions
+        .chunks(3)
+        .fold(([f32::MAX; 3], [f32::MIN; 3]), |(mut min, mut max), p| {
+            for i in 0..3 {
+                min[i] = min[i].min(p[i]);
+                max[i] = max[i].max(p[i]);
+            }
+            (min, max)
+        });
+
+    // glTF's JSON chunk is small and fixed-shape enough to build by hand,
+    // matching how `write_stl`/`write_ply` above emit their own formats
+    // directly rather than pulling in a serialization crate.
+    let mut buffer_views = format!(
+        "{{\"buffer\":0,\"byteOffset\":{positions_offset},\"byteLength\":{},\"target\":34962}}",
+        positions.len() * 4
+    );
+    let mut accessors = format!(
+        "{{\"bufferView\":0,\"componentType\":5126,\"count\":{vertex_count},\"type\":\"VEC3\",\
+         \"min\":[{},{},{}],\"max\":[{},{},{}]}}",
+        min[0], min[1], min[2], max[0], max[1], max[2]
+    );
+    let mut attributes = "\"POSITION\":0".to_string();
+
+    if has_normals {
+        buffer_views += &format!(
+            ",{{\"buffer\":0,\"byteOffset\":{normals_offset},\"byteLength\":{},\"target\":34962}}",
+            normals.len() * 4
+        );
+        accessors += &format!(",{{\"bufferView\":1,\"componentType\":5126,\"count\":{vertex_count},\"type\":\"VEC3\"}}");
+        attributes += ",\"NORMAL\":1";
+    }
+
+    let indices_bufferview = if has_normals { 2 } else { 1 };
+    let indices_accessor = if has_normals { 2 } else { 1 };
+    buffer_views += &format!(
+        ",{{\"buffer\":0,\"byteOffset\":{indices_offset},\"byteLength\":{},\"target\":34963}}",
+        indices.len() * 4
+    );
+    accessors += &format!(
+        ",{{\"bufferView\":{indices_bufferview},\"componentType\":5125,\"count\":{},\"type\":\"SCALAR\"}}",
+        indices.len()
+    );
+
+    let json = format!(
+        "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"manifold-rs\"}},\
+         \"scene\":0,\"scenes\":[{{\"nodes\":[0]}}],\"nodes\":[{{\"mesh\":0}}],\
+         \"meshes\":[{{\"primitives\":[{{\"attributes\":{{{attributes}}},\"indices\":{indices_accessor},\"mode\":4}}]}}],\
+         \"buffers\":[{{\"byteLength\":{}}}],\"bufferViews\":[{buffer_views}],\"accessors\":[{accessors}]}}",
+        bin.len()
+    );
+    let mut json_bytes = json.into_bytes();
+    while json_bytes.len() % 4 != 0 {
+        json_bytes.push(b' ');
+    }
+
+    // GLB container: 12-byte header, then a JSON chunk, then a BIN chunk.
+    let total_len = 12 + 8 + json_bytes.len() + 8 + bin.len();
+    writer.write_all(b"glTF")?;
+    writer.write_all(&2u32.to_le_bytes())?;
+    writer.write_all(&(total_len as u32).to_le_bytes())?;
+
+    writer.write_all(&(json_bytes.len() as u32).to_le_bytes())?;
+    writer.write_all(b"JSON")?;
+    writer.write_all(&json_bytes)?;
+
+    writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+    writer.write_all(b"BIN\0")?;
+    writer.write_all(&bin)?;
+
+    Ok(())
+}
+
+pub trait WriteGltf {
+    fn write_gltf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
+    fn write_gltf_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::fs::File::create(filename)?;
+        self.write_gltf(&mut writer)
+    }
+}
+
+impl WriteGltf for Mesh {
+    fn write_gltf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_gltf(&self.vertices(), self.num_props(), &self.indices(), writer)
+    }
+}
+
+impl WriteGltf for Manifold {
+    fn write_gltf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.to_mesh().write_gltf(writer)
+    }
+}
+
+/// IEEE CRC-32 of `data`, needed for each entry in the uncompressed ZIP
+/// package [`write_3mf`] builds by hand, the same way [`write_gltf`] builds
+/// its own binary chunks directly rather than pulling in a packaging crate.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        }
+    }
+    !crc
+}
+
+/// A single uncompressed ("stored") entry to append to a minimal ZIP
+/// package, written by [`write_zip_store`].
+struct ZipEntry<'a> {
+    name: &'a str,
+    data: Vec<u8>,
+}
+
+/// Pack `entries` into an uncompressed ZIP archive (store method, no
+/// compression), the container format a 3MF package is. Good enough to
+/// open in any 3MF-aware viewer or slicer; doesn't attempt Deflate since
+/// none of this crate's other writers pull in a compression dependency
+/// either.
+fn write_zip_store(entries: &[ZipEntry], writer: &mut impl std::io::Write) -> std::io::Result<()> {
+    let mut central_directory = Vec::new();
+    let mut local_offset = 0u32;
+    let mut body = Vec::new();
+
+    for entry in entries {
+        let crc = crc32(&entry.data);
+        let size = entry.data.len() as u32;
+        let name_bytes = entry.name.as_bytes();
+
+        body.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        body.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        body.extend_from_slice(&0u16.to_le_bytes()); // flags
+        body.extend_from_slice(&0u16.to_le_bytes()); // compression: store
+        body.extend_from_slice(&0u16.to_le_bytes()); // mod time
+        body.extend_from_slice(&0u16.to_le_bytes()); // mod date
+        body.extend_from_slice(&crc.to_le_bytes());
+        body.extend_from_slice(&size.to_le_bytes()); // compressed size
+        body.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        body.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+        body.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        body.extend_from_slice(name_bytes);
+        body.extend_from_slice(&entry.data);
+
+        central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central_directory.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // flags
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // compression
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod time
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod date
+        central_directory.extend_from_slice(&crc.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&size.to_le_bytes());
+        central_directory.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // file comment length
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // disk number start
+        central_directory.extend_from_slice(&0u16.to_le_bytes()); // internal file attrs
+        central_directory.extend_from_slice(&0u32.to_le_bytes()); // external file attrs
+        central_directory.extend_from_slice(&local_offset.to_le_bytes());
+        central_directory.extend_from_slice(name_bytes);
+
+        local_offset += 30 + name_bytes.len() as u32 + size;
+    }
+
+    let central_directory_offset = body.len() as u32;
+    writer.write_all(&body)?;
+    writer.write_all(&central_directory)?;
+
+    writer.write_all(&0x0605_4b50u32.to_le_bytes())?;
+    writer.write_all(&0u16.to_le_bytes())?; // disk number
+    writer.write_all(&0u16.to_le_bytes())?; // disk with central directory
+    writer.write_all(&(entries.len() as u16).to_le_bytes())?; // entries on this disk
+    writer.write_all(&(entries.len() as u16).to_le_bytes())?; // total entries
+    writer.write_all(&(central_directory.len() as u32).to_le_bytes())?;
+    writer.write_all(&central_directory_offset.to_le_bytes())?;
+    writer.write_all(&0u16.to_le_bytes())?; // comment length
+
+    Ok(())
+}
+
+/// Write vertices/indices as a 3MF package: an uncompressed ZIP containing
+/// `[Content_Types].xml`, `_rels/.rels`, and `3D/3dmodel.model`. When
+/// `triangle_material_ids` is given (one id per triangle, as produced by
+/// e.g. [`crate::properties::TaggedManifold::label_faces`]), each distinct
+/// id becomes a `<basematerials>` entry and every triangle references its
+/// group via `pid`/`p1`, so a multi-material boolean result (e.g. a red
+/// cutter leaving red walls) round-trips its per-face materials into
+/// slicers and viewers.
+pub fn write_3mf(
+    vertices: &[f32],
+    num_props: u32,
+    indices: &[u32],
+    triangle_material_ids: Option<&[u32]>,
+    writer: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    let num_props = num_props as usize;
+    assert!(
+        vertices.len() % num_props == 0,
+        "Number of vertices elements must be divisible by num_props"
+    );
+    assert!(
+        indices.len() % 3 == 0,
+        "Number of indices must be divisible by 3"
+    );
+    if let Some(ids) = triangle_material_ids {
+        assert_eq!(
+            ids.len(),
+            indices.len() / 3,
+            "triangle_material_ids must supply one id per triangle"
+        );
+    }
+
+    let content_types = br#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>
+<Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/>
+</Types>
+"#
+    .to_vec();
+
+    let rels = br#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/>
+</Relationships>
+"#
+    .to_vec();
+
+    let distinct_material_ids: Vec<u32> = match triangle_material_ids {
+        Some(ids) => {
+            let mut seen: Vec<u32> = ids.to_vec();
+            seen.sort_unstable();
+            seen.dedup();
+            seen
+        }
+        None => Vec::new(),
+    };
+
+    let mut model = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>
+<model unit="millimeter" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+<resources>
+"#);
+
+    if !distinct_material_ids.is_empty() {
+        model += "<basematerials id=\"1\">\n";
+        for id in &distinct_material_ids {
+            model += &format!("<base name=\"Material{id}\" displaycolor=\"#CCCCCCFF\"/>\n");
+        }
+        model += "</basematerials>\n";
+    }
+
+    model += "<object id=\"2\" type=\"model\">\n<mesh>\n<vertices>\n";
+    for chunk in vertices.chunks(num_props) {
+        model += &format!("<vertex x=\"{}\" y=\"{}\" z=\"{}\"/>\n", chunk[0], chunk[1], chunk[2]);
+    }
+    model += "</vertices>\n<triangles>\n";
+    for (tri_idx, triangle) in indices.chunks(3).enumerate() {
+        match triangle_material_ids {
+            Some(ids) => {
+                let group = distinct_material_ids.iter().position(|&id| id == ids[tri_idx]).unwrap();
+                model += &format!(
+                    "<triangle v1=\"{}\" v2=\"{}\" v3=\"{}\" pid=\"1\" p1=\"{group}\"/>\n",
+                    triangle[0], triangle[1], triangle[2]
+                );
+            }
+            None => {
+                model += &format!("<triangle v1=\"{}\" v2=\"{}\" v3=\"{}\"/>\n", triangle[0], triangle[1], triangle[2]);
+            }
+        }
+    }
+    model += "</triangles>\n</mesh>\n</object>\n</resources>\n<build>\n<item objectid=\"2\"/>\n</build>\n</model>\n";
+
+    write_zip_store(
+        &[
+            ZipEntry { name: "[Content_Types].xml", data: content_types },
+            ZipEntry { name: "_rels/.rels", data: rels },
+            ZipEntry { name: "3D/3dmodel.model", data: model.into_bytes() },
+        ],
+        writer,
+    )
+}
+
+pub trait WriteThreeMf {
+    fn write_3mf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
+    fn write_3mf_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let mut writer = std::fs::File::create(filename)?;
+        self.write_3mf(&mut writer)
+    }
+}
+
+impl WriteThreeMf for Mesh {
+    fn write_3mf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_3mf(&self.vertices(), self.num_props(), &self.indices(), None, writer)
+    }
+}
+
+impl WriteThreeMf for Manifold {
+    fn write_3mf(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        self.to_mesh().write_3mf(writer)
+    }
+}
+
+/// Which format [`MeshExport::write_to_file`]/[`MeshExport::write_to_writer`]
+/// should emit. [`ExportFormat::from_extension`] maps a file extension to one
+/// of these so callers can just pass a path and let the format follow from it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Stl,
+    Ply,
+    Obj,
+    Gltf,
+    ThreeMf,
+}
+
+impl ExportFormat {
+    /// Map a file extension (case-insensitive, no leading dot) to its
+    /// format, or `None` if it isn't one `MeshExport` supports.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_ascii_lowercase().as_str() {
+            "stl" => Some(Self::Stl),
+            "ply" => Some(Self::Ply),
+            "obj" => Some(Self::Obj),
+            "glb" | "gltf" => Some(Self::Gltf),
+            "3mf" => Some(Self::ThreeMf),
+            _ => None,
+        }
+    }
+}
+
+/// A single entry point for every mesh serialization format this crate
+/// supports, so callers (and the examples in this workspace) don't need to
+/// match on `WriteStl`/`WritePly`/`WriteObj`/`WriteGltf` themselves — they
+/// can pick a format explicitly or let [`Self::write_to_file`] infer it from
+/// the output path's extension.
+pub trait MeshExport {
+    fn write_to_writer(&self, format: ExportFormat, writer: &mut impl std::io::Write) -> std::io::Result<()>;
+
+    /// Write to `filename`, inferring the format from its extension.
+    ///
+    /// # Errors
+    /// Returns an `InvalidInput` error if the extension isn't recognized.
+    fn write_to_file(&self, filename: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        let path = filename.as_ref();
+        let format = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(ExportFormat::from_extension)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("unrecognized export extension: {}", path.display()),
+                )
+            })?;
+        let mut writer = std::fs::File::create(path)?;
+        self.write_to_writer(format, &mut writer)
+    }
+}
+
+impl<T> MeshExport for T
+where
+    T: WriteStl + WritePly + WriteObj + WriteGltf + WriteThreeMf,
+{
+    fn write_to_writer(&self, format: ExportFormat, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        match format {
+            ExportFormat::Stl => self.write_stl(writer),
+            ExportFormat::Ply => self.write_ply(writer),
+            ExportFormat::Obj => self.write_obj(writer),
+            ExportFormat::Gltf => self.write_gltf(writer),
+            ExportFormat::ThreeMf => self.write_3mf(writer),
+        }
+    }
 }
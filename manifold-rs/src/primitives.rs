@@ -0,0 +1,180 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! A small library of primitive manifolds, mirroring Manifold's own
+//! primitives plus a Bevy-style sphere kind selector.
+
+use crate::ops;
+use crate::Manifold;
+
+/// Maximum vertex count an icosphere subdivision is allowed to produce,
+/// matching the sane-budget behavior users expect from Bevy's `SphereKind`.
+const MAX_ICOSPHERE_VERTICES: usize = 1_000_000;
+
+/// Selects how [`Manifold::sphere_kind`] tessellates a sphere.
+pub enum SphereKind {
+    /// Latitude/longitude rings, i.e. Manifold's native UV sphere.
+    Uv {
+        /// Number of circular segments (the `segments` of [`Manifold::sphere`]).
+        segments: u32,
+    },
+    /// An icosphere: an icosahedron, recursively subdivided `subdivisions`
+    /// times with each new midpoint projected back onto the sphere.
+    Ico {
+        /// Number of recursive subdivisions.
+        subdivisions: u32,
+    },
+}
+
+impl Manifold {
+    /// Create a cube manifold of the given `size`, optionally centered at the
+    /// origin, mirroring Manifold's native `Cube(size, center)` primitive.
+    pub fn cube_primitive(size: [f64; 3], center: bool) -> Self {
+        let cube = Self::cube(size[0], size[1], size[2]);
+        if center {
+            cube.translate(-size[0] / 2.0, -size[1] / 2.0, -size[2] / 2.0)
+        } else {
+            cube
+        }
+    }
+
+    /// Create a tetrahedron manifold with unit circumradius, centered at the
+    /// origin.
+    pub fn tetrahedron() -> Self {
+        let a = 1.0_f32 / ops::sqrt_f32(3.0);
+        let vertices: [f32; 12] = [
+            a, a, a, //
+            a, -a, -a, //
+            -a, a, -a, //
+            -a, -a, a,
+        ];
+        let indices: [u32; 12] = [0, 1, 2, 0, 3, 1, 0, 2, 3, 1, 3, 2];
+
+        crate::Mesh::new(&vertices, &indices).to_manifold()
+    }
+
+    /// Create a sphere manifold using an explicit tessellation strategy.
+    ///
+    /// Panics if an icosphere's subdivision count would exceed a sane vertex
+    /// budget, matching the behavior users expect from Bevy's builder.
+    pub fn sphere_kind(radius: f64, kind: SphereKind) -> Self {
+        match kind {
+            SphereKind::Uv { segments } => Self::sphere(radius, segments),
+            SphereKind::Ico { subdivisions } => Self::icosphere(radius, subdivisions),
+        }
+    }
+
+    /// Build an icosphere by recursively subdividing an icosahedron
+    /// `subdivisions` times, projecting each new midpoint back onto the
+    /// sphere of the given `radius`.
+    fn icosphere(radius: f64, subdivisions: u32) -> Self {
+        let expected_vertices = 12usize.saturating_mul(4usize.saturating_pow(subdivisions));
+        assert!(
+            expected_vertices <= MAX_ICOSPHERE_VERTICES,
+            "icosphere subdivision {subdivisions} would produce ~{expected_vertices} vertices, \
+             exceeding the {MAX_ICOSPHERE_VERTICES} vertex budget"
+        );
+
+        let t = (1.0 + ops::sqrt(5.0)) / 2.0;
+        let mut positions: Vec<[f64; 3]> = vec![
+            [-1.0, t, 0.0],
+            [1.0, t, 0.0],
+            [-1.0, -t, 0.0],
+            [1.0, -t, 0.0],
+            [0.0, -1.0, t],
+            [0.0, 1.0, t],
+            [0.0, -1.0, -t],
+            [0.0, 1.0, -t],
+            [t, 0.0, -1.0],
+            [t, 0.0, 1.0],
+            [-t, 0.0, -1.0],
+            [-t, 0.0, 1.0],
+        ];
+        for p in &mut positions {
+            *p = normalize(*p);
+        }
+
+        let mut indices: Vec<[u32; 3]> = vec![
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ];
+
+        let mut midpoint_cache = std::collections::HashMap::new();
+        for _ in 0..subdivisions {
+            let mut next_indices = Vec::with_capacity(indices.len() * 4);
+            for [a, b, c] in indices {
+                let ab = midpoint(&mut positions, &mut midpoint_cache, a, b);
+                let bc = midpoint(&mut positions, &mut midpoint_cache, b, c);
+                let ca = midpoint(&mut positions, &mut midpoint_cache, c, a);
+
+                next_indices.push([a, ab, ca]);
+                next_indices.push([b, bc, ab]);
+                next_indices.push([c, ca, bc]);
+                next_indices.push([ab, bc, ca]);
+            }
+            indices = next_indices;
+        }
+
+        let vertices: Vec<f32> = positions
+            .iter()
+            .flat_map(|p| {
+                [
+                    (p[0] * radius) as f32,
+                    (p[1] * radius) as f32,
+                    (p[2] * radius) as f32,
+                ]
+            })
+            .collect();
+        let flat_indices: Vec<u32> = indices.iter().flatten().copied().collect();
+
+        crate::Mesh::new(&vertices, &flat_indices).to_manifold()
+    }
+}
+
+fn normalize(p: [f64; 3]) -> [f64; 3] {
+    let len = ops::sqrt(p[0] * p[0] + p[1] * p[1] + p[2] * p[2]);
+    [p[0] / len, p[1] / len, p[2] / len]
+}
+
+fn midpoint(
+    positions: &mut Vec<[f64; 3]>,
+    cache: &mut std::collections::HashMap<(u32, u32), u32>,
+    a: u32,
+    b: u32,
+) -> u32 {
+    let key = if a < b { (a, b) } else { (b, a) };
+    if let Some(&idx) = cache.get(&key) {
+        return idx;
+    }
+
+    let pa = positions[a as usize];
+    let pb = positions[b as usize];
+    let mid = normalize([
+        (pa[0] + pb[0]) / 2.0,
+        (pa[1] + pb[1]) / 2.0,
+        (pa[2] + pb[2]) / 2.0,
+    ]);
+
+    let idx = positions.len() as u32;
+    positions.push(mid);
+    cache.insert(key, idx);
+    idx
+}
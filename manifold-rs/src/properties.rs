@@ -0,0 +1,171 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Named, arbitrary per-vertex property channels that survive `boolean_op`.
+//!
+//! Manifold's mesh representation already carries whatever extra float
+//! properties are baked into its vertices past position (and normal); this
+//! module just gives those extra floats names so they can be attached before
+//! a boolean op and read back afterwards.
+
+use crate::{BooleanOp, Manifold, Mesh};
+
+/// Describes a single named property channel within a vertex's property
+/// slice, e.g. `{ name: "color", offset: 6, size: 4 }` for an RGBA channel
+/// sitting after a position+normal (offset 6) vertex.
+#[derive(Clone, Debug)]
+pub struct PropertyChannel {
+    /// Channel name, e.g. `"color"` or `"material_id"`.
+    pub name: String,
+    /// Float offset into each vertex's property slice.
+    pub offset: usize,
+    /// Number of floats in the channel.
+    pub size: usize,
+}
+
+/// A [`Manifold`] paired with the layout of its named property channels.
+///
+/// `boolean_op` carries the layout through unchanged: Manifold's kernel
+/// already interpolates/splits arbitrary vertex properties along cut edges,
+/// so only the two operands' layouts need to agree.
+pub struct TaggedManifold {
+    manifold: Manifold,
+    channels: Vec<PropertyChannel>,
+    num_props: usize,
+}
+
+impl TaggedManifold {
+    /// Build a `TaggedManifold` from a mesh whose vertices already contain
+    /// the given property channels, interleaved after position.
+    pub fn new(vertices: &[f32], indices: &[u32], channels: Vec<PropertyChannel>) -> Self {
+        let layout_props = channels.iter().map(|c| c.offset + c.size).max().unwrap_or(3).max(3);
+        let manifold = Mesh::with_num_props(vertices, layout_props as u32, indices).to_manifold();
+        let num_props = manifold.to_mesh().num_props() as usize;
+        Self {
+            manifold,
+            channels,
+            num_props,
+        }
+    }
+
+    /// Wrap an existing [`Manifold`] with a property layout describing its
+    /// current vertex properties, without rebuilding any geometry.
+    pub fn from_manifold(manifold: Manifold, channels: Vec<PropertyChannel>) -> Self {
+        let num_props = manifold.to_mesh().num_props() as usize;
+        Self {
+            manifold,
+            channels,
+            num_props,
+        }
+    }
+
+    /// Set (or overwrite) a named property channel on every vertex, rebuilding
+    /// the underlying mesh so the new channel's floats are interleaved into
+    /// each vertex's property slice.
+    ///
+    /// `data` must contain `stride` floats per vertex, in vertex order.
+    pub fn set_property_channel(&mut self, name: &str, stride: usize, data: &[f32]) {
+        let mesh = self.manifold.to_mesh();
+        let old_props = self.num_props;
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        let num_verts = vertices.len() / old_props.max(1);
+        assert_eq!(
+            data.len(),
+            num_verts * stride,
+            "property channel data must supply `stride` floats per vertex"
+        );
+
+        let offset = match self.channels.iter().position(|c| c.name == name) {
+            Some(i) => {
+                let offset = self.channels[i].offset;
+                self.channels[i].size = stride;
+                offset
+            }
+            None => {
+                let offset = old_props;
+                self.channels.push(PropertyChannel {
+                    name: name.to_string(),
+                    offset,
+                    size: stride,
+                });
+                offset
+            }
+        };
+
+        let new_props = self.channels.iter().map(|c| c.offset + c.size).max().unwrap_or(old_props);
+        let mut new_vertices = Vec::with_capacity(num_verts * new_props);
+        for v in 0..num_verts {
+            let old_slice = &vertices[v * old_props..(v + 1) * old_props];
+            let mut row = vec![0.0f32; new_props];
+            row[..old_props].copy_from_slice(old_slice);
+            row[offset..offset + stride].copy_from_slice(&data[v * stride..(v + 1) * stride]);
+            new_vertices.extend_from_slice(&row);
+        }
+
+        self.manifold = Mesh::with_num_props(&new_vertices, new_props as u32, &indices).to_manifold();
+        self.num_props = new_props;
+    }
+
+    /// Tag each triangle's three vertices with a per-face label value (e.g.
+    /// "which solid did this triangle come from") via `labeler(triangle_idx)`.
+    pub fn label_faces(&mut self, name: &str, labeler: impl Fn(usize) -> f32) {
+        let mesh = self.manifold.to_mesh();
+        let num_props = self.num_props;
+        let vertices = mesh.vertices();
+        let num_verts = vertices.len() / num_props.max(1);
+        let indices = mesh.indices();
+
+        let mut labels = vec![0.0f32; num_verts];
+        for (tri_idx, tri) in indices.chunks_exact(3).enumerate() {
+            let label = labeler(tri_idx);
+            for &i in tri {
+                labels[i as usize] = label;
+            }
+        }
+
+        self.set_property_channel(name, 1, &labels);
+    }
+
+    /// Read back the current values of a named channel, one `size`-float
+    /// chunk per vertex.
+    pub fn channel_values(&self, name: &str) -> Option<Vec<f32>> {
+        let channel = self.channels.iter().find(|c| c.name == name)?;
+        let mesh = self.manifold.to_mesh();
+        let vertices = mesh.vertices();
+        let num_verts = vertices.len() / self.num_props.max(1);
+
+        let mut values = Vec::with_capacity(num_verts * channel.size);
+        for v in 0..num_verts {
+            let base = v * self.num_props + channel.offset;
+            values.extend_from_slice(&vertices[base..base + channel.size]);
+        }
+        Some(values)
+    }
+
+    /// Run a boolean operation, preserving the (matching) property layout of
+    /// both operands.
+    pub fn boolean_op(&self, other: &Self, op: BooleanOp) -> Self {
+        assert_eq!(
+            self.channels.len(),
+            other.channels.len(),
+            "boolean_op operands must share the same property channel layout"
+        );
+
+        Self {
+            manifold: self.manifold.boolean_op(&other.manifold, op),
+            channels: self.channels.clone(),
+            num_props: self.num_props,
+        }
+    }
+
+    /// Borrow the underlying [`Manifold`].
+    pub fn manifold(&self) -> &Manifold {
+        &self.manifold
+    }
+
+    /// List the currently attached property channels.
+    pub fn channels(&self) -> &[PropertyChannel] {
+        &self.channels
+    }
+}
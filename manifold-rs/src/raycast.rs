@@ -0,0 +1,237 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Ray–manifold intersection queries for picking and headless tooling.
+
+use crate::bvh::TriangleBvh;
+use crate::Manifold;
+
+/// The result of a successful [`Manifold::raycast`].
+pub struct RayHit {
+    /// Index of the hit triangle (into the manifold's mesh indices, divided
+    /// by 3).
+    pub triangle: usize,
+    /// Distance from the ray origin to the hit point.
+    pub t: f64,
+    /// Barycentric coordinates of the hit point within the triangle.
+    pub barycentric: [f64; 3],
+    /// Interpolated position at the hit point.
+    pub position: [f64; 3],
+    /// Interpolated normal at the hit point, if the mesh carries normals.
+    pub normal: Option<[f64; 3]>,
+}
+
+impl Manifold {
+    /// Cast a ray against the manifold's triangles and return the nearest
+    /// hit in front of the origin (front- or back-facing), or `None` if the
+    /// ray misses.
+    ///
+    /// Triangles are organized into a [`TriangleBvh`] and traversed
+    /// front-to-back, pruning any subtree whose slab-test entry distance
+    /// already exceeds the nearest hit found so far, so a pick ray against a
+    /// high-triangle-count boolean result doesn't have to test every
+    /// triangle.
+    pub fn raycast(&self, origin: [f64; 3], dir: [f64; 3]) -> Option<RayHit> {
+        if !ray_hits_box(origin, dir, self.bounding_box()) {
+            return None;
+        }
+
+        let mesh = self.to_mesh();
+        let num_props = mesh.num_props() as usize;
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        let bvh = TriangleBvh::build(&mesh);
+
+        let pos = |i: u32| -> [f64; 3] {
+            let base = i as usize * num_props;
+            [
+                vertices[base] as f64,
+                vertices[base + 1] as f64,
+                vertices[base + 2] as f64,
+            ]
+        };
+        let normal = |i: u32| -> Option<[f64; 3]> {
+            if num_props >= 6 {
+                let base = i as usize * num_props;
+                Some([
+                    vertices[base + 3] as f64,
+                    vertices[base + 4] as f64,
+                    vertices[base + 5] as f64,
+                ])
+            } else {
+                None
+            }
+        };
+
+        let origin_f32 = [origin[0] as f32, origin[1] as f32, origin[2] as f32];
+        let dir_f32 = [dir[0] as f32, dir[1] as f32, dir[2] as f32];
+
+        let mut best: Option<RayHit> = None;
+        bvh.raycast(origin_f32, dir_f32, |tri_idx| {
+            let tri = &indices[tri_idx as usize * 3..tri_idx as usize * 3 + 3];
+            let (i0, i1, i2) = (tri[0], tri[1], tri[2]);
+            let (v0, v1, v2) = (pos(i0), pos(i1), pos(i2));
+
+            let (t, u, v) = moller_trumbore(origin, dir, v0, v1, v2)?;
+            if best.as_ref().map_or(false, |hit| t >= hit.t) {
+                return Some(t as f32);
+            }
+
+            let w = 1.0 - u - v;
+            let position = [
+                w * v0[0] + u * v1[0] + v * v2[0],
+                w * v0[1] + u * v1[1] + v * v2[1],
+                w * v0[2] + u * v1[2] + v * v2[2],
+            ];
+            let normal = match (normal(i0), normal(i1), normal(i2)) {
+                (Some(n0), Some(n1), Some(n2)) => Some([
+                    w * n0[0] + u * n1[0] + v * n2[0],
+                    w * n0[1] + u * n1[1] + v * n2[1],
+                    w * n0[2] + u * n1[2] + v * n2[2],
+                ]),
+                _ => None,
+            };
+
+            best = Some(RayHit {
+                triangle: tri_idx as usize,
+                t,
+                barycentric: [w, u, v],
+                position,
+                normal,
+            });
+            Some(t as f32)
+        });
+
+        best
+    }
+
+    /// Test whether `point` lies inside this manifold's enclosed volume, via
+    /// the parity of ray crossings: cast a ray from `point` along an
+    /// arbitrary fixed direction and count how many triangles it crosses in
+    /// front of it. An odd count means `point` is enclosed by the (closed,
+    /// watertight) surface; an even count, including zero, means it's
+    /// outside.
+    pub fn contains(&self, point: [f64; 3]) -> bool {
+        let (min, max) = self.bounding_box();
+        if (0..3).any(|axis| point[axis] < min[axis] || point[axis] > max[axis]) {
+            return false;
+        }
+
+        let mesh = self.to_mesh();
+        let num_props = mesh.num_props() as usize;
+        let vertices = mesh.vertices();
+        let indices = mesh.indices();
+        let bvh = TriangleBvh::build(&mesh);
+
+        let pos = |i: u32| -> [f64; 3] {
+            let base = i as usize * num_props;
+            [
+                vertices[base] as f64,
+                vertices[base + 1] as f64,
+                vertices[base + 2] as f64,
+            ]
+        };
+
+        // Any fixed direction works for the parity test; this one avoids
+        // being axis-aligned with a typical bounding box face, which would
+        // otherwise risk grazing an edge or vertex exactly.
+        const DIR: [f64; 3] = [0.6133574961, 0.5081648714, 0.6051747697];
+        let origin_f32 = [point[0] as f32, point[1] as f32, point[2] as f32];
+        let dir_f32 = [DIR[0] as f32, DIR[1] as f32, DIR[2] as f32];
+
+        let mut crossings = 0u32;
+        for tri_idx in bvh.ray_candidates(origin_f32, dir_f32) {
+            let tri = &indices[tri_idx as usize * 3..tri_idx as usize * 3 + 3];
+            let (v0, v1, v2) = (pos(tri[0]), pos(tri[1]), pos(tri[2]));
+            if moller_trumbore(point, DIR, v0, v1, v2).is_some() {
+                crossings += 1;
+            }
+        }
+
+        crossings % 2 == 1
+    }
+}
+
+/// Standard Möller–Trumbore ray-triangle intersection. Returns `(t, u, v)`
+/// for a hit at positive `t`; back-facing triangles are not culled.
+fn moller_trumbore(
+    origin: [f64; 3],
+    dir: [f64; 3],
+    v0: [f64; 3],
+    v1: [f64; 3],
+    v2: [f64; 3],
+) -> Option<(f64, f64, f64)> {
+    const EPSILON: f64 = 1e-9;
+
+    let edge1 = sub(v1, v0);
+    let edge2 = sub(v2, v0);
+    let h = cross(dir, edge2);
+    let a = dot(edge1, h);
+    if a.abs() < EPSILON {
+        return None; // Ray is parallel to the triangle.
+    }
+
+    let f = 1.0 / a;
+    let s = sub(origin, v0);
+    let u = f * dot(s, h);
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = cross(s, edge1);
+    let v = f * dot(dir, q);
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let t = f * dot(edge2, q);
+    if t > EPSILON {
+        Some((t, u, v))
+    } else {
+        None
+    }
+}
+
+fn ray_hits_box(origin: [f64; 3], dir: [f64; 3], (min, max): ([f64; 3], [f64; 3])) -> bool {
+    let mut t_min = f64::NEG_INFINITY;
+    let mut t_max = f64::INFINITY;
+
+    for axis in 0..3 {
+        if dir[axis].abs() < f64::EPSILON {
+            if origin[axis] < min[axis] || origin[axis] > max[axis] {
+                return false;
+            }
+            continue;
+        }
+
+        let inv_d = 1.0 / dir[axis];
+        let mut t0 = (min[axis] - origin[axis]) * inv_d;
+        let mut t1 = (max[axis] - origin[axis]) * inv_d;
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        t_min = t_min.max(t0);
+        t_max = t_max.min(t1);
+        if t_min > t_max {
+            return false;
+        }
+    }
+
+    t_max >= 0.0
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
@@ -0,0 +1,103 @@
+// Copyright © 2024 The µCAD authors <info@ucad.xyz>
+// SPDX-License-Identifier: Apache-2.0
+
+//! Per-vertex tangent generation for normal-mapped output.
+//!
+//! Manifold output carries no UVs yet, so this operates on caller-supplied
+//! position/normal/UV buffers rather than [`crate::Mesh`] directly. Once a UV
+//! property channel lands on [`crate::Manifold`], the Bevy conversion can call
+//! straight into [`generate_tangents`] to populate `ATTRIBUTE_TANGENT`.
+
+/// Computes a `[f32; 4]` tangent (xyz tangent, w handedness) per vertex using
+/// the standard Lengyel method.
+///
+/// `positions` and `normals` are per-vertex, `uvs` are per-vertex `[u, v]`,
+/// and `indices` is a flat triangle list. Degenerate/zero-area UV triangles
+/// are skipped during accumulation so they don't poison their vertices'
+/// tangent space.
+pub fn generate_tangents(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    uvs: &[[f32; 2]],
+    indices: &[u32],
+) -> Vec<[f32; 4]> {
+    assert_eq!(positions.len(), normals.len());
+    assert_eq!(positions.len(), uvs.len());
+
+    let mut tangents = vec![[0.0f32; 3]; positions.len()];
+    let mut bitangents = vec![[0.0f32; 3]; positions.len()];
+
+    for tri in indices.chunks_exact(3) {
+        let (i0, i1, i2) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+
+        let e1 = sub(positions[i1], positions[i0]);
+        let e2 = sub(positions[i2], positions[i0]);
+        let (du1, dv1) = (uvs[i1][0] - uvs[i0][0], uvs[i1][1] - uvs[i0][1]);
+        let (du2, dv2) = (uvs[i2][0] - uvs[i0][0], uvs[i2][1] - uvs[i0][1]);
+
+        let denom = du1 * dv2 - du2 * dv1;
+        if denom.abs() < f32::EPSILON {
+            // Degenerate/zero-area UV triangle: skip accumulation.
+            continue;
+        }
+        let r = 1.0 / denom;
+
+        let t = scale(sub(scale(e1, dv2), scale(e2, dv1)), r);
+        let b = scale(sub(scale(e2, du1), scale(e1, du2)), r);
+
+        for i in [i0, i1, i2] {
+            tangents[i] = add(tangents[i], t);
+            bitangents[i] = add(bitangents[i], b);
+        }
+    }
+
+    positions
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            let n = normals[i];
+            let t = tangents[i];
+            // Gram-Schmidt orthonormalize: T' = normalize(T - N * dot(N, T))
+            let t_ortho = normalize(sub(t, scale(n, dot(n, t))));
+            let w = if dot(cross(n, t_ortho), bitangents[i]) < 0.0 {
+                -1.0
+            } else {
+                1.0
+            };
+            [t_ortho[0], t_ortho[1], t_ortho[2], w]
+        })
+        .collect()
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: [f32; 3], s: f32) -> [f32; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let len = crate::ops::sqrt_f32(dot(a, a));
+    if len < f32::EPSILON {
+        [0.0, 0.0, 0.0]
+    } else {
+        scale(a, 1.0 / len)
+    }
+}
@@ -23,6 +23,8 @@ fn main() {
             DefaultPlugins.set(ImagePlugin::default_nearest()),
             MeshBooleanPlugin,
             StepPlugin,
+            OrbitCameraPlugin,
+            AutoFramePlugin,
         ))
         .insert_resource(ClearColor(Color::srgb(0.15, 0.15, 0.15)))
         .insert_resource(AmbientLight {
@@ -35,7 +37,6 @@ fn main() {
         .add_systems(Update, (
             load_step_and_setup_meshes,
             cycle_boolean_op,
-            orbit_camera,
             exit_on_q_key,
         ))
         .run();
@@ -53,16 +54,6 @@ struct SecondaryShape;
 #[derive(Component)]
 struct ResultShape;
 
-#[derive(Component)]
-struct OrbitCamera;
-
-#[derive(Resource, Default)]
-struct OrbitState {
-    angle: f32,
-    center: Vec3,
-    distance: f32,
-}
-
 #[derive(Component)]
 struct OperationText;
 
@@ -87,21 +78,15 @@ fn setup_scene(
         ..default()
     });
 
-    // Spawn camera with orbit capability
+    // Spawn camera with orbit capability: left-drag orbits, right/middle-drag
+    // pans, scroll zooms.
     commands.spawn((
         Camera3dBundle {
             transform: Transform::from_xyz(0.0, 2.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
             ..default()
         },
-        OrbitCamera,
+        OrbitCameraController::new(Vec3::ZERO, 10.0, 0.0, 10f32.to_radians()),
     ));
-    
-    // Initialize orbit state
-    commands.insert_resource(OrbitState {
-        angle: 0.0,
-        center: Vec3::ZERO,
-        distance: 10.0,
-    });
 }
 
 fn setup_ui(mut commands: Commands) {
@@ -125,32 +110,6 @@ fn setup_ui(mut commands: Commands) {
     ));
 }
 
-fn calculate_mesh_bounds(mesh: &Mesh) -> (Vec3, Vec3) {
-    if let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        match positions {
-            bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => {
-                if pos.is_empty() {
-                    return (Vec3::ZERO, Vec3::ZERO);
-                }
-                
-                let mut min_bound = Vec3::new(pos[0][0], pos[0][1], pos[0][2]);
-                let mut max_bound = min_bound;
-                
-                for vertex in pos.iter() {
-                    let v = Vec3::new(vertex[0], vertex[1], vertex[2]);
-                    min_bound = min_bound.min(v);
-                    max_bound = max_bound.max(v);
-                }
-                
-                (min_bound, max_bound)
-            },
-            _ => (Vec3::ZERO, Vec3::ZERO),
-        }
-    } else {
-        (Vec3::ZERO, Vec3::ZERO)
-    }
-}
-
 fn cycle_boolean_op(
     keys: Res<ButtonInput<KeyCode>>,
     mut op_state: ResMut<BooleanOpState>,
@@ -166,21 +125,6 @@ fn cycle_boolean_op(
     }
 }
 
-fn orbit_camera(
-    mut query: Query<&mut Transform, With<OrbitCamera>>,
-    mut orbit_state: ResMut<OrbitState>,
-) {
-    orbit_state.angle += 0.005; // Slowly rotate the camera
-    if let Ok(mut transform) = query.get_single_mut() {
-        let x = orbit_state.center.x + orbit_state.distance * orbit_state.angle.cos();
-        let z = orbit_state.center.z + orbit_state.distance * orbit_state.angle.sin();
-        let y = orbit_state.center.y + 2.0; // Keep a slight elevation
-        
-        *transform = Transform::from_translation(Vec3::new(x, y, z))
-            .looking_at(orbit_state.center, Vec3::Y);
-    }
-}
-
 fn exit_on_q_key(
     keys: Res<ButtonInput<KeyCode>>,
     mut exit: EventWriter<AppExit>,
@@ -12,6 +12,8 @@ fn main() {
         .add_plugins((
             DefaultPlugins.set(ImagePlugin::default_nearest()),
             MeshBooleanPlugin,
+            OrbitCameraPlugin,
+            AutoFramePlugin,
         ))
         .insert_resource(ClearColor(Color::srgb(0.15, 0.15, 0.15)))
         .insert_resource(AmbientLight {
@@ -21,7 +23,6 @@ fn main() {
         .add_systems(Startup, setup)
         .add_systems(Update, (
             cycle_boolean_op,
-            orbit_camera,
             exit_on_q_key,
         ))
         .run();
@@ -33,16 +34,6 @@ struct PrimaryShape;
 #[derive(Component)]
 struct SecondaryShape;
 
-#[derive(Component)]
-struct OrbitCamera;
-
-#[derive(Resource, Default)]
-struct OrbitState {
-    angle: f32,
-    center: Vec3,
-    distance: f32,
-}
-
 fn setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
@@ -126,21 +117,16 @@ fn setup(
         result_entity,
     });
 
-    // Camera with orbit capability
+    // Camera with orbit capability: left-drag orbits, right/middle-drag
+    // pans, scroll zooms — see `OrbitCameraController` for the tunable
+    // sensitivity/damping fields.
     commands.spawn((
         Camera3dBundle {
             transform: Transform::from_xyz(0.0, 2.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
             ..default()
         },
-        OrbitCamera,
+        OrbitCameraController::new(Vec3::ZERO, 10.0, 0.0, 10f32.to_radians()),
     ));
-    
-    // Initialize orbit state
-    commands.insert_resource(OrbitState {
-        angle: 0.0,
-        center: Vec3::ZERO,
-        distance: 10.0,
-    });
 
     // UI text
     commands.spawn((
@@ -181,21 +167,6 @@ fn cycle_boolean_op(
     }
 }
 
-// System for orbit camera
-fn orbit_camera(
-    mut query: Query<&mut Transform, With<OrbitCamera>>,
-    mut orbit_state: ResMut<OrbitState>,
-) {
-    orbit_state.angle += 0.005; // Slowly rotate the camera
-    if let Ok(mut transform) = query.get_single_mut() {
-        let x = orbit_state.center.x + orbit_state.distance * orbit_state.angle.cos();
-        let z = orbit_state.center.z + orbit_state.distance * orbit_state.angle.sin();
-        let y = orbit_state.center.y + 2.0; // Keep a slight elevation
-        
-        *transform = Transform::from_translation(Vec3::new(x, y, z))
-            .looking_at(orbit_state.center, Vec3::Y);
-    }
-}
 
 // System to exit when 'q' is pressed with error message
 fn exit_on_q_key(
@@ -0,0 +1,159 @@
+//! Auto-wire a boolean operation from named nodes in a single glTF/`.glb`
+//! scene, so the whole primary/secondary (or primary/N-operand) setup can be
+//! laid out by an artist in Blender and exported with zero per-entity Rust
+//! wiring: tag each operand node with a `"BooleanRole"` custom property
+//! (Blender's glTF exporter writes arbitrary custom properties straight
+//! through as that node's `extras` JSON, no addon or glTF extension needed)
+//! set to `"Primary"`, `"Secondary"`, or `{"Operand": "Subtract"}` (any
+//! [`BooleanOpState`] variant name). Once the scene finishes spawning,
+//! [`wire_boolean_blueprint`] reads that back out of Bevy's own
+//! [`GltfExtras`] (already attached per-node by `bevy_gltf`) and builds
+//! either a classic [`PrimaryBooleanMesh`]/[`SecondaryBooleanMesh`] pair —
+//! the same wiring [`MeshBooleanPlugin::spawn_boolean_operation`] builds by
+//! hand — or, when more than one secondary/operand node is present, a
+//! [`BooleanTree`] instead, since that's the construct already built for a
+//! flat list of per-operand ops.
+//!
+//! Only covers the common case of one glTF mesh primitive per node (where
+//! `bevy_gltf` puts `Handle<Mesh>` directly on the node entity); a node
+//! whose glTF mesh has several primitives gets its own child entities per
+//! primitive instead, which this doesn't walk into.
+
+use crate::{
+    BooleanEntityBundle, BooleanHandles, BooleanOpState, BooleanOperations, BooleanResultMaterials, BooleanTree, BooleanTreeOperand, PrimaryBooleanMesh,
+    SecondaryBooleanMesh,
+};
+use bevy::gltf::GltfExtras;
+use bevy::prelude::*;
+use bevy::scene::SceneInstanceReady;
+
+/// A glTF node's role in a boolean operation, parsed out of its
+/// `"BooleanRole"` extras property by [`wire_boolean_blueprint`] and
+/// re-inserted as a real component on the matching entity (purely for
+/// inspection — the wiring itself has already happened by the time it's
+/// there). `Secondary` carries no op of its own, since a bare custom
+/// property has no natural place to put one; it's treated as `Subtract`,
+/// the single most common "cut a feature out of the primary" case.
+/// `Operand` is for anything else, including a second, third, etc. operand
+/// each combined under its own op via a generated [`BooleanTree`].
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+#[reflect(Component)]
+pub enum BooleanRole {
+    Primary,
+    Secondary,
+    Operand(BooleanOpState),
+}
+
+/// Registers [`BooleanRole`] and adds [`wire_boolean_blueprint`]. Separate
+/// from [`crate::MeshBooleanPlugin`] (same split as
+/// [`crate::GpuClassifyPlugin`]) since not every app using this crate spawns
+/// boolean setups from glTF scenes.
+pub struct BooleanBlueprintPlugin;
+
+impl Plugin for BooleanBlueprintPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BooleanRole>().add_systems(Update, wire_boolean_blueprint);
+    }
+}
+
+/// For every glTF scene that just finished spawning, walk its hierarchy for
+/// `"BooleanRole"` extras and wire up the boolean relationship they
+/// describe. A scene with no `"Primary"` node, or a `"Primary"` with no
+/// `"Secondary"`/`"Operand"` siblings, is left untouched — it's just a plain
+/// model, not a CSG blueprint.
+pub fn wire_boolean_blueprint(
+    mut events: EventReader<SceneInstanceReady>,
+    mut commands: Commands,
+    children_query: Query<&Children>,
+    extras_query: Query<&GltfExtras>,
+    material_query: Query<&Handle<StandardMaterial>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    for event in events.read() {
+        let mut primary = None;
+        let mut operands: Vec<(Entity, BooleanOpState)> = Vec::new();
+        collect_boolean_roles(event.parent, &children_query, &extras_query, &mut commands, &mut primary, &mut operands);
+
+        let Some(primary_entity) = primary else { continue };
+        if operands.is_empty() {
+            continue;
+        }
+
+        let primary_material = material_query.get(primary_entity).cloned().unwrap_or_else(|| materials.add(Color::srgb(0.8, 0.7, 0.6)));
+        let result_material = materials.add(Color::srgb(0.8, 0.75, 0.7));
+        let result_entity = commands.spawn(BooleanEntityBundle::new(Handle::default(), result_material, Transform::IDENTITY)).id();
+
+        if let [(secondary_entity, op)] = operands[..] {
+            let secondary_material = material_query.get(secondary_entity).cloned().unwrap_or_else(|| materials.add(Color::srgb(0.6, 0.7, 0.8)));
+            commands.entity(primary_entity).insert(PrimaryBooleanMesh { secondary_entity });
+            commands.entity(secondary_entity).insert(SecondaryBooleanMesh { primary_entity });
+            commands
+                .entity(result_entity)
+                .insert((BooleanResultMaterials(vec![primary_material, secondary_material]), op));
+
+            let handles = BooleanHandles { primary_entity, secondary_entity, result_entity };
+            commands.add(move |world: &mut World| {
+                world.resource_mut::<BooleanOperations>().insert(handles);
+            });
+        } else {
+            // The first operand seeds a `BooleanTree`'s fold; its own `op`
+            // field is never read (see `BooleanTreeOperand`), so `None` here
+            // is just a placeholder.
+            let mut tree_operands = vec![BooleanTreeOperand { entity: primary_entity, op: BooleanOpState::None }];
+            tree_operands.extend(operands.into_iter().map(|(entity, op)| BooleanTreeOperand { entity, op }));
+            commands.entity(result_entity).insert(BooleanTree(tree_operands));
+        }
+    }
+}
+
+/// Depth-first walk from `root`, reading each entity's [`GltfExtras`] (if
+/// any) for a `"BooleanRole"` property: the first `"Primary"` found wins,
+/// every `"Secondary"`/`"Operand"` is collected in traversal order. Matching
+/// entities get the parsed [`BooleanRole`] inserted back as a component.
+fn collect_boolean_roles(
+    root: Entity,
+    children_query: &Query<&Children>,
+    extras_query: &Query<&GltfExtras>,
+    commands: &mut Commands,
+    primary: &mut Option<Entity>,
+    operands: &mut Vec<(Entity, BooleanOpState)>,
+) {
+    if let Some(role) = extras_query.get(root).ok().and_then(|extras| parse_boolean_role(&extras.value)) {
+        match role {
+            BooleanRole::Primary => {
+                primary.get_or_insert(root);
+            }
+            BooleanRole::Secondary => operands.push((root, BooleanOpState::Subtract)),
+            BooleanRole::Operand(op) => operands.push((root, op)),
+        }
+        commands.entity(root).insert(role);
+    }
+
+    if let Ok(children) = children_query.get(root) {
+        for &child in children {
+            collect_boolean_roles(child, children_query, extras_query, commands, primary, operands);
+        }
+    }
+}
+
+/// Parse a `"BooleanRole"` property out of one glTF node's raw `extras` JSON
+/// — `"Primary"`, `"Secondary"`, or `{"Operand": "<BooleanOpState variant>"}`
+/// — ignoring anything else the node's extras might carry.
+fn parse_boolean_role(extras_json: &str) -> Option<BooleanRole> {
+    let value: serde_json::Value = serde_json::from_str(extras_json).ok()?;
+    match value.get("BooleanRole")? {
+        serde_json::Value::String(s) if s == "Primary" => Some(BooleanRole::Primary),
+        serde_json::Value::String(s) if s == "Secondary" => Some(BooleanRole::Secondary),
+        serde_json::Value::Object(fields) => {
+            let op = match fields.get("Operand")?.as_str()? {
+                "Union" => BooleanOpState::Union,
+                "Intersect" => BooleanOpState::Intersect,
+                "Subtract" => BooleanOpState::Subtract,
+                "None" => BooleanOpState::None,
+                _ => return None,
+            };
+            Some(BooleanRole::Operand(op))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,132 @@
+//! Mesh bounding-box helpers: a public [`calculate_mesh_bounds`] (promoted
+//! out of the `direct_step_demo` example, which had its own private copy),
+//! plus an [`AutoFramePlugin`] that keeps [`OrbitCameraController`] pointed
+//! at whatever [`BooleanOperations`] currently has registered and draws
+//! wireframe boxes around each operand/result so a large or off-origin STEP
+//! import doesn't silently land outside the camera's view (the orbit camera
+//! used to default to a fixed `distance: 10.0` centered on the origin,
+//! regardless of what was actually loaded).
+
+use crate::camera::OrbitCameraController;
+use crate::BooleanOperations;
+use bevy::prelude::*;
+use bevy::render::primitives::Aabb;
+
+/// Axis-aligned bounds of `mesh`'s `ATTRIBUTE_POSITION` data, in the mesh's
+/// own local space. `None` if the mesh has no position attribute or no
+/// vertices at all (an empty result from a degenerate boolean, say).
+pub fn calculate_mesh_bounds(mesh: &Mesh) -> Option<Aabb> {
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        return None;
+    };
+    if positions.is_empty() {
+        return None;
+    }
+
+    let mut min = Vec3::from(positions[0]);
+    let mut max = min;
+    for &p in positions.iter() {
+        let p = Vec3::from(p);
+        min = min.min(p);
+        max = max.max(p);
+    }
+
+    Some(Aabb::from_min_max(min, max))
+}
+
+/// Registers [`auto_frame_orbit_camera`] and [`draw_bounds_gizmos`].
+pub struct AutoFramePlugin;
+
+impl Plugin for AutoFramePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LastFramedBounds>().add_systems(Update, (auto_frame_orbit_camera, draw_bounds_gizmos));
+    }
+}
+
+/// The union bounds [`auto_frame_orbit_camera`] last pointed the camera at,
+/// so it only re-targets `target_center`/`target_distance` when the union
+/// actually changes instead of fighting a user's manual orbit/pan/zoom every
+/// frame with the same values.
+#[derive(Resource, Default, PartialEq)]
+struct LastFramedBounds {
+    center: Vec3,
+    radius: f32,
+}
+
+/// The three entities a single [`BooleanHandles`](crate::BooleanHandles)
+/// tracks, each optionally carrying a `Handle<Mesh>` — a secondary operand
+/// added but not yet meshed, or a result not yet computed, still contributes
+/// whatever other entities in the union already have bounds for.
+fn mesh_bounds(entity: Entity, mesh_query: &Query<&Handle<Mesh>>, meshes: &Assets<Mesh>) -> Option<Aabb> {
+    let handle = mesh_query.get(entity).ok()?;
+    calculate_mesh_bounds(meshes.get(handle)?)
+}
+
+fn union_aabb(a: Aabb, b: Aabb) -> Aabb {
+    let min = Vec3::from(a.min()).min(Vec3::from(b.min()));
+    let max = Vec3::from(a.max()).max(Vec3::from(b.max()));
+    Aabb::from_min_max(min, max)
+}
+
+/// Union every registered operation's primary/secondary/result bounds and,
+/// if that union moved since the last frame it changed, point every
+/// [`OrbitCameraController`] at its center with `target_distance` set to
+/// `radius / tan(fov / 2)` (the distance at which the bounding sphere just
+/// reaches the view's edges), halved, so the camera sits closer than that
+/// and the bounding sphere itself extends past the view's edges.
+fn auto_frame_orbit_camera(
+    operations: Res<BooleanOperations>,
+    mesh_query: Query<&Handle<Mesh>>,
+    meshes: Res<Assets<Mesh>>,
+    mut last: ResMut<LastFramedBounds>,
+    mut cameras: Query<(&mut OrbitCameraController, Option<&Projection>)>,
+) {
+    let union = operations
+        .handles()
+        .flat_map(|h| [h.primary_entity, h.secondary_entity, h.result_entity])
+        .filter_map(|e| mesh_bounds(e, &mesh_query, &meshes))
+        .reduce(union_aabb);
+
+    let Some(union) = union else { return };
+    let center = Vec3::from(union.center);
+    let radius = crate::detmath::length(Vec3::from(union.half_extents));
+
+    if *last == (LastFramedBounds { center, radius }) {
+        return;
+    }
+    *last = LastFramedBounds { center, radius };
+
+    for (mut controller, projection) in &mut cameras {
+        let fov = match projection {
+            Some(Projection::Perspective(perspective)) => perspective.fov,
+            _ => std::f32::consts::FRAC_PI_4,
+        };
+        controller.target_center = center;
+        controller.target_distance = (radius / (fov / 2.0).tan()).max(0.01) * 0.5;
+    }
+}
+
+/// Draw a wireframe box around every registered operation's primary,
+/// secondary and result mesh, so the scene makes it visually obvious how
+/// the boolean result's extent compares to its operands.
+fn draw_bounds_gizmos(
+    operations: Res<BooleanOperations>,
+    mesh_query: Query<(&Handle<Mesh>, &GlobalTransform)>,
+    meshes: Res<Assets<Mesh>>,
+    mut gizmos: Gizmos,
+) {
+    let draw = |entity: Entity, color: Color, gizmos: &mut Gizmos| {
+        let Ok((handle, transform)) = mesh_query.get(entity) else { return };
+        let Some(aabb) = meshes.get(handle).and_then(calculate_mesh_bounds) else { return };
+
+        let center = transform.transform_point(Vec3::from(aabb.center));
+        let half_extents = Vec3::from(aabb.half_extents) * transform.compute_transform().scale;
+        gizmos.cuboid(Transform::from_translation(center).with_scale(half_extents * 2.0), color);
+    };
+
+    for handles in operations.handles() {
+        draw(handles.primary_entity, Color::srgb(0.3, 0.6, 1.0), &mut gizmos);
+        draw(handles.secondary_entity, Color::srgb(1.0, 0.6, 0.3), &mut gizmos);
+        draw(handles.result_entity, Color::srgb(0.3, 1.0, 0.4), &mut gizmos);
+    }
+}
@@ -0,0 +1,163 @@
+//! Reusable orbit/pan/zoom camera, replacing the fixed auto-rotating
+//! `orbit_camera` system the demo examples used to hand-roll. Exported from
+//! the crate (not just a demo-local helper) since inspecting a boolean
+//! result — rotating freely and zooming into thin coplanar regions where a
+//! `BooleanSolver::Fast` misclassification tends to show up — is something
+//! every consumer of this crate needs, not just the bundled examples.
+//!
+//! Input directly sets a *target* orbit/pan/zoom state; [`update_orbit_camera`]
+//! exponentially lerps the camera's actual `Transform` toward that target
+//! every frame (an exponential rather than linear lerp so the approach rate
+//! doesn't depend on the frame's `delta`), so a drag or scroll step reads as
+//! smooth camera motion instead of an instant jump.
+
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::prelude::*;
+
+/// Registers [`OrbitCameraController`]'s input and smoothing systems.
+pub struct OrbitCameraPlugin;
+
+impl Plugin for OrbitCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (orbit_camera_input, auto_orbit_camera, update_orbit_camera).chain());
+    }
+}
+
+/// Attach alongside a `Camera3d` + `Transform` to make it an orbit camera.
+/// `target_*` fields are the input-driven destination state; the un-prefixed
+/// fields are the smoothed state actually written to the entity's
+/// `Transform` each frame. Constructing with [`OrbitCameraController::new`]
+/// keeps both in sync so the camera doesn't animate in from the origin on
+/// the first frame.
+#[derive(Component, Clone, Debug)]
+pub struct OrbitCameraController {
+    pub target_center: Vec3,
+    pub target_yaw: f32,
+    pub target_pitch: f32,
+    pub target_distance: f32,
+
+    center: Vec3,
+    yaw: f32,
+    pitch: f32,
+    distance: f32,
+
+    /// Radians of yaw/pitch per pixel of left-drag motion.
+    pub orbit_sensitivity: f32,
+    /// World units of pan per pixel of right/middle-drag motion, scaled by
+    /// `distance` so panning feels consistent whether zoomed in or out.
+    pub pan_sensitivity: f32,
+    /// Fraction `distance` scales by per scroll-wheel notch (exponential
+    /// zoom, so the same scroll step feels equally responsive whether
+    /// close-up or far away).
+    pub zoom_sensitivity: f32,
+    /// Exponential approach rate (per second) the smoothed state closes the
+    /// gap to the target state at. Higher is snappier, lower is floatier.
+    pub damping: f32,
+
+    /// When set, [`auto_orbit_camera`] advances `target_yaw` on its own at
+    /// `auto_orbit_speed` radians/sec, reproducing the old demos' constant
+    /// spin. Off by default — opt in for a kiosk/screensaver-style view.
+    pub auto_orbit: bool,
+    pub auto_orbit_speed: f32,
+}
+
+impl Default for OrbitCameraController {
+    fn default() -> Self {
+        Self::new(Vec3::ZERO, 10.0, -45f32.to_radians(), -20f32.to_radians())
+    }
+}
+
+impl OrbitCameraController {
+    /// A controller already settled on `center`/`distance`/`yaw`/`pitch`
+    /// (both the target and smoothed state start here), so the first frame
+    /// doesn't animate in from a mismatched default.
+    pub fn new(center: Vec3, distance: f32, yaw: f32, pitch: f32) -> Self {
+        Self {
+            target_center: center,
+            target_yaw: yaw,
+            target_pitch: pitch,
+            target_distance: distance,
+            center,
+            yaw,
+            pitch,
+            distance,
+            orbit_sensitivity: 0.005,
+            pan_sensitivity: 0.0015,
+            zoom_sensitivity: 0.15,
+            damping: 8.0,
+            auto_orbit: false,
+            auto_orbit_speed: 0.25,
+        }
+    }
+
+    fn eye_position(&self) -> Vec3 {
+        let (sin_yaw, cos_yaw) = self.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = self.pitch.sin_cos();
+        self.center + self.distance * Vec3::new(cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw)
+    }
+}
+
+/// Left-drag orbits (adjusts `target_yaw`/`target_pitch`, clamped so the
+/// camera can't flip past looking straight up/down), right- or middle-drag
+/// pans `target_center` in the camera's own right/up plane, and the scroll
+/// wheel scales `target_distance`.
+fn orbit_camera_input(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut motion: EventReader<MouseMotion>,
+    mut wheel: EventReader<MouseWheel>,
+    mut query: Query<&mut OrbitCameraController>,
+) {
+    let delta: Vec2 = motion.read().map(|e| e.delta).sum();
+    let scroll: f32 = wheel.read().map(|e| e.y).sum();
+    let orbiting = mouse_buttons.pressed(MouseButton::Left);
+    let panning = mouse_buttons.pressed(MouseButton::Right) || mouse_buttons.pressed(MouseButton::Middle);
+
+    for mut controller in &mut query {
+        if orbiting && delta != Vec2::ZERO {
+            controller.target_yaw -= delta.x * controller.orbit_sensitivity;
+            controller.target_pitch =
+                (controller.target_pitch - delta.y * controller.orbit_sensitivity).clamp(-89f32.to_radians(), 89f32.to_radians());
+        }
+
+        if panning && delta != Vec2::ZERO {
+            let (sin_yaw, cos_yaw) = controller.target_yaw.sin_cos();
+            let right = Vec3::new(cos_yaw, 0.0, -sin_yaw);
+            let up = Vec3::Y;
+            let scale = controller.pan_sensitivity * controller.target_distance;
+            controller.target_center += (-right * delta.x + up * delta.y) * scale;
+        }
+
+        if scroll != 0.0 {
+            controller.target_distance =
+                (controller.target_distance * (1.0 - scroll * controller.zoom_sensitivity)).max(0.01);
+        }
+    }
+}
+
+/// Opt-in replacement for the demos' old unconditional spin: advances
+/// `target_yaw` at `auto_orbit_speed` for any controller with `auto_orbit`
+/// set, leaving manually-driven cameras alone.
+fn auto_orbit_camera(time: Res<Time>, mut query: Query<&mut OrbitCameraController>) {
+    for mut controller in &mut query {
+        if controller.auto_orbit {
+            controller.target_yaw += controller.auto_orbit_speed * time.delta_seconds();
+        }
+    }
+}
+
+/// Exponentially lerp every controller's smoothed state toward its target
+/// state and write the resulting eye position/orientation to `Transform`.
+/// The lerp factor `1 - exp(-damping * dt)` approaches 1 as `dt` grows, so a
+/// dropped frame still catches up instead of permanently lagging behind.
+fn update_orbit_camera(time: Res<Time>, mut query: Query<(&mut OrbitCameraController, &mut Transform)>) {
+    let dt = time.delta_seconds();
+    for (mut controller, mut transform) in &mut query {
+        let t = 1.0 - (-controller.damping * dt).exp();
+        controller.center = controller.center.lerp(controller.target_center, t);
+        controller.yaw += (controller.target_yaw - controller.yaw) * t;
+        controller.pitch += (controller.target_pitch - controller.pitch) * t;
+        controller.distance += (controller.target_distance - controller.distance) * t;
+
+        *transform = Transform::from_translation(controller.eye_position()).looking_at(controller.center, Vec3::Y);
+    }
+}
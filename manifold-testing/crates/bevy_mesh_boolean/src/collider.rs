@@ -0,0 +1,199 @@
+//! Collision-geometry descriptors for boolean result meshes, decoupled from
+//! any one physics crate (no `bevy_rapier`/`avian` dependency): callers opt
+//! a result entity in via [`GenerateCollider`], then convert whatever
+//! [`ColliderDescriptor`] comes back out on [`BooleanColliderReady`] into
+//! their own backend's collider type.
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, Mesh, VertexAttributeValues};
+use std::collections::HashMap;
+
+/// Which collider shape [`generate_boolean_collider`] builds from a result
+/// mesh. Boolean results are generally non-convex, so there's no single
+/// right answer: a static body wants the exact [`TriMesh`](ColliderStrategy::TriMesh),
+/// a dynamic body usually needs something convex instead.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColliderStrategy {
+    /// Exact triangle-soup collider, built directly from the mesh's own
+    /// positions/indices — cheap to build, expensive to query against, the
+    /// usual tradeoff for static/fixed bodies only.
+    #[default]
+    TriMesh,
+    /// Partition the mesh into connected components (triangles sharing a
+    /// vertex index) and take each component's own convex hull via
+    /// `Manifold::hull`. This is connectivity-based, not concavity-aware —
+    /// a real decomposition (e.g. V-HACD) splits a single connected but
+    /// non-convex blob along its concave features; this only separates
+    /// pieces a boolean op already left disjoint, so "block minus a
+    /// through-hole" (still one connected piece) still hulls as one
+    /// overly-permissive convex shape. Good enough for a dynamic body where
+    /// an approximate shape beats none, not a substitute for a dedicated
+    /// decomposition library.
+    ConvexDecomposition,
+}
+
+/// Attach to a boolean-op result entity to opt into collider generation;
+/// [`generate_boolean_collider`] picks this up once the entity's
+/// `Handle<Mesh>` is in place and recomputes only when that handle changes.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct GenerateCollider(pub ColliderStrategy);
+
+/// One convex piece of a [`ColliderDescriptor::ConvexDecomposition`]: a
+/// single hull's own positions and (triangulated) indices.
+#[derive(Debug, Clone)]
+pub struct ConvexHullPiece {
+    pub positions: Vec<[f32; 3]>,
+    pub indices: Vec<u32>,
+}
+
+/// Collision geometry computed for one result entity, independent of any
+/// physics backend — a `bevy_rapier`/`avian` integration reads this and
+/// builds its own `Collider` type from it.
+#[derive(Debug, Clone)]
+pub enum ColliderDescriptor {
+    TriMesh { positions: Vec<[f32; 3]>, indices: Vec<u32> },
+    ConvexDecomposition { hulls: Vec<ConvexHullPiece> },
+}
+
+/// Emitted once per recompute so physics-backend glue code can react to new
+/// collision geometry without this crate depending on any one physics
+/// crate, mirroring [`crate::BooleanOpResult`] reporting the mesh side.
+#[derive(Event, Debug, Clone)]
+pub struct BooleanColliderReady {
+    pub result_entity: Entity,
+    pub descriptor: ColliderDescriptor,
+}
+
+/// For every entity with [`GenerateCollider`] whose `Handle<Mesh>` just
+/// changed, build the requested [`ColliderDescriptor`] and emit it as a
+/// [`BooleanColliderReady`] event.
+pub fn generate_boolean_collider(
+    query: Query<(Entity, &GenerateCollider, &Handle<Mesh>), Changed<Handle<Mesh>>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut events: EventWriter<BooleanColliderReady>,
+) {
+    for (entity, mode, mesh_handle) in &query {
+        let Some(mesh) = mesh_assets.get(mesh_handle) else { continue };
+        let Some((positions, indices)) = mesh_positions_indices(mesh) else { continue };
+
+        let descriptor = match mode.0 {
+            ColliderStrategy::TriMesh => ColliderDescriptor::TriMesh { positions, indices },
+            ColliderStrategy::ConvexDecomposition => {
+                ColliderDescriptor::ConvexDecomposition { hulls: convex_decompose(&positions, &indices) }
+            }
+        };
+
+        events.send(BooleanColliderReady { result_entity: entity, descriptor });
+    }
+}
+
+/// Primary/secondary-pipeline variant of [`generate_boolean_collider`]: that
+/// pipeline's result entity never gets its own `Handle<Mesh>` — its geometry
+/// is split across one [`crate::BooleanResultSubmesh`] child per material
+/// region instead — so this merges every submesh child's mesh into one
+/// combined (positions, indices) pair before building the descriptor,
+/// triggering off the children list itself changing rather than a mesh
+/// handle, since [`crate::poll_boolean_op`] despawns and respawns the
+/// submesh children on every recompute.
+pub fn generate_boolean_collider_from_submeshes(
+    query: Query<(Entity, &GenerateCollider, &Children), Changed<Children>>,
+    submeshes: Query<&Handle<Mesh>, With<crate::BooleanResultSubmesh>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut events: EventWriter<BooleanColliderReady>,
+) {
+    for (entity, mode, children) in &query {
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+        for &child in children {
+            let Ok(mesh_handle) = submeshes.get(child) else { continue };
+            let Some(mesh) = mesh_assets.get(mesh_handle) else { continue };
+            let Some((child_positions, child_indices)) = mesh_positions_indices(mesh) else { continue };
+            let offset = positions.len() as u32;
+            positions.extend(child_positions);
+            indices.extend(child_indices.into_iter().map(|i| i + offset));
+        }
+        if positions.is_empty() {
+            continue;
+        }
+
+        let descriptor = match mode.0 {
+            ColliderStrategy::TriMesh => ColliderDescriptor::TriMesh { positions, indices },
+            ColliderStrategy::ConvexDecomposition => {
+                ColliderDescriptor::ConvexDecomposition { hulls: convex_decompose(&positions, &indices) }
+            }
+        };
+        events.send(BooleanColliderReady { result_entity: entity, descriptor });
+    }
+}
+
+fn mesh_positions_indices(mesh: &Mesh) -> Option<(Vec<[f32; 3]>, Vec<u32>)> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float32x3(p) => p.clone(),
+        _ => return None,
+    };
+    let indices = match mesh.indices()? {
+        Indices::U32(v) => v.clone(),
+        Indices::U16(v) => v.iter().map(|&i| i as u32).collect(),
+    };
+    Some((positions, indices))
+}
+
+/// Split `indices` into connected components by shared vertex index (a
+/// union-find over every triangle's three corners), then take each
+/// component's own convex hull.
+fn convex_decompose(positions: &[[f32; 3]], indices: &[u32]) -> Vec<ConvexHullPiece> {
+    let mut parent: Vec<u32> = (0..positions.len() as u32).collect();
+
+    fn find(parent: &mut [u32], mut x: u32) -> u32 {
+        while parent[x as usize] != x {
+            parent[x as usize] = parent[parent[x as usize] as usize];
+            x = parent[x as usize];
+        }
+        x
+    }
+    fn union(parent: &mut [u32], a: u32, b: u32) {
+        let (ra, rb) = (find(parent, a), find(parent, b));
+        if ra != rb {
+            parent[ra as usize] = rb;
+        }
+    }
+
+    for tri in indices.chunks_exact(3) {
+        union(&mut parent, tri[0], tri[1]);
+        union(&mut parent, tri[1], tri[2]);
+    }
+
+    let mut components: HashMap<u32, Vec<u32>> = HashMap::new();
+    for tri in indices.chunks_exact(3) {
+        let root = find(&mut parent, tri[0]);
+        components.entry(root).or_default().extend_from_slice(tri);
+    }
+
+    components.into_values().filter_map(|component_indices| hull_of_component(positions, &component_indices)).collect()
+}
+
+/// Build one connected component's convex hull via `Manifold::hull`, first
+/// remapping its vertex indices down to just the positions the component
+/// actually uses.
+fn hull_of_component(positions: &[[f32; 3]], component_indices: &[u32]) -> Option<ConvexHullPiece> {
+    let mut remap: HashMap<u32, u32> = HashMap::new();
+    let mut component_positions: Vec<f32> = Vec::new();
+    let mut local_indices: Vec<u32> = Vec::with_capacity(component_indices.len());
+    for &i in component_indices {
+        let local = *remap.entry(i).or_insert_with(|| {
+            let id = (component_positions.len() / 3) as u32;
+            component_positions.extend_from_slice(&positions[i as usize]);
+            id
+        });
+        local_indices.push(local);
+    }
+
+    if component_positions.is_empty() {
+        return None;
+    }
+
+    let hull = manifold_rs::Mesh::new(&component_positions, &local_indices).to_manifold().hull();
+    let hull_mesh = hull.to_mesh();
+    let hull_positions = hull_mesh.vertices().chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect();
+    Some(ConvexHullPiece { positions: hull_positions, indices: hull_mesh.indices() })
+}
@@ -0,0 +1,188 @@
+//! Serialize a Bevy `Mesh` out to common CAD/interchange formats: binary
+//! STL, Wavefront OBJ, and a (non-binary) glTF document with its geometry
+//! embedded as a base64 data URI. This is the write-side counterpart to
+//! `bevy_mesh_loader`'s `StlLoader`/`ObjLoader` — the obvious missing half
+//! of the pipeline, since without it a computed union/difference can only
+//! be viewed in this app, never carried anywhere else.
+//!
+//! STL and glTF write each triangle's corners independently, matching how
+//! `bevy_mesh_loader::StlLoader` already reads STL back in; OBJ reuses the
+//! mesh's own vertex list. No format welds shared vertices beyond what the
+//! `Mesh`'s own indexed triangle list already shares — callers after a
+//! smaller file can run the result through whatever mesh-welding pass they
+//! already use for other imports.
+
+use base64::Engine;
+use bevy::render::mesh::{Indices, Mesh, VertexAttributeValues};
+
+/// One triangle's three corner positions plus a flat face normal —
+/// the common shape every format below iterates the mesh into first.
+struct Triangle {
+    positions: [[f32; 3]; 3],
+    normal: [f32; 3],
+}
+
+fn triangles(mesh: &Mesh) -> Option<Vec<Triangle>> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float32x3(p) => p,
+        _ => return None,
+    };
+    let indices: Vec<u32> = match mesh.indices()? {
+        Indices::U32(v) => v.clone(),
+        Indices::U16(v) => v.iter().map(|&i| i as u32).collect(),
+    };
+
+    Some(
+        indices
+            .chunks_exact(3)
+            .map(|tri| {
+                let corners = [positions[tri[0] as usize], positions[tri[1] as usize], positions[tri[2] as usize]];
+                let normal = face_normal(corners);
+                Triangle { positions: corners, normal }
+            })
+            .collect(),
+    )
+}
+
+fn face_normal(corners: [[f32; 3]; 3]) -> [f32; 3] {
+    let a = bevy::math::Vec3::from(corners[0]);
+    let b = bevy::math::Vec3::from(corners[1]);
+    let c = bevy::math::Vec3::from(corners[2]);
+    crate::detmath::normalize_or_zero((b - a).cross(c - a)).to_array()
+}
+
+/// Binary STL (an 80-byte ignored header, a `u32` triangle count, then one
+/// 50-byte record per triangle: facet normal, three vertices, a 2-byte
+/// attribute count left zero) — `None` if `mesh` has no position attribute
+/// or no index buffer to read triangles from.
+pub fn mesh_to_stl_binary(mesh: &Mesh) -> Option<Vec<u8>> {
+    let triangles = triangles(mesh)?;
+
+    let mut bytes = Vec::with_capacity(84 + triangles.len() * 50);
+    bytes.extend_from_slice(&[0u8; 80]);
+    bytes.extend_from_slice(&(triangles.len() as u32).to_le_bytes());
+    for tri in &triangles {
+        for component in tri.normal {
+            bytes.extend_from_slice(&component.to_le_bytes());
+        }
+        for vertex in tri.positions {
+            for component in vertex {
+                bytes.extend_from_slice(&component.to_le_bytes());
+            }
+        }
+        bytes.extend_from_slice(&[0u8; 2]);
+    }
+    Some(bytes)
+}
+
+/// Wavefront OBJ with positions (`v`), one flat normal per face (`vn`) and,
+/// if `mesh` carries `ATTRIBUTE_UV_0`, texture coordinates (`vt`) — faces
+/// (`f`) reference all three per corner (OBJ's 1-indexed), and an `mtllib`
+/// line points at `material_name` (e.g. `"result.mtl"`) for callers that
+/// also want to write out a companion material.
+pub fn mesh_to_obj(mesh: &Mesh, material_name: &str) -> Option<String> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float32x3(p) => p,
+        _ => return None,
+    };
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(uv)) if uv.len() == positions.len() => Some(uv),
+        _ => None,
+    };
+    let indices: Vec<u32> = match mesh.indices()? {
+        Indices::U32(v) => v.clone(),
+        Indices::U16(v) => v.iter().map(|&i| i as u32).collect(),
+    };
+
+    let mut obj = format!("mtllib {material_name}\nusemtl default\n");
+    for p in positions {
+        obj.push_str(&format!("v {} {} {}\n", p[0], p[1], p[2]));
+    }
+    if let Some(uvs) = uvs {
+        for uv in uvs {
+            obj.push_str(&format!("vt {} {}\n", uv[0], uv[1]));
+        }
+    }
+    for (face_index, tri) in indices.chunks_exact(3).enumerate() {
+        let normal = face_normal([positions[tri[0] as usize], positions[tri[1] as usize], positions[tri[2] as usize]]);
+        obj.push_str(&format!("vn {} {} {}\n", normal[0], normal[1], normal[2]));
+        let vn = face_index + 1;
+        let corner = |i: u32| {
+            let v = i + 1;
+            if uvs.is_some() { format!("{v}/{v}/{vn}") } else { format!("{v}//{vn}") }
+        };
+        obj.push_str(&format!("f {} {} {}\n", corner(tri[0]), corner(tri[1]), corner(tri[2])));
+    }
+    Some(obj)
+}
+
+/// A minimal glTF 2.0 document (JSON `.gltf`, not binary `.glb`) with one
+/// mesh primitive whose position/normal/index data are packed into a
+/// single buffer (one `bufferView` each), embedded directly in the JSON as a base64 data
+/// URI — valid glTF that any loader's data-URI path accepts, without this
+/// crate taking on a glTF-writing dependency for full binary chunking.
+pub fn mesh_to_gltf(mesh: &Mesh) -> Option<String> {
+    let triangles = triangles(mesh)?;
+    let vertex_count = triangles.len() * 3;
+
+    let mut position_bytes = Vec::with_capacity(vertex_count * 12);
+    let mut normal_bytes = Vec::with_capacity(vertex_count * 12);
+    let mut min = [f32::MAX; 3];
+    let mut max = [f32::MIN; 3];
+    for tri in &triangles {
+        for vertex in tri.positions {
+            for (i, component) in vertex.iter().enumerate() {
+                min[i] = min[i].min(*component);
+                max[i] = max[i].max(*component);
+                position_bytes.extend_from_slice(&component.to_le_bytes());
+            }
+            for component in tri.normal {
+                normal_bytes.extend_from_slice(&component.to_le_bytes());
+            }
+        }
+    }
+    let index_bytes: Vec<u8> = (0..vertex_count as u32).flat_map(|i| i.to_le_bytes()).collect();
+
+    let position_offset = 0;
+    let normal_offset = position_bytes.len();
+    let index_offset = normal_offset + normal_bytes.len();
+    let mut buffer = position_bytes;
+    buffer.extend(normal_bytes);
+    buffer.extend(index_bytes);
+    let data_uri = base64::engine::general_purpose::STANDARD.encode(&buffer);
+
+    Some(format!(
+        r#"{{
+  "asset": {{ "version": "2.0", "generator": "bevy_mesh_boolean" }},
+  "scenes": [{{ "nodes": [0] }}],
+  "scene": 0,
+  "nodes": [{{ "mesh": 0 }}],
+  "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0, "NORMAL": 1 }}, "indices": 2 }}] }}],
+  "buffers": [{{ "byteLength": {buffer_len}, "uri": "data:application/octet-stream;base64,{data_uri}" }}],
+  "bufferViews": [
+    {{ "buffer": 0, "byteOffset": {position_offset}, "byteLength": {position_len} }},
+    {{ "buffer": 0, "byteOffset": {normal_offset}, "byteLength": {normal_len} }},
+    {{ "buffer": 0, "byteOffset": {index_offset}, "byteLength": {index_len} }}
+  ],
+  "accessors": [
+    {{ "bufferView": 0, "componentType": 5126, "count": {vertex_count}, "type": "VEC3", "min": [{min0}, {min1}, {min2}], "max": [{max0}, {max1}, {max2}] }},
+    {{ "bufferView": 1, "componentType": 5126, "count": {vertex_count}, "type": "VEC3" }},
+    {{ "bufferView": 2, "componentType": 5125, "count": {vertex_count}, "type": "SCALAR" }}
+  ]
+}}"#,
+        buffer_len = buffer.len(),
+        position_offset = position_offset,
+        position_len = vertex_count * 12,
+        normal_offset = normal_offset,
+        normal_len = vertex_count * 12,
+        index_offset = index_offset,
+        index_len = vertex_count * 4,
+        vertex_count = vertex_count,
+        min0 = min[0],
+        min1 = min[1],
+        min2 = min[2],
+        max0 = max[0],
+        max1 = max[1],
+        max2 = max[2],
+    ))
+}
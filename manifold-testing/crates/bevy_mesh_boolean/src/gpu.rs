@@ -0,0 +1,198 @@
+//! GPU-accelerated alternative to [`crate::classify_operand`]'s per-triangle
+//! ray-parity test — the real `O(n·m)` cost `BooleanSolver::Fast` pays on
+//! large STEP meshes, where every triangle of one operand is tested against
+//! every triangle of the other. Each test is independent, so it maps
+//! directly onto a compute dispatch: upload the "other" operand's
+//! positions/indices plus every candidate triangle's centroid into storage
+//! buffers, classify them all in one pass, and read the per-triangle
+//! inside/outside flags back.
+//!
+//! This only covers `BooleanSolver::Fast`'s classification loop, which is
+//! plain Rust this crate owns. `BooleanSolver::Exact`'s triangle cutting
+//! happens inside `manifold_rs`'s C++ `boolean_op` over an FFI boundary this
+//! crate has no shader-level access into, so that path is untouched.
+//!
+//! Opt in with [`GpuBroadphase`]; [`GpuClassifyPlugin`] builds the pipeline
+//! once the render sub-app exists, bypassing the render graph entirely since
+//! this dispatch has no view to attach to and needs to run synchronously
+//! inside `compute_boolean_op_fast`'s async task, not once per frame.
+//! [`classify_triangles_gpu`] returns `None` (falling back to the CPU path)
+//! when the readback fails; with no device ready yet, e.g. a headless test
+//! runner with no GPU, the caller takes the CPU path without dispatching.
+
+use bevy::math::{Vec3, Vec4};
+use bevy::prelude::*;
+use bevy::render::render_resource::{
+    wgpu, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, Buffer, BufferDescriptor, BufferInitDescriptor, BufferUsages, ComputePassDescriptor,
+    MapMode, Maintain, ShaderStages,
+};
+use bevy::render::render_resource::binding_types::{storage_buffer, storage_buffer_read_only};
+use bevy::render::renderer::{RenderDevice, RenderQueue};
+use bevy::render::RenderApp;
+
+const SHADER_SOURCE: &str = include_str!("shaders/triangle_classify.wgsl");
+
+/// Global opt-in for routing `BooleanSolver::Fast`'s triangle classification
+/// through [`classify_triangles_gpu`] instead of `classify_operand`'s CPU
+/// ray-parity loop (itself already `rayon`-parallel with the `parallel`
+/// feature). A plain config flag, not a per-operation component, since it
+/// depends on [`GpuClassifyPlugin`] having a pipeline ready at all rather
+/// than varying per call. Defaults to `false`.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpuBroadphase(pub bool);
+
+/// The pipeline and bind group layout [`classify_triangles_gpu`] dispatches
+/// against, built once at startup from the render sub-app's device. Cloning
+/// this resource is cheap — `RenderDevice`/`RenderQueue`/the wgpu pipeline
+/// handle are all `Arc`-backed — so `dispatch_boolean_op` clones it straight
+/// into the async task alongside `BooleanSolver`/`HoleTolerant`.
+#[derive(Resource, Clone)]
+pub struct GpuClassifyDevice {
+    device: RenderDevice,
+    queue: RenderQueue,
+    pipeline: std::sync::Arc<wgpu::ComputePipeline>,
+    bind_group_layout: BindGroupLayout,
+}
+
+/// Adds [`GpuBroadphase`] and, once the render sub-app exists, builds the
+/// classification compute pipeline into [`GpuClassifyDevice`]. Separate from
+/// [`crate::MeshBooleanPlugin`] (same split as [`crate::AutoFramePlugin`]/
+/// [`crate::OrbitCameraPlugin`]) since not every app embedding this crate
+/// runs with rendering enabled (e.g. a headless batch converter).
+pub struct GpuClassifyPlugin;
+
+impl Plugin for GpuClassifyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GpuBroadphase>();
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Some(render_app) = app.get_sub_app(RenderApp) else { return };
+        let device = render_app.world().resource::<RenderDevice>().clone();
+        let queue = render_app.world().resource::<RenderQueue>().clone();
+
+        let bind_group_layout = device.create_bind_group_layout(
+            "gpu_classify_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::COMPUTE,
+                (
+                    storage_buffer_read_only::<Vec4>(false),
+                    storage_buffer_read_only::<u32>(false),
+                    storage_buffer_read_only::<Vec4>(false),
+                    storage_buffer::<u32>(false),
+                ),
+            ),
+        );
+
+        let shader_module = device.wgpu_device().create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("triangle_classify_shader"),
+            source: wgpu::ShaderSource::Wgsl(SHADER_SOURCE.into()),
+        });
+        let pipeline_layout = device.wgpu_device().create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("gpu_classify_pipeline_layout"),
+            bind_group_layouts: &[bind_group_layout.value()],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.wgpu_device().create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("gpu_classify_pipeline"),
+            layout: Some(&pipeline_layout),
+            module: &shader_module,
+            entry_point: "classify",
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        app.insert_resource(GpuClassifyDevice { device, queue, pipeline: std::sync::Arc::new(pipeline), bind_group_layout });
+    }
+}
+
+/// Classify every centroid in `centroids` as inside/outside `other` on the
+/// GPU, mirroring `point_inside`'s ray-parity test exactly (same fixed cast
+/// direction, both sides). Returns `None` — meaning "fall back to the CPU
+/// path" — if the blocking buffer readback never completes.
+pub fn classify_triangles_gpu(gpu: &GpuClassifyDevice, centroids: &[Vec3], other: &(Vec<[f32; 3]>, Vec<u32>)) -> Option<Vec<bool>> {
+    if centroids.is_empty() || other.1.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let other_positions: Vec<Vec4> = other.0.iter().map(|p| Vec3::from_array(*p).extend(0.0)).collect();
+    let centroid_data: Vec<Vec4> = centroids.iter().map(|p| p.extend(0.0)).collect();
+
+    let positions_buffer = gpu
+        .device
+        .create_buffer_with_data(&BufferInitDescriptor { label: Some("gpu_classify_other_positions"), contents: cast_slice(&other_positions), usage: BufferUsages::STORAGE });
+    let indices_buffer = gpu
+        .device
+        .create_buffer_with_data(&BufferInitDescriptor { label: Some("gpu_classify_other_indices"), contents: cast_slice(&other.1), usage: BufferUsages::STORAGE });
+    let centroids_buffer = gpu
+        .device
+        .create_buffer_with_data(&BufferInitDescriptor { label: Some("gpu_classify_centroids"), contents: cast_slice(&centroid_data), usage: BufferUsages::STORAGE });
+    let output_size = (centroids.len() * std::mem::size_of::<u32>()) as u64;
+    let output_buffer = gpu.device.create_buffer(&BufferDescriptor {
+        label: Some("gpu_classify_output"),
+        size: output_size,
+        usage: BufferUsages::STORAGE | BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let readback_buffer = gpu.device.create_buffer(&BufferDescriptor {
+        label: Some("gpu_classify_readback"),
+        size: output_size,
+        usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let bind_group = gpu.device.create_bind_group(
+        "gpu_classify_bind_group",
+        &gpu.bind_group_layout,
+        &BindGroupEntries::sequential((
+            positions_buffer.as_entire_binding(),
+            indices_buffer.as_entire_binding(),
+            centroids_buffer.as_entire_binding(),
+            output_buffer.as_entire_binding(),
+        )),
+    );
+
+    let mut encoder = gpu.device.wgpu_device().create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor { label: Some("gpu_classify_pass"), timestamp_writes: None });
+        pass.set_pipeline(&gpu.pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        let workgroups = (centroids.len() as u32).div_ceil(64).max(1);
+        pass.dispatch_workgroups(workgroups, 1, 1);
+    }
+    encoder.copy_buffer_to_buffer(&output_buffer, 0, &readback_buffer, 0, output_size);
+    gpu.queue.submit(std::iter::once(encoder.finish()));
+
+    read_buffer_blocking(&gpu.device, &readback_buffer, centroids.len())
+}
+
+/// Map `buffer` for reading and block until either the copy lands or the
+/// device reports it never will — the synchronous counterpart to the
+/// `poll_once`/`Task` pattern this crate uses for CPU async work, since a
+/// classification result feeds straight back into `fast_boolean`'s
+/// already-synchronous per-triangle loop rather than another pollable task.
+fn read_buffer_blocking(device: &RenderDevice, buffer: &Buffer, count: usize) -> Option<Vec<bool>> {
+    let slice = buffer.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    device.wgpu_device().poll(Maintain::Wait);
+    match receiver.recv() {
+        Ok(Ok(())) => {
+            let data = slice.get_mapped_range();
+            let flags: Vec<bool> = data.chunks_exact(4).take(count).map(|c| u32::from_ne_bytes([c[0], c[1], c[2], c[3]]) != 0).collect();
+            drop(data);
+            buffer.unmap();
+            Some(flags)
+        }
+        _ => None,
+    }
+}
+
+/// Reinterpret a `Copy` slice as raw bytes to hand to `create_buffer_with_data`.
+fn cast_slice<T: Copy>(data: &[T]) -> &[u8] {
+    // SAFETY: every type this is called with (`Vec4`, `u32`) has no padding
+    // bytes that matter to the shader and outlives the call.
+    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) }
+}
@@ -1,7 +1,39 @@
+use bevy::math::Affine3A;
 use bevy::prelude::*;
+use bevy::tasks::{AsyncComputeTaskPool, Task};
 use manifold_rs;
+use manifold_rs::properties::TaggedManifold;
+use rayon::prelude::*;
 use std::panic;
 
+mod bounds;
+pub use bounds::{calculate_mesh_bounds, AutoFramePlugin};
+mod camera;
+pub use camera::{OrbitCameraController, OrbitCameraPlugin};
+pub mod detmath;
+mod meshlet;
+pub use meshlet::{mesh_to_meshlets, Meshlet, MeshletAsset, MeshletGroup, MAX_MESHLET_TRIANGLES, MAX_MESHLET_VERTICES};
+mod repair;
+pub use repair::{find_boundary_loops, repair_mesh, RepairReport, DEFAULT_WELD_EPSILON};
+mod simplify;
+pub use simplify::{
+    dispatch_boolean_lod_chain, poll_boolean_lod_chain, select_boolean_lod, simplify_mesh, BooleanLodChain, BooleanLodLevels,
+    MeshLod,
+};
+mod polyhedra;
+pub use polyhedra::conway_polyhedron_mesh;
+mod export;
+pub use export::{mesh_to_gltf, mesh_to_obj, mesh_to_stl_binary};
+mod collider;
+pub use collider::{
+    generate_boolean_collider, generate_boolean_collider_from_submeshes, BooleanColliderReady, ColliderDescriptor,
+    ColliderStrategy, ConvexHullPiece, GenerateCollider,
+};
+mod gpu;
+pub use gpu::{GpuBroadphase, GpuClassifyDevice, GpuClassifyPlugin};
+mod blueprint;
+pub use blueprint::{wire_boolean_blueprint, BooleanBlueprintPlugin, BooleanRole};
+
 /// Bundle containing all components needed for a boolean operation entity
 #[derive(Bundle)]
 pub struct BooleanEntityBundle {
@@ -27,7 +59,11 @@ impl BooleanEntityBundle {
 pub struct MeshBooleanPlugin;
 
 impl MeshBooleanPlugin {
-    /// Spawns two entities with a boolean operation between them
+    /// Spawns two entities with a boolean operation between them. Each
+    /// operand takes its own `Vec` of materials (the first is what's shown
+    /// on the operand entity itself and what its faces keep in the result);
+    /// `result_material` is only used as a placeholder until the first
+    /// operation finishes and its per-material submeshes are spawned.
     pub fn spawn_boolean_operation(
         commands: &mut Commands,
         _meshes: &mut ResMut<Assets<Mesh>>,
@@ -37,12 +73,23 @@ impl MeshBooleanPlugin {
         primary_transform: Transform,
         secondary_transform: Transform,
         result_material: Handle<StandardMaterial>,
+        primary_materials: Vec<Handle<StandardMaterial>>,
+        secondary_materials: Vec<Handle<StandardMaterial>>,
     ) -> BooleanOperationBundle {
+        let primary_material = primary_materials
+            .first()
+            .cloned()
+            .unwrap_or_else(|| materials.add(Color::srgb(0.8, 0.7, 0.6)));
+        let secondary_material = secondary_materials
+            .first()
+            .cloned()
+            .unwrap_or_else(|| materials.add(Color::srgb(0.6, 0.7, 0.8)));
+
         // Spawn the primary mesh entity
         let primary_entity = commands
             .spawn(BooleanEntityBundle::new(
                 primary_mesh.clone(),
-                materials.add(Color::srgb(0.8, 0.7, 0.6)),
+                primary_material.clone(),
                 primary_transform,
             ))
             .insert(PrimaryBooleanMesh {
@@ -54,7 +101,7 @@ impl MeshBooleanPlugin {
         let secondary_entity = commands
             .spawn(BooleanEntityBundle::new(
                 secondary_mesh.clone(),
-                materials.add(Color::srgb(0.6, 0.7, 0.8)),
+                secondary_material.clone(),
                 secondary_transform,
             ))
             .insert(SecondaryBooleanMesh {
@@ -62,13 +109,17 @@ impl MeshBooleanPlugin {
             })
             .id();
 
-        // Spawn the result entity (initially hidden)
+        // Spawn the result entity (initially hidden); its visible geometry
+        // is the per-material children that `poll_boolean_op` attaches once
+        // the first operation completes.
         let result_entity = commands
             .spawn(BooleanEntityBundle::new(
                 primary_mesh, // Placeholder, will be replaced
                 result_material,
                 Transform::from_translation(Vec3::ZERO),
             ))
+            .insert(BooleanResultMaterials(vec![primary_material, secondary_material]))
+            .insert(BooleanOpState::default())
             .id();
 
         // Update the primary entity to reference the secondary
@@ -76,589 +127,2468 @@ impl MeshBooleanPlugin {
             secondary_entity,
         });
 
-        // Insert the handles resource to track all entities
-        commands.insert_resource(BooleanHandles {
+        let handles = BooleanHandles {
             primary_entity,
             secondary_entity,
             result_entity,
+        };
+        commands.add(move |world: &mut World| {
+            world.resource_mut::<BooleanOperations>().insert(handles);
+        });
+
+        BooleanOperationBundle {
+            primary: primary_entity,
+            secondary: secondary_entity,
+            result: result_entity,
+        }
+    }
+}
+
+/// A bundle that represents a complete boolean operation setup
+pub struct BooleanOperationBundle {
+    pub primary: Entity,
+    pub secondary: Entity,
+    pub result: Entity,
+}
+
+impl Plugin for MeshBooleanPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BooleanOpState>()
+            .init_resource::<BooleanSolver>()
+            .init_resource::<HoleTolerant>()
+            .init_resource::<MeshRepair>()
+            .init_resource::<GpuBroadphase>()
+            .init_resource::<BooleanProgress>()
+            .init_resource::<BooleanOpStatus>()
+            .init_resource::<BooleanOperations>()
+            .init_resource::<CsgEvalCache>()
+            .init_resource::<MeshContentVersions>()
+            .init_asset::<meshlet::MeshletAsset>()
+            .add_event::<BooleanOpResult>()
+            .add_event::<BooleanColliderReady>()
+            .add_systems(Update, (dispatch_boolean_op.run_if(boolean_inputs_changed), poll_boolean_op))
+            .add_systems(Update, evict_despawned_boolean_operations)
+            .add_systems(Update, (spawn_multi_operand_tasks, poll_multi_operand_tasks))
+            .add_systems(Update, (dispatch_csg_eval, poll_csg_eval))
+            .add_systems(Update, (dispatch_boolean_tree, poll_boolean_tree))
+            .add_systems(Update, (dispatch_csg_operation.run_if(csg_operation_inputs_changed), poll_csg_operation))
+            .add_systems(Update, (dispatch_boolean_lod_chain, poll_boolean_lod_chain, select_boolean_lod).chain())
+            .add_systems(Update, (generate_boolean_collider, generate_boolean_collider_from_submeshes));
+    }
+}
+
+/// Marks an entity as the result of combining an arbitrary number of operand
+/// entities under one operation, e.g. union of N meshes or a large shape
+/// minus several holes. Unlike [`PrimaryBooleanMesh`]/[`SecondaryBooleanMesh`],
+/// which model exactly two operands, this takes any number of them, and
+/// drives the reduction off-thread through Bevy's async task pool so the
+/// frame isn't blocked while N operands are combined.
+#[derive(Component)]
+pub struct MultiOperandBoolean {
+    pub operands: Vec<Entity>,
+    pub op: BooleanOpState,
+}
+
+/// The in-flight reduction task for a [`MultiOperandBoolean`] entity, polled
+/// each frame until it completes.
+#[derive(Component)]
+struct MultiOperandTask(Task<(Vec<f32>, Vec<u32>)>);
+
+/// For every `MultiOperandBoolean` entity without an in-flight task, snapshot
+/// its operands' mesh data and spawn the (potentially expensive) chunked
+/// parallel reduction on the async compute task pool.
+fn spawn_multi_operand_tasks(
+    mut commands: Commands,
+    query: Query<(Entity, &MultiOperandBoolean), Without<MultiOperandTask>>,
+    pbr_query: Query<(&Handle<Mesh>, &Transform)>,
+    mesh_assets: Res<Assets<Mesh>>,
+) {
+    for (entity, multi) in &query {
+        if multi.op == BooleanOpState::None {
+            continue;
+        }
+
+        let Some(op) = boolean_op_from_state(multi.op) else {
+            continue;
+        };
+
+        let mut operand_data = Vec::with_capacity(multi.operands.len());
+        for &operand in &multi.operands {
+            let Ok((mesh_handle, transform)) = pbr_query.get(operand) else {
+                continue;
+            };
+            let Some(mesh) = mesh_assets.get(mesh_handle) else {
+                continue;
+            };
+            let Some(manifold) = bevy_mesh_to_manifold(mesh) else {
+                continue;
+            };
+            let translation = transform.translation;
+            let manifold = manifold.translate(
+                translation.x as f64,
+                translation.y as f64,
+                translation.z as f64,
+            );
+            let result_mesh = manifold.to_mesh();
+            operand_data.push((result_mesh.vertices(), result_mesh.indices()));
+        }
+
+        if operand_data.is_empty() {
+            continue;
+        }
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move { reduce_boolean_parallel(operand_data, op) });
+        commands.entity(entity).insert(MultiOperandTask(task));
+    }
+}
+
+/// Poll every in-flight [`MultiOperandTask`], writing the finished manifold's
+/// mesh back onto its entity as soon as the task completes.
+fn poll_multi_operand_tasks(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut MultiOperandTask)>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+) {
+    for (entity, mut task) in &mut query {
+        let Some((vertices, indices)) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+
+        let manifold = manifold_rs::Mesh::new(&vertices, &indices).to_manifold();
+        let mesh_handle = mesh_assets.add(manifold_to_bevy_mesh(manifold));
+
+        commands
+            .entity(entity)
+            .insert(mesh_handle)
+            .remove::<MultiOperandTask>();
+    }
+}
+
+fn boolean_op_from_state(state: BooleanOpState) -> Option<manifold_rs::BooleanOp> {
+    match state {
+        BooleanOpState::None => None,
+        BooleanOpState::Intersect => Some(manifold_rs::BooleanOp::Intersection),
+        BooleanOpState::Union => Some(manifold_rs::BooleanOp::Union),
+        BooleanOpState::Subtract => Some(manifold_rs::BooleanOp::Difference),
+    }
+}
+
+/// Run `a op b` on raw mesh data, returning the result as raw mesh data so
+/// it can cross back out of a parallel task (the `manifold_rs::Manifold`
+/// C++ wrapper itself is not `Send`).
+fn boolean_op_raw(
+    a: &(Vec<f32>, Vec<u32>),
+    b: &(Vec<f32>, Vec<u32>),
+    op: manifold_rs::BooleanOp,
+) -> (Vec<f32>, Vec<u32>) {
+    let manifold_a = manifold_rs::Mesh::new(&a.0, &a.1).to_manifold();
+    let manifold_b = manifold_rs::Mesh::new(&b.0, &b.1).to_manifold();
+    let result = manifold_a.boolean_op(&manifold_b, op);
+    let mesh = result.to_mesh();
+    (mesh.vertices(), mesh.indices())
+}
+
+/// Combine `operands` under `op` via a rayon-style chunked parallel
+/// reduction: split the list into chunks, fold each chunk into a partial
+/// result in parallel, then combine the partials in a balanced binary tree
+/// so no single thread serializes all N operations.
+pub fn reduce_boolean_parallel(
+    operands: Vec<(Vec<f32>, Vec<u32>)>,
+    op: manifold_rs::BooleanOp,
+) -> (Vec<f32>, Vec<u32>) {
+    const CHUNK_SIZE: usize = 4;
+
+    if operands.len() <= 1 {
+        return operands.into_iter().next().unwrap_or_default();
+    }
+
+    let partials: Vec<(Vec<f32>, Vec<u32>)> = operands
+        .par_chunks(CHUNK_SIZE)
+        .map(|chunk| {
+            let mut acc = chunk[0].clone();
+            for next in &chunk[1..] {
+                acc = boolean_op_raw(&acc, next, op);
+            }
+            acc
+        })
+        .collect();
+
+    combine_balanced(partials, op)
+}
+
+fn combine_balanced(mut items: Vec<(Vec<f32>, Vec<u32>)>, op: manifold_rs::BooleanOp) -> (Vec<f32>, Vec<u32>) {
+    while items.len() > 1 {
+        items = items
+            .par_chunks(2)
+            .map(|pair| {
+                if pair.len() == 2 {
+                    boolean_op_raw(&pair[0], &pair[1], op)
+                } else {
+                    pair[0].clone()
+                }
+            })
+            .collect();
+    }
+    items.into_iter().next().unwrap_or_default()
+}
+
+/// Marks the root of an entity-based [`CsgNode`] tree. [`dispatch_csg_eval`]
+/// and [`poll_csg_eval`] write the tree's combined result onto this same
+/// entity's `Handle<Mesh>` whenever anything under it changes.
+#[derive(Component)]
+pub struct CsgRoot;
+
+/// A node in an entity-based CSG tree, letting a boolean modifier stack more
+/// than the two operands `PrimaryBooleanMesh`/`SecondaryBooleanMesh` allow
+/// for: "cube minus three cylinders then union a sphere" is a root `Op` over
+/// an `Op` over the cube and three cylinder leaves, unioned with a sphere
+/// leaf.
+///
+/// A `Leaf` node is any entity with its own `Handle<Mesh>` + `Transform`. An
+/// `Op` node combines its `children`, in order, by folding `boolean_op` left
+/// to right under `op`.
+#[derive(Component, Clone)]
+pub enum CsgNode {
+    Leaf,
+    Op {
+        op: BooleanOpState,
+        children: Vec<Entity>,
+    },
+}
+
+/// Per-entity memoized evaluation results, keyed by a hash of whatever fed
+/// into that node: a leaf's mesh handle + translation, or an op node's
+/// `op` plus its children's own (already-memoized) hashes. An unchanged hash
+/// means the node's raw mesh data is reused as-is instead of recomputed.
+#[derive(Resource, Default)]
+pub struct CsgEvalCache {
+    entries: std::collections::HashMap<Entity, (u64, (Vec<f32>, Vec<u32>))>,
+}
+
+/// Bumped for a [`Handle<Mesh>`]'s asset every time its contents are
+/// overwritten in place, so [`hash_leaf`]/[`hash_leaf_affine`] can tell a
+/// reused handle's geometry apart from frame to frame. `poll_csg_eval`,
+/// `poll_boolean_tree`, and `poll_csg_operation` all write their folded
+/// result onto an existing `Handle<Mesh>` rather than allocating a new one
+/// each frame — the handle's `AssetId` alone is therefore not enough to spot
+/// a change when, e.g., a [`CsgRoot`]'s result entity is reused as a
+/// [`CsgNode::Leaf`] inside a second tree: without this, the second tree's
+/// cached leaf hash would never change even though the first tree's geometry
+/// does, and the composed result would go stale after the first evaluation.
+#[derive(Resource, Default)]
+struct MeshContentVersions(std::collections::HashMap<AssetId<Mesh>, u64>);
+
+impl MeshContentVersions {
+    fn bump(&mut self, id: AssetId<Mesh>) {
+        *self.0.entry(id).or_insert(0) += 1;
+    }
+
+    fn get(&self, handle: Option<&Handle<Mesh>>) -> u64 {
+        handle.map(|h| self.0.get(&h.id()).copied().unwrap_or(0)).unwrap_or(0)
+    }
+}
+
+/// Owned snapshot of a [`CsgNode`] subtree, good enough to hand to the async
+/// compute task pool: leaves are already flattened into raw
+/// `(positions, indices)` data. That conversion stays on the main thread —
+/// it's cheap, since the mesh data is already resident in `Assets<Mesh>` —
+/// exactly like [`dispatch_boolean_op`] snapshotting its primary/secondary
+/// mesh data before handing the actual boolean work off-thread.
+enum CsgSnapshot {
+    Leaf {
+        entity: Entity,
+        hash: u64,
+        data: (Vec<f32>, Vec<u32>),
+    },
+    Op {
+        entity: Entity,
+        op: BooleanOpState,
+        children_entities: Vec<Entity>,
+        children: Vec<CsgSnapshot>,
+        /// The `Op` node's own `Transform`, applied to the folded result of
+        /// its children — so an intermediate subtree ("cube minus three
+        /// cylinders" as one `Op`) can itself be repositioned, not just its
+        /// leaves.
+        translation: Vec3,
+    },
+}
+
+fn snapshot_csg_node(
+    entity: Entity,
+    nodes: &Query<(&CsgNode, Option<&Handle<Mesh>>, Option<&Transform>)>,
+    mesh_assets: &Assets<Mesh>,
+    content_versions: &MeshContentVersions,
+) -> CsgSnapshot {
+    let Ok((node, mesh_handle, transform)) = nodes.get(entity) else {
+        return CsgSnapshot::Leaf { entity, hash: 0, data: (Vec::new(), Vec::new()) };
+    };
+
+    match node {
+        CsgNode::Leaf => {
+            let translation = transform.map(|t| t.translation).unwrap_or(Vec3::ZERO);
+            let hash = hash_leaf(mesh_handle, translation, content_versions);
+            let data = mesh_handle
+                .and_then(|handle| mesh_assets.get(handle))
+                .map(|mesh| bevy_mesh_to_flat(mesh, translation))
+                .unwrap_or_default();
+            CsgSnapshot::Leaf { entity, hash, data }
+        }
+        CsgNode::Op { op, children } => {
+            let translation = transform.map(|t| t.translation).unwrap_or(Vec3::ZERO);
+            let children_snapshots =
+                children.iter().map(|&child| snapshot_csg_node(child, nodes, mesh_assets, content_versions)).collect();
+            CsgSnapshot::Op { entity, op: *op, children_entities: children.clone(), children: children_snapshots, translation }
+        }
+    }
+}
+
+/// Walk a [`CsgSnapshot`] bottom-up, folding each `Op` node's children under
+/// `boolean_op_raw`, consulting (and appending to) `results` rather than a
+/// live [`CsgEvalCache`] so this can run off the main thread; [`poll_csg_eval`]
+/// merges `results` back into the real cache once the task resolves.
+fn evaluate_csg_snapshot(
+    snapshot: &CsgSnapshot,
+    cache: &std::collections::HashMap<Entity, (u64, (Vec<f32>, Vec<u32>))>,
+    results: &mut Vec<(Entity, u64, (Vec<f32>, Vec<u32>))>,
+) -> (u64, (Vec<f32>, Vec<u32>)) {
+    match snapshot {
+        CsgSnapshot::Leaf { entity, hash, data } => {
+            if let Some((cached_hash, cached_data)) = cache.get(entity) {
+                if cached_hash == hash {
+                    return (*hash, cached_data.clone());
+                }
+            }
+            results.push((*entity, *hash, data.clone()));
+            (*hash, data.clone())
+        }
+        CsgSnapshot::Op { entity, op, children_entities, children, translation } => {
+            let child_results: Vec<(u64, (Vec<f32>, Vec<u32>))> =
+                children.iter().map(|child| evaluate_csg_snapshot(child, cache, results)).collect();
+
+            let child_hashes: Vec<u64> = child_results.iter().map(|(h, _)| *h).collect();
+            let hash = hash_op(*op, children_entities, &child_hashes, *translation);
+            if let Some((cached_hash, cached_data)) = cache.get(entity) {
+                if *cached_hash == hash {
+                    return (hash, cached_data.clone());
+                }
+            }
+
+            let ffi_op = boolean_op_from_state(*op).unwrap_or(manifold_rs::BooleanOp::Union);
+            let mut results_iter = child_results.into_iter().map(|(_, data)| data);
+            let data = match results_iter.next() {
+                Some(first) => results_iter.fold(first, |acc, next| boolean_op_raw(&acc, &next, ffi_op)),
+                None => (Vec::new(), Vec::new()),
+            };
+            let data = translate_flat_raw(&data, *translation);
+
+            results.push((*entity, hash, data.clone()));
+            (hash, data)
+        }
+    }
+}
+
+/// Snapshot every [`CsgRoot`] tree that isn't already being evaluated and
+/// hand the hashing/cache-lookup/boolean-fold work to the async compute task
+/// pool, mirroring [`dispatch_boolean_op`]/[`poll_boolean_op`] for the
+/// primary/secondary pipeline so a deep tree's boolean folds don't stall the
+/// frame the same way a single large op wouldn't.
+fn dispatch_csg_eval(
+    mut commands: Commands,
+    roots: Query<Entity, (With<CsgRoot>, Without<CsgEvalTask>)>,
+    nodes: Query<(&CsgNode, Option<&Handle<Mesh>>, Option<&Transform>)>,
+    mesh_assets: Res<Assets<Mesh>>,
+    cache: Res<CsgEvalCache>,
+    content_versions: Res<MeshContentVersions>,
+) {
+    for root in &roots {
+        let snapshot = snapshot_csg_node(root, &nodes, &mesh_assets, &content_versions);
+        let cache_snapshot = cache.entries.clone();
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            let mut results = Vec::new();
+            let (_, root_data) = evaluate_csg_snapshot(&snapshot, &cache_snapshot, &mut results);
+            (root_data, results)
+        });
+        commands.entity(root).insert(CsgEvalTask(task));
+    }
+}
+
+/// Poll each in-flight [`CsgEvalTask`], merge its returned per-node cache
+/// entries back into [`CsgEvalCache`], and write the root's combined result
+/// onto its own mesh.
+fn poll_csg_eval(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut CsgEvalTask)>,
+    nodes: Query<Option<&Handle<Mesh>>, With<CsgRoot>>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+    mut cache: ResMut<CsgEvalCache>,
+    mut content_versions: ResMut<MeshContentVersions>,
+) {
+    for (root, mut task) in &mut query {
+        let Some(((positions, indices), results)) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(root).remove::<CsgEvalTask>();
+
+        for (entity, hash, data) in results {
+            cache.entries.insert(entity, (hash, data));
+        }
+
+        let vertex_positions: Vec<[f32; 3]> = positions.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+        let mesh = raw_submesh_to_bevy_mesh(vertex_positions, None, None, indices, false);
+
+        if let Ok(Some(mesh_handle)) = nodes.get(root) {
+            if let Some(existing) = mesh_assets.get_mut(mesh_handle) {
+                *existing = mesh;
+                content_versions.bump(mesh_handle.id());
+                continue;
+            }
+        }
+        let new_handle = mesh_assets.add(mesh);
+        commands.entity(root).insert(new_handle);
+    }
+}
+
+/// The in-flight task for a [`CsgRoot`]'s tree evaluation, mirroring
+/// [`BooleanOpTask`] for the primary/secondary pipeline.
+#[derive(Component)]
+struct CsgEvalTask(Task<((Vec<f32>, Vec<u32>), Vec<(Entity, u64, (Vec<f32>, Vec<u32>))>)>);
+
+/// One step in a [`BooleanTree`]'s fold: combine the running accumulator
+/// with `entity`'s mesh (baked into place by its current full
+/// [`GlobalTransform`] affine) under `op`. The first operand's `op` is never
+/// read — it seeds the fold rather than being combined with anything.
+#[derive(Clone, Copy)]
+pub struct BooleanTreeOperand {
+    pub entity: Entity,
+    pub op: BooleanOpState,
+}
+
+/// An ordered list of operands folded left-to-right into a single result
+/// mesh: `acc = operand_0`, then `acc = op_i(acc, operand_i)` for each
+/// following operand — e.g. "block minus three holes then intersected with
+/// a shell" as one entity, instead of chaining `PrimaryBooleanMesh`/
+/// `SecondaryBooleanMesh` pairs or building a [`CsgNode`] tree where every
+/// `Op` node shares a single `op` across all its children. Suits CSG
+/// hierarchies authored in a DCC tool and exported flat, e.g. from a
+/// Blender→glTF blueprint.
+///
+/// [`dispatch_boolean_tree`]/[`poll_boolean_tree`] write the folded result
+/// onto this same entity's `Handle<Mesh>`, reusing [`CsgEvalCache`] (keyed
+/// by this entity) to skip the whole fold when no operand's mesh or
+/// [`GlobalTransform`] changed since the last run.
+#[derive(Component, Clone)]
+pub struct BooleanTree(pub Vec<BooleanTreeOperand>);
+
+/// The in-flight task for a [`BooleanTree`]'s fold, mirroring
+/// [`MultiOperandTask`]/[`CsgEvalTask`].
+#[derive(Component)]
+struct BooleanTreeTask(Task<(u64, (Vec<f32>, Vec<u32>))>);
+
+/// Flatten a Bevy mesh's positions (baked into place by `affine`) and
+/// indices into the `(Vec<f32>, Vec<u32>)` shape [`boolean_op_raw`] operates
+/// on — the full-affine counterpart of [`bevy_mesh_to_flat`], for operands
+/// whose [`GlobalTransform`] may carry rotation or non-uniform scale as well
+/// as translation.
+fn bevy_mesh_to_flat_affine(mesh: &Mesh, affine: Affine3A) -> (Vec<f32>, Vec<u32>) {
+    let (positions, indices) = bevy_mesh_to_raw(mesh);
+    let flat = positions
+        .iter()
+        .flat_map(|&p| affine.transform_point3(Vec3::from(p)).to_array())
+        .collect();
+    (flat, indices)
+}
+
+fn hash_leaf_affine(mesh_handle: Option<&Handle<Mesh>>, affine: Affine3A, content_versions: &MeshContentVersions) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    mesh_handle.map(|h| h.id()).hash(&mut hasher);
+    content_versions.get(mesh_handle).hash(&mut hasher);
+    for axis in [affine.matrix3.x_axis, affine.matrix3.y_axis, affine.matrix3.z_axis, Vec3::from(affine.translation)] {
+        axis.x.to_bits().hash(&mut hasher);
+        axis.y.to_bits().hash(&mut hasher);
+        axis.z.to_bits().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn hash_boolean_tree(operands: &[BooleanTreeOperand], leaf_hashes: &[u64]) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    for (operand, leaf_hash) in operands.iter().zip(leaf_hashes) {
+        operand.op.hash(&mut hasher);
+        leaf_hash.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// For every [`BooleanTree`] entity without an in-flight task, snapshot its
+/// operands' mesh data at their current [`GlobalTransform`], skip the fold
+/// entirely if [`CsgEvalCache`] already holds this entity's combined hash
+/// (nothing changed), and otherwise hand the left-to-right fold to the
+/// async compute task pool, mirroring [`dispatch_csg_eval`].
+fn dispatch_boolean_tree(
+    mut commands: Commands,
+    trees: Query<(Entity, &BooleanTree), Without<BooleanTreeTask>>,
+    operands: Query<(Option<&Handle<Mesh>>, Option<&GlobalTransform>)>,
+    mesh_assets: Res<Assets<Mesh>>,
+    cache: Res<CsgEvalCache>,
+    content_versions: Res<MeshContentVersions>,
+) {
+    for (entity, tree) in &trees {
+        if tree.0.is_empty() {
+            continue;
+        }
+
+        let mut leaf_hashes = Vec::with_capacity(tree.0.len());
+        let mut operand_data = Vec::with_capacity(tree.0.len());
+        for operand in &tree.0 {
+            let (mesh_handle, transform) = operands.get(operand.entity).unwrap_or((None, None));
+            let affine = transform.map(|t| t.affine()).unwrap_or(Affine3A::IDENTITY);
+            leaf_hashes.push(hash_leaf_affine(mesh_handle, affine, &content_versions));
+            let data = mesh_handle
+                .and_then(|handle| mesh_assets.get(handle))
+                .map(|mesh| bevy_mesh_to_flat_affine(mesh, affine))
+                .unwrap_or_default();
+            operand_data.push(data);
+        }
+
+        let hash = hash_boolean_tree(&tree.0, &leaf_hashes);
+        if let Some((cached_hash, _)) = cache.entries.get(&entity) {
+            if *cached_hash == hash {
+                continue;
+            }
+        }
+
+        let ops: Vec<BooleanOpState> = tree.0.iter().map(|o| o.op).collect();
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            let mut operands = operand_data.into_iter();
+            let Some(first) = operands.next() else {
+                return (hash, (Vec::new(), Vec::new()));
+            };
+            let acc = operands.zip(ops.into_iter().skip(1)).fold(first, |acc, (next, op)| {
+                let ffi_op = boolean_op_from_state(op).unwrap_or(manifold_rs::BooleanOp::Union);
+                boolean_op_raw(&acc, &next, ffi_op)
+            });
+            (hash, acc)
+        });
+        commands.entity(entity).insert(BooleanTreeTask(task));
+    }
+}
+
+/// Poll each in-flight [`BooleanTreeTask`], cache its hash under
+/// [`CsgEvalCache`] so an unchanged tree is skipped entirely next frame, and
+/// write the folded result onto the entity's own mesh, mirroring
+/// [`poll_csg_eval`].
+fn poll_boolean_tree(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut BooleanTreeTask, Option<&Handle<Mesh>>)>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+    mut cache: ResMut<CsgEvalCache>,
+    mut content_versions: ResMut<MeshContentVersions>,
+) {
+    for (entity, mut task, mesh_handle) in &mut query {
+        let Some((hash, (positions, indices))) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<BooleanTreeTask>();
+
+        let vertex_positions: Vec<[f32; 3]> = positions.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+        cache.entries.insert(entity, (hash, (positions, indices.clone())));
+        let mesh = raw_submesh_to_bevy_mesh(vertex_positions, None, None, indices, false);
+
+        if let Some(handle) = mesh_handle {
+            if let Some(existing) = mesh_assets.get_mut(handle) {
+                *existing = mesh;
+                content_versions.bump(handle.id());
+                continue;
+            }
+        }
+        let new_handle = mesh_assets.add(mesh);
+        commands.entity(entity).insert(new_handle);
+    }
+}
+
+/// A single binary boolean result living entirely on one entity: `op` over
+/// `lhs`/`rhs`, without the `PrimaryBooleanMesh`/`SecondaryBooleanMesh`/
+/// [`BooleanHandles`] trio [`MeshBooleanPlugin::spawn_boolean_operation`]
+/// wires up and registers in [`BooleanOperations`]. Many `CsgOperation`
+/// entities can coexist in one `World` — e.g. an assembly of parts, each
+/// with its own cut.
+#[derive(Component, Clone, Copy)]
+pub struct CsgOperation {
+    pub op: BooleanOpState,
+    pub lhs: Entity,
+    pub rhs: Entity,
+}
+
+/// Marks the entity a [`CsgOperation`] writes its folded `Handle<Mesh>`
+/// onto — the per-entity model's counterpart to [`BooleanResultSubmesh`],
+/// so other systems can query "every CSG result" without also matching
+/// plain operand entities.
+#[derive(Component)]
+pub struct CsgResult;
+
+/// The in-flight task for a [`CsgOperation`]'s fold, mirroring
+/// [`BooleanTreeTask`] but for exactly two operands instead of an ordered
+/// list.
+#[derive(Component)]
+struct CsgOperationTask(Task<(Vec<f32>, Vec<u32>)>);
+
+/// Run condition gating [`dispatch_csg_operation`]: `true` when some
+/// [`CsgOperation`] component itself changed, or either of its `lhs`/`rhs`
+/// entities' `Handle<Mesh>` changed — mirrors [`boolean_inputs_changed`]'s
+/// reasoning but scoped to this per-entity model instead of
+/// [`BooleanOperations`]'s registry.
+pub fn csg_operation_inputs_changed(
+    op_changed: Query<(), Changed<CsgOperation>>,
+    mesh_handle_changed: Query<(), Changed<Handle<Mesh>>>,
+    operations: Query<&CsgOperation>,
+) -> bool {
+    if !op_changed.is_empty() {
+        return true;
+    }
+    operations.iter().any(|csg| mesh_handle_changed.contains(csg.lhs) || mesh_handle_changed.contains(csg.rhs))
+}
+
+/// For every [`CsgOperation`] entity without an in-flight task whose `op`,
+/// `lhs`, or `rhs` geometry actually changed, snapshot both operands' mesh
+/// data at their current [`GlobalTransform`] and hand the fold to the async
+/// compute task pool, same as [`dispatch_boolean_tree`] but for a fixed pair
+/// of operands rather than a folded list.
+fn dispatch_csg_operation(
+    mut commands: Commands,
+    operations: Query<(Entity, &CsgOperation), (With<CsgResult>, Without<CsgOperationTask>)>,
+    op_changed: Query<(), Changed<CsgOperation>>,
+    mesh_handle_changed: Query<(), Changed<Handle<Mesh>>>,
+    operands: Query<(&Handle<Mesh>, &GlobalTransform)>,
+    mesh_assets: Res<Assets<Mesh>>,
+) {
+    for (entity, csg) in &operations {
+        let inputs_changed =
+            op_changed.contains(entity) || mesh_handle_changed.contains(csg.lhs) || mesh_handle_changed.contains(csg.rhs);
+        if !inputs_changed {
+            continue;
+        }
+
+        let Ok((lhs_handle, lhs_transform)) = operands.get(csg.lhs) else { continue };
+        let Ok((rhs_handle, rhs_transform)) = operands.get(csg.rhs) else { continue };
+        let Some(lhs_mesh) = mesh_assets.get(lhs_handle) else { continue };
+        let Some(rhs_mesh) = mesh_assets.get(rhs_handle) else { continue };
+
+        let lhs_data = bevy_mesh_to_flat_affine(lhs_mesh, lhs_transform.affine());
+        let rhs_data = bevy_mesh_to_flat_affine(rhs_mesh, rhs_transform.affine());
+        let op = csg.op;
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            let ffi_op = boolean_op_from_state(op).unwrap_or(manifold_rs::BooleanOp::Union);
+            boolean_op_raw(&lhs_data, &rhs_data, ffi_op)
+        });
+        commands.entity(entity).insert(CsgOperationTask(task));
+    }
+}
+
+/// Poll each in-flight [`CsgOperationTask`] and write the folded result back
+/// onto its own `Handle<Mesh>`, mirroring [`poll_boolean_tree`].
+fn poll_csg_operation(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut CsgOperationTask, Option<&Handle<Mesh>>), With<CsgResult>>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+    mut content_versions: ResMut<MeshContentVersions>,
+) {
+    for (entity, mut task, mesh_handle) in &mut query {
+        let Some((positions, indices)) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<CsgOperationTask>();
+
+        let vertex_positions: Vec<[f32; 3]> = positions.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect();
+        let mesh = raw_submesh_to_bevy_mesh(vertex_positions, None, None, indices, false);
+
+        if let Some(handle) = mesh_handle {
+            if let Some(existing) = mesh_assets.get_mut(handle) {
+                *existing = mesh;
+                content_versions.bump(handle.id());
+                continue;
+            }
+        }
+        let new_handle = mesh_assets.add(mesh);
+        commands.entity(entity).insert(new_handle);
+    }
+}
+
+/// Flatten a Bevy mesh's positions (translated into place) and indices into
+/// the `(Vec<f32>, Vec<u32>)` shape [`boolean_op_raw`]/[`reduce_boolean_parallel`]
+/// already operate on.
+fn bevy_mesh_to_flat(mesh: &Mesh, translation: Vec3) -> (Vec<f32>, Vec<u32>) {
+    let (positions, indices) = bevy_mesh_to_raw(mesh);
+    let flat = positions
+        .iter()
+        .flat_map(|p| [p[0] + translation.x, p[1] + translation.y, p[2] + translation.z])
+        .collect();
+    (flat, indices)
+}
+
+fn hash_leaf(mesh_handle: Option<&Handle<Mesh>>, translation: Vec3, content_versions: &MeshContentVersions) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    mesh_handle.map(|h| h.id()).hash(&mut hasher);
+    content_versions.get(mesh_handle).hash(&mut hasher);
+    translation.x.to_bits().hash(&mut hasher);
+    translation.y.to_bits().hash(&mut hasher);
+    translation.z.to_bits().hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_op(op: BooleanOpState, children: &[Entity], child_hashes: &[u64], translation: Vec3) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    op.hash(&mut hasher);
+    children.hash(&mut hasher);
+    child_hashes.hash(&mut hasher);
+    translation.x.to_bits().hash(&mut hasher);
+    translation.y.to_bits().hash(&mut hasher);
+    translation.z.to_bits().hash(&mut hasher);
+    hasher.finish()
+}
+
+// Per-operation component controlling that operation's result. Also derives
+// `Resource` for callers still driving a single global operation, and
+// `Reflect` so `BooleanRole::Operand` (see `blueprint`) can be reflected too.
+#[derive(Resource, Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub enum BooleanOpState {
+    #[default]
+    None,
+    Intersect,
+    Union,
+    Subtract,
+}
+
+/// Which algorithm [`compute_boolean_op`] runs for one operation. An optional
+/// per-operation component, queried by [`dispatch_boolean_op`] on the result
+/// entity the same way [`SubdivisionSettings`] is; an operation with none
+/// attached runs `Exact`. Also derives `Resource` so an app driving a single
+/// global operation (alongside the global `BooleanOpState`) can pick the
+/// solver the same way.
+#[derive(Resource, Component, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanSolver {
+    /// Classify each triangle of one operand as inside/outside the other
+    /// with a ray-parity test and keep the side the operator calls for, with
+    /// no cutting of triangles that straddle the other operand's boundary.
+    /// Cheap, and correct for clean, non-overlapping manifold input, but a
+    /// coplanar face or an edge lying exactly on the other mesh's surface
+    /// can misclassify a triangle.
+    Fast,
+    /// Delegate to `manifold_rs`'s exact-arithmetic `boolean_op`, which cuts
+    /// every straddling triangle and resolves coplanar overlaps correctly.
+    #[default]
+    Exact,
+}
+
+/// Per-operation opt-in for `BooleanSolver::Exact`'s `hole_tolerant`
+/// correction pass: STEP-loaded meshes (e.g. `multifeature.step` in the Bevy
+/// example) are frequently non-manifold or contain holes, which trips up the
+/// fast face-adjacency-propagated classification `boolean_op` normally
+/// relies on. Attach with `true` to re-verify every output triangle against
+/// both operands with an independent ray-cast insideness test instead —
+/// ~9x slower on large meshes (measured ~90s vs ~10s on a ~1.2M-triangle
+/// input) but correct on meshes the fast path gets wrong. Queried by
+/// [`dispatch_boolean_op`] the same way [`SubdivisionSettings`] is; an
+/// operation with none attached behaves as `false`.
+#[derive(Resource, Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HoleTolerant(pub bool);
+
+/// Per-operation opt-in for running [`repair_mesh`] on both operands before
+/// `BooleanSolver::Exact` builds a `Manifold` from them, so a STEP import
+/// with unwelded seams or a few degenerate triangles doesn't need to go
+/// through [`make_mesh_watertight`]'s much coarser convex-hull/bounding-box
+/// fallback. Queried by [`dispatch_boolean_op`] the same way
+/// [`HoleTolerant`] is; an operation with none attached behaves as `false`.
+/// Not consulted by `BooleanSolver::Fast`, same as `HoleTolerant`.
+#[derive(Resource, Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MeshRepair(pub bool);
+
+/// Keep 32-bit indices on a boolean result's submeshes even when the vertex
+/// count would fit in 16 bits. [`poll_boolean_op`] downcasts to `U16`
+/// automatically below 65536 vertices to halve the index buffer's size;
+/// attach this with `true` to opt a result entity out, e.g. if a downstream
+/// system assumes `Indices::U32`. An entity with none attached gets the
+/// automatic downcast.
+#[derive(Resource, Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForceU32Indices(pub bool);
+
+/// Whether a boolean operation is currently computing off-thread. Set to
+/// `Running` by [`dispatch_boolean_op`] the frame it spawns a
+/// [`BooleanOpTask`], and back to `Idle` by [`poll_boolean_op`] the frame
+/// that task resolves — so a UI text system can show something truer than a
+/// static "Current Operation: Subtract" label while a multi-second op is
+/// still running in the background.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanProgress {
+    #[default]
+    Idle,
+    Running {
+        op: BooleanOpState,
+    },
+}
+
+/// Terminal-state counterpart to [`BooleanProgress`]: where `BooleanProgress`
+/// only distinguishes "running" from "not running" (collapsing every
+/// finished op back to `Idle`), this also remembers whether the *last*
+/// finished op actually succeeded, so UI can show a persistent "failed"
+/// indicator instead of it flashing back to a neutral idle state the same
+/// frame the task resolves. Set to `Pending` by [`dispatch_boolean_op`] and
+/// to `Done`/`Failed` by [`poll_boolean_op`] depending on the outcome.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOpStatus {
+    #[default]
+    Idle,
+    Pending,
+    Done,
+    Failed,
+}
+
+// Component to mark the primary entity in a boolean operation
+#[derive(Component)]
+pub struct PrimaryBooleanMesh {
+    pub secondary_entity: Entity,
+}
+
+// Component to mark the secondary entity in a boolean operation
+#[derive(Component)]
+pub struct SecondaryBooleanMesh {
+    pub primary_entity: Entity,
+}
+
+/// A single operation's primary/secondary/result entities. Stored as a value
+/// in [`BooleanOperations`] rather than as its own `Resource`, since an app
+/// can host more than one of these at once.
+#[derive(Clone, Copy)]
+pub struct BooleanHandles {
+    pub primary_entity: Entity,
+    pub secondary_entity: Entity,
+    pub result_entity: Entity,
+}
+
+/// A `Hasher` that only ever hashes a single `u64` — an [`Entity`]'s raw
+/// bits — by spreading them with a fixed multiplier so low bits (the
+/// entity's index, which is often small) still land
+/// across the whole hash. Deliberately panics on any other input so it can
+/// never silently degrade into hashing something it wasn't built for.
+#[derive(Default)]
+pub struct EntityHasher(u64);
+
+impl std::hash::Hasher for EntityHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, _bytes: &[u8]) {
+        panic!("EntityHasher only hashes u64 entity bits");
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = i | (i.wrapping_mul(0x517cc1b727220a95) << 32);
+    }
+}
+
+/// Builds [`EntityHasher`]s. A no-op: the hasher needs no per-map seed since
+/// it's keyed purely off of the `Entity` being hashed.
+#[derive(Default, Clone, Copy)]
+pub struct EntityBuildHasher;
+
+impl std::hash::BuildHasher for EntityBuildHasher {
+    type Hasher = EntityHasher;
+
+    fn build_hasher(&self) -> EntityHasher {
+        EntityHasher::default()
+    }
+}
+
+/// A `HashMap` keyed by [`Entity`] and hashed with [`EntityHasher`] instead
+/// of the default SipHash, since an `Entity` is already a well-mixed `u64`
+/// and doesn't need a general-purpose hasher on this per-operation lookup
+/// path.
+pub type EntityHashMap<V> = std::collections::HashMap<Entity, V, EntityBuildHasher>;
+
+/// Registry of every active boolean operation, keyed by its result entity,
+/// so an app can host dozens of simultaneous boolean widgets instead of only
+/// ever evaluating the most recently spawned one.
+#[derive(Resource, Default)]
+pub struct BooleanOperations {
+    entries: EntityHashMap<BooleanHandles>,
+}
+
+impl BooleanOperations {
+    pub fn insert(&mut self, handles: BooleanHandles) {
+        self.entries.insert(handles.result_entity, handles);
+    }
+
+    pub fn get(&self, result_entity: Entity) -> Option<&BooleanHandles> {
+        self.entries.get(&result_entity)
+    }
+
+    pub fn remove(&mut self, result_entity: Entity) -> Option<BooleanHandles> {
+        self.entries.remove(&result_entity)
+    }
+
+    /// Every currently-registered operation's handles, for a run condition
+    /// or other read-only sweep that needs to look at all of them without a
+    /// result entity in hand.
+    pub fn handles(&self) -> impl Iterator<Item = &BooleanHandles> {
+        self.entries.values()
+    }
+}
+
+/// Evicts a [`BooleanOperations`] entry once its result entity is despawned.
+/// `spawn_boolean_operation` always attaches `BooleanOpState` to the result
+/// entity, and Bevy reports that component's removal the same way whether it
+/// was removed in place or the whole entity despawned — so this is the only
+/// signal needed to keep [`BooleanOperations`] from growing unbounded in an
+/// app that spawns and despawns boolean widgets dynamically, since nothing
+/// else ever calls [`BooleanOperations::remove`].
+fn evict_despawned_boolean_operations(mut removed: RemovedComponents<BooleanOpState>, mut operations: ResMut<BooleanOperations>) {
+    for entity in removed.read() {
+        operations.remove(entity);
+    }
+}
+
+/// Run condition gating [`dispatch_boolean_op`]: `true` when at least one
+/// registered operation's `BooleanOpState` changed, or either of its
+/// primary/secondary entities' `Handle<Mesh>` changed — so editing an
+/// operand's geometry in place (without touching the operator) still
+/// triggers a recompute, and the dispatch system (which otherwise has to
+/// walk every registered operation each frame) only runs on frames where
+/// something actually did. Replaces gating dispatch on a one-shot
+/// "has this run yet" bool flag, which only ever fires the op once.
+pub fn boolean_inputs_changed(
+    operations: Res<BooleanOperations>,
+    op_state_changed: Query<(), Changed<BooleanOpState>>,
+    mesh_handle_changed: Query<(), Changed<Handle<Mesh>>>,
+) -> bool {
+    if !op_state_changed.is_empty() {
+        return true;
+    }
+    operations
+        .handles()
+        .any(|handles| mesh_handle_changed.contains(handles.primary_entity) || mesh_handle_changed.contains(handles.secondary_entity))
+}
+
+// The dispatch system: for every registered operation whose `BooleanOpState`
+// changed, snapshot the primary and secondary mesh data plus transforms and
+// hand the conversion + `boolean_op` + `manifold_to_bevy_mesh` work to the
+// async compute task pool, rather than stalling the frame doing it inline.
+fn dispatch_boolean_op(
+    mut commands: Commands,
+    operations: Res<BooleanOperations>,
+    op_states: Query<(Entity, &BooleanOpState)>,
+    op_state_changed: Query<(), Changed<BooleanOpState>>,
+    mesh_handle_changed: Query<(), Changed<Handle<Mesh>>>,
+    subdivision_query: Query<&SubdivisionSettings>,
+    tolerance_query: Query<&ToleranceSettings>,
+    solver_query: Query<&BooleanSolver>,
+    hole_tolerant_query: Query<&HoleTolerant>,
+    mesh_repair_query: Query<&MeshRepair>,
+    gpu_broadphase: Res<GpuBroadphase>,
+    gpu_device: Option<Res<GpuClassifyDevice>>,
+    pbr_query: Query<(&Handle<Mesh>, &GlobalTransform)>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut visibility_query: Query<&mut Visibility>,
+    mut progress: ResMut<BooleanProgress>,
+    mut status: ResMut<BooleanOpStatus>,
+    in_flight: Query<(), With<BooleanOpTask>>,
+) {
+    for (result_entity, op_state) in &op_states {
+        let Some(handles) = operations.get(result_entity) else {
+            continue;
+        };
+
+        // This system only runs at all when `boolean_inputs_changed` says
+        // something did; per-entity, only recompute the ones where it was
+        // *this* entity's state or operand geometry, not some unrelated
+        // registered operation elsewhere in `operations`.
+        let inputs_changed = op_state_changed.contains(result_entity)
+            || mesh_handle_changed.contains(handles.primary_entity)
+            || mesh_handle_changed.contains(handles.secondary_entity);
+        if !inputs_changed {
+            continue;
+        }
+
+        // `op_state` just changed again while the previous op was still
+        // running off-thread: dropping its `BooleanOpTask` cancels that task,
+        // so the new one spawned below supersedes it instead of both racing
+        // to write the same result entity's children.
+        if in_flight.contains(result_entity) {
+            commands.entity(result_entity).remove::<BooleanOpTask>();
+        }
+
+        let primary_entity = handles.primary_entity;
+        let secondary_entity = handles.secondary_entity;
+
+        let Ok((primary_handle, primary_global_transform)) = pbr_query.get(primary_entity) else {
+            continue;
+        };
+        let Ok((secondary_handle, secondary_global_transform)) = pbr_query.get(secondary_entity) else {
+            continue;
+        };
+        let Some(primary_mesh) = mesh_assets.get(primary_handle) else {
+            continue;
+        };
+        let Some(secondary_mesh) = mesh_assets.get(secondary_handle) else {
+            continue;
+        };
+
+        if *op_state == BooleanOpState::None {
+            if let Ok(mut vis) = visibility_query.get_mut(primary_entity) {
+                *vis = Visibility::Visible;
+            }
+            if let Ok(mut vis) = visibility_query.get_mut(secondary_entity) {
+                *vis = Visibility::Visible;
+            }
+            if let Ok(mut vis) = visibility_query.get_mut(result_entity) {
+                *vis = Visibility::Hidden;
+            }
+            *progress = BooleanProgress::Idle;
+            *status = BooleanOpStatus::Idle;
+            continue;
+        }
+
+        if let Ok(mut vis) = visibility_query.get_mut(primary_entity) {
+            *vis = Visibility::Hidden;
+        }
+        if let Ok(mut vis) = visibility_query.get_mut(secondary_entity) {
+            *vis = Visibility::Hidden;
+        }
+
+        let dispatch_start = std::time::Instant::now();
+        let primary_data = bevy_mesh_to_raw(primary_mesh);
+        let secondary_data = bevy_mesh_to_raw(secondary_mesh);
+        let primary_normals = mesh_attribute_f32x3(primary_mesh, Mesh::ATTRIBUTE_NORMAL);
+        let primary_uv0 = mesh_attribute_f32x2(primary_mesh, Mesh::ATTRIBUTE_UV_0);
+        let secondary_normals = mesh_attribute_f32x3(secondary_mesh, Mesh::ATTRIBUTE_NORMAL);
+        let secondary_uv0 = mesh_attribute_f32x2(secondary_mesh, Mesh::ATTRIBUTE_UV_0);
+        // `GlobalTransform` (not the local `Transform`) so a cutting entity
+        // under a rotated/scaled parent still lines up with the primary — and
+        // its full affine, not just `.translation`, so rotation and
+        // non-uniform scale on the entity itself (e.g. `--cube-size-factor`)
+        // actually reach the boolean instead of only position.
+        let primary_affine = primary_global_transform.affine();
+        let secondary_affine = secondary_global_transform.affine();
+        let op = *op_state;
+        let subdivision = subdivision_query.get(result_entity).ok().copied();
+        let tolerance = tolerance_query.get(result_entity).copied().unwrap_or_default();
+        let solver = solver_query.get(result_entity).copied().unwrap_or_default();
+        let hole_tolerant = hole_tolerant_query.get(result_entity).copied().unwrap_or_default().0;
+        let mesh_repair = mesh_repair_query.get(result_entity).copied().unwrap_or_default().0;
+        // Cloning is cheap (`RenderDevice`/`RenderQueue`/the pipeline handle
+        // are all `Arc`-backed) and lets `classify_operand` dispatch GPU work
+        // from inside the async task below without reaching back into ECS.
+        let gpu_device = gpu_broadphase.0.then(|| gpu_device.as_deref().cloned()).flatten();
+
+        let dispatch_duration = dispatch_start.elapsed();
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            compute_boolean_op(
+                primary_data,
+                primary_affine,
+                primary_normals,
+                primary_uv0,
+                secondary_data,
+                secondary_affine,
+                secondary_normals,
+                secondary_uv0,
+                op,
+                subdivision,
+                tolerance,
+                solver,
+                hole_tolerant,
+                mesh_repair,
+                gpu_device,
+            )
         });
+        commands.entity(result_entity).insert((BooleanOpTask(task), DispatchTiming(dispatch_duration)));
+        *progress = BooleanProgress::Running { op };
+        *status = BooleanOpStatus::Pending;
+    }
+}
+
+/// Poll the in-flight [`BooleanOpTask`] on the result entity, replacing its
+/// previous [`BooleanResultSubmesh`]/[`BooleanSeamMesh`] children with one
+/// new child per material region (plus a seam-curve child, if any seam
+/// edges came back) and flipping visibility once it completes.
+fn poll_boolean_op(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut BooleanOpTask, &DispatchTiming, Option<&BooleanResultMaterials>, Option<&Children>)>,
+    submesh_query: Query<(), With<BooleanResultSubmesh>>,
+    seam_mesh_query: Query<(), With<BooleanSeamMesh>>,
+    index_format_query: Query<&ForceU32Indices>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+    mut visibility_query: Query<&mut Visibility>,
+    mut result_events: EventWriter<BooleanOpResult>,
+    mut progress: ResMut<BooleanProgress>,
+    mut status: ResMut<BooleanOpStatus>,
+) {
+    for (entity, mut task, dispatch_timing, result_materials, children) in &mut query {
+        let Some((outcome, groups, seam_edges)) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+        let update_entity_start = std::time::Instant::now();
+
+        *progress = BooleanProgress::Idle;
+        *status = if matches!(outcome, BooleanOpOutcome::Panicked) {
+            BooleanOpStatus::Failed
+        } else {
+            BooleanOpStatus::Done
+        };
+
+        if let Some(children) = children {
+            for &child in children {
+                if submesh_query.contains(child) || seam_mesh_query.contains(child) {
+                    commands.entity(child).despawn();
+                }
+            }
+        }
+
+        let vertex_count = groups.iter().map(|(_, positions, _, _, _)| positions.len()).sum();
+        let triangle_count = groups.iter().map(|(_, _, _, _, indices)| indices.len() / 3).sum();
+        let force_u32 = index_format_query.get(entity).copied().unwrap_or_default().0;
+
+        for (material_id, positions, normals, uv0, indices) in groups {
+            let mesh_handle = mesh_assets.add(raw_submesh_to_bevy_mesh(positions, normals, uv0, indices, force_u32));
+            let material = result_materials
+                .and_then(|materials| materials.0.get(material_id.max(0) as usize))
+                .cloned()
+                .unwrap_or_default();
+
+            let child = commands
+                .spawn(BooleanEntityBundle::new(mesh_handle, material, Transform::IDENTITY))
+                .insert(BooleanResultSubmesh)
+                .id();
+            commands.entity(entity).push_children(&[child]);
+        }
+
+        let seams: Vec<[Vec3; 2]> =
+            seam_edges.iter().map(|[a, b]| [Vec3::from_array(*a), Vec3::from_array(*b)]).collect();
+        if !seams.is_empty() {
+            let seam_mesh_handle = mesh_assets.add(seam_edges_to_bevy_mesh(&seams));
+            let child = commands
+                .spawn(BooleanEntityBundle::new(seam_mesh_handle, Handle::default(), Transform::IDENTITY))
+                .insert(BooleanSeamMesh)
+                .id();
+            commands.entity(entity).push_children(&[child]);
+        }
+        commands.entity(entity).insert(BooleanSeams(seams));
+
+        let dispatch_duration = dispatch_timing.0;
+        commands.entity(entity).remove::<(BooleanOpTask, DispatchTiming)>();
+
+        if let Ok(mut vis) = visibility_query.get_mut(entity) {
+            *vis = Visibility::Visible;
+        }
+
+        let update_entity_duration = update_entity_start.elapsed();
+
+        result_events.send(outcome.into_event(entity, vertex_count, triangle_count, dispatch_duration, update_entity_duration));
+    }
+}
+
+/// The in-flight task for the primary/secondary boolean operation, stored on
+/// the result entity and polled by [`poll_boolean_op`]. Each tuple is one
+/// material region's `(material_id, positions, normals, uv0, indices)`;
+/// `normals` is carried through from a `"normal"` property channel attached
+/// by [`tag_and_transform`] (or recomputed wholesale when the result went
+/// through [`SubdivisionSettings`]), and `uv0` from a `"uv0"` channel the
+/// same way, minus the recompute (subdivision doesn't touch UVs).
+#[derive(Component)]
+struct BooleanOpTask(
+    Task<(
+        BooleanOpOutcome,
+        Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)>,
+        Vec<SeamEdge>,
+    )>,
+);
+
+/// How long [`dispatch_boolean_op`] spent snapshotting mesh data and
+/// transforms on the main thread before handing the rest off to the async
+/// task, stashed alongside [`BooleanOpTask`] so [`poll_boolean_op`] can fold
+/// it into the [`BooleanOpResult`] it reports once the task completes.
+#[derive(Component)]
+struct DispatchTiming(std::time::Duration);
+
+/// What happened inside [`compute_boolean_op`], independent of the entity it
+/// ran for, so the worker thread doesn't need a `World` handle to report it.
+/// Turned into a [`BooleanOpResult`] event by [`BooleanOpOutcome::into_event`]
+/// once [`poll_boolean_op`] knows which entity and how much geometry came out.
+enum BooleanOpOutcome {
+    /// The op ran normally and produced a result, whether or not it's empty.
+    Completed {
+        conversion: std::time::Duration,
+        operation: std::time::Duration,
+        writeback: std::time::Duration,
+        /// The whole `operation` time above, repeated here when
+        /// `BooleanSolver::Fast` had a GPU classification device available
+        /// (GPU and CPU shares of the classification are not separated) —
+        /// `None` whenever [`GpuBroadphase`] was off, no pipeline was ready,
+        /// or `solver` was `Exact` (which never touches the GPU path; see
+        /// [`gpu`]). Reported separately purely so runs with and without the
+        /// GPU path can be benchmarked side by side.
+        gpu_classify: Option<std::time::Duration>,
+    },
+    /// One or both operands weren't watertight, so a primitive cube/sphere
+    /// stood in for the real geometry.
+    PrimitiveFallback,
+    /// `BooleanSolver::Exact` ran on two watertight operands with an
+    /// operator other than `Intersect` (where an empty result is expected
+    /// whenever the operands don't overlap) but still came back with zero
+    /// triangles — almost certainly a coplanar-overlap or edge-on-face case
+    /// the exact solver couldn't resolve. Fell back to the primary operand
+    /// alone so the result entity still shows something.
+    CoplanarFallback,
+    /// The underlying `boolean_op` call panicked and was caught.
+    Panicked,
+}
+
+impl BooleanOpOutcome {
+    /// `dispatch` and `update_entity` come from outside `compute_boolean_op`
+    /// itself — the main-thread snapshot time [`dispatch_boolean_op`] stashed
+    /// in a [`DispatchTiming`] component, and [`poll_boolean_op`]'s own
+    /// children/mesh-asset writeback time — so they're threaded in here
+    /// rather than captured as part of `self`.
+    fn into_event(
+        self,
+        result_entity: Entity,
+        vertex_count: usize,
+        triangle_count: usize,
+        dispatch: std::time::Duration,
+        update_entity: std::time::Duration,
+    ) -> BooleanOpResult {
+        match self {
+            BooleanOpOutcome::Panicked => BooleanOpResult::Panicked { result_entity },
+            BooleanOpOutcome::PrimitiveFallback => BooleanOpResult::PrimitiveFallback { result_entity },
+            BooleanOpOutcome::CoplanarFallback => BooleanOpResult::CoplanarFallback { result_entity },
+            BooleanOpOutcome::Completed { .. } if vertex_count == 0 => BooleanOpResult::Empty { result_entity },
+            BooleanOpOutcome::Completed { conversion, operation, writeback, gpu_classify } => BooleanOpResult::Success {
+                result_entity,
+                vertex_count,
+                triangle_count,
+                dispatch,
+                conversion,
+                operation,
+                writeback,
+                update_entity,
+                gpu_classify,
+            },
+        }
+    }
+}
+
+/// Reported once per boolean-op run so UI code can show a message (e.g.
+/// "shapes don't overlap") instead of the operation just silently producing
+/// nothing, and so tests can assert on an emitted event instead of scraping
+/// stderr.
+#[derive(Event, Debug, Clone, Copy)]
+pub enum BooleanOpResult {
+    /// The op completed and produced at least one triangle. The five
+    /// durations cover the whole round trip: `dispatch` (main-thread mesh/
+    /// transform snapshot), `conversion` (raw buffers to `Manifold`),
+    /// `operation` (the actual `boolean_op` call), `writeback` (`Manifold`
+    /// back to raw submesh buffers) and `update_entity` (spawning the result
+    /// children and updating visibility) — `dispatch` and `update_entity` run
+    /// on the main thread around the async task, the other three inside it.
+    Success {
+        result_entity: Entity,
+        vertex_count: usize,
+        triangle_count: usize,
+        dispatch: std::time::Duration,
+        conversion: std::time::Duration,
+        operation: std::time::Duration,
+        writeback: std::time::Duration,
+        update_entity: std::time::Duration,
+        /// Set to the whole `operation` time when `BooleanSolver::Fast` had a
+        /// [`GpuBroadphase`] device available for this op (GPU and CPU shares
+        /// of the classification are not separated); `None` when no device
+        /// was available, including the whole `BooleanSolver::Exact` path.
+        gpu_classify: Option<std::time::Duration>,
+    },
+    /// The op completed but the result has no geometry, e.g. the operands
+    /// don't overlap (`Intersect`) or exactly cancel out.
+    Empty { result_entity: Entity },
+    /// One or both operands weren't watertight, so a primitive cube/sphere
+    /// stood in for the real geometry.
+    PrimitiveFallback { result_entity: Entity },
+    /// `BooleanSolver::Exact` hit a coplanar-overlap/degenerate case it
+    /// couldn't resolve and fell back to the primary operand alone.
+    CoplanarFallback { result_entity: Entity },
+    /// The underlying `boolean_op` call panicked and was caught.
+    Panicked { result_entity: Entity },
+}
+
+impl BooleanOpResult {
+    /// The result entity every variant carries, regardless of outcome —
+    /// lets [`simplify::dispatch_boolean_lod_chain`] react to a
+    /// [`BooleanOpResult`] without re-matching all five variants itself.
+    pub fn result_entity(&self) -> Entity {
+        match *self {
+            BooleanOpResult::Success { result_entity, .. }
+            | BooleanOpResult::Empty { result_entity }
+            | BooleanOpResult::PrimitiveFallback { result_entity }
+            | BooleanOpResult::CoplanarFallback { result_entity }
+            | BooleanOpResult::Panicked { result_entity } => result_entity,
+        }
+    }
+}
+
+/// Per-result-entity record of which [`StandardMaterial`] each originating
+/// solid's faces should keep, indexed by `material_id` (`0` = primary's
+/// material, `1` = secondary's).
+#[derive(Component, Clone)]
+pub struct BooleanResultMaterials(pub Vec<Handle<StandardMaterial>>);
+
+/// Marks a child of the result entity holding one material region's
+/// triangles, split out of the combined boolean result by origin.
+#[derive(Component)]
+pub struct BooleanResultSubmesh;
+
+/// Marks the child of the result entity holding the intersection seam
+/// curve — a `PrimitiveTopology::LineList` mesh of every edge where the two
+/// adjacent triangles came from different operands, built by
+/// [`extract_seam_edges`] and spawned/replaced by [`poll_boolean_op`]
+/// alongside the [`BooleanResultSubmesh`] children. Only spawned when the
+/// result actually has seam edges (an empty boolean, or a `Union` of
+/// disjoint operands, has none).
+#[derive(Component)]
+pub struct BooleanSeamMesh;
+
+/// The result entity's own copy of its seam edges — every `[start, end]`
+/// position pair [`extract_seam_edges`] found, independent of the
+/// [`BooleanSeamMesh`] child's `Mesh` asset, for callers that want to stroke
+/// cut lines, drive bevel/chamfer tooling, or just inspect the seam without
+/// reading mesh vertex buffers back out.
+#[derive(Component, Clone, Debug, Default)]
+pub struct BooleanSeams(pub Vec<[Vec3; 2]>);
+
+/// Build a `PrimitiveTopology::LineList` mesh from seam edges: two vertices
+/// per segment, indexed `0, 1, 2, 3, ...` in pairs (no shared-vertex
+/// welding, matching how [`crate::export`]'s STL and glTF writers emit each
+/// triangle's corners independently rather than deduplicating across them).
+fn seam_edges_to_bevy_mesh(seams: &[[Vec3; 2]]) -> Mesh {
+    let mut mesh = Mesh::new(bevy::render::mesh::PrimitiveTopology::LineList, bevy::render::render_asset::RenderAssetUsages::all());
+    let positions: Vec<[f32; 3]> = seams.iter().flat_map(|edge| [edge[0].to_array(), edge[1].to_array()]).collect();
+    let indices: Vec<u32> = (0..positions.len() as u32).collect();
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Optional post-process refinement for a boolean result, e.g. "subtract,
+/// then subdivide twice keeping creases sharper than 30° crisp." Attach to
+/// the result entity; [`dispatch_boolean_op`] picks it up on the next
+/// operation.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SubdivisionSettings {
+    /// Tessellates each triangle into `4^depth` sub-triangles.
+    pub depth: u32,
+    /// Edges whose dihedral angle is below this are smoothed; at or above
+    /// it, they're kept sharp.
+    pub crease_angle_degrees: f64,
+}
+
+/// Merge tolerance and post-process refinement for a boolean operation.
+/// Attach to the result entity alongside [`SubdivisionSettings`];
+/// [`dispatch_boolean_op`] picks it up the same way. Defaults (`None`/
+/// `false` everywhere) reproduce Manifold's own default tolerance and skip
+/// refinement, matching behavior before this existed.
+///
+/// `tolerance` is worth raising when combining operands exported at
+/// different scales/units, where Manifold's default (derived from
+/// floating-point precision at the geometry's own scale) can incorrectly
+/// collapse or fail to merge coincident vertices at the seam. `simplify`
+/// and `refine_to_length` run on the *result*, after the op completes, so
+/// they trade triangle count for fidelity without changing which vertices
+/// weld together during the op itself.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct ToleranceSettings {
+    /// Merge tolerance applied to both operands before the op, via
+    /// [`manifold_rs::Manifold::set_tolerance`]. `None` keeps Manifold's own
+    /// default.
+    pub tolerance: Option<f64>,
+    /// If set, the result is refined so no edge is longer than this length.
+    pub refine_to_length: Option<f64>,
+    /// If true, the result is simplified (collapsing features smaller than
+    /// its tolerance) after the op and any `refine_to_length` pass.
+    pub simplify: bool,
+}
+
+/// Flatten a Bevy mesh's position/index buffers into plain `Vec`s so they
+/// can cross into an async task (a `&Mesh` borrow can't outlive the frame).
+pub(crate) fn bevy_mesh_to_raw(mesh: &Mesh) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x3(pos)) => pos.clone(),
+        _ => Vec::new(),
+    };
+    let indices = match mesh.indices() {
+        Some(bevy::render::mesh::Indices::U32(indices)) => indices.clone(),
+        Some(bevy::render::mesh::Indices::U16(indices)) => indices.iter().map(|&i| i as u32).collect(),
+        None => Vec::new(),
+    };
+    (positions, indices)
+}
+
+/// Read a Bevy mesh's `Float32x3` vertex attribute (e.g. `ATTRIBUTE_NORMAL`)
+/// as a plain `Vec`, if it's present and of that format.
+fn mesh_attribute_f32x3(mesh: &Mesh, attribute: bevy::render::mesh::MeshVertexAttribute) -> Option<Vec<[f32; 3]>> {
+    match mesh.attribute(attribute) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x3(values)) => Some(values.clone()),
+        _ => None,
+    }
+}
+
+/// Read a Bevy mesh's `Float32x2` vertex attribute (e.g. `ATTRIBUTE_UV_0`) as
+/// a plain `Vec`, if it's present and of that format.
+fn mesh_attribute_f32x2(mesh: &Mesh, attribute: bevy::render::mesh::MeshVertexAttribute) -> Option<Vec<[f32; 2]>> {
+    match mesh.attribute(attribute) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x2(values)) => Some(values.clone()),
+        _ => None,
+    }
+}
+
+/// One seam segment: the two endpoints of a result edge where the triangles
+/// on either side came from different origins (see [`extract_seam_edges`]).
+/// A literal position pair rather than an index pair, since
+/// `compute_boolean_op`'s handful of return paths don't share a single
+/// index space to key an index pair against.
+type SeamEdge = [[f32; 3]; 2];
+
+/// Which input solid a submesh's triangles originated from: `0` for the
+/// primary operand, `1` for the secondary. Carried through `boolean_op` as a
+/// `material_id` vertex property channel so the result can be split back
+/// into per-material regions in [`split_by_material`].
+const PRIMARY_MATERIAL_ID: f32 = 0.0;
+const SECONDARY_MATERIAL_ID: f32 = 1.0;
+
+/// Build a [`TaggedManifold`] out of raw mesh data, tag every face with
+/// `material_id`, and attach whatever shading attributes are given as
+/// `"normal"`/`"uv0"` channels. `affine` (an entity's `GlobalTransform`, baked
+/// down to `Affine3A`) is applied to the positions before the manifold is
+/// ever built, and to the normals via its inverse-transpose (so non-uniform
+/// scale doesn't skew them) — unlike the old translation-only path, this
+/// can't be done as a post-build `Manifold::translate` call, since rotation
+/// and scale have to land on the same vertices the `"normal"`/`"uv0"`
+/// channels are keyed to.
+///
+/// `normals`/`uv0` are only copied in when their length matches the rebuilt
+/// manifold's own vertex count 1:1; a mismatch means `TaggedManifold::new`
+/// already welded or reordered vertices building the watertight mesh, and
+/// there's no cheap way back to which original vertex a given slot came
+/// from. The channels are attached (zero-filled) either way, though, so
+/// `primary`/`secondary` always end up with the same channel count — letting
+/// only one side through would trip the assert in `TaggedManifold::boolean_op`.
+fn tag_and_transform(
+    data: &(Vec<[f32; 3]>, Vec<u32>),
+    affine: Affine3A,
+    material_id: f32,
+    normals: Option<&[[f32; 3]]>,
+    uv0: Option<&[[f32; 2]]>,
+) -> Option<TaggedManifold> {
+    if data.0.is_empty() {
+        return None;
+    }
+    let (positions, _) = transform_raw(data, affine);
+    let vertices: Vec<f32> = positions.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
+    let mut tagged = TaggedManifold::new(&vertices, &data.1, Vec::new());
+    let rebuilt = tagged.manifold().to_mesh();
+    if rebuilt.vertices().is_empty() {
+        return None;
+    }
+    let rebuilt_num_verts = rebuilt.vertices().len() / rebuilt.num_props().max(1) as usize;
+
+    let transformed_normals = normals.map(|n| transform_normals_raw(n, affine));
+    let normal_data: Vec<f32> = match &transformed_normals {
+        Some(n) if n.len() == rebuilt_num_verts => n.iter().flat_map(|v| [v[0], v[1], v[2]]).collect(),
+        _ => vec![0.0; rebuilt_num_verts * 3],
+    };
+    tagged.set_property_channel("normal", 3, &normal_data);
+
+    let uv_data: Vec<f32> = match uv0 {
+        Some(uv) if uv.len() == rebuilt_num_verts => uv.iter().flat_map(|v| [v[0], v[1]]).collect(),
+        _ => vec![0.0; rebuilt_num_verts * 2],
+    };
+    tagged.set_property_channel("uv0", 2, &uv_data);
+
+    tagged.label_faces("material_id", |_| material_id);
+
+    Some(tagged)
+}
+
+fn fallback_tagged(manifold: manifold_rs::Manifold, material_id: f32) -> TaggedManifold {
+    let mut tagged = TaggedManifold::from_manifold(manifold, Vec::new());
+    tagged.label_faces("material_id", |_| material_id);
+    tagged
+}
+
+/// The axis-aligned bounding box (`(min, max)` corners) of a raw position
+/// buffer, in whatever space those positions are already in. `pub` so a
+/// caller building its own dispatch pipeline around this crate's raw mesh
+/// helpers gets the same cheap culling [`broadphase_shortcut`] uses instead
+/// of reimplementing it. Returns `(Vec3::MAX, Vec3::MIN)` for an empty
+/// buffer, which overlaps nothing.
+pub fn raw_aabb(positions: &[[f32; 3]]) -> (Vec3, Vec3) {
+    positions.iter().fold((Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)), |(min, max), &p| {
+        let p = Vec3::from(p);
+        (min.min(p), max.max(p))
+    })
+}
+
+/// Whether two `(min, max)` AABBs overlap on all three axes.
+fn aabbs_overlap(a: (Vec3, Vec3), b: (Vec3, Vec3)) -> bool {
+    a.0.x <= b.1.x && a.1.x >= b.0.x && a.0.y <= b.1.y && a.1.y >= b.0.y && a.0.z <= b.1.z && a.1.z >= b.0.z
+}
+
+/// A cheap broadphase ahead of the real solver: when the two (transformed)
+/// operands' AABBs don't even touch, `Intersect` can only come back empty,
+/// `Subtract` can only return the primary operand untouched, and `Union` is
+/// just the two operands side by side with nothing for either solver to cut
+/// — so answer directly from the transformed buffers instead of
+/// reconstructing `Manifold`s (or running the fast solver's per-triangle
+/// classification) for an answer that was never in doubt. Returns `None`
+/// (falling through to [`compute_boolean_op_exact`]/[`compute_boolean_op_fast`])
+/// when either operand is empty, the AABBs do overlap, or `op` is
+/// `BooleanOpState::None` (never actually dispatched; see
+/// `dispatch_boolean_op`'s early-continue for that state).
+fn broadphase_shortcut(
+    primary: &(Vec<[f32; 3]>, Vec<u32>),
+    primary_affine: Affine3A,
+    primary_normals: Option<&[[f32; 3]]>,
+    primary_uv0: Option<&[[f32; 2]]>,
+    secondary: &(Vec<[f32; 3]>, Vec<u32>),
+    secondary_affine: Affine3A,
+    secondary_normals: Option<&[[f32; 3]]>,
+    secondary_uv0: Option<&[[f32; 2]]>,
+    op: BooleanOpState,
+) -> Option<(
+    BooleanOpOutcome,
+    Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)>,
+    Vec<SeamEdge>,
+)> {
+    if primary.0.is_empty() || secondary.0.is_empty() {
+        return None;
+    }
+
+    let primary_world = transform_raw(primary, primary_affine);
+    let secondary_world = transform_raw(secondary, secondary_affine);
+    if aabbs_overlap(raw_aabb(&primary_world.0), raw_aabb(&secondary_world.0)) {
+        return None;
+    }
+
+    let outcome = BooleanOpOutcome::Completed {
+        conversion: std::time::Duration::ZERO,
+        operation: std::time::Duration::ZERO,
+        writeback: std::time::Duration::ZERO,
+        gpu_classify: None,
+    };
+    let groups = match op {
+        BooleanOpState::None => return None,
+        BooleanOpState::Intersect => Vec::new(),
+        BooleanOpState::Subtract => {
+            let normals = primary_normals.map(|n| transform_normals_raw(n, primary_affine));
+            vec![(PRIMARY_MATERIAL_ID as i32, primary_world.0, normals, primary_uv0.map(|uv| uv.to_vec()), primary_world.1)]
+        }
+        BooleanOpState::Union => {
+            let primary_normals = primary_normals.map(|n| transform_normals_raw(n, primary_affine));
+            let secondary_normals = secondary_normals.map(|n| transform_normals_raw(n, secondary_affine));
+            vec![
+                (PRIMARY_MATERIAL_ID as i32, primary_world.0, primary_normals, primary_uv0.map(|uv| uv.to_vec()), primary_world.1),
+                (SECONDARY_MATERIAL_ID as i32, secondary_world.0, secondary_normals, secondary_uv0.map(|uv| uv.to_vec()), secondary_world.1),
+            ]
+        }
+    };
+    // Disjoint AABBs mean no triangle from either operand ever touches one
+    // from the other, so there's nothing for a seam to run along.
+    Some((outcome, groups, Vec::new()))
+}
+
+/// Runs entirely off the main thread: reconstruct manifolds from raw mesh
+/// data, translate into place, run `boolean_op` with a `material_id`
+/// property channel carried through, and split the result back into one
+/// submesh per originating material. Falls back to primitive cube/sphere
+/// manifolds if either input isn't watertight, same as the old inline path.
+/// Panics inside the operation are caught so a pathological operand
+/// resolves to an empty manifold instead of killing the worker thread.
+/// The returned [`BooleanOpOutcome`] carries the conversion/operation/
+/// writeback timings (or records why there aren't any) for [`poll_boolean_op`]
+/// to turn into a [`BooleanOpResult`] event. Dispatches to
+/// [`compute_boolean_op_exact`] or [`compute_boolean_op_fast`] per `solver`,
+/// unless [`broadphase_shortcut`] already has the answer.
+fn compute_boolean_op(
+    primary: (Vec<[f32; 3]>, Vec<u32>),
+    primary_affine: Affine3A,
+    primary_normals: Option<Vec<[f32; 3]>>,
+    primary_uv0: Option<Vec<[f32; 2]>>,
+    secondary: (Vec<[f32; 3]>, Vec<u32>),
+    secondary_affine: Affine3A,
+    secondary_normals: Option<Vec<[f32; 3]>>,
+    secondary_uv0: Option<Vec<[f32; 2]>>,
+    op: BooleanOpState,
+    subdivision: Option<SubdivisionSettings>,
+    tolerance: ToleranceSettings,
+    solver: BooleanSolver,
+    hole_tolerant: bool,
+    mesh_repair: bool,
+    gpu_device: Option<gpu::GpuClassifyDevice>,
+) -> (
+    BooleanOpOutcome,
+    Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)>,
+    Vec<SeamEdge>,
+) {
+    if let Some(shortcut) = broadphase_shortcut(
+        &primary,
+        primary_affine,
+        primary_normals.as_deref(),
+        primary_uv0.as_deref(),
+        &secondary,
+        secondary_affine,
+        secondary_normals.as_deref(),
+        secondary_uv0.as_deref(),
+        op,
+    ) {
+        return shortcut;
+    }
+
+    match solver {
+        BooleanSolver::Exact => compute_boolean_op_exact(
+            primary,
+            primary_affine,
+            primary_normals,
+            primary_uv0,
+            secondary,
+            secondary_affine,
+            secondary_normals,
+            secondary_uv0,
+            op,
+            subdivision,
+            tolerance,
+            hole_tolerant,
+            mesh_repair,
+        ),
+        // `compute_boolean_op_fast` classifies every triangle independently
+        // already (no face-adjacency propagation to go wrong on holes or
+        // non-manifold input), so `hole_tolerant` has nothing to add here;
+        // like `SubdivisionSettings`, it's silently ignored on this path.
+        // Shading attributes are likewise dropped: see `compute_boolean_op_fast`.
+        // `ToleranceSettings` is Manifold-specific (the fast path never builds
+        // a `Manifold`, only classifies raw triangles), so it's ignored too.
+        // `MeshRepair` only matters to a `Manifold`-based reconstruction, same
+        // reasoning, so it's ignored here as well.
+        BooleanSolver::Fast => compute_boolean_op_fast(primary, primary_affine, secondary, secondary_affine, op, gpu_device),
+    }
+}
+
+/// `BooleanSolver::Exact`: reconstruct manifolds from raw mesh data, bake
+/// each operand's full `GlobalTransform` affine into place (translation,
+/// rotation, and scale — not just translation, so a rotated or non-uniformly
+/// scaled cutting entity actually cuts where and how large it visually is),
+/// run `boolean_op` with a `material_id` property channel carried through,
+/// and split the result back into one submesh per originating material.
+/// Falls back to primitive cube/sphere manifolds if either input isn't
+/// watertight, same as the old inline path.
+///
+/// `hole_tolerant` is for STEP-loaded meshes that are frequently non-manifold
+/// or contain holes, where `boolean_op`'s normal face-adjacency-propagated
+/// classification can mislabel a region. When set, every output triangle is
+/// independently re-checked with a per-triangle ray-cast insideness test
+/// against both (transformed) operands and dropped if the check disagrees
+/// with what `op` calls for — expensive (O(output tris × operand tris), on
+/// the order of 9x slower on large meshes) so it's opt-in rather than
+/// always-on.
+///
+/// `mesh_repair` runs [`repair_mesh`] on both operands first, at
+/// [`DEFAULT_WELD_EPSILON`], so the seams and degenerate triangles common in
+/// tessellated CAD imports don't immediately fail the watertightness check
+/// below and fall back to a primitive cube/sphere.
+fn compute_boolean_op_exact(
+    primary: (Vec<[f32; 3]>, Vec<u32>),
+    primary_affine: Affine3A,
+    primary_normals: Option<Vec<[f32; 3]>>,
+    primary_uv0: Option<Vec<[f32; 2]>>,
+    secondary: (Vec<[f32; 3]>, Vec<u32>),
+    secondary_affine: Affine3A,
+    secondary_normals: Option<Vec<[f32; 3]>>,
+    secondary_uv0: Option<Vec<[f32; 2]>>,
+    op: BooleanOpState,
+    subdivision: Option<SubdivisionSettings>,
+    tolerance: ToleranceSettings,
+    hole_tolerant: bool,
+    mesh_repair: bool,
+) -> (
+    BooleanOpOutcome,
+    Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)>,
+    Vec<SeamEdge>,
+) {
+    // Repair runs on the untransformed operands, before `tag_and_transform`
+    // attaches any "normal"/"uv0" property channel below — welding can change
+    // the vertex count, and those channels are keyed to `primary`/
+    // `secondary`'s positions by index, so this only applies when there's no
+    // such channel to desync. Meshes that carry their own normals/UVs (e.g.
+    // non-STEP sources) are the less common case for this opt-in anyway.
+    let (primary, secondary) = if mesh_repair {
+        let primary = if primary_normals.is_none() && primary_uv0.is_none() {
+            repair_mesh(&primary.0, &primary.1, DEFAULT_WELD_EPSILON).0
+        } else {
+            primary
+        };
+        let secondary = if secondary_normals.is_none() && secondary_uv0.is_none() {
+            repair_mesh(&secondary.0, &secondary.1, DEFAULT_WELD_EPSILON).0
+        } else {
+            secondary
+        };
+        (primary, secondary)
+    } else {
+        (primary, secondary)
+    };
+
+    let primary_world = transform_raw(&primary, primary_affine);
+    let secondary_world = transform_raw(&secondary, secondary_affine);
+
+    let conversion_start = std::time::Instant::now();
+    let (mut primary_tagged, mut secondary_tagged, fallback_used) = match (
+        tag_and_transform(&primary, primary_affine, PRIMARY_MATERIAL_ID, primary_normals.as_deref(), primary_uv0.as_deref()),
+        tag_and_transform(&secondary, secondary_affine, SECONDARY_MATERIAL_ID, secondary_normals.as_deref(), secondary_uv0.as_deref()),
+    ) {
+        (Some(a), Some(b)) => (a, b, false),
+        _ => (
+            fallback_tagged(manifold_rs::Manifold::cube(1.0, 1.0, 1.0), PRIMARY_MATERIAL_ID),
+            fallback_tagged(manifold_rs::Manifold::sphere(0.8, 64), SECONDARY_MATERIAL_ID),
+            true,
+        ),
+    };
+    if let Some(tol) = tolerance.tolerance {
+        primary_tagged = TaggedManifold::from_manifold(primary_tagged.manifold().set_tolerance(tol), primary_tagged.channels().to_vec());
+        secondary_tagged = TaggedManifold::from_manifold(secondary_tagged.manifold().set_tolerance(tol), secondary_tagged.channels().to_vec());
+    }
+    let conversion = conversion_start.elapsed();
+
+    let operation_start = std::time::Instant::now();
+    let ffi_op = boolean_op_from_state(op).unwrap_or(manifold_rs::BooleanOp::Union);
+    let op_result = panic::catch_unwind(|| primary_tagged.boolean_op(&secondary_tagged, ffi_op));
+    let panicked = op_result.is_err();
+    let result = op_result.unwrap_or_else(|_| fallback_tagged(manifold_rs::Manifold::empty(), PRIMARY_MATERIAL_ID));
+    let operation = operation_start.elapsed();
+
+    // `Intersect` legitimately comes back empty whenever the operands don't
+    // overlap (see `BooleanOpOutcome::into_event`'s `Empty` case); `Union`
+    // and `Subtract` on two watertight operands practically never should,
+    // so an empty result from either is treated as the exact solver having
+    // choked on a degenerate case (a coplanar face overlap, an edge lying
+    // exactly on the other operand's surface) rather than a real answer.
+    let degenerate = !fallback_used && !panicked && op != BooleanOpState::Intersect && result.manifold().to_mesh().indices().is_empty();
+    let result = if degenerate {
+        fallback_tagged(primary_tagged.manifold().translate(0.0, 0.0, 0.0), PRIMARY_MATERIAL_ID)
+    } else {
+        result
+    };
+    // Refinement/simplification happen post-op, on the combined result,
+    // rather than on each operand beforehand: `refine_to_length` subdivides
+    // triangles that are too large for smooth shading, and `simplify`
+    // collapses features the boolean introduced (slivers along the cut
+    // seam) — both only make sense once the two operands have been merged.
+    let result = match tolerance.refine_to_length {
+        Some(length) => TaggedManifold::from_manifold(result.manifold().refine_to_length(length), result.channels().to_vec()),
+        None => result,
+    };
+    let result = if tolerance.simplify {
+        TaggedManifold::from_manifold(result.manifold().simplify(), result.channels().to_vec())
+    } else {
+        result
+    };
+
+    let material_offset = result
+        .channels()
+        .iter()
+        .find(|c| c.name == "material_id")
+        .map(|c| c.offset)
+        .unwrap_or(3);
+    // `smooth_out`/`refine` (run by `subdivide` below) interpolate every
+    // existing property channel across cut/split vertices the same way
+    // `material_id` survives them, so the pre-subdivision "uv0" offset is
+    // still valid afterward; only `calculate_normals` overwrites a channel
+    // outright (the fresh slot it's told to write, handled separately below).
+    let uv_offset = result.channels().iter().find(|c| c.name == "uv0").map(|c| c.offset);
+
+    let writeback_start = std::time::Instant::now();
+    let (groups, seam_edges) = match subdivision {
+        Some(settings) => {
+            let base_props = result.manifold().to_mesh().num_props() as i32;
+            let refined = panic::catch_unwind(|| subdivide(result.manifold(), settings, base_props))
+                .unwrap_or_else(|_| result.manifold().translate(0.0, 0.0, 0.0));
+
+            let mesh = refined.to_mesh();
+            let num_props = mesh.num_props() as usize;
+            let positions = mesh.vertices();
+            let indices = if hole_tolerant {
+                hole_tolerant_filter(&positions, num_props, &mesh.indices(), op, &primary_world, &secondary_world)
+            } else {
+                mesh.indices()
+            };
+            let material_ids: Vec<f32> = positions
+                .chunks_exact(num_props.max(1))
+                .map(|v| v.get(material_offset).copied().unwrap_or(PRIMARY_MATERIAL_ID))
+                .collect();
+
+            let seam_edges = extract_seam_edges(&positions, num_props, &indices, &material_ids);
+            (split_by_material(&positions, num_props, Some(base_props as usize), uv_offset, &indices, &material_ids), seam_edges)
+        }
+        None => {
+            let mesh = result.manifold().to_mesh();
+            let num_props = mesh.num_props() as usize;
+            let positions = mesh.vertices();
+            let indices = if hole_tolerant {
+                hole_tolerant_filter(&positions, num_props, &mesh.indices(), op, &primary_world, &secondary_world)
+            } else {
+                mesh.indices()
+            };
+            let material_ids = result.channel_values("material_id").unwrap_or_default();
+            // No `SubdivisionSettings` ran, so the original "normal" channel
+            // `tag_and_transform` attached (if any) is still exactly where
+            // Manifold left it — carry it through instead of leaving the
+            // result entity with no normals until a recompute.
+            let normal_offset = result.channels().iter().find(|c| c.name == "normal").map(|c| c.offset);
+
+            let seam_edges = extract_seam_edges(&positions, num_props, &indices, &material_ids);
+            (split_by_material(&positions, num_props, normal_offset, uv_offset, &indices, &material_ids), seam_edges)
+        }
+    };
+    let writeback = writeback_start.elapsed();
+
+    let outcome = if panicked {
+        BooleanOpOutcome::Panicked
+    } else if degenerate {
+        BooleanOpOutcome::CoplanarFallback
+    } else if fallback_used {
+        BooleanOpOutcome::PrimitiveFallback
+    } else {
+        BooleanOpOutcome::Completed { conversion, operation, writeback, gpu_classify: None }
+    };
+
+    (outcome, groups, seam_edges)
+}
+
+/// `BooleanSolver::Fast`: bake each operand's full affine transform into
+/// place, classify each triangle of one against the other with a ray-parity
+/// point-in-mesh test, and keep whichever side the operator calls for — no
+/// triangle cutting, no `SubdivisionSettings`. Correct only when the operands
+/// don't share a coplanar face or an edge lying exactly on the other's
+/// surface; `BooleanSolver::Exact` is the one built to handle that case.
+fn compute_boolean_op_fast(
+    primary: (Vec<[f32; 3]>, Vec<u32>),
+    primary_affine: Affine3A,
+    secondary: (Vec<[f32; 3]>, Vec<u32>),
+    secondary_affine: Affine3A,
+    op: BooleanOpState,
+    gpu_device: Option<gpu::GpuClassifyDevice>,
+) -> (
+    BooleanOpOutcome,
+    Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)>,
+    Vec<SeamEdge>,
+) {
+    let conversion_start = std::time::Instant::now();
+    let a = transform_raw(&primary, primary_affine);
+    let b = transform_raw(&secondary, secondary_affine);
+    let conversion = conversion_start.elapsed();
+
+    let operation_start = std::time::Instant::now();
+    let op_result = panic::catch_unwind(|| fast_boolean(&a, &b, op, gpu_device.as_ref()));
+    let panicked = op_result.is_err();
+    let (positions, indices, material_ids) = op_result.unwrap_or_default();
+    let operation = operation_start.elapsed();
+
+    let writeback_start = std::time::Instant::now();
+    let flat_positions: Vec<f32> = positions.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
+    // The fast solver classifies whole triangles rather than cutting through
+    // Manifold's property-interpolating kernel, so it has no normal/uv0
+    // channel to carry through; `BooleanSolver::Exact` is the path that does.
+    let groups = split_by_material(&flat_positions, 3, None, None, &indices, &material_ids);
+    let seam_edges = extract_seam_edges(&flat_positions, 3, &indices, &material_ids);
+    let writeback = writeback_start.elapsed();
+
+    // Whether `classify_operand` actually took the GPU branch for every
+    // triangle isn't tracked per-call, only whether a device was available
+    // to try — good enough for "did the GPU path run at all" benchmarking,
+    // not a per-triangle GPU/CPU split.
+    let gpu_classify = gpu_device.is_some().then_some(operation);
+    let outcome = if panicked {
+        BooleanOpOutcome::Panicked
+    } else {
+        BooleanOpOutcome::Completed { conversion, operation, writeback, gpu_classify }
+    };
+
+    (outcome, groups, seam_edges)
+}
+
+/// Apply a full affine transform (translation, rotation, and scale) to raw
+/// mesh positions, leaving the index buffer untouched.
+fn transform_raw(data: &(Vec<[f32; 3]>, Vec<u32>), affine: Affine3A) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let positions = data.0.iter().map(|&p| affine.transform_point3(Vec3::from(p)).to_array()).collect();
+    (positions, data.1.clone())
+}
+
+/// Transform normals by `affine`'s inverse-transpose and renormalize — unlike
+/// positions, normals aren't carried correctly by the forward transform
+/// itself whenever `affine` has non-uniform scale.
+fn transform_normals_raw(normals: &[[f32; 3]], affine: Affine3A) -> Vec<[f32; 3]> {
+    let normal_matrix = affine.matrix3.inverse().transpose();
+    normals.iter().map(|&n| normal_matrix.mul_vec3(Vec3::from(n)).normalize_or_zero().to_array()).collect()
+}
+
+/// Like [`transform_raw`], but translation-only and for the flattened
+/// `(Vec<f32>, Vec<u32>)` shape used by the CSG snapshot pipeline: offset
+/// every `(x, y, z)` position triple by `translation`, leaving the index
+/// buffer untouched.
+fn translate_flat_raw(data: &(Vec<f32>, Vec<u32>), translation: Vec3) -> (Vec<f32>, Vec<u32>) {
+    let positions = data
+        .0
+        .chunks_exact(3)
+        .flat_map(|p| [p[0] + translation.x, p[1] + translation.y, p[2] + translation.z])
+        .collect();
+    (positions, data.1.clone())
+}
+
+/// One operand triangle's classification against the other operand: whether
+/// it's kept, with what winding, and which material it contributes.
+struct Classified {
+    tri: [Vec3; 3],
+    keep: bool,
+    flip: bool,
+    material_id: f32,
+}
+
+/// Classify every triangle of `mesh` as inside/outside `other` and decide
+/// whether `op` keeps it. `is_secondary` selects `b`'s keep/flip rules
+/// (`Subtract` keeps `b`'s inside triangles, flipped, instead of `a`'s
+/// outside ones). Runs each triangle's classification independently, so
+/// with the `parallel` feature this fans out over rayon; the order of the
+/// returned `Vec` always matches `mesh`'s own triangle order regardless of
+/// how many threads did the work, which is what keeps `fast_boolean`'s
+/// output deterministic without a separate merge/sort step.
+///
+/// When `gpu` is `Some`, the inside/outside test itself runs as one compute
+/// dispatch over every triangle's centroid (see [`gpu::classify_triangles_gpu`])
+/// instead of the CPU ray-parity loop below; a `None` readback (pipeline not
+/// ready, dispatch failed) falls back to the CPU path for this call, same as
+/// `gpu` being absent in the first place.
+fn classify_operand(
+    mesh: &(Vec<[f32; 3]>, Vec<u32>),
+    other: &(Vec<[f32; 3]>, Vec<u32>),
+    op: BooleanOpState,
+    is_secondary: bool,
+    gpu: Option<&gpu::GpuClassifyDevice>,
+) -> Vec<Classified> {
+    let triangles: Vec<[Vec3; 3]> = mesh
+        .1
+        .chunks_exact(3)
+        .map(|tri| [Vec3::from_array(mesh.0[tri[0] as usize]), Vec3::from_array(mesh.0[tri[1] as usize]), Vec3::from_array(mesh.0[tri[2] as usize])])
+        .collect();
+
+    let keep_flip_material = |inside: bool| -> (bool, bool, f32) {
+        let keep = match (op, is_secondary) {
+            (BooleanOpState::Union | BooleanOpState::None, _) => !inside,
+            (BooleanOpState::Intersect, _) => inside,
+            (BooleanOpState::Subtract, false) => !inside,
+            (BooleanOpState::Subtract, true) => inside,
+        };
+        let flip = is_secondary && op == BooleanOpState::Subtract;
+        let material_id = if is_secondary { SECONDARY_MATERIAL_ID } else { PRIMARY_MATERIAL_ID };
+        (keep, flip, material_id)
+    };
+
+    if let Some(gpu) = gpu {
+        let centroids: Vec<Vec3> = triangles.iter().map(|tri| (tri[0] + tri[1] + tri[2]) / 3.0).collect();
+        if let Some(inside_flags) = gpu::classify_triangles_gpu(gpu, &centroids, other) {
+            return triangles
+                .into_iter()
+                .zip(inside_flags)
+                .map(|(tri, inside)| {
+                    let (keep, flip, material_id) = keep_flip_material(inside);
+                    Classified { tri, keep, flip, material_id }
+                })
+                .collect();
+        }
+    }
+
+    let classify_one = |tri: [Vec3; 3]| -> Classified {
+        let inside = triangle_inside(tri, other);
+        let (keep, flip, material_id) = keep_flip_material(inside);
+        Classified { tri, keep, flip, material_id }
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        triangles.into_par_iter().map(classify_one).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        triangles.into_iter().map(classify_one).collect()
+    }
+}
+
+/// Classify every triangle of `a` and of `b` as inside/outside the other
+/// mesh and keep whichever side `op` calls for, flipping the winding of any
+/// kept `b` triangle that ends up facing into the result's interior
+/// (`Subtract`'s `b`-inside-`a` case). Vertices aren't welded between kept
+/// triangles, only duplicated per-triangle, since [`split_by_material`]
+/// (the only consumer) doesn't need a shared index space.
+///
+/// The classification itself (`classify_operand`) is where the `parallel`
+/// feature does its work; this function always walks the results in a
+/// single pass in `a`-then-`b`, original-triangle-order, so the emitted
+/// vertex/index buffers are byte-identical whether `parallel` is on or off.
+fn fast_boolean(
+    a: &(Vec<[f32; 3]>, Vec<u32>),
+    b: &(Vec<[f32; 3]>, Vec<u32>),
+    op: BooleanOpState,
+    gpu: Option<&gpu::GpuClassifyDevice>,
+) -> (Vec<[f32; 3]>, Vec<u32>, Vec<f32>) {
+    let from_a = classify_operand(a, b, op, false, gpu);
+    let from_b = classify_operand(b, a, op, true, gpu);
+
+    let mut positions = Vec::new();
+    let mut indices = Vec::new();
+    let mut material_ids = Vec::new();
+
+    for c in from_a.into_iter().chain(from_b) {
+        if !c.keep {
+            continue;
+        }
+        let order: [usize; 3] = if c.flip { [0, 2, 1] } else { [0, 1, 2] };
+        for &o in &order {
+            indices.push(positions.len() as u32);
+            positions.push(c.tri[o].to_array());
+            material_ids.push(c.material_id);
+        }
+    }
+
+    (positions, indices, material_ids)
+}
+
+/// Call `f` once per triangle of `mesh`, as its three vertex positions.
+fn for_each_triangle(mesh: &(Vec<[f32; 3]>, Vec<u32>), mut f: impl FnMut([Vec3; 3])) {
+    for tri in mesh.1.chunks_exact(3) {
+        f([
+            Vec3::from_array(mesh.0[tri[0] as usize]),
+            Vec3::from_array(mesh.0[tri[1] as usize]),
+            Vec3::from_array(mesh.0[tri[2] as usize]),
+        ]);
+    }
+}
+
+/// `hole_tolerant`'s correction pass: independently re-classify every
+/// triangle of an exact-solver result against both original operands and
+/// drop any that disagree with what `op` calls for. The sample point is
+/// nudged slightly off the triangle against its own outward normal, into
+/// the result's volume, before the insideness test, since the unnudged centroid can sit exactly on one
+/// operand's surface (most output triangles originated from one operand or
+/// the other), where ray parity is ill-defined.
+fn hole_tolerant_filter(
+    positions: &[f32],
+    num_props: usize,
+    indices: &[u32],
+    op: BooleanOpState,
+    primary_world: &(Vec<[f32; 3]>, Vec<u32>),
+    secondary_world: &(Vec<[f32; 3]>, Vec<u32>),
+) -> Vec<u32> {
+    let stride = num_props.max(1);
+    let vertex = |i: u32| -> Vec3 {
+        let base = i as usize * stride;
+        Vec3::new(positions[base], positions[base + 1], positions[base + 2])
+    };
+
+    let keep_triangle = |tri: &[u32]| -> bool {
+        let (a, b, c) = (vertex(tri[0]), vertex(tri[1]), vertex(tri[2]));
+        let centroid = (a + b + c) / 3.0;
+        let normal = (b - a).cross(c - a).normalize_or_zero();
+        let sample = centroid - normal * 1e-4;
 
-        BooleanOperationBundle {
-            primary: primary_entity,
-            secondary: secondary_entity,
-            result: result_entity,
+        let in_primary = point_inside(sample, primary_world);
+        let in_secondary = point_inside(sample, secondary_world);
+        match op {
+            BooleanOpState::Union | BooleanOpState::None => in_primary || in_secondary,
+            BooleanOpState::Intersect => in_primary && in_secondary,
+            BooleanOpState::Subtract => in_primary && !in_secondary,
         }
+    };
+
+    // Same order-preserving-collect trick as `classify_operand`: each
+    // triangle's keep/drop decision is independent, so chunking it out over
+    // rayon under the `parallel` feature doesn't change which triangles end
+    // up in `kept` or their order.
+    let triangles = indices.chunks_exact(3);
+    #[cfg(feature = "parallel")]
+    let kept_chunks: Vec<&[u32]> = triangles.collect::<Vec<_>>().into_par_iter().filter(|tri| keep_triangle(tri)).collect();
+    #[cfg(not(feature = "parallel"))]
+    let kept_chunks: Vec<&[u32]> = triangles.filter(|tri| keep_triangle(tri)).collect();
+
+    let mut kept = Vec::with_capacity(indices.len());
+    for tri in kept_chunks {
+        kept.extend_from_slice(tri);
     }
+    kept
 }
 
-/// A bundle that represents a complete boolean operation setup
-pub struct BooleanOperationBundle {
-    pub primary: Entity,
-    pub secondary: Entity,
-    pub result: Entity,
+/// Is `tri`'s centroid inside the closed surface described by `mesh`, via an
+/// even-odd ray-parity test.
+fn triangle_inside(tri: [Vec3; 3], mesh: &(Vec<[f32; 3]>, Vec<u32>)) -> bool {
+    let centroid = (tri[0] + tri[1] + tri[2]) / 3.0;
+    point_inside(centroid, mesh)
 }
 
-impl Plugin for MeshBooleanPlugin {
-    fn build(&self, app: &mut App) {
-        app.init_resource::<BooleanOpState>()
-            .add_systems(Update, apply_boolean_op);
+/// Even-odd ray-triangle-intersection-count parity test: `point` is inside
+/// `mesh` if a ray cast from it crosses the mesh's surface an odd number of
+/// times. The cast direction is arbitrary but fixed (and deliberately not
+/// axis-aligned, since CAD-derived meshes often have many axis-aligned
+/// faces a grazing ray would hit edge-on) so every sample within one
+/// `fast_boolean` call is consistent.
+fn point_inside(point: Vec3, mesh: &(Vec<[f32; 3]>, Vec<u32>)) -> bool {
+    const DIR: Vec3 = Vec3::new(0.9134123, 0.309017, 0.2679492);
+    let mut crossings = 0;
+    for tri in mesh.1.chunks_exact(3) {
+        let (a, b, c) = (
+            Vec3::from_array(mesh.0[tri[0] as usize]),
+            Vec3::from_array(mesh.0[tri[1] as usize]),
+            Vec3::from_array(mesh.0[tri[2] as usize]),
+        );
+        if ray_triangle_intersect(point, DIR, a, b, c) {
+            crossings += 1;
+        }
     }
+    crossings % 2 == 1
 }
 
-// Resource to control the boolean operation
-#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
-pub enum BooleanOpState {
-    #[default]
-    None,
-    Intersect,
-    Union,
-    Subtract,
+/// Möller–Trumbore ray-triangle intersection, counting only forward (`t >
+/// 0`) hits.
+fn ray_triangle_intersect(origin: Vec3, dir: Vec3, a: Vec3, b: Vec3, c: Vec3) -> bool {
+    const EPSILON: f32 = 1e-7;
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let h = dir.cross(edge2);
+    let det = edge1.dot(h);
+    if det.abs() < EPSILON {
+        return false;
+    }
+    let inv_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(h) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return false;
+    }
+    let q = s.cross(edge1);
+    let v = dir.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return false;
+    }
+    let t = edge2.dot(q) * inv_det;
+    t > EPSILON
 }
 
-// Component to mark the primary entity in a boolean operation
-#[derive(Component)]
-pub struct PrimaryBooleanMesh {
-    pub secondary_entity: Entity,
+/// Smooth creases softer than `settings.crease_angle_degrees` into the
+/// manifold's tangent data (sharper edges stay sharp), then tessellate each triangle into `4^depth`
+/// sub-triangles (`2^depth` edge splits) repositioned toward the resulting
+/// limit surface, and append a recomputed per-vertex normal channel at
+/// `normal_idx` so lighting is smooth across the rounded regions and crisp
+/// at the retained sharp edges.
+fn subdivide(manifold: &manifold_rs::Manifold, settings: SubdivisionSettings, normal_idx: i32) -> manifold_rs::Manifold {
+    let crease_radians = settings.crease_angle_degrees.to_radians();
+    let edge_splits = 2i32.pow(settings.depth);
+    manifold
+        .smooth_out(crease_radians, 1.0)
+        .refine(edge_splits)
+        .calculate_normals(normal_idx, crease_radians)
 }
 
-// Component to mark the secondary entity in a boolean operation
-#[derive(Component)]
-pub struct SecondaryBooleanMesh {
-    pub primary_entity: Entity,
-}
+/// Partition a mesh's triangles by the rounded average `material_id` of
+/// their three vertices, producing one self-contained (position, index)
+/// buffer pair per originating material region. Cut-edge vertices carry an
+/// interpolated id between the two source labels, so per-triangle rounding
+/// (rather than a single per-vertex id) is what keeps the seam on one side
+/// or the other instead of splitting individual triangles down the middle.
+fn split_by_material(
+    positions: &[f32],
+    num_props: usize,
+    normal_offset: Option<usize>,
+    uv_offset: Option<usize>,
+    indices: &[u32],
+    material_ids: &[f32],
+) -> Vec<(i32, Vec<[f32; 3]>, Option<Vec<[f32; 3]>>, Option<Vec<[f32; 2]>>, Vec<u32>)> {
+    let mut groups: std::collections::BTreeMap<
+        i32,
+        (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<[f32; 2]>, Vec<u32>, std::collections::HashMap<u32, u32>),
+    > = std::collections::BTreeMap::new();
 
-// Resource to hold entity handles for boolean operations
-#[derive(Resource)]
-pub struct BooleanHandles {
-    pub primary_entity: Entity,
-    pub secondary_entity: Entity,
-    pub result_entity: Entity,
-}
+    for tri in indices.chunks_exact(3) {
+        let avg = tri
+            .iter()
+            .map(|&i| material_ids.get(i as usize).copied().unwrap_or(PRIMARY_MATERIAL_ID))
+            .sum::<f32>()
+            / 3.0;
+        let id = avg.round() as i32;
+        let group = groups.entry(id).or_default();
 
-// The system that applies the boolean operation
-fn apply_boolean_op(
-    mut commands: Commands,
-    boolean_handles: Option<Res<BooleanHandles>>,
-    pbr_query: Query<(&Handle<Mesh>, &Transform)>,
-    mut mesh_assets: ResMut<Assets<Mesh>>,
-    mut visibility_query: Query<&mut Visibility>,
-    op_state: Res<BooleanOpState>,
-) {
-    let operation_start_time = std::time::Instant::now();
-    
-    if !op_state.is_changed() {
-        return;
+        for &orig_idx in tri {
+            let new_idx = if let Some(&existing) = group.4.get(&orig_idx) {
+                existing
+            } else {
+                let base = orig_idx as usize * num_props.max(1);
+                let new_idx = group.0.len() as u32;
+                group.0.push([positions[base], positions[base + 1], positions[base + 2]]);
+                if let Some(normal_offset) = normal_offset {
+                    let base = base + normal_offset;
+                    group.1.push([positions[base], positions[base + 1], positions[base + 2]]);
+                }
+                if let Some(uv_offset) = uv_offset {
+                    let base = base + uv_offset;
+                    group.2.push([positions[base], positions[base + 1]]);
+                }
+                group.4.insert(orig_idx, new_idx);
+                new_idx
+            };
+            group.3.push(new_idx);
+        }
     }
 
-    eprintln!("[TIMING {}] Boolean operation triggered. State: {:?}", 
-             operation_start_time.elapsed().as_micros(), *op_state);
-    
-    let handles = match boolean_handles {
-        Some(h) => h,
-        None => {
-            eprintln!("[TIMING {}] No BooleanHandles resource found, skipping operation", 
-                     operation_start_time.elapsed().as_micros());
-            // Just return if no handles exist yet - this can happen during startup
-            // before the demo is fully set up
-            return;
-        },
-    };
-
-    let primary_entity = handles.primary_entity;
-    let secondary_entity = handles.secondary_entity;
-    let result_entity = handles.result_entity;
-
-    eprintln!("[TIMING {}] Processing boolean operation on entities - Primary: {:?}, Secondary: {:?}, Result: {:?}", 
-             operation_start_time.elapsed().as_micros(), primary_entity, secondary_entity, result_entity);
-
-    // Get primary mesh data
-    let (primary_mesh_handle, primary_transform) = {
-        if let Ok((mesh_handle, transform)) = pbr_query.get(primary_entity) {
-            eprintln!("[TIMING {}] Found primary mesh handle and transform", 
-                     operation_start_time.elapsed().as_micros());
-            (mesh_handle.clone(), *transform)
-        } else {
-            eprintln!("[TIMING {}] [ERROR] Could not get primary mesh data for entity {:?}", 
-                     operation_start_time.elapsed().as_micros(), primary_entity);
-            return;
-        }
-    };
+    groups
+        .into_iter()
+        .map(|(id, (verts, normals, uvs, idx, _))| {
+            let normals = (!normals.is_empty()).then_some(normals);
+            let uvs = (!uvs.is_empty()).then_some(uvs);
+            (id, verts, normals, uvs, idx)
+        })
+        .collect()
+}
 
-    // Get secondary mesh data
-    let (secondary_mesh_handle, secondary_transform) = {
-        if let Ok((mesh_handle, transform)) = pbr_query.get(secondary_entity) {
-            eprintln!("[TIMING {}] Found secondary mesh handle and transform", 
-                     operation_start_time.elapsed().as_micros());
-            (mesh_handle.clone(), *transform)
-        } else {
-            eprintln!("[TIMING {}] [ERROR] Could not get secondary mesh data for entity {:?}", 
-                     operation_start_time.elapsed().as_micros(), secondary_entity);
-            return;
+/// Find every result edge whose two adjacent triangles have different
+/// (rounded, per-triangle-averaged) `material_id`s — exactly the edges
+/// [`split_by_material`] would place on opposite sides of the split, i.e.
+/// the intersection seam carved by `boolean_op` between the two operands,
+/// using the same id-rounding so the two stay consistent with each other.
+fn extract_seam_edges(positions: &[f32], num_props: usize, indices: &[u32], material_ids: &[f32]) -> Vec<SeamEdge> {
+    let mut edge_ids: std::collections::HashMap<(u32, u32), Vec<i32>> = std::collections::HashMap::new();
+    for tri in indices.chunks_exact(3) {
+        let avg = tri.iter().map(|&i| material_ids.get(i as usize).copied().unwrap_or(PRIMARY_MATERIAL_ID)).sum::<f32>() / 3.0;
+        let id = avg.round() as i32;
+        for &(a, b) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            edge_ids.entry((a.min(b), a.max(b))).or_default().push(id);
         }
-    };
+    }
 
-    // Get actual mesh assets
-    let primary_mesh = match mesh_assets.get(&primary_mesh_handle) {
-        Some(mesh) => {
-            eprintln!("[TIMING {}] Retrieved primary mesh asset", 
-                     operation_start_time.elapsed().as_micros());
-            mesh
-        },
-        None => {
-            eprintln!("[TIMING {}] [ERROR] Could not retrieve primary mesh asset", 
-                     operation_start_time.elapsed().as_micros());
-            return;
-        }
+    let vertex_pos = |i: u32| {
+        let base = i as usize * num_props.max(1);
+        [positions[base], positions[base + 1], positions[base + 2]]
     };
 
-    let secondary_mesh = match mesh_assets.get(&secondary_mesh_handle) {
-        Some(mesh) => {
-            eprintln!("[TIMING {}] Retrieved secondary mesh asset", 
-                     operation_start_time.elapsed().as_micros());
-            mesh
-        },
-        None => {
-            eprintln!("[TIMING {}] [ERROR] Could not retrieve secondary mesh asset", 
-                     operation_start_time.elapsed().as_micros());
-            return;
-        }
-    };
+    edge_ids
+        .into_iter()
+        .filter(|(_, ids)| ids.iter().any(|&id| id != ids[0]))
+        .map(|((a, b), _)| [vertex_pos(a), vertex_pos(b)])
+        .collect()
+}
 
-    // Log mesh statistics
-    let primary_vertex_count = if let Some(positions) = primary_mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        match positions {
-            bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => pos.len(),
-            _ => 0,
-        }
+/// Pack a triangle index buffer as `U16` when `vertex_count` fits (below
+/// 65536) and `force_u32` hasn't opted out, otherwise as `U32`. Bevy's own
+/// mesh pipeline specializes on the index format, so this halves GPU index
+/// buffer size for free on the common case of a small boolean-result
+/// submesh.
+fn pack_indices(vertex_count: usize, indices: Vec<u32>, force_u32: bool) -> bevy::render::mesh::Indices {
+    if !force_u32 && vertex_count <= u16::MAX as usize {
+        bevy::render::mesh::Indices::U16(indices.into_iter().map(|i| i as u16).collect())
     } else {
-        0
+        bevy::render::mesh::Indices::U32(indices)
+    }
+}
+
+/// Build a Bevy `Mesh` for one material region's submesh, with normals/UVs
+/// if [`split_by_material`] carried any through. Also generates
+/// `ATTRIBUTE_TANGENT` whenever normals are present, since that's the one
+/// ingredient (positions + normals + indices) [`generate_tangents`] actually
+/// needs — it supplies its own planar-projection UVs when the submesh has
+/// none of its own.
+pub(crate) fn raw_submesh_to_bevy_mesh(positions: Vec<[f32; 3]>, normals: Option<Vec<[f32; 3]>>, uv0: Option<Vec<[f32; 2]>>, indices: Vec<u32>, force_u32: bool) -> Mesh {
+    let mut mesh = Mesh::new(
+        bevy::render::mesh::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::all(),
+    );
+    let vertex_count = positions.len();
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    let has_normals = normals.is_some();
+    if let Some(normals) = normals {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    }
+    if let Some(uv0) = uv0 {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uv0);
+    }
+    mesh.insert_indices(pack_indices(vertex_count, indices, force_u32));
+
+    if has_normals {
+        generate_tangents(&mut mesh);
+    }
+
+    mesh
+}
+
+/// Per-vertex planar ("box") UV fallback for [`generate_tangents`], used only
+/// when a result mesh has no real UV0 from its source meshes. Projects each
+/// vertex onto the axis plane perpendicular to its normal's dominant
+/// component — not seam-correct, but enough of a UV derivative for the
+/// tangent algorithm below to have something to work from on boolean cut
+/// surfaces that were never textured to begin with.
+fn planar_project_uvs(positions: &[[f32; 3]], normals: &[[f32; 3]]) -> Vec<[f32; 2]> {
+    positions
+        .iter()
+        .zip(normals)
+        .map(|(p, n)| {
+            let (ax, ay, az) = (n[0].abs(), n[1].abs(), n[2].abs());
+            if ax >= ay && ax >= az {
+                [p[1], p[2]]
+            } else if ay >= az {
+                [p[0], p[2]]
+            } else {
+                [p[0], p[1]]
+            }
+        })
+        .collect()
+}
+
+/// Post-processing step that generates `ATTRIBUTE_TANGENT` for a mesh that
+/// has positions, normals and indices but no tangent basis of its own — the
+/// situation every boolean-op result is in, since neither `Manifold` nor the
+/// `Fast` solver ever emit one. Lacking real UVs on the cut geometry, this
+/// falls back to [`planar_project_uvs`] before computing tangents the same
+/// way Bevy's mikktspace path does: accumulate each triangle's UV-delta
+/// tangent/bitangent per vertex, then Gram-Schmidt orthonormalize against the
+/// vertex normal and store handedness in the tangent's `w`. A no-op if the
+/// mesh is missing positions, normals or indices.
+fn generate_tangents(mesh: &mut Mesh) {
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION).cloned() else {
+        return;
     };
-    
-    let secondary_vertex_count = if let Some(positions) = secondary_mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-        match positions {
-            bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => pos.len(),
-            _ => 0,
-        }
-    } else {
-        0
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x3(normals)) = mesh.attribute(Mesh::ATTRIBUTE_NORMAL).cloned() else {
+        return;
     };
-    
-    eprintln!("[TIMING {}] Mesh statistics - Primary: {} vertices, Secondary: {} vertices", 
-             operation_start_time.elapsed().as_micros(), primary_vertex_count, secondary_vertex_count);
+    let Some(indices) = mesh.indices() else { return };
+    let indices: Vec<u32> = indices.iter().map(|i| i as u32).collect();
 
-    // If no operation, show original shapes
-    if *op_state == BooleanOpState::None {
-        eprintln!("[TIMING {}] No operation selected, showing original shapes", 
-                 operation_start_time.elapsed().as_micros());
-        if let Ok(mut primary_vis) = visibility_query.get_mut(primary_entity) {
-            *primary_vis = Visibility::Visible;
-        }
-        if let Ok(mut secondary_vis) = visibility_query.get_mut(secondary_entity) {
-            *secondary_vis = Visibility::Visible;
+    if mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_none() {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, planar_project_uvs(&positions, &normals));
+    }
+    let Some(bevy::render::mesh::VertexAttributeValues::Float32x2(uvs)) = mesh.attribute(Mesh::ATTRIBUTE_UV_0).cloned() else {
+        return;
+    };
+
+    let mut tangent_accum = vec![Vec3::ZERO; positions.len()];
+    let mut bitangent_accum = vec![Vec3::ZERO; positions.len()];
+
+    for tri in indices.chunks_exact(3) {
+        let (i0, i1, i2) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+        let (p0, p1, p2) = (Vec3::from_array(positions[i0]), Vec3::from_array(positions[i1]), Vec3::from_array(positions[i2]));
+        let (uv0, uv1, uv2) = (uvs[i0], uvs[i1], uvs[i2]);
+
+        let edge1 = p1 - p0;
+        let edge2 = p2 - p0;
+        let delta_uv1 = [uv1[0] - uv0[0], uv1[1] - uv0[1]];
+        let delta_uv2 = [uv2[0] - uv0[0], uv2[1] - uv0[1]];
+
+        let denom = delta_uv1[0] * delta_uv2[1] - delta_uv2[0] * delta_uv1[1];
+        if denom.abs() < 1e-12 {
+            continue;
         }
-        if let Ok(mut result_vis) = visibility_query.get_mut(result_entity) {
-            *result_vis = Visibility::Hidden;
+        let r = 1.0 / denom;
+        let tangent = (edge1 * delta_uv2[1] - edge2 * delta_uv1[1]) * r;
+        let bitangent = (edge2 * delta_uv1[0] - edge1 * delta_uv2[0]) * r;
+
+        for &i in &[i0, i1, i2] {
+            tangent_accum[i] += tangent;
+            bitangent_accum[i] += bitangent;
         }
-        return;
     }
 
-    // Hide original shapes and show result
-    eprintln!("[TIMING {}] Hiding original shapes and preparing for boolean operation", 
-             operation_start_time.elapsed().as_micros());
-    if let Ok(mut primary_vis) = visibility_query.get_mut(primary_entity) {
-        *primary_vis = Visibility::Hidden;
-    }
-    if let Ok(mut secondary_vis) = visibility_query.get_mut(secondary_entity) {
-        *secondary_vis = Visibility::Hidden;
-    }
+    let tangents: Vec<[f32; 4]> = (0..positions.len())
+        .map(|i| {
+            let n = Vec3::from_array(normals[i]);
+            let t = tangent_accum[i];
+            let orthogonal = detmath::normalize_or_zero(t - n * n.dot(t));
+            // Handedness: +1 if (N x T) agrees with the accumulated bitangent, else -1.
+            let handedness = if n.cross(orthogonal).dot(bitangent_accum[i]) < 0.0 { -1.0 } else { 1.0 };
+            [orthogonal.x, orthogonal.y, orthogonal.z, handedness]
+        })
+        .collect();
 
-    // Try to convert Bevy meshes to manifold-rs manifolds
-    eprintln!("[TIMING {}] Attempting to convert Bevy meshes to manifold-rs manifolds...", 
-             operation_start_time.elapsed().as_micros());
-    
-    let start_time = std::time::Instant::now();
-    
-    // Try actual conversion first
-    let primary_manifold_opt = bevy_mesh_to_manifold(primary_mesh);
-    let secondary_manifold_opt = bevy_mesh_to_manifold(secondary_mesh);
-    
-    let conversion_time = start_time.elapsed();
-    eprintln!("[TIMING {}] [CONVERSION] Mesh conversion took: {:?}", 
-             operation_start_time.elapsed().as_micros(), conversion_time);
-    
-    match (primary_manifold_opt, secondary_manifold_opt) {
-        (Some(mut primary_manifold), Some(mut secondary_manifold)) => {
-            eprintln!("[TIMING {}] [SUCCESS] Successfully converted both meshes to manifolds", 
-                     operation_start_time.elapsed().as_micros());
-            
-            // Apply transformations to position the shapes for intersection
-            let primary_pos = primary_transform.translation;
-            let secondary_pos = secondary_transform.translation;
+    mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+}
 
-            eprintln!("[TIMING {}] Applying transformations - Primary: {:?}, Secondary: {:?}", 
-                     operation_start_time.elapsed().as_micros(), primary_pos, secondary_pos);
-            
-            primary_manifold = primary_manifold.translate(
-                primary_pos.x as f64,
-                primary_pos.y as f64,
-                primary_pos.z as f64,
-            );
-            secondary_manifold = secondary_manifold.translate(
-                secondary_pos.x as f64,
-                secondary_pos.y as f64,
-                secondary_pos.z as f64,
-            );
 
-            // Log initial mesh info
-            let prim_mesh_info = primary_manifold.to_mesh();
-            let sec_mesh_info = secondary_manifold.to_mesh();
-            let prim_vertices_before = prim_mesh_info.vertices().len();
-            let sec_vertices_before = sec_mesh_info.vertices().len();
-            eprintln!("[TIMING {}] Boolean operation: Primary vertices: {}, Secondary vertices: {}", 
-                     operation_start_time.elapsed().as_micros(), prim_vertices_before, sec_vertices_before);
-
-            // Perform boolean operation
-            let operation_start = std::time::Instant::now();
-            let result_manifold = match *op_state {
-                BooleanOpState::Intersect => {
-                    eprintln!("[TIMING {}] Performing intersection operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Intersection)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [SUCCESS] Intersection operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [ERROR] Intersection operation panicked - likely due to mesh complexity, returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::Union => {
-                    eprintln!("[TIMING {}] Performing union operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Union)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [SUCCESS] Union operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [ERROR] Union operation panicked - likely due to mesh complexity, returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::Subtract => {
-                    eprintln!("[TIMING {}] Performing subtraction operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Difference)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [SUCCESS] Subtraction operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [ERROR] Subtraction operation panicked - likely due to mesh complexity, returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::None => return, // Already handled above
-            };
-            let operation_time = operation_start.elapsed();
-            eprintln!("[TIMING {}] [BOOLEAN OP] Boolean operation took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), operation_time);
-
-            // Log the result info
-            let result_mesh_info = result_manifold.to_mesh();
-            let result_vertices = result_mesh_info.vertices().len();
-            let result_triangles = result_mesh_info.indices().len() / 3;
-            eprintln!("[TIMING {}] [RESULT] Result after operation - Vertices: {}, Triangles: {}", 
-                     operation_start_time.elapsed().as_micros(), result_vertices, result_triangles);
-
-            // Convert back to Bevy mesh with detailed tracing
-            eprintln!("[TRACE] Converting result manifold to Bevy mesh...");
-            let conversion_back_start = std::time::Instant::now();
-            let result_bevy_mesh = manifold_to_bevy_mesh(result_manifold);
-            let conversion_back_time = conversion_back_start.elapsed();
-            eprintln!("[TIMING {}] [CONVERSION BACK] Mesh conversion back to Bevy took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), conversion_back_time);
-            
-            // Log mesh stats before adding to assets
-            let pre_add_vertex_count = if let Some(positions) = result_bevy_mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
-                match positions {
-                    bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => pos.len(),
-                    _ => 0,
-                }
-            } else {
-                0
-            };
-            
-            let pre_add_index_count = if let Some(indices) = result_bevy_mesh.indices() {
-                match indices {
-                    bevy::render::mesh::Indices::U32(indices_vec) => indices_vec.len(),
-                    bevy::render::mesh::Indices::U16(indices_vec) => indices_vec.len(),
-                }
-            } else {
-                0
-            };
-            
-            eprintln!("[TRACE] Pre-add mesh stats - Vertices: {}, Indices: {}", pre_add_vertex_count, pre_add_index_count);
-            
-            let result_mesh_handle = mesh_assets.add(result_bevy_mesh);
-            eprintln!("[TRACE] Added result mesh to assets with handle");
-
-            // Log detailed mesh information before updating entity
-            eprintln!("[DEBUG] Preparing to update result entity with mesh data...");
-            if let Some(result_mesh_asset) = mesh_assets.get(&result_mesh_handle) {
-                let vertex_count = if let Some(positions) = result_mesh_asset.attribute(Mesh::ATTRIBUTE_POSITION) {
-                    match positions {
-                        bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => pos.len(),
-                        _ => 0,
-                    }
-                } else {
-                    0
-                };
-                
-                let index_count = if let Some(indices) = result_mesh_asset.indices() {
-                    match indices {
-                        bevy::render::mesh::Indices::U32(indices_vec) => indices_vec.len(),
-                        bevy::render::mesh::Indices::U16(indices_vec) => indices_vec.len(),
-                    }
+/// Expand `indices` into a plain triangle list according to `topology`.
+/// `TriangleList` passes through unchanged; `TriangleStrip` and
+/// `TriangleFan` are unrolled into their constituent triangles (N indices
+/// become `N - 2`, same as the winding Bevy's own software rasterizer
+/// uses). Line/point topologies carry no triangles at all, so they return
+/// `None` rather than a nonsensical result.
+fn triangulate_indices(topology: bevy::render::mesh::PrimitiveTopology, indices: Vec<u32>) -> Option<Vec<u32>> {
+    use bevy::render::mesh::PrimitiveTopology as Topology;
+    match topology {
+        Topology::TriangleList => Some(indices),
+        Topology::TriangleStrip => {
+            if indices.len() < 3 {
+                return Some(Vec::new());
+            }
+            let mut triangles = Vec::with_capacity((indices.len() - 2) * 3);
+            for (i, window) in indices.windows(3).enumerate() {
+                // Alternate winding every other triangle so the strip's
+                // front face stays consistent, matching how Bevy's own
+                // rasterizer interprets `TriangleStrip`.
+                if i % 2 == 0 {
+                    triangles.extend_from_slice(&[window[0], window[1], window[2]]);
                 } else {
-                    0
-                };
-                
-                eprintln!("[DEBUG] Result mesh asset stats - Vertices: {}, Indices: {}", vertex_count, index_count);
-                
-                // Check first few vertex positions to verify mesh data
-                if let Some(positions) = result_mesh_asset.attribute(Mesh::ATTRIBUTE_POSITION) {
-                    match positions {
-                        bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => {
-                            if !pos.is_empty() {
-                                eprintln!("[DEBUG] First 3 result vertices: {:?}", &pos[..pos.len().min(3)]);
-                            }
-                        },
-                        _ => eprintln!("[DEBUG] Result mesh position attribute is not Float32x3"),
-                    }
+                    triangles.extend_from_slice(&[window[1], window[0], window[2]]);
                 }
-            } else {
-                eprintln!("[ERROR] Could not retrieve result mesh asset!");
-            }
-            
-            // Update the result entity
-            let update_start = std::time::Instant::now();
-            commands.entity(result_entity).insert(result_mesh_handle);
-            let update_time = update_start.elapsed();
-            eprintln!("[TIMING {}] [ENTITY UPDATE] Entity update took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), update_time);
-            
-            if let Ok(mut result_vis) = visibility_query.get_mut(result_entity) {
-                *result_vis = Visibility::Visible;
-                eprintln!("[DEBUG] Set result entity visibility to Visible");
-            } else {
-                eprintln!("[ERROR] Could not get visibility for result entity!");
-            }
-            
-            // Check if the result is empty
-            if result_vertices == 0 {
-                eprintln!("[TIMING {}] [PANIC] Result mesh has 0 vertices - boolean operation failed", 
-                         operation_start_time.elapsed().as_micros());
-                panic!("Boolean operation {:?} failed: Result mesh has 0 vertices. This indicates that the operation was not desirable or the input shapes didn't properly overlap for the operation. Ensure shapes overlap for boolean operations to work properly.", *op_state);
-            } else {
-                eprintln!("[TIMING {}] [SUCCESS] Boolean operation completed with {} vertices in result", 
-                         operation_start_time.elapsed().as_micros(), result_vertices);
             }
-        },
-        _ => {
-            eprintln!("[TIMING {}] [FALLBACK] Failed to convert one or both meshes to manifolds, falling back to primitive shapes", 
-                     operation_start_time.elapsed().as_micros());
-            eprintln!("[TIMING {}] [FALLBACK] This typically happens when STEP meshes are not watertight solids", 
-                     operation_start_time.elapsed().as_micros());
-            eprintln!("[TIMING {}] [FALLBACK] Consider using mesh repair tools or ensuring STEP file exports watertight solids", 
-                     operation_start_time.elapsed().as_micros());
-            
-            // Fall back to primitive manifolds directly
-            let primitive1 = manifold_rs::Manifold::cube(1.0, 1.0, 1.0);  // Cube
-            let primitive2 = manifold_rs::Manifold::sphere(0.8, 64);      // Sphere
-
-            // Apply transformations to position the shapes for intersection
-            let primary_pos = primary_transform.translation;
-            let secondary_pos = secondary_transform.translation;
-
-            let primary_manifold = primitive1.translate(
-                primary_pos.x as f64,
-                primary_pos.y as f64,
-                primary_pos.z as f64,
-            );
-            let secondary_manifold = primitive2.translate(
-                secondary_pos.x as f64,
-                secondary_pos.y as f64,
-                secondary_pos.z as f64,
-            );
-
-            // Log initial mesh info
-            let prim_mesh_info = primary_manifold.to_mesh();
-            let sec_mesh_info = secondary_manifold.to_mesh();
-            let prim_vertices_before = prim_mesh_info.vertices().len();
-            let sec_vertices_before = sec_mesh_info.vertices().len();
-            eprintln!("[TIMING {}] [FALLBACK] Boolean operation: Primary vertices: {}, Secondary vertices: {}", 
-                     operation_start_time.elapsed().as_micros(), prim_vertices_before, sec_vertices_before);
-
-            // Perform boolean operation
-            let operation_start = std::time::Instant::now();
-            let result_manifold = match *op_state {
-                BooleanOpState::Intersect => {
-                    eprintln!("[TIMING {}] [FALLBACK] Performing intersection operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Intersection)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [FALLBACK SUCCESS] Intersection operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [FALLBACK ERROR] Intersection operation panicked - returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::Union => {
-                    eprintln!("[TIMING {}] [FALLBACK] Performing union operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Union)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [FALLBACK SUCCESS] Union operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [FALLBACK ERROR] Union operation panicked - returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::Subtract => {
-                    eprintln!("[TIMING {}] [FALLBACK] Performing subtraction operation...", 
-                             operation_start_time.elapsed().as_micros());
-                    // Wrap in catch_unwind to prevent crashes
-                    let result = std::panic::catch_unwind(|| {
-                        primary_manifold.boolean_op(&secondary_manifold, manifold_rs::BooleanOp::Difference)
-                    });
-                    
-                    match result {
-                        Ok(manifold) => {
-                            eprintln!("[TIMING {}] [FALLBACK SUCCESS] Subtraction operation completed successfully", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold
-                        },
-                        Err(_) => {
-                            eprintln!("[TIMING {}] [FALLBACK ERROR] Subtraction operation panicked - returning empty manifold", 
-                                     operation_start_time.elapsed().as_micros());
-                            manifold_rs::Manifold::empty()
-                        }
-                    }
-                },
-                BooleanOpState::None => return, // Already handled above
-            };
-            let operation_time = operation_start.elapsed();
-            eprintln!("[TIMING {}] [FALLBACK BOOLEAN] Boolean operation took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), operation_time);
-
-            // Log the result info
-            let result_mesh_info = result_manifold.to_mesh();
-            let result_vertices = result_mesh_info.vertices().len();
-            let result_triangles = result_mesh_info.indices().len() / 3;
-            eprintln!("[TIMING {}] [FALLBACK RESULT] Result after operation - Vertices: {}, Triangles: {}", 
-                     operation_start_time.elapsed().as_micros(), result_vertices, result_triangles);
-
-            // Convert back to Bevy mesh
-            let conversion_back_start = std::time::Instant::now();
-            let result_bevy_mesh = manifold_to_bevy_mesh(result_manifold);
-            let conversion_back_time = conversion_back_start.elapsed();
-            eprintln!("[TIMING {}] [FALLBACK CONVERSION] Mesh conversion back to Bevy took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), conversion_back_time);
-
-            let result_mesh_handle = mesh_assets.add(result_bevy_mesh);
-
-            // Update the result entity
-            let update_start = std::time::Instant::now();
-            commands.entity(result_entity).insert(result_mesh_handle);
-            let update_time = update_start.elapsed();
-            eprintln!("[TIMING {}] [FALLBACK UPDATE] Entity update took: {:?}", 
-                     operation_start_time.elapsed().as_micros(), update_time);
-            
-            if let Ok(mut result_vis) = visibility_query.get_mut(result_entity) {
-                *result_vis = Visibility::Visible;
+            Some(triangles)
+        }
+        Topology::TriangleFan => {
+            if indices.len() < 3 {
+                return Some(Vec::new());
             }
-            
-            // Check if the result is empty
-            if result_vertices == 0 {
-                eprintln!("[TIMING {}] [PANIC FALLBACK] Result mesh has 0 vertices - boolean operation failed", 
-                         operation_start_time.elapsed().as_micros());
-                panic!("Boolean operation {:?} failed: Result mesh has 0 vertices (fallback path). This indicates that the operation was not desirable or the input shapes didn't properly overlap for the operation. Ensure shapes overlap for boolean operations to work properly.", *op_state);
-            } else {
-                eprintln!("[TIMING {}] [SUCCESS FALLBACK] Boolean operation completed with {} vertices in result", 
-                         operation_start_time.elapsed().as_micros(), result_vertices);
+            let pivot = indices[0];
+            let mut triangles = Vec::with_capacity((indices.len() - 2) * 3);
+            for edge in indices[1..].windows(2) {
+                triangles.extend_from_slice(&[pivot, edge[0], edge[1]]);
             }
+            Some(triangles)
         }
+        Topology::LineList | Topology::LineStrip | Topology::PointList => None,
     }
-    
-    let total_time = operation_start_time.elapsed();
-    eprintln!("[TIMING {}] [TOTAL] Boolean operation sequence completed in {:?}", 
-             total_time.as_micros(), total_time);
 }
 
 // Converts a Bevy mesh to a manifold-rs Manifold
 pub fn bevy_mesh_to_manifold(mesh: &Mesh) -> Option<manifold_rs::Manifold> {
-    eprintln!("[DEBUG] Converting Bevy mesh to manifold-rs Manifold");
-    
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Converting Bevy mesh to manifold-rs Manifold");
+
     // Get positions
     let positions = if let Some(positions) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
         match positions {
             bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => {
-                eprintln!("[DEBUG] Found {} position vertices", pos.len());
+                debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Found {} position vertices", pos.len());
                 pos
             },
             _ => {
-                eprintln!("[DEBUG] Position attribute is not Float32x3");
+                debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Position attribute is not Float32x3");
                 return None;
             }
         }
     } else {
-        eprintln!("[DEBUG] No position attribute found");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] No position attribute found");
         return None;
     };
 
@@ -666,24 +2596,65 @@ pub fn bevy_mesh_to_manifold(mesh: &Mesh) -> Option<manifold_rs::Manifold> {
     let indices = if let Some(indices) = mesh.indices() {
         match indices {
             bevy::render::mesh::Indices::U32(indices_vec) => {
-                eprintln!("[DEBUG] Found {} U32 indices", indices_vec.len());
+                debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Found {} U32 indices", indices_vec.len());
                 indices_vec.clone()
             },
             bevy::render::mesh::Indices::U16(indices_vec) => {
-                eprintln!("[DEBUG] Found {} U16 indices, converting to U32", indices_vec.len());
+                debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Found {} U16 indices, converting to U32", indices_vec.len());
                 // Convert u16 to u32 indices
                 indices_vec.iter().map(|&i| i as u32).collect()
             }
         }
     } else {
-        eprintln!("[DEBUG] No indices found, creating indices for {} positions", positions.len());
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] No indices found, creating indices for {} positions", positions.len());
         // If no indices, create indices for all vertices
         (0..positions.len() as u32).collect()
     };
 
-    // Convert vertices to the format expected by manifold-rs
-    let vertices_f32: Vec<f32> = positions.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
-    eprintln!("[DEBUG] Flattened to {} float values", vertices_f32.len());
+    // `indices` above assumed a triangle list; expand strips/fans into one
+    // now, and bail out on topologies with no triangles to extract.
+    let Some(indices) = triangulate_indices(mesh.primitive_topology(), indices) else {
+        debug!(
+            target: "bevy_mesh_boolean::convert",
+            "[DEBUG] Mesh topology {:?} has no triangles to convert", mesh.primitive_topology()
+        );
+        return None;
+    };
+
+    // Convert vertices to the format expected by manifold-rs, packing any of
+    // normal/UV0/vertex-color the source mesh carries on as extra property
+    // floats per [`PlainPropertyLayout`] so Manifold interpolates them
+    // along cut edges the same way it does positions; `manifold_to_bevy_mesh`
+    // decodes the resulting `num_props()` back into the same attributes.
+    let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x3(n)) if n.len() == positions.len() => Some(n),
+        _ => None,
+    };
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x2(uv)) if uv.len() == positions.len() => Some(uv),
+        _ => None,
+    };
+    let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x4(c)) if c.len() == positions.len() => Some(c),
+        _ => None,
+    };
+
+    let vertices_f32: Vec<f32> = (0..positions.len())
+        .flat_map(|i| {
+            let mut row = positions[i].to_vec();
+            if let Some(n) = normals {
+                row.extend(n[i]);
+            }
+            if let Some(uv) = uvs {
+                row.extend(uv[i]);
+            }
+            if let Some(c) = colors {
+                row.extend(c[i]);
+            }
+            row
+        })
+        .collect();
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Flattened to {} float values", vertices_f32.len());
 
     let conversion_start = std::time::Instant::now();
     let mesh = manifold_rs::Mesh::new(&vertices_f32, &indices);
@@ -695,28 +2666,28 @@ pub fn bevy_mesh_to_manifold(mesh: &Mesh) -> Option<manifold_rs::Manifold> {
     let result_vertices = mesh_info.vertices().len();
     let result_indices = mesh_info.indices().len();
     
-    eprintln!("[DEBUG] Manifold conversion completed in {:?} - Result: {} vertices, {} indices", 
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Manifold conversion completed in {:?} - Result: {} vertices, {} indices", 
              conversion_time, result_vertices, result_indices);
     
     if result_vertices > 0 {
-        eprintln!("[DEBUG] Successfully converted to manifold with {} vertices", result_vertices);
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Successfully converted to manifold with {} vertices", result_vertices);
         Some(manifold)
     } else {
-        eprintln!("[DEBUG] Conversion resulted in 0 vertices - mesh may not be a valid solid");
-        eprintln!("[DEBUG] This typically happens when:");
-        eprintln!("[DEBUG]   - Mesh is not watertight (has holes)");
-        eprintln!("[DEBUG]   - Triangle winding order is inconsistent"); 
-        eprintln!("[DEBUG]   - Mesh contains degenerate/self-intersecting geometry");
-        eprintln!("[DEBUG]   - Mesh normals are inconsistent");
-        eprintln!("[DEBUG] Attempting to make mesh watertight...");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Conversion resulted in 0 vertices - mesh may not be a valid solid");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] This typically happens when:");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG]   - Mesh is not watertight (has holes)");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG]   - Triangle winding order is inconsistent"); 
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG]   - Mesh contains degenerate/self-intersecting geometry");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG]   - Mesh normals are inconsistent");
+        debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Attempting to make mesh watertight...");
         
         // Try to make the mesh watertight
         if let Some(watertight_manifold) = make_mesh_watertight(positions, &indices) {
-            eprintln!("[DEBUG] Successfully made mesh watertight");
+            debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Successfully made mesh watertight");
             Some(watertight_manifold)
         } else {
-            eprintln!("[DEBUG] Failed to make mesh watertight");
-            eprintln!("[DEBUG] Consider using mesh repair tools or ensuring STEP file exports watertight solids");
+            debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Failed to make mesh watertight");
+            debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Consider using mesh repair tools or ensuring STEP file exports watertight solids");
             // If conversion resulted in no vertices, this might indicate the mesh isn't a valid solid
             // In that case, we return None to indicate failure
             None
@@ -724,18 +2695,198 @@ pub fn bevy_mesh_to_manifold(mesh: &Mesh) -> Option<manifold_rs::Manifold> {
     }
 }
 
+/// Per-face-corner index triple for an OBJ-style import: position index,
+/// plus optional independent normal/UV indices for formats where a shared
+/// position can carry a different normal or UV per incident face (e.g. a
+/// hard-edged cube corner, or a UV seam).
+pub type FaceCorner = (u32, Option<u32>, Option<u32>);
+
+fn corner_vertex_stride(has_normal: bool, has_uv: bool) -> usize {
+    3 + if has_normal { 3 } else { 0 } + if has_uv { 2 } else { 0 }
+}
+
+fn push_corner_vertex(vertices: &mut Vec<f32>, positions: &[[f32; 3]], normals: Option<&[[f32; 3]]>, uvs: Option<&[[f32; 2]]>, corner: FaceCorner) {
+    let (pos_idx, normal_idx, uv_idx) = corner;
+    vertices.extend_from_slice(&positions[pos_idx as usize]);
+    if let Some(normals) = normals {
+        vertices.extend_from_slice(&normals[normal_idx.unwrap_or(pos_idx) as usize]);
+    }
+    if let Some(uvs) = uvs {
+        vertices.extend_from_slice(&uvs[uv_idx.unwrap_or(pos_idx) as usize]);
+    }
+}
+
+/// Dedup `corners` by their exact `(pos_idx, normal_idx, uv_idx)` triple:
+/// two corners only share a manifold vertex when they agree on all three,
+/// so a position with per-face normals (a hard edge) correctly gets one
+/// manifold vertex per distinct normal.
+fn weld_by_corner_index(positions: &[[f32; 3]], normals: Option<&[[f32; 3]]>, uvs: Option<&[[f32; 2]]>, corners: &[FaceCorner]) -> manifold_rs::Manifold {
+    let stride = corner_vertex_stride(normals.is_some(), uvs.is_some());
+    let mut seen: std::collections::HashMap<FaceCorner, u32> = std::collections::HashMap::new();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(corners.len());
+
+    for &corner in corners {
+        let index = *seen.entry(corner).or_insert_with(|| {
+            let next = (vertices.len() / stride) as u32;
+            push_corner_vertex(&mut vertices, positions, normals, uvs, corner);
+            next
+        });
+        indices.push(index);
+    }
+
+    manifold_rs::Mesh::with_num_props(&vertices, stride as u32, &indices).to_manifold()
+}
+
+/// Dedup `corners` purely by position, snapped to a grid of `tolerance`
+/// size — used when [`weld_by_corner_index`] leaves the mesh non-manifold
+/// because near-duplicate positions were never meant to be separate
+/// indices (a common OBJ export artifact). The first corner to land in
+/// each grid cell donates its normal/UV to every corner that welds onto it.
+fn weld_by_rounded_position(positions: &[[f32; 3]], normals: Option<&[[f32; 3]]>, uvs: Option<&[[f32; 2]]>, corners: &[FaceCorner], tolerance: f32) -> manifold_rs::Manifold {
+    let stride = corner_vertex_stride(normals.is_some(), uvs.is_some());
+    let scale = if tolerance > 0.0 { 1.0 / tolerance } else { 1.0 };
+    let mut seen: std::collections::HashMap<[i64; 3], u32> = std::collections::HashMap::new();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::with_capacity(corners.len());
+
+    for &corner in corners {
+        let p = positions[corner.0 as usize];
+        let key = [(p[0] * scale).round() as i64, (p[1] * scale).round() as i64, (p[2] * scale).round() as i64];
+        let index = *seen.entry(key).or_insert_with(|| {
+            let next = (vertices.len() / stride) as u32;
+            push_corner_vertex(&mut vertices, positions, normals, uvs, corner);
+            next
+        });
+        indices.push(index);
+    }
+
+    manifold_rs::Mesh::with_num_props(&vertices, stride as u32, &indices).to_manifold()
+}
+
+/// Build a watertight indexed [`manifold_rs::Manifold`] from an OBJ-style
+/// import whose position/normal/UV streams are indexed independently per
+/// face corner, rather than already merged into Bevy's one-index-per-stream
+/// `Mesh` layout. `corners` is read three at a time as triangles (its
+/// length must be a multiple of 3).
+///
+/// Corners are first welded by their exact index triple via
+/// [`weld_by_corner_index`], the same dedup [`bevy_mesh_to_manifold`] gets
+/// for free from Bevy's shared-index `Mesh`. If that still isn't manifold
+/// (Manifold's own constructor collapses to empty on failure), falls back
+/// to [`weld_by_rounded_position`], welding any corners within
+/// `weld_tolerance` of each other regardless of their source indices.
+pub fn indexed_manifold_from_corners(positions: &[[f32; 3]], normals: Option<&[[f32; 3]]>, uvs: Option<&[[f32; 2]]>, corners: &[FaceCorner], weld_tolerance: f32) -> Option<manifold_rs::Manifold> {
+    if corners.is_empty() || corners.len() % 3 != 0 {
+        return None;
+    }
+
+    let manifold = weld_by_corner_index(positions, normals, uvs, corners);
+    if !manifold.is_empty() {
+        return Some(manifold);
+    }
+
+    let manifold = weld_by_rounded_position(positions, normals, uvs, corners, weld_tolerance);
+    (!manifold.is_empty()).then_some(manifold)
+}
+
+/// Which Bevy vertex attributes [`bevy_mesh_to_manifold_with_properties`]
+/// and [`manifold_to_bevy_mesh_with_properties`] carry through a boolean op
+/// as named [`TaggedManifold`] property channels, and how many floats each
+/// one packs per vertex (1/2/3/4, matching a
+/// `Float32`/`Float32x2`/`Float32x3`/`Float32x4` attribute). Callers pick
+/// which attributes are worth the property-channel cost, since every
+/// channel widens every vertex's property row even on vertices where the
+/// source mesh didn't carry it (those get zero-filled).
+#[derive(Clone, Copy, Debug)]
+pub struct AttributeChannel {
+    pub attribute: bevy::render::mesh::MeshVertexAttribute,
+    pub width: usize,
+}
+
+/// Read one Bevy vertex attribute's values as a flat, per-vertex `width`-wide
+/// float buffer, for packing into a [`TaggedManifold`] property channel.
+/// `None` if the attribute's actual format doesn't match `width`.
+fn attribute_values_as_f32(values: &bevy::render::mesh::VertexAttributeValues, width: usize) -> Option<Vec<f32>> {
+    use bevy::render::mesh::VertexAttributeValues as V;
+    match (values, width) {
+        (V::Float32(v), 1) => Some(v.clone()),
+        (V::Float32x2(v), 2) => Some(v.iter().flat_map(|a| a.to_vec()).collect()),
+        (V::Float32x3(v), 3) => Some(v.iter().flat_map(|a| a.to_vec()).collect()),
+        (V::Float32x4(v), 4) => Some(v.iter().flat_map(|a| a.to_vec()).collect()),
+        _ => None,
+    }
+}
+
+/// The inverse of [`attribute_values_as_f32`]: rebuild a
+/// `VertexAttributeValues` array of the given `width` from flat per-vertex
+/// float data.
+fn f32_to_attribute_values(width: usize, data: &[f32]) -> Option<bevy::render::mesh::VertexAttributeValues> {
+    use bevy::render::mesh::VertexAttributeValues as V;
+    match width {
+        1 => Some(V::Float32(data.to_vec())),
+        2 => Some(V::Float32x2(data.chunks_exact(2).map(|c| [c[0], c[1]]).collect())),
+        3 => Some(V::Float32x3(data.chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect())),
+        4 => Some(V::Float32x4(data.chunks_exact(4).map(|c| [c[0], c[1], c[2], c[3]]).collect())),
+        _ => None,
+    }
+}
+
+/// [`bevy_mesh_to_manifold`], but additionally packs `channels` (e.g.
+/// `ATTRIBUTE_UV_0`, `ATTRIBUTE_COLOR`, or any custom float attribute) into
+/// the result as named [`TaggedManifold`] property channels, so Manifold's
+/// own kernel interpolates them across any new intersection vertices the
+/// boolean op introduces — the same mechanism [`tag_and_transform`] already
+/// uses for the `"normal"`/`"uv0"` channels it attaches internally. A channel
+/// missing from `mesh` (or present in an unexpected format) is zero-filled
+/// rather than skipped, so every operand keeps the same property layout
+/// `TaggedManifold::boolean_op` requires.
+pub fn bevy_mesh_to_manifold_with_properties(mesh: &Mesh, channels: &[AttributeChannel]) -> Option<TaggedManifold> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        bevy::render::mesh::VertexAttributeValues::Float32x3(pos) => pos,
+        _ => return None,
+    };
+
+    let indices: Vec<u32> = match mesh.indices()? {
+        bevy::render::mesh::Indices::U32(v) => v.clone(),
+        bevy::render::mesh::Indices::U16(v) => v.iter().map(|&i| i as u32).collect(),
+    };
+
+    let vertices_f32: Vec<f32> = positions.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
+    let mut tagged = TaggedManifold::new(&vertices_f32, &indices, Vec::new());
+    let rebuilt = tagged.manifold().to_mesh();
+    if rebuilt.vertices().is_empty() {
+        return None;
+    }
+    // Derive the vertex count from the *rebuilt* manifold, not `positions`,
+    // since `to_manifold()` may weld/reorder vertices while making the mesh
+    // watertight — the same subtlety `tag_and_transform` guards against.
+    let num_verts = rebuilt.vertices().len() / rebuilt.num_props().max(1) as usize;
+
+    for channel in channels {
+        let data = mesh
+            .attribute(channel.attribute)
+            .and_then(|v| attribute_values_as_f32(v, channel.width))
+            .filter(|d| d.len() == num_verts * channel.width)
+            .unwrap_or_else(|| vec![0.0; num_verts * channel.width]);
+        tagged.set_property_channel(channel.attribute.name, channel.width, &data);
+    }
+
+    Some(tagged)
+}
+
 // Attempts to make a mesh watertight by creating a convex hull or other repair techniques
 pub fn make_mesh_watertight(
     positions: &[[f32; 3]], 
     indices: &[u32]
 ) -> Option<manifold_rs::Manifold> {
-    eprintln!("[MAKE_WATERTIGHT] Attempting to make mesh watertight...");
-    eprintln!("[MAKE_WATERTIGHT] Input: {} positions, {} indices", positions.len(), indices.len());
+    debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Attempting to make mesh watertight...");
+    debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Input: {} positions, {} indices", positions.len(), indices.len());
     
     // For very large meshes, skip convex hull to prevent stack overflow
     if positions.len() > 10000 {
-        eprintln!("[MAKE_WATERTIGHT] Large mesh detected ({} vertices), skipping convex hull computation", positions.len());
-        eprintln!("[MAKE_WATERTIGHT] Falling back to bounding box for watertight approximation");
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Large mesh detected ({} vertices), skipping convex hull computation", positions.len());
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Falling back to bounding box for watertight approximation");
         
         // Calculate bounding box
         let mut min_bound = Vec3::new(positions[0][0], positions[0][1], positions[0][2]);
@@ -750,7 +2901,7 @@ pub fn make_mesh_watertight(
         let size = max_bound - min_bound;
         let center = (min_bound + max_bound) * 0.5;
         
-        eprintln!("[MAKE_WATERTIGHT] Bounding box: min={:?}, max={:?}, size={:?}, center={:?}", 
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box: min={:?}, max={:?}, size={:?}, center={:?}", 
                  min_bound, max_bound, size, center);
         
         // Create a cube that encompasses the mesh
@@ -768,20 +2919,20 @@ pub fn make_mesh_watertight(
         let mesh_info = manifold.to_mesh();
         let result_vertices = mesh_info.vertices().len();
         let result_indices = mesh_info.indices().len();
-        eprintln!("[MAKE_WATERTIGHT] Bounding box result: {} vertices, {} indices", result_vertices, result_indices);
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box result: {} vertices, {} indices", result_vertices, result_indices);
         
         if result_vertices > 0 {
-            eprintln!("[MAKE_WATERTIGHT] Successfully created watertight manifold with bounding box");
+            debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Successfully created watertight manifold with bounding box");
             return Some(manifold);
         } else {
-            eprintln!("[MAKE_WATERTIGHT] Bounding box failed to create valid manifold");
+            debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box failed to create valid manifold");
         }
     } else if positions.len() >= 4 {
-        eprintln!("[MAKE_WATERTIGHT] Creating convex hull from {} vertices", positions.len());
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Creating convex hull from {} vertices", positions.len());
         
         // Convert to manifold-rs format
         let vertices_f32: Vec<f32> = positions.iter().flat_map(|p| [p[0], p[1], p[2]]).collect();
-        eprintln!("[MAKE_WATERTIGHT] Flattened to {} float values", vertices_f32.len());
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Flattened to {} float values", vertices_f32.len());
         
         // Try to create a convex hull - wrap in catch_unwind to prevent crashes
         let result = std::panic::catch_unwind(|| {
@@ -803,7 +2954,7 @@ pub fn make_mesh_watertight(
                 let mesh_info = manifold.to_mesh();
                 let result_vertices = mesh_info.vertices().len();
                 let result_indices = mesh_info.indices().len();
-                eprintln!("[MAKE_WATERTIGHT] Convex hull result: {} vertices, {} indices", result_vertices, result_indices);
+                debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Convex hull result: {} vertices, {} indices", result_vertices, result_indices);
                 
                 if result_vertices > 0 {
                     Some(manifold)
@@ -817,21 +2968,21 @@ pub fn make_mesh_watertight(
         
         match result {
             Ok(Some(manifold)) => {
-                eprintln!("[MAKE_WATERTIGHT] Successfully created watertight manifold with convex hull");
+                debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Successfully created watertight manifold with convex hull");
                 return Some(manifold);
             },
             Ok(None) => {
-                eprintln!("[MAKE_WATERTIGHT] Convex hull failed to create valid manifold");
+                debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Convex hull failed to create valid manifold");
             },
             Err(_) => {
-                eprintln!("[MAKE_WATERTIGHT] Convex hull computation panicked - likely stack overflow, falling back to bounding box");
+                debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Convex hull computation panicked - likely stack overflow, falling back to bounding box");
             }
         }
     }
     
     // If convex hull fails or we have too few vertices, try to create a bounding box
     if !positions.is_empty() {
-        eprintln!("[MAKE_WATERTIGHT] Creating bounding box from {} vertices", positions.len());
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Creating bounding box from {} vertices", positions.len());
         
         // Calculate bounding box
         let mut min_bound = Vec3::new(positions[0][0], positions[0][1], positions[0][2]);
@@ -846,7 +2997,7 @@ pub fn make_mesh_watertight(
         let size = max_bound - min_bound;
         let center = (min_bound + max_bound) * 0.5;
         
-        eprintln!("[MAKE_WATERTIGHT] Bounding box: min={:?}, max={:?}, size={:?}, center={:?}", 
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box: min={:?}, max={:?}, size={:?}, center={:?}", 
                  min_bound, max_bound, size, center);
         
         // Create a cube that encompasses the mesh
@@ -864,38 +3015,69 @@ pub fn make_mesh_watertight(
         let mesh_info = manifold.to_mesh();
         let result_vertices = mesh_info.vertices().len();
         let result_indices = mesh_info.indices().len();
-        eprintln!("[MAKE_WATERTIGHT] Bounding box result: {} vertices, {} indices", result_vertices, result_indices);
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box result: {} vertices, {} indices", result_vertices, result_indices);
         
         if result_vertices > 0 {
-            eprintln!("[MAKE_WATERTIGHT] Successfully created watertight manifold with bounding box");
+            debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Successfully created watertight manifold with bounding box");
             return Some(manifold);
         } else {
-            eprintln!("[MAKE_WATERTIGHT] Bounding box failed to create valid manifold");
+            debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Bounding box failed to create valid manifold");
         }
     }
     
     // If all else fails, create a small cube at the origin
-    eprintln!("[MAKE_WATERTIGHT] Creating fallback cube");
+    debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Creating fallback cube");
     let manifold = manifold_rs::Manifold::cube(1.0, 1.0, 1.0);
     
     // Check if the resulting manifold is valid
     let mesh_info = manifold.to_mesh();
     let result_vertices = mesh_info.vertices().len();
     let result_indices = mesh_info.indices().len();
-    eprintln!("[MAKE_WATERTIGHT] Fallback cube result: {} vertices, {} indices", result_vertices, result_indices);
+    debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Fallback cube result: {} vertices, {} indices", result_vertices, result_indices);
     
     if result_vertices > 0 {
-        eprintln!("[MAKE_WATERTIGHT] Successfully created watertight manifold with fallback cube");
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Successfully created watertight manifold with fallback cube");
         Some(manifold)
     } else {
-        eprintln!("[MAKE_WATERTIGHT] Even fallback cube failed");
+        debug!(target: "bevy_mesh_boolean::convert", "[MAKE_WATERTIGHT] Even fallback cube failed");
         None
     }
 }
 
 // Converts a manifold-rs Manifold to a Bevy mesh
+/// Which optional attributes [`bevy_mesh_to_manifold`] packed onto its flat
+/// per-vertex property rows, decoded from `num_props - 3` (the position
+/// triple is always present). Normal (3 floats), UV0 (2 floats), and color
+/// (4 floats) give eight distinct combinations with eight distinct sums —
+/// `0, 2, 3, 4, 5, 6, 7, 9` — so the combination that produced a given
+/// `num_props` is unambiguous without needing named property channels the
+/// way [`TaggedManifold`] does.
+struct PlainPropertyLayout {
+    has_normal: bool,
+    has_uv: bool,
+    has_color: bool,
+}
+
+impl PlainPropertyLayout {
+    fn decode(num_props: usize) -> Option<Self> {
+        let extra = num_props.checked_sub(3)?;
+        let (has_normal, has_uv, has_color) = match extra {
+            0 => (false, false, false),
+            2 => (false, true, false),
+            3 => (true, false, false),
+            4 => (false, false, true),
+            5 => (true, true, false),
+            6 => (false, true, true),
+            7 => (true, false, true),
+            9 => (true, true, true),
+            _ => return None,
+        };
+        Some(Self { has_normal, has_uv, has_color })
+    }
+}
+
 pub fn manifold_to_bevy_mesh(manifold: manifold_rs::Manifold) -> Mesh {
-    eprintln!("[DEBUG] Converting manifold-rs Manifold to Bevy mesh");
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Converting manifold-rs Manifold to Bevy mesh");
     let conversion_start = std::time::Instant::now();
     
     let mesh = manifold.to_mesh();
@@ -904,98 +3086,136 @@ pub fn manifold_to_bevy_mesh(manifold: manifold_rs::Manifold) -> Mesh {
     let vertices = mesh.vertices();
     let indices = mesh.indices();
     
-    eprintln!("[DEBUG] Manifold->Bevy conversion took {:?} - Vertices: {}, Indices: {}, Properties: {}", 
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Manifold->Bevy conversion took {:?} - Vertices: {}, Indices: {}, Properties: {}", 
              conversion_time, vertices.len(), indices.len(), mesh.num_props());
 
-    match mesh.num_props() {
-        3 => {
-            eprintln!("[DEBUG] Processing vertex data without normals");
-            // Vertex without normals - vertices is a flat Vec<f32> where every 3 values are x,y,z
-            let vertex_positions: Vec<[f32; 3]> = vertices.chunks(3).map(|chunk| [chunk[0], chunk[1], chunk[2]]).collect();
-            eprintln!("[DEBUG] Created {} vertex positions from {} float values", vertex_positions.len(), vertices.len());
-
-            let mesh_build_start = std::time::Instant::now();
-            let mut result = Mesh::new(
-                bevy::render::mesh::PrimitiveTopology::TriangleList,
-                bevy::render::render_asset::RenderAssetUsages::all(),
-            );
-            
-            eprintln!("[DEBUG] Inserting {} vertex positions", vertex_positions.len());
-            result.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertex_positions);
-            
-            eprintln!("[DEBUG] Inserting {} indices", indices.len());
-            result.insert_indices(bevy::render::mesh::Indices::U32(indices.clone()));
-            
-            // DO NOT call duplicate_vertices() or compute_flat_normals() as they can corrupt the mesh indices
-            // Instead, let the renderer handle normals if needed
-            
-            let mesh_build_time = mesh_build_start.elapsed();
-            
-            // Verify the mesh has indices after construction
-            if let Some(mesh_indices) = result.indices() {
-                match mesh_indices {
-                    bevy::render::mesh::Indices::U32(indices_vec) => {
-                        eprintln!("[DEBUG] Mesh construction verified - {} U32 indices", indices_vec.len());
-                    },
-                    bevy::render::mesh::Indices::U16(indices_vec) => {
-                        eprintln!("[DEBUG] Mesh construction verified - {} U16 indices", indices_vec.len());
-                    }
-                }
-            } else {
-                eprintln!("[DEBUG] Mesh construction completed but has no indices!");
-            }
-            
-            eprintln!("[DEBUG] Bevy mesh construction took {:?}", mesh_build_time);
-            result
-        }
-        6 => {
-            eprintln!("[DEBUG] Processing vertex data with normals");
-            // Vertex with normals - vertices is a flat Vec<f32> where every 6 values are x,y,z,nx,ny,nz
-            let normals: Vec<[f32; 3]> = vertices.chunks(6).map(|chunk| [chunk[3], chunk[4], chunk[5]]).collect();
-            let vertex_positions: Vec<[f32; 3]> = vertices.chunks(6).map(|chunk| [chunk[0], chunk[1], chunk[2]]).collect();
-            eprintln!("[DEBUG] Created {} vertex positions and {} normals from {} float values", 
-                     vertex_positions.len(), normals.len(), vertices.len());
-
-            let mesh_build_start = std::time::Instant::now();
-            let mut result = Mesh::new(
-                bevy::render::mesh::PrimitiveTopology::TriangleList,
-                bevy::render::render_asset::RenderAssetUsages::all(),
-            );
-            
-            eprintln!("[DEBUG] Inserting {} vertex positions with normals", vertex_positions.len());
-            result.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertex_positions);
-            
-            eprintln!("[DEBUG] Inserting {} normals", normals.len());
-            result.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-            
-            eprintln!("[DEBUG] Inserting {} indices", indices.len());
-            result.insert_indices(bevy::render::mesh::Indices::U32(indices.clone()));
-            
-            // DO NOT call duplicate_vertices() or compute_flat_normals() as they can corrupt the mesh indices
-            // The normals are already provided, so we don't need to compute them
-            
-            let mesh_build_time = mesh_build_start.elapsed();
-            
-            // Verify the mesh has indices after construction
-            if let Some(mesh_indices) = result.indices() {
-                match mesh_indices {
-                    bevy::render::mesh::Indices::U32(indices_vec) => {
-                        eprintln!("[DEBUG] Mesh construction with normals verified - {} U32 indices", indices_vec.len());
-                    },
-                    bevy::render::mesh::Indices::U16(indices_vec) => {
-                        eprintln!("[DEBUG] Mesh construction with normals verified - {} U16 indices", indices_vec.len());
-                    }
-                }
-            } else {
-                eprintln!("[DEBUG] Mesh construction with normals completed but has no indices!");
-            }
-            
-            eprintln!("[DEBUG] Bevy mesh construction with normals took {:?}", mesh_build_time);
-            result
+    let num_props = mesh.num_props() as usize;
+    let mesh_build_start = std::time::Instant::now();
+    let mut result = Mesh::new(
+        bevy::render::mesh::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::all(),
+    );
+
+    let Some(layout) = PlainPropertyLayout::decode(num_props) else {
+        warn!(
+            target: "bevy_mesh_boolean::convert",
+            "manifold has an unexpected property count ({num_props}); keeping only positions"
+        );
+        let vertex_positions: Vec<[f32; 3]> = vertices
+            .chunks(num_props.max(3))
+            .map(|chunk| [chunk[0], chunk[1], chunk[2]])
+            .collect();
+        let vertex_count = vertex_positions.len();
+        result.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertex_positions);
+        result.insert_indices(pack_indices(vertex_count, indices, false));
+        return result;
+    };
+
+    debug!(
+        target: "bevy_mesh_boolean::convert",
+        "[DEBUG] Decoded layout for {num_props} properties: normal={}, uv={}, color={}",
+        layout.has_normal, layout.has_uv, layout.has_color
+    );
+
+    let mut vertex_positions = Vec::with_capacity(vertices.len() / num_props);
+    let mut normals = Vec::with_capacity(if layout.has_normal { vertex_positions.capacity() } else { 0 });
+    let mut uvs = Vec::with_capacity(if layout.has_uv { vertex_positions.capacity() } else { 0 });
+    let mut colors = Vec::with_capacity(if layout.has_color { vertex_positions.capacity() } else { 0 });
+
+    for row in vertices.chunks(num_props) {
+        let mut offset = 3;
+        vertex_positions.push([row[0], row[1], row[2]]);
+        if layout.has_normal {
+            // A cut-edge vertex's normal is Manifold's own linear blend of
+            // its two source corners' normals (the same property-channel
+            // interpolation positions themselves get), which isn't unit
+            // length in general — renormalize the same way
+            // `transform_normals_raw` already does after its own blend.
+            let n = crate::detmath::normalize_or_zero(Vec3::new(row[offset], row[offset + 1], row[offset + 2]));
+            normals.push(n.to_array());
+            offset += 3;
+        }
+        if layout.has_uv {
+            uvs.push([row[offset], row[offset + 1]]);
+            offset += 2;
+        }
+        if layout.has_color {
+            colors.push([row[offset], row[offset + 1], row[offset + 2], row[offset + 3]]);
         }
-        num_props => {
-            eprintln!("[ERROR] Invalid property count {num_props}");
-            panic!("Invalid property count {num_props}")
-        },
     }
+
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Inserting {} vertex positions", vertex_positions.len());
+    let vertex_count = vertex_positions.len();
+    result.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertex_positions);
+    if layout.has_normal {
+        result.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    }
+    if layout.has_uv {
+        result.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    }
+    if layout.has_color {
+        result.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Inserting {} indices", indices.len());
+    result.insert_indices(pack_indices(vertex_count, indices, false));
+
+    debug!(target: "bevy_mesh_boolean::convert", "[DEBUG] Bevy mesh construction took {:?}", mesh_build_start.elapsed());
+    result
+}
+
+/// [`manifold_to_bevy_mesh`], plus an `ATTRIBUTE_TANGENT` pass via
+/// [`generate_tangents`] — for callers that convert a `Manifold` directly
+/// (bypassing the `dispatch_boolean_op`/`poll_boolean_op` pipeline, which
+/// already generates tangents on every result submesh through
+/// [`raw_submesh_to_bevy_mesh`]) and still want a normal map to shade
+/// correctly on the result.
+pub fn manifold_to_bevy_mesh_with_tangents(manifold: manifold_rs::Manifold) -> Mesh {
+    let mut mesh = manifold_to_bevy_mesh(manifold);
+    generate_tangents(&mut mesh);
+    mesh
+}
+
+/// A Bevy `Mesh` for a regular tetrahedron, built from
+/// [`manifold_rs::Manifold::tetrahedron`] rather than faked out of another
+/// primitive — so it's a genuine manifold solid a boolean op can consume,
+/// not just a stand-in shape that happens to render.
+///
+/// This tree has no Bevy-facing primitive-shape registry (a
+/// `cube`/`sphere`/... selector enum) to hang this off of yet — callers
+/// needing one of those today reach for Bevy's own `Cuboid`/`Sphere`/etc.
+/// directly. Until such a registry exists, this is exposed standalone next
+/// to [`manifold_to_bevy_mesh_with_tangents`].
+pub fn tetrahedron_mesh() -> Mesh {
+    manifold_to_bevy_mesh(manifold_rs::Manifold::tetrahedron())
+}
+
+/// The inverse of [`bevy_mesh_to_manifold_with_properties`]: rebuild a Bevy
+/// `Mesh` (positions + indices) from a (typically post-`boolean_op`)
+/// [`TaggedManifold`], then reconstruct each of `channels`' original
+/// `VertexAttributeValues` from its property channel. A channel `tagged`
+/// doesn't actually carry — e.g. it was never attached on this side, or both
+/// operands agreed to drop it — is simply omitted from the result rather
+/// than inserted empty.
+pub fn manifold_to_bevy_mesh_with_properties(tagged: &TaggedManifold, channels: &[AttributeChannel]) -> Mesh {
+    let mesh_info = tagged.manifold().to_mesh();
+    let num_props = mesh_info.num_props() as usize;
+    let vertex_positions: Vec<[f32; 3]> =
+        mesh_info.vertices().chunks(num_props.max(3)).map(|chunk| [chunk[0], chunk[1], chunk[2]]).collect();
+
+    let mut result = Mesh::new(
+        bevy::render::mesh::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::all(),
+    );
+    let vertex_count = vertex_positions.len();
+    result.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertex_positions);
+    result.insert_indices(pack_indices(vertex_count, mesh_info.indices().clone(), false));
+
+    for channel in channels {
+        let Some(values) = tagged.channel_values(channel.attribute.name) else { continue };
+        let Some(attribute_values) = f32_to_attribute_values(channel.width, &values) else { continue };
+        result.insert_attribute(channel.attribute, attribute_values);
+    }
+
+    result
 }
\ No newline at end of file
@@ -0,0 +1,378 @@
+//! Meshlet/LOD generation for boolean-result meshes.
+//!
+//! STEP imports like `multifeature.step` tessellate into very dense meshes,
+//! and subtracting a large cube from one only makes the result denser —
+//! rendering the full-resolution triangle soup every frame is wasteful once
+//! the part is more than a few pixels on screen. This module clusters a
+//! result mesh's triangles into small, spatially-local meshlets, groups
+//! neighbouring meshlets and simplifies each group with quadric edge
+//! collapse to build a simplification DAG, and records a bounding sphere
+//! plus screen-space error bound per meshlet so a runtime system can select
+//! the coarsest LOD that still stays under an error threshold.
+//!
+//! This is a standalone conversion (`mesh_to_meshlets`), not wired into the
+//! boolean dispatch systems automatically — callers opt in by running it on
+//! a `BooleanOpResult::Success` mesh when the result is large enough to
+//! matter.
+
+use bevy::asset::Asset;
+use bevy::math::Vec3;
+use bevy::reflect::TypePath;
+use std::collections::{HashMap, HashSet};
+
+/// Meshlets are capped at this many unique vertices, matching the limit
+/// common GPU meshlet pipelines (e.g. mesh shaders) expect.
+pub const MAX_MESHLET_VERTICES: usize = 64;
+/// Meshlets are capped at this many triangles.
+pub const MAX_MESHLET_TRIANGLES: usize = 124;
+
+/// One cluster of spatially-local triangles: a self-contained little
+/// triangle list (indices into [`MeshletAsset::vertices`]) plus the bounds
+/// a runtime LOD selector needs.
+#[derive(Debug, Clone)]
+pub struct Meshlet {
+    /// Indices into `MeshletAsset::vertices` for this meshlet's unique verts.
+    pub vertices: Vec<u32>,
+    /// Triangles as indices into `Self::vertices` (not the global vertex list).
+    pub triangles: Vec<[u8; 3]>,
+    /// Center and radius of the meshlet's bounding sphere, in mesh space.
+    pub bounding_sphere: (Vec3, f32),
+    /// Simplification error this meshlet introduces relative to its source
+    /// triangles, in mesh-space units. Zero for leaf (full-resolution) meshlets.
+    pub error: f32,
+}
+
+/// One level of the simplification DAG: a set of meshlets built by merging
+/// and simplifying several `source` meshlets from the level below. Runtime
+/// selection walks the DAG top-down, descending into `source` meshlets only
+/// when `error` is still too large for the desired screen-space tolerance.
+#[derive(Debug, Clone)]
+pub struct MeshletGroup {
+    /// Indices into `MeshletAsset::meshlets` for this group's simplified meshlets.
+    pub meshlets: Vec<usize>,
+    /// Indices into `MeshletAsset::meshlets` for the higher-detail meshlets
+    /// this group was simplified from.
+    pub source: Vec<usize>,
+}
+
+/// A meshlet/LOD representation of one boolean-result mesh, stored as its
+/// own asset type alongside the `StandardMaterial` result path so a large
+/// CAD import can be rendered at a coarser LOD instead of its full
+/// tessellation.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct MeshletAsset {
+    /// Deduplicated source positions; all of `Meshlet::vertices` index into this.
+    pub vertices: Vec<[f32; 3]>,
+    /// Every meshlet across every DAG level, finest (leaf) first.
+    pub meshlets: Vec<Meshlet>,
+    /// Simplification DAG levels, finest-to-coarsest as `groups[0]` merges
+    /// the full set of leaf meshlets and each following level merges the
+    /// previous one further. Empty if the mesh fit in a single meshlet.
+    pub groups: Vec<MeshletGroup>,
+}
+
+impl MeshletAsset {
+    /// Walk the DAG top-down, picking the coarsest meshlets whose error
+    /// still stays under `max_error` at the given `screen_space_scale`
+    /// (e.g. `object_radius / distance_to_camera`, so error shrinks as the
+    /// object recedes). Returns indices into `self.meshlets` to draw.
+    pub fn select_lod(&self, max_error: f32, screen_space_scale: f32) -> Vec<usize> {
+        let Some(coarsest) = self.groups.last() else {
+            return (0..self.meshlets.len()).collect();
+        };
+
+        let mut selected = Vec::new();
+        let mut queue = coarsest.meshlets.clone();
+
+        // Walk downward from the coarsest level: a meshlet is kept once its
+        // projected error is under the threshold, otherwise its group's
+        // `source` (finer) meshlets replace it in the queue.
+        while let Some(m) = queue.pop() {
+            let projected_error = self.meshlets[m].error * screen_space_scale;
+            let owning_group = self.groups.iter().find(|g| g.meshlets.contains(&m));
+            match owning_group {
+                Some(g) if projected_error > max_error => queue.extend(g.source.iter().copied()),
+                _ => selected.push(m),
+            }
+        }
+
+        selected.sort_unstable();
+        selected.dedup();
+        selected
+    }
+}
+
+/// Build a [`MeshletAsset`] from a flat triangle mesh: cluster triangles
+/// into meshlets, then repeatedly group and simplify until the whole mesh
+/// collapses into a handful of coarse meshlets (or stops shrinking).
+pub fn mesh_to_meshlets(positions: &[[f32; 3]], indices: &[u32]) -> MeshletAsset {
+    let verts: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+    let triangles: Vec<[u32; 3]> = indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect();
+
+    let mut meshlets = cluster_triangles(&verts, &triangles, (0..triangles.len()).collect());
+    let mut groups = Vec::new();
+    let mut level_start = 0;
+
+    // Repeatedly group adjacent meshlets (sharing a vertex) and simplify
+    // each group into one coarser meshlet, stopping once a level fails to
+    // shrink the meshlet count (the whole mesh fits in too few meshlets to
+    // keep merging, or simplification stalls).
+    loop {
+        let level: Vec<usize> = (level_start..meshlets.len()).collect();
+        if level.len() <= 1 {
+            break;
+        }
+        let partition = partition_by_adjacency(&meshlets, &level);
+        if partition.len() >= level.len() {
+            break;
+        }
+
+        let mut group = MeshletGroup { meshlets: Vec::new(), source: level.clone() };
+        for cluster in partition {
+            let simplified = simplify_group(&verts, &meshlets, &cluster);
+            let new_index = meshlets.len();
+            meshlets.push(simplified);
+            group.meshlets.push(new_index);
+        }
+        level_start = meshlets.len() - group.meshlets.len();
+        groups.push(group);
+    }
+
+    MeshletAsset { vertices: positions.to_vec(), meshlets, groups }
+}
+
+/// Greedily partition `triangle_indices` (into `triangles`) into meshlets by
+/// repeatedly growing a cluster from a seed triangle, pulling in
+/// face-adjacent triangles (sharing an edge) until either the vertex or
+/// triangle budget is hit, which keeps each meshlet spatially local.
+fn cluster_triangles(verts: &[Vec3], triangles: &[[u32; 3]], triangle_indices: Vec<usize>) -> Vec<Meshlet> {
+    let mut edge_to_tris: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for &t in &triangle_indices {
+        let tri = triangles[t];
+        for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_to_tris.entry(key).or_default().push(t);
+        }
+    }
+
+    let mut remaining: HashSet<usize> = triangle_indices.into_iter().collect();
+    let mut meshlets = Vec::new();
+
+    while let Some(&seed) = remaining.iter().next() {
+        remaining.remove(&seed);
+        let mut cluster_tris = vec![seed];
+        let mut cluster_verts: Vec<u32> = triangles[seed].to_vec();
+        let mut frontier = vec![seed];
+
+        while let Some(t) = frontier.pop() {
+            if cluster_tris.len() >= MAX_MESHLET_TRIANGLES {
+                break;
+            }
+            let tri = triangles[t];
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let key = if a < b { (a, b) } else { (b, a) };
+                let Some(neighbours) = edge_to_tris.get(&key) else { continue };
+                for &n in neighbours {
+                    if !remaining.contains(&n) {
+                        continue;
+                    }
+                    let n_tri = triangles[n];
+                    let new_verts: Vec<u32> = n_tri.iter().copied().filter(|v| !cluster_verts.contains(v)).collect();
+                    if cluster_verts.len() + new_verts.len() > MAX_MESHLET_VERTICES {
+                        continue;
+                    }
+                    if cluster_tris.len() >= MAX_MESHLET_TRIANGLES {
+                        break;
+                    }
+                    remaining.remove(&n);
+                    cluster_tris.push(n);
+                    cluster_verts.extend(new_verts);
+                    frontier.push(n);
+                }
+            }
+        }
+
+        meshlets.push(build_meshlet(verts, triangles, &cluster_tris, &cluster_verts, 0.0));
+    }
+
+    meshlets
+}
+
+/// Turn a list of triangle indices plus the unique global vertex ids they
+/// touch into a self-contained [`Meshlet`] (local triangle indices, bounding
+/// sphere, and the given simplification `error`).
+fn build_meshlet(verts: &[Vec3], triangles: &[[u32; 3]], cluster_tris: &[usize], cluster_verts: &[u32], error: f32) -> Meshlet {
+    let local_index: HashMap<u32, u8> = cluster_verts.iter().enumerate().map(|(i, &v)| (v, i as u8)).collect();
+    let local_triangles = cluster_tris
+        .iter()
+        .map(|&t| {
+            let tri = triangles[t];
+            [local_index[&tri[0]], local_index[&tri[1]], local_index[&tri[2]]]
+        })
+        .collect();
+
+    let points: Vec<Vec3> = cluster_verts.iter().map(|&v| verts[v as usize]).collect();
+    let bounding_sphere = bounding_sphere(&points);
+
+    Meshlet {
+        vertices: cluster_verts.to_vec(),
+        triangles: local_triangles,
+        bounding_sphere,
+        error,
+    }
+}
+
+/// Welzl-lite bounding sphere: center at the point set's centroid, radius
+/// the farthest point from it. Not minimal, but stable and cheap enough to
+/// run per meshlet without becoming the bottleneck.
+fn bounding_sphere(points: &[Vec3]) -> (Vec3, f32) {
+    if points.is_empty() {
+        return (Vec3::ZERO, 0.0);
+    }
+    let centroid = points.iter().copied().sum::<Vec3>() / points.len() as f32;
+    let radius = points.iter().map(|p| crate::detmath::length(*p - centroid)).fold(0.0_f32, f32::max);
+    (centroid, radius)
+}
+
+/// Union-find grouping of meshlets in `level` into clusters of mutually
+/// vertex-adjacent meshlets, simulating the graph-partitioning step: two
+/// meshlets merge into the same group if they share at least one vertex,
+/// joined pairwise until no more merges apply. Capped at a handful of
+/// meshlets per group so simplification doesn't collapse half the mesh at once.
+fn partition_by_adjacency(meshlets: &[Meshlet], level: &[usize]) -> Vec<Vec<usize>> {
+    const MAX_GROUP_SIZE: usize = 4;
+
+    let mut parent: HashMap<usize, usize> = level.iter().map(|&i| (i, i)).collect();
+    fn find(parent: &mut HashMap<usize, usize>, x: usize) -> usize {
+        let p = parent[&x];
+        if p != x {
+            let root = find(parent, p);
+            parent.insert(x, root);
+            root
+        } else {
+            x
+        }
+    }
+
+    let mut group_size: HashMap<usize, usize> = level.iter().map(|&i| (i, 1)).collect();
+
+    for (pos, &i) in level.iter().enumerate() {
+        let vi: HashSet<u32> = meshlets[i].vertices.iter().copied().collect();
+        for &j in &level[pos + 1..] {
+            if meshlets[j].vertices.iter().any(|v| vi.contains(v)) {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj && group_size[&ri] + group_size[&rj] <= MAX_GROUP_SIZE {
+                    parent.insert(ri, rj);
+                    *group_size.get_mut(&rj).unwrap() += group_size[&ri];
+                }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &i in level {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(i);
+    }
+    groups.into_values().collect()
+}
+
+/// Simplify a group of adjacent meshlets into one coarser meshlet: merge
+/// their triangles back into a flat triangle soup, run quadric edge
+/// collapse until the triangle count drops by half, and record the extra
+/// geometric error introduced (a tenth of the simplified meshlet's bounding
+/// sphere radius, added to the largest error among its source meshlets, as
+/// a conservative stand-in for per-vertex quadric error).
+fn simplify_group(verts: &[Vec3], meshlets: &[Meshlet], cluster: &[usize]) -> Meshlet {
+    let mut global_triangles: Vec<[u32; 3]> = Vec::new();
+    let mut vertex_set: HashSet<u32> = HashSet::new();
+    for &m in cluster {
+        let meshlet = &meshlets[m];
+        for tri in &meshlet.triangles {
+            global_triangles.push([
+                meshlet.vertices[tri[0] as usize],
+                meshlet.vertices[tri[1] as usize],
+                meshlet.vertices[tri[2] as usize],
+            ]);
+        }
+        vertex_set.extend(meshlet.vertices.iter().copied());
+    }
+
+    let target_triangles = (global_triangles.len() / 2).max(1);
+    let simplified = quadric_edge_collapse(verts, global_triangles, target_triangles);
+
+    let mut cluster_verts: Vec<u32> = simplified.iter().flatten().copied().collect();
+    cluster_verts.sort_unstable();
+    cluster_verts.dedup();
+
+    let points: Vec<Vec3> = cluster_verts.iter().map(|&v| verts[v as usize]).collect();
+    let (center, radius) = bounding_sphere(&points);
+
+    let source_error = cluster.iter().map(|&m| meshlets[m].error).fold(0.0_f32, f32::max);
+    let error = source_error + radius * 0.1;
+
+    let local_index: HashMap<u32, u8> = cluster_verts.iter().enumerate().map(|(i, &v)| (v, i as u8)).collect();
+    let triangles = simplified.iter().map(|tri| [local_index[&tri[0]], local_index[&tri[1]], local_index[&tri[2]]]).collect();
+
+    Meshlet {
+        vertices: cluster_verts,
+        triangles,
+        bounding_sphere: (center, radius),
+        error,
+    }
+}
+
+/// Greedy quadric-error-metric edge collapse: repeatedly merges the
+/// cheapest remaining edge (shortest, as a stand-in for a full per-vertex
+/// quadric since meshlets are small enough that edge length tracks
+/// curvature-driven error closely) by welding one endpoint onto the other,
+/// until `target_triangles` is reached or no collapsible edge remains.
+fn quadric_edge_collapse(verts: &[Vec3], mut triangles: Vec<[u32; 3]>, target_triangles: usize) -> Vec<[u32; 3]> {
+    let mut remap: HashMap<u32, u32> = HashMap::new();
+    let resolve = |remap: &HashMap<u32, u32>, mut v: u32| {
+        while let Some(&next) = remap.get(&v) {
+            if next == v {
+                break;
+            }
+            v = next;
+        }
+        v
+    };
+
+    while triangles.len() > target_triangles {
+        let mut edges: HashSet<(u32, u32)> = HashSet::new();
+        for tri in &triangles {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let (a, b) = (resolve(&remap, a), resolve(&remap, b));
+                if a != b {
+                    edges.insert(if a < b { (a, b) } else { (b, a) });
+                }
+            }
+        }
+        let Some(&(a, b)) = edges
+            .iter()
+            .min_by(|x, y| {
+                let dx = (verts[x.0 as usize] - verts[x.1 as usize]).length_squared();
+                let dy = (verts[y.0 as usize] - verts[y.1 as usize]).length_squared();
+                dx.partial_cmp(&dy).unwrap()
+            })
+        else {
+            break;
+        };
+
+        remap.insert(a, b);
+        triangles = triangles
+            .iter()
+            .filter_map(|tri| {
+                let resolved = [resolve(&remap, tri[0]), resolve(&remap, tri[1]), resolve(&remap, tri[2])];
+                if resolved[0] == resolved[1] || resolved[1] == resolved[2] || resolved[2] == resolved[0] {
+                    None
+                } else {
+                    Some(resolved)
+                }
+            })
+            .collect();
+    }
+
+    triangles
+}
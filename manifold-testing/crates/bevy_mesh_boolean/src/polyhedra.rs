@@ -0,0 +1,421 @@
+//! Conway/Kaplan polyhedron operators: build a closed, manifold Bevy mesh
+//! from a compact operator string over a Platonic seed (`T`/`C`/`O`/`D`/`I`)
+//! composed with `t`(truncate)/`a`(ambo)/`k`(kis)/`d`(dual)/`s`(snub), e.g.
+//! `conway_polyhedron_mesh("dakD")` reads innermost-first: seed `D`, then
+//! `k`, then `a`, then `d`. Gives CSG-kernel tests and demos a deterministic,
+//! parametric corpus of watertight operands to fuzz genus/hole edge cases
+//! against, without needing a STEP file.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// The intermediate representation every operator below transforms: plain
+/// vertices plus explicit CCW n-gon faces. Only [`to_bevy_mesh`] flattens
+/// this down to a triangulated, per-face-normal Bevy `Mesh`.
+struct PolyMesh {
+    vertices: Vec<Vec3>,
+    faces: Vec<Vec<usize>>,
+}
+
+/// Build the Bevy mesh for `spec`, e.g. `"T"`, `"tC"`, `"dakD"`. `None` if
+/// the trailing seed letter or any operator letter isn't recognized.
+pub fn conway_polyhedron_mesh(spec: &str) -> Option<Mesh> {
+    let chars: Vec<char> = spec.chars().collect();
+    let (&seed_char, ops) = chars.split_last()?;
+    let mut poly = seed_polyhedron(seed_char)?;
+    for &op in ops.iter().rev() {
+        poly = apply_operator(&poly, op)?;
+    }
+    fix_winding(&mut poly);
+    planarize(&mut poly, 20);
+    Some(to_bevy_mesh(&poly))
+}
+
+/// `C` (cube) and `D` (dodecahedron) are built as [`dual`] of the
+/// hand-coded `O`/`I` seeds rather than getting their own vertex/face
+/// tables — `dual(octahedron)` and `dual(icosahedron)` are exactly a cube
+/// and a dodecahedron, so this reuses the same general operator instead of
+/// hand-transcribing two more coordinate lists.
+fn seed_polyhedron(c: char) -> Option<PolyMesh> {
+    Some(match c {
+        'T' => seed_tetrahedron(),
+        'O' => seed_octahedron(),
+        'I' => seed_icosahedron(),
+        'C' => dual(&seed_octahedron()),
+        'D' => dual(&seed_icosahedron()),
+        _ => return None,
+    })
+}
+
+fn apply_operator(poly: &PolyMesh, op: char) -> Option<PolyMesh> {
+    Some(match op {
+        'd' => dual(poly),
+        'a' => ambo(poly),
+        't' => truncate(poly, 1.0 / 3.0),
+        'k' => kis(poly, 0.4),
+        's' => snub(poly),
+        _ => return None,
+    })
+}
+
+fn seed_tetrahedron() -> PolyMesh {
+    let vertices = vec![
+        Vec3::new(1.0, 1.0, 1.0),
+        Vec3::new(1.0, -1.0, -1.0),
+        Vec3::new(-1.0, 1.0, -1.0),
+        Vec3::new(-1.0, -1.0, 1.0),
+    ];
+    let faces = vec![vec![0, 1, 2], vec![0, 3, 1], vec![0, 2, 3], vec![1, 3, 2]];
+    PolyMesh { vertices, faces }
+}
+
+fn seed_octahedron() -> PolyMesh {
+    let vertices = vec![
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(-1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, -1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(0.0, 0.0, -1.0),
+    ];
+    let faces = vec![
+        vec![0, 2, 4],
+        vec![2, 1, 4],
+        vec![1, 3, 4],
+        vec![3, 0, 4],
+        vec![2, 0, 5],
+        vec![1, 2, 5],
+        vec![3, 1, 5],
+        vec![0, 3, 5],
+    ];
+    PolyMesh { vertices, faces }
+}
+
+/// Same coordinates and face winding as the reference icosahedron
+/// `meshbool`'s geodesic-sphere code is built from (phi-based, 12 vertices, 20
+/// CCW-outward triangles) — there's no reason for a second derivation to
+/// disagree with the one already proven out there.
+fn seed_icosahedron() -> PolyMesh {
+    let phi = (1.0 + crate::detmath::sqrt(5.0_f32)) / 2.0;
+    let vertices = vec![
+        Vec3::new(-1.0, phi, 0.0),
+        Vec3::new(1.0, phi, 0.0),
+        Vec3::new(-1.0, -phi, 0.0),
+        Vec3::new(1.0, -phi, 0.0),
+        Vec3::new(0.0, -1.0, phi),
+        Vec3::new(0.0, 1.0, phi),
+        Vec3::new(0.0, -1.0, -phi),
+        Vec3::new(0.0, 1.0, -phi),
+        Vec3::new(phi, 0.0, -1.0),
+        Vec3::new(phi, 0.0, 1.0),
+        Vec3::new(-phi, 0.0, -1.0),
+        Vec3::new(-phi, 0.0, 1.0),
+    ];
+    let faces = vec![
+        vec![0, 11, 5],
+        vec![0, 5, 1],
+        vec![0, 1, 7],
+        vec![0, 7, 10],
+        vec![0, 10, 11],
+        vec![1, 5, 9],
+        vec![5, 11, 4],
+        vec![11, 10, 2],
+        vec![10, 7, 6],
+        vec![7, 1, 8],
+        vec![3, 9, 4],
+        vec![3, 4, 2],
+        vec![3, 2, 6],
+        vec![3, 6, 8],
+        vec![3, 8, 9],
+        vec![4, 9, 5],
+        vec![2, 4, 11],
+        vec![6, 2, 10],
+        vec![8, 6, 7],
+        vec![9, 8, 1],
+    ];
+    PolyMesh { vertices, faces }
+}
+
+/// For every vertex, the faces touching it in cyclic (rotational) order —
+/// the shared building block [`dual`], [`ambo`] and [`truncate`] all walk
+/// to build their own "around this vertex" face or vertex list. Found by
+/// repeatedly hopping from a face to the one sharing its next edge at `v`:
+/// if face `F` has `v` immediately followed by `w`, the face continuing the
+/// rotation around `v` is whichever face has `w` immediately followed by
+/// `v` (the same undirected edge, traversed the opposite way, the way two
+/// consistently-wound adjacent faces always do).
+fn vertex_face_order(poly: &PolyMesh) -> Vec<Vec<usize>> {
+    let mut edge_face: HashMap<(usize, usize), usize> = HashMap::new();
+    for (fi, face) in poly.faces.iter().enumerate() {
+        let n = face.len();
+        for i in 0..n {
+            edge_face.insert((face[i], face[(i + 1) % n]), fi);
+        }
+    }
+
+    let mut order = vec![Vec::new(); poly.vertices.len()];
+    for (v, slot) in order.iter_mut().enumerate() {
+        let Some(start) = poly.faces.iter().position(|f| f.contains(&v)) else {
+            continue;
+        };
+        let mut current = start;
+        loop {
+            if slot.contains(&current) {
+                break;
+            }
+            slot.push(current);
+            let face = &poly.faces[current];
+            let pos = face.iter().position(|&x| x == v).unwrap();
+            let next_vertex = face[(pos + 1) % face.len()];
+            match edge_face.get(&(next_vertex, v)) {
+                Some(&nf) if nf != current => current = nf,
+                _ => break,
+            }
+        }
+    }
+    order
+}
+
+/// Swap faces for vertices: a new vertex per original face (its centroid),
+/// a new face per original vertex (that vertex's incident faces, in the
+/// cyclic order [`vertex_face_order`] finds) — exactly Conway `d`.
+fn dual(poly: &PolyMesh) -> PolyMesh {
+    let vertices: Vec<Vec3> = poly.faces.iter().map(|f| face_plane(poly, f).0).collect();
+    let faces: Vec<Vec<usize>> = vertex_face_order(poly).into_iter().filter(|f| f.len() >= 3).collect();
+    PolyMesh { vertices, faces }
+}
+
+/// Truncate every edge down to its midpoint: a new vertex per original
+/// edge, a face per original face (still `n` sides, one per edge midpoint,
+/// nipping each corner) plus a face per original vertex (the nipped-off corner itself)
+/// — Conway `a`.
+fn ambo(poly: &PolyMesh) -> PolyMesh {
+    let key = |a: usize, b: usize| if a < b { (a, b) } else { (b, a) };
+    let mut edge_id: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut vertices = Vec::new();
+    for face in &poly.faces {
+        let n = face.len();
+        for i in 0..n {
+            let (a, b) = (face[i], face[(i + 1) % n]);
+            edge_id.entry(key(a, b)).or_insert_with(|| {
+                let idx = vertices.len();
+                vertices.push((poly.vertices[a] + poly.vertices[b]) * 0.5);
+                idx
+            });
+        }
+    }
+
+    let mut faces: Vec<Vec<usize>> = poly
+        .faces
+        .iter()
+        .map(|face| {
+            let n = face.len();
+            (0..n).map(|i| edge_id[&key(face[i], face[(i + 1) % n])]).collect()
+        })
+        .collect();
+
+    for (v, incident) in vertex_face_order(poly).into_iter().enumerate() {
+        if incident.len() < 3 {
+            continue;
+        }
+        let vertex_face = incident
+            .iter()
+            .map(|&fi| {
+                let face = &poly.faces[fi];
+                let pos = face.iter().position(|&x| x == v).unwrap();
+                edge_id[&key(v, face[(pos + 1) % face.len()])]
+            })
+            .collect();
+        faces.push(vertex_face);
+    }
+
+    PolyMesh { vertices, faces }
+}
+
+/// Cut each vertex into its own face: two new vertices per original face
+/// corner (placed a fraction `t` of the way along each adjacent edge),
+/// plus one new face per original vertex connecting the cuts around it —
+/// Conway `t`.
+fn truncate(poly: &PolyMesh, t: f32) -> PolyMesh {
+    let mut vertices = Vec::new();
+    let mut cut: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut get = |v: usize, w: usize, vertices: &mut Vec<Vec3>| -> usize {
+        *cut.entry((v, w)).or_insert_with(|| {
+            let idx = vertices.len();
+            vertices.push(poly.vertices[v].lerp(poly.vertices[w], t));
+            idx
+        })
+    };
+
+    let mut faces = Vec::new();
+    for face in &poly.faces {
+        let n = face.len();
+        let mut f = Vec::with_capacity(n * 2);
+        for i in 0..n {
+            let v = face[i];
+            let prev = face[(i + n - 1) % n];
+            let next = face[(i + 1) % n];
+            f.push(get(v, prev, &mut vertices));
+            f.push(get(v, next, &mut vertices));
+        }
+        faces.push(f);
+    }
+
+    for (v, incident) in vertex_face_order(poly).into_iter().enumerate() {
+        if incident.len() < 3 {
+            continue;
+        }
+        let f = incident
+            .iter()
+            .map(|&fi| {
+                let face = &poly.faces[fi];
+                let pos = face.iter().position(|&x| x == v).unwrap();
+                get(v, face[(pos + 1) % face.len()], &mut vertices)
+            })
+            .collect();
+        faces.push(f);
+    }
+
+    PolyMesh { vertices, faces }
+}
+
+/// Raise a pyramid on every face: one new apex vertex per face, pushed
+/// outward from the polyhedron's center past the face's own centroid by
+/// `height` (a fraction of the centroid's own distance from center, so it
+/// scales with the shape rather than needing an absolute size), with the
+/// face replaced by a fan of triangles to that apex — Conway `k`.
+fn kis(poly: &PolyMesh, height: f32) -> PolyMesh {
+    let center: Vec3 = poly.vertices.iter().copied().sum::<Vec3>() / poly.vertices.len() as f32;
+    let mut vertices = poly.vertices.clone();
+    let mut faces = Vec::new();
+    for face in &poly.faces {
+        let (centroid, _) = face_plane(poly, face);
+        let apex_idx = vertices.len();
+        vertices.push(centroid + (centroid - center) * height);
+        let n = face.len();
+        for i in 0..n {
+            faces.push(vec![face[i], face[(i + 1) % n], apex_idx]);
+        }
+    }
+    PolyMesh { vertices, faces }
+}
+
+/// Conway `s` doesn't have as clean a face-local definition as the other
+/// four operators, so this approximates it via the standard decomposition
+/// "expand" = `ambo(ambo(seed))`, then splits every quadrilateral face
+/// `expand` produces (always one per original edge) along one diagonal, in
+/// a single consistent direction, to give the result its chirality. That's
+/// a valid, planarizable snub topology; it isn't a metrically-regular snub
+/// polyhedron, which needs a numerical vertex relaxation this module
+/// doesn't attempt — tracked as a known gap rather than silently faked.
+fn snub(poly: &PolyMesh) -> PolyMesh {
+    let expanded = ambo(&ambo(poly));
+    let faces = expanded
+        .faces
+        .into_iter()
+        .flat_map(|f| {
+            if f.len() == 4 {
+                vec![vec![f[0], f[1], f[2]], vec![f[0], f[2], f[3]]]
+            } else {
+                vec![f]
+            }
+        })
+        .collect();
+    PolyMesh { vertices: expanded.vertices, faces }
+}
+
+/// A face's centroid and outward-ish unit normal via Newell's method, which
+/// (unlike a three-point cross product) stays well-conditioned for the
+/// non-triangular, not-always-quite-planar faces these operators produce.
+fn face_plane(poly: &PolyMesh, face: &[usize]) -> (Vec3, Vec3) {
+    let n = face.len();
+    let centroid = face.iter().map(|&vi| poly.vertices[vi]).sum::<Vec3>() / n as f32;
+    let mut normal = Vec3::ZERO;
+    for i in 0..n {
+        let a = poly.vertices[face[i]];
+        let b = poly.vertices[face[(i + 1) % n]];
+        normal += Vec3::new((a.y - b.y) * (a.z + b.z), (a.z - b.z) * (a.x + b.x), (a.x - b.x) * (a.y + b.y));
+    }
+    (centroid, crate::detmath::normalize_or_zero(normal))
+}
+
+/// Reverse any face whose own plane normal points toward the polyhedron's
+/// center instead of away from it, so every operator above can build faces
+/// in whatever cyclic order its own construction falls out to without
+/// having to separately reason about which direction is "outward" — a
+/// single pass at the end fixes every face's winding consistently.
+fn fix_winding(poly: &mut PolyMesh) {
+    let center: Vec3 = poly.vertices.iter().copied().sum::<Vec3>() / poly.vertices.len() as f32;
+    let reverse: Vec<bool> = poly
+        .faces
+        .iter()
+        .map(|f| {
+            let (centroid, normal) = face_plane(poly, f);
+            (centroid - center).dot(normal) < 0.0
+        })
+        .collect();
+    for (face, rev) in poly.faces.iter_mut().zip(reverse) {
+        if rev {
+            face.reverse();
+        }
+    }
+}
+
+/// Relax every vertex toward the average, over its incident faces, of that
+/// vertex projected onto each face's own best-fit plane — the simplest
+/// iterative scheme that converges non-planar faces (which `kis`, `ambo`
+/// and friends readily produce from non-regular operands) toward planar
+/// without otherwise distorting the shape.
+fn planarize(poly: &mut PolyMesh, iterations: u32) {
+    for _ in 0..iterations {
+        let mut accum = vec![Vec3::ZERO; poly.vertices.len()];
+        let mut count = vec![0u32; poly.vertices.len()];
+        for face in &poly.faces {
+            let (centroid, normal) = face_plane(poly, face);
+            if normal == Vec3::ZERO {
+                continue;
+            }
+            for &vi in face {
+                let v = poly.vertices[vi];
+                let projected = v - normal * (v - centroid).dot(normal);
+                accum[vi] += projected;
+                count[vi] += 1;
+            }
+        }
+        for (vi, v) in poly.vertices.iter_mut().enumerate() {
+            if count[vi] > 0 {
+                *v = accum[vi] / count[vi] as f32;
+            }
+        }
+    }
+}
+
+/// Flatten `poly` into a triangulated Bevy mesh, fan-triangulating each
+/// face and duplicating its vertices with a flat per-face normal — the
+/// low-poly, hard-edged look expected of a faceted procedural polyhedron.
+fn to_bevy_mesh(poly: &PolyMesh) -> Mesh {
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+
+    for face in &poly.faces {
+        if face.len() < 3 {
+            continue;
+        }
+        let (_, normal) = face_plane(poly, face);
+        let base = positions.len() as u32;
+        for &vi in face {
+            positions.push(poly.vertices[vi].to_array());
+            normals.push(normal.to_array());
+        }
+        for i in 1..face.len() - 1 {
+            indices.extend_from_slice(&[base, base + i as u32, base + (i + 1) as u32]);
+        }
+    }
+
+    let mut mesh = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, bevy::render::render_asset::RenderAssetUsages::all());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
+    mesh
+}
@@ -0,0 +1,241 @@
+//! Mesh-healing preprocessing so non-watertight imports (STEP tessellations,
+//! CAD exports with coincident-but-unwelded seams) survive the trip into a
+//! boolean manifold instead of failing to build one or falling back to
+//! [`crate::make_mesh_watertight`]'s convex-hull/bounding-box approximation.
+//!
+//! [`repair_mesh`] runs three independent passes over raw `(positions,
+//! indices)` data — spatial vertex welding, degenerate-triangle removal, and
+//! winding consistency via edge-adjacency flood-fill — and reports what it
+//! did via [`RepairReport`] rather than silently mutating the mesh into
+//! something unrecognizable.
+
+use bevy::math::Vec3;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Grid cell size for the welding pass, in mesh units. Coincident vertices
+/// within this distance of each other are merged; wider than machine
+/// epsilon by design, since CAD tessellators routinely leave seams a few
+/// ULPs apart, not bit-identical.
+pub const DEFAULT_WELD_EPSILON: f32 = 1e-4;
+
+/// What [`repair_mesh`] found and fixed, for diagnostics/logging — so a
+/// caller can tell *why* a repaired mesh looks different from the input
+/// instead of just that it does.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RepairReport {
+    /// Vertices merged together by the welding pass.
+    pub vertices_welded: usize,
+    /// Triangles dropped for being degenerate (a repeated vertex or zero area).
+    pub degenerate_triangles_dropped: usize,
+    /// Triangles whose winding was flipped to match the lowest-indexed
+    /// triangle of their connected component.
+    pub triangles_reoriented: usize,
+    /// Boundary edges remaining after welding and degenerate removal —
+    /// nonzero means the mesh still isn't watertight. Use
+    /// [`find_boundary_loops`] on the repaired indices to see how they group
+    /// into loops (holes).
+    pub open_boundary_edges: usize,
+}
+
+/// Run the full healing pipeline: weld near-coincident vertices, drop
+/// degenerate triangles, and make triangle winding consistent within each
+/// connected component. Returns the repaired `(positions, indices)` plus a
+/// [`RepairReport`] describing what changed.
+pub fn repair_mesh(positions: &[[f32; 3]], indices: &[u32], weld_epsilon: f32) -> ((Vec<[f32; 3]>, Vec<u32>), RepairReport) {
+    let mut report = RepairReport::default();
+
+    let (positions, indices, vertices_welded) = weld_vertices(positions, indices, weld_epsilon);
+    report.vertices_welded = vertices_welded;
+
+    let (indices, degenerate_triangles_dropped) = drop_degenerate_triangles(&positions, &indices);
+    report.degenerate_triangles_dropped = degenerate_triangles_dropped;
+
+    let (indices, triangles_reoriented) = reorient_triangles(&indices);
+    report.triangles_reoriented = triangles_reoriented;
+
+    report.open_boundary_edges = find_boundary_loops(&indices).iter().map(Vec::len).sum();
+
+    ((positions, indices), report)
+}
+
+/// Merge vertices within `epsilon` of each other using a uniform spatial
+/// grid keyed on `floor(position / epsilon)`, so only nearby vertices are
+/// ever compared instead of an all-pairs scan. Returns the deduped
+/// positions, remapped indices, and how many vertices were merged away.
+fn weld_vertices(positions: &[[f32; 3]], indices: &[u32], epsilon: f32) -> (Vec<[f32; 3]>, Vec<u32>, usize) {
+    let epsilon = epsilon.max(f32::EPSILON);
+    let cell_of = |p: [f32; 3]| -> (i64, i64, i64) {
+        ((p[0] / epsilon).floor() as i64, (p[1] / epsilon).floor() as i64, (p[2] / epsilon).floor() as i64)
+    };
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    let mut remap = vec![0u32; positions.len()];
+    let mut welded: Vec<[f32; 3]> = Vec::with_capacity(positions.len());
+    let epsilon_sq = epsilon * epsilon;
+
+    for (i, &p) in positions.iter().enumerate() {
+        let (cx, cy, cz) = cell_of(p);
+        let mut found = None;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) else { continue };
+                    for &candidate in candidates {
+                        let q = welded[candidate];
+                        let dist_sq: f32 = (0..3).map(|a| (p[a] - q[a]) * (p[a] - q[a])).sum();
+                        if dist_sq <= epsilon_sq {
+                            found = Some(candidate);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        let target = found.unwrap_or_else(|| {
+            let new_index = welded.len();
+            welded.push(p);
+            grid.entry((cx, cy, cz)).or_default().push(new_index);
+            new_index
+        });
+        remap[i] = target as u32;
+    }
+
+    let vertices_welded = positions.len() - welded.len();
+    let indices = indices.iter().map(|&i| remap[i as usize]).collect();
+    (welded, indices, vertices_welded)
+}
+
+/// Drop any triangle with a repeated vertex index or (near-)zero area.
+fn drop_degenerate_triangles(positions: &[[f32; 3]], indices: &[u32]) -> (Vec<u32>, usize) {
+    let mut kept = Vec::with_capacity(indices.len());
+    let mut dropped = 0;
+    for tri in indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]];
+        if a == b || b == c || a == c {
+            dropped += 1;
+            continue;
+        }
+        let pa = Vec3::from(positions[a as usize]);
+        let pb = Vec3::from(positions[b as usize]);
+        let pc = Vec3::from(positions[c as usize]);
+        if (pb - pa).cross(pc - pa).length_squared() <= f32::EPSILON {
+            dropped += 1;
+            continue;
+        }
+        kept.extend_from_slice(tri);
+    }
+    (kept, dropped)
+}
+
+/// Make triangle winding consistent within each connected component, via a
+/// flood fill over edge adjacency: two triangles sharing an edge in opposite
+/// directions (`u -> v` in one, `v -> u` in the other) already agree; two
+/// sharing it in the *same* direction disagree, so one gets flipped relative
+/// to the other. Components aren't compared against each other — this
+/// can't tell which of two disconnected shells is "outward", only make each
+/// one internally consistent.
+fn reorient_triangles(indices: &[u32]) -> (Vec<u32>, usize) {
+    let num_tris = indices.len() / 3;
+    let tri_at = |t: usize| [indices[t * 3], indices[t * 3 + 1], indices[t * 3 + 2]];
+
+    let mut edge_owner: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for t in 0..num_tris {
+        let [a, b, c] = tri_at(t);
+        for &(u, v) in &[(a, b), (b, c), (c, a)] {
+            edge_owner.entry((u, v)).or_default().push(t);
+        }
+    }
+
+    // `neighbors[t]` is every other triangle sharing an edge with `t`, along
+    // with whether that shared edge runs the same direction in both (an
+    // orientation mismatch) or opposite (already consistent).
+    let mut neighbors: Vec<Vec<(usize, bool)>> = vec![Vec::new(); num_tris];
+    for t in 0..num_tris {
+        let [a, b, c] = tri_at(t);
+        for &(u, v) in &[(a, b), (b, c), (c, a)] {
+            if let Some(opposite) = edge_owner.get(&(v, u)) {
+                neighbors[t].extend(opposite.iter().filter(|&&other| other != t).map(|&other| (other, false)));
+            }
+            if let Some(same_dir) = edge_owner.get(&(u, v)) {
+                neighbors[t].extend(same_dir.iter().filter(|&&other| other != t).map(|&other| (other, true)));
+            }
+        }
+    }
+
+    let mut flip = vec![false; num_tris];
+    let mut visited = vec![false; num_tris];
+    for start in 0..num_tris {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        while let Some(t) = queue.pop_front() {
+            for &(other, mismatched) in &neighbors[t] {
+                if visited[other] {
+                    continue;
+                }
+                visited[other] = true;
+                flip[other] = flip[t] ^ mismatched;
+                queue.push_back(other);
+            }
+        }
+    }
+
+    let mut reoriented = 0;
+    let mut out = Vec::with_capacity(indices.len());
+    for t in 0..num_tris {
+        let [a, b, c] = tri_at(t);
+        if flip[t] {
+            out.extend_from_slice(&[a, c, b]);
+            reoriented += 1;
+        } else {
+            out.extend_from_slice(&[a, b, c]);
+        }
+    }
+    (out, reoriented)
+}
+
+/// Group unmatched directed edges (no reverse edge elsewhere in the mesh)
+/// into boundary loops. A watertight mesh has none; each returned loop is a
+/// hole's boundary, as a sequence of vertex indices walked in order.
+pub fn find_boundary_loops(indices: &[u32]) -> Vec<Vec<u32>> {
+    let num_tris = indices.len() / 3;
+    let mut edges: HashSet<(u32, u32)> = HashSet::new();
+    for t in 0..num_tris {
+        let [a, b, c] = [indices[t * 3], indices[t * 3 + 1], indices[t * 3 + 2]];
+        edges.insert((a, b));
+        edges.insert((b, c));
+        edges.insert((c, a));
+    }
+
+    let mut boundary_next: HashMap<u32, Vec<u32>> = HashMap::new();
+    for &(u, v) in &edges {
+        if !edges.contains(&(v, u)) {
+            boundary_next.entry(u).or_default().push(v);
+        }
+    }
+
+    let mut loops = Vec::new();
+    let mut visited: HashSet<(u32, u32)> = HashSet::new();
+    for (&start, nexts) in &boundary_next {
+        for &first in nexts {
+            if visited.contains(&(start, first)) {
+                continue;
+            }
+            let mut loop_verts = vec![start];
+            visited.insert((start, first));
+            let mut current = first;
+            while current != start {
+                loop_verts.push(current);
+                let Some(options) = boundary_next.get(&current) else { break };
+                let Some(&next) = options.iter().find(|&&n| !visited.contains(&(current, n))) else { break };
+                visited.insert((current, next));
+                current = next;
+            }
+            loops.push(loop_verts);
+        }
+    }
+    loops
+}
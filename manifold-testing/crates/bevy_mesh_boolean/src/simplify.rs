@@ -0,0 +1,370 @@
+//! Quadric-error-metric LOD decimation for boolean-result meshes.
+//!
+//! Unlike [`crate::meshlet`]'s `quadric_edge_collapse` (a shortest-edge proxy
+//! scoped to simplifying small meshlet groups for the LOD DAG), this module
+//! accumulates a full 4x4 quadric error matrix per vertex (Garland &
+//! Heckbert, "Surface Simplification Using Quadric Error Metrics") and runs
+//! a min-heap priority edge collapse with lazy invalidation, so a dense
+//! STEP-tessellated boolean result can be decimated to a target vertex
+//! budget in one pass.
+//!
+//! Like [`crate::mesh_to_meshlets`], [`simplify_mesh`] itself is a standalone
+//! conversion, not wired into the boolean dispatch systems automatically —
+//! callers opt in by running it on a `BooleanOpResult::Success` mesh, using
+//! the attached [`MeshLod`] for the target ratio.
+//!
+//! [`BooleanLodChain`] is the automatic counterpart: attach it to a result
+//! entity and [`dispatch_boolean_lod_chain`]/[`poll_boolean_lod_chain`] build
+//! a whole [`BooleanLodLevels`] chain off [`simplify_mesh`] the moment that
+//! entity's next `BooleanOpResult::Success` fires, with [`select_boolean_lod`]
+//! swapping the entity's `Handle<Mesh>` between levels each frame based on
+//! camera distance — no per-mesh `MeshLod` tuning required.
+
+use bevy::asset::{Assets, Handle};
+use bevy::ecs::system::{Commands, Query, Res, ResMut};
+use bevy::math::Vec3;
+use bevy::prelude::{Camera, Component, Entity, EventReader, GlobalTransform, Mesh, With};
+use bevy::tasks::{AsyncComputeTaskPool, Task};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Attach to a boolean result entity to decimate it to `target_ratio` of its
+/// original vertex count (e.g. `0.5` halves it) the next time a caller runs
+/// [`simplify_mesh`] on it. Unlike `meshlet`'s LOD DAG (built eagerly, for
+/// runtime level selection), this is a one-shot reduction applied on demand,
+/// e.g. after a `BooleanOpResult::Success` event for a result that turned
+/// out far denser than the scene needs.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MeshLod {
+    pub target_ratio: f32,
+}
+
+/// A symmetric 4x4 quadric `Q = sum Kp` accumulated from the planes of every
+/// triangle touching a vertex, stored as its 10 distinct entries (`Kp = p *
+/// p^T` for plane `p = (a, b, c, d)`, `Q` symmetric so only the upper
+/// triangle needs storing). `error(v)` gives `v^T Q v`: the sum of squared
+/// distances from `v` to each contributing plane.
+#[derive(Debug, Clone, Copy, Default)]
+struct Quadric {
+    // Upper triangle of the symmetric 4x4 matrix, row-major: (aa, ab, ac, ad, bb, bc, bd, cc, cd, dd).
+    m: [f64; 10],
+}
+
+impl Quadric {
+    fn from_plane(a: f64, b: f64, c: f64, d: f64) -> Self {
+        Self { m: [a * a, a * b, a * c, a * d, b * b, b * c, b * d, c * c, c * d, d * d] }
+    }
+
+    fn add(self, other: Self) -> Self {
+        let mut m = [0.0; 10];
+        for i in 0..10 {
+            m[i] = self.m[i] + other.m[i];
+        }
+        Self { m }
+    }
+
+    /// `v^T Q v` for homogeneous `v = (x, y, z, 1)`.
+    fn error(&self, p: Vec3) -> f64 {
+        let (x, y, z) = (p.x as f64, p.y as f64, p.z as f64);
+        let [aa, ab, ac, ad, bb, bc, bd, cc, cd, dd] = self.m;
+        aa * x * x + 2.0 * ab * x * y + 2.0 * ac * x * z + 2.0 * ad * x + bb * y * y + 2.0 * bc * y * z + 2.0 * bd * y + cc * z * z
+            + 2.0 * cd * z
+            + dd
+    }
+}
+
+/// A candidate edge collapse, ordered cheapest-first in the [`BinaryHeap`]
+/// used by [`simplify_mesh`] (which is otherwise a max-heap, hence the
+/// reversed `Ord`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct EdgeCost {
+    cost: f64,
+    a: u32,
+    b: u32,
+    target: Vec3,
+}
+
+impl Eq for EdgeCost {}
+impl Ord for EdgeCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+impl PartialOrd for EdgeCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Decimate `(positions, indices)` to roughly `target_ratio` of its original
+/// vertex count via quadric-error edge collapse:
+///
+/// 1. Every vertex accumulates a quadric from the planes of its incident
+///    triangles.
+/// 2. Every edge is scored by its endpoints' combined quadric, evaluated at
+///    whichever of the two endpoints or their midpoint minimizes it, and
+///    pushed onto a min-heap once.
+/// 3. The cheapest edge collapses first — its lower-indexed endpoint is
+///    merged into the higher one, which moves to the chosen target position
+///    and inherits the combined quadric — until the vertex budget is met or
+///    the heap is exhausted. Already-collapsed endpoints popped off the heap
+///    are discarded (lazy invalidation) rather than eagerly removed when a
+///    neighboring edge collapses.
+/// 4. A collapse that would flip any of the collapsing vertex's remaining
+///    triangles past a 90-degree normal change is skipped, to avoid visible
+///    folding artifacts near sharp features.
+///
+/// Costs are computed once up front rather than re-scored after each merge,
+/// so this is a single O(E log E) pass, not an exact optimum — simplification
+/// quality degrades gracefully at aggressive ratios rather than being
+/// perfectly minimal at every step.
+pub fn simplify_mesh(positions: &[[f32; 3]], indices: &[u32], target_ratio: f32) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let target_ratio = target_ratio.clamp(0.0, 1.0);
+    let triangles: Vec<[u32; 3]> = indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect();
+    let verts: Vec<Vec3> = positions.iter().map(|&p| Vec3::from(p)).collect();
+
+    let mut quadrics = vec![Quadric::default(); verts.len()];
+    let mut vertex_triangles: Vec<Vec<usize>> = vec![Vec::new(); verts.len()];
+    for (t, tri) in triangles.iter().enumerate() {
+        let [pa, pb, pc] = [verts[tri[0] as usize], verts[tri[1] as usize], verts[tri[2] as usize]];
+        let raw_normal = (pb - pa).cross(pc - pa);
+        if raw_normal.length_squared() <= f32::EPSILON {
+            continue;
+        }
+        let normal = crate::detmath::normalize_or_zero(raw_normal);
+        let d = -normal.dot(pa);
+        let plane = Quadric::from_plane(normal.x as f64, normal.y as f64, normal.z as f64, d as f64);
+        for &v in tri {
+            quadrics[v as usize] = quadrics[v as usize].add(plane);
+            vertex_triangles[v as usize].push(t);
+        }
+    }
+
+    let mut edges: HashSet<(u32, u32)> = HashSet::new();
+    for tri in &triangles {
+        for (x, y) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            edges.insert(if x < y { (x, y) } else { (y, x) });
+        }
+    }
+
+    let mut heap: BinaryHeap<EdgeCost> = edges
+        .into_iter()
+        .map(|(a, b)| {
+            let combined = quadrics[a as usize].add(quadrics[b as usize]);
+            let midpoint = (verts[a as usize] + verts[b as usize]) * 0.5;
+            let (target, cost) = [verts[a as usize], verts[b as usize], midpoint]
+                .into_iter()
+                .map(|p| (p, combined.error(p)))
+                .min_by(|x, y| x.1.total_cmp(&y.1))
+                .unwrap();
+            EdgeCost { cost, a, b, target }
+        })
+        .collect();
+
+    let target_vertices = ((verts.len() as f32) * target_ratio).ceil().max(3.0) as usize;
+    let mut positions_out = verts;
+    let mut remap: HashMap<u32, u32> = HashMap::new();
+    let resolve = |remap: &HashMap<u32, u32>, mut v: u32| {
+        while let Some(&next) = remap.get(&v) {
+            if next == v {
+                break;
+            }
+            v = next;
+        }
+        v
+    };
+    let mut live_count = positions_out.len();
+
+    while live_count > target_vertices {
+        let Some(EdgeCost { a, b, target, .. }) = heap.pop() else { break };
+        // Stale entries (one or both endpoints already collapsed elsewhere)
+        // resolve to the same live representative, or to vertices that no
+        // longer form a meaningful distinct pair; either way, skip.
+        let (a, b) = (resolve(&remap, a), resolve(&remap, b));
+        if a == b {
+            continue;
+        }
+
+        // Normal-flip guard: check every triangle still touching `a` (other
+        // than the ones that degenerate when `a` and `b` become the same
+        // vertex) against its pre-collapse normal.
+        let flips = vertex_triangles[a as usize].iter().any(|&t| {
+            let tri = triangles[t];
+            if tri.contains(&b) {
+                return false; // this triangle degenerates away, not flips
+            }
+            let original: Vec<Vec3> = tri.iter().map(|&v| positions_out[v as usize]).collect();
+            let before = (original[1] - original[0]).cross(original[2] - original[0]);
+            let after_positions: Vec<Vec3> = tri.iter().map(|&v| if v == a { target } else { positions_out[v as usize] }).collect();
+            let after = (after_positions[1] - after_positions[0]).cross(after_positions[2] - after_positions[0]);
+            before.dot(after) < 0.0
+        });
+        if flips {
+            continue;
+        }
+
+        positions_out[b as usize] = target;
+        quadrics[b as usize] = quadrics[a as usize].add(quadrics[b as usize]);
+        let moved = std::mem::take(&mut vertex_triangles[a as usize]);
+        vertex_triangles[b as usize].extend(moved);
+        remap.insert(a, b);
+        live_count -= 1;
+    }
+
+    // Resolve every original triangle through the final collapse chain,
+    // dropping any that degenerated (two or more corners landed on the same
+    // vertex), then compact away now-unused vertices so the output doesn't
+    // carry dead entries `indices` never references.
+    let mut resolved_triangles: Vec<[u32; 3]> = Vec::with_capacity(triangles.len());
+    for tri in &triangles {
+        let resolved = [resolve(&remap, tri[0]), resolve(&remap, tri[1]), resolve(&remap, tri[2])];
+        if resolved[0] != resolved[1] && resolved[1] != resolved[2] && resolved[2] != resolved[0] {
+            resolved_triangles.push(resolved);
+        }
+    }
+
+    let mut compact: HashMap<u32, u32> = HashMap::new();
+    let mut final_positions = Vec::new();
+    let mut final_indices = Vec::with_capacity(resolved_triangles.len() * 3);
+    for tri in &resolved_triangles {
+        for &v in tri {
+            let new_index = *compact.entry(v).or_insert_with(|| {
+                let idx = final_positions.len() as u32;
+                let p = positions_out[v as usize];
+                final_positions.push([p.x, p.y, p.z]);
+                idx
+            });
+            final_indices.push(new_index);
+        }
+    }
+
+    (final_positions, final_indices)
+}
+
+/// Attach to a boolean result entity (alongside [`crate::BooleanResultMaterials`]/
+/// [`crate::CsgOperation`], wherever that entity's `Handle<Mesh>` gets
+/// (re)written) to have [`dispatch_boolean_lod_chain`] auto-generate a small
+/// LOD chain the next time a [`crate::BooleanOpResult::Success`] event fires
+/// for it, instead of a caller running [`simplify_mesh`] by hand after every
+/// op the way [`MeshLod`] (a one-shot, manually-triggered reduction) expects.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct BooleanLodChain;
+
+/// [`dispatch_boolean_lod_chain`]'s target ratios, coarsest-last so
+/// [`BooleanLodLevels::levels`] can be indexed by "how many distance
+/// thresholds the camera has crossed": `levels[0]` is the full-detail mesh,
+/// `levels[1..]` are each successively coarser.
+const LOD_CHAIN_RATIOS: [f32; 3] = [0.5, 0.25, 0.12];
+
+/// How many AABB radii away the camera must be before [`select_boolean_lod`]
+/// steps down to `levels[i + 1]`, one entry per [`LOD_CHAIN_RATIOS`] step.
+const LOD_DISTANCE_FACTORS: [f32; 3] = [4.0, 10.0, 20.0];
+
+/// The in-flight task for a [`BooleanLodChain`] entity's decimation, one
+/// `(positions, indices)` pair per [`LOD_CHAIN_RATIOS`] entry, polled by
+/// [`poll_boolean_lod_chain`].
+#[derive(Component)]
+struct BooleanLodTask(Task<Vec<(Vec<[f32; 3]>, Vec<u32>)>>);
+
+/// The generated chain: `levels[0]` is the entity's full-detail mesh (the
+/// same handle [`crate::poll_boolean_op`] wrote), `levels[1..]` are
+/// progressively coarser meshes [`dispatch_boolean_lod_chain`] built via
+/// [`simplify_mesh`]. `thresholds[i]` is the world-space camera distance
+/// past which `levels[i + 1]` is preferred over `levels[i]`; `current` is
+/// whichever index [`select_boolean_lod`] last swapped the entity's
+/// `Handle<Mesh>` to, so it only writes the handle on an actual change.
+#[derive(Component, Debug, Clone)]
+pub struct BooleanLodLevels {
+    pub levels: Vec<Handle<Mesh>>,
+    pub thresholds: Vec<f32>,
+    pub current: usize,
+}
+
+/// For every [`crate::BooleanOpResult::Success`] event whose entity carries
+/// [`BooleanLodChain`], snapshot that entity's just-written full-detail mesh
+/// and hand [`LOD_CHAIN_RATIOS`]' worth of [`simplify_mesh`] calls to the
+/// async compute task pool — decimation at the deepest ratio is the same
+/// cost class as the boolean op itself, so this runs off-thread rather than
+/// stalling the frame the result just landed in.
+pub fn dispatch_boolean_lod_chain(
+    mut commands: Commands,
+    mut results: EventReader<crate::BooleanOpResult>,
+    chain_query: Query<&BooleanLodChain>,
+    mesh_handle_query: Query<&Handle<Mesh>>,
+    mesh_assets: Res<Assets<Mesh>>,
+) {
+    for event in results.read() {
+        if !matches!(event, crate::BooleanOpResult::Success { .. }) {
+            continue;
+        }
+        let entity = event.result_entity();
+        if chain_query.get(entity).is_err() {
+            continue;
+        }
+        let Ok(handle) = mesh_handle_query.get(entity) else { continue };
+        let Some(mesh) = mesh_assets.get(handle) else { continue };
+        let (positions, indices) = crate::bevy_mesh_to_raw(mesh);
+
+        let pool = AsyncComputeTaskPool::get();
+        let task = pool.spawn(async move {
+            LOD_CHAIN_RATIOS.iter().map(|&ratio| simplify_mesh(&positions, &indices, ratio)).collect()
+        });
+        commands.entity(entity).insert(BooleanLodTask(task));
+    }
+}
+
+/// Poll each in-flight [`BooleanLodTask`], upload its decimated meshes, and
+/// attach [`BooleanLodLevels`] with distance thresholds scaled off the
+/// full-detail mesh's own AABB radius via [`crate::raw_aabb`] — a unit cube
+/// and a room-sized STEP import should start stepping down to a coarser LOD
+/// at proportionally similar camera distances, not the same absolute one.
+pub fn poll_boolean_lod_chain(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut BooleanLodTask, &Handle<Mesh>)>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+) {
+    for (entity, mut task, full_handle) in &mut query {
+        let Some(chain) = bevy::tasks::block_on(bevy::tasks::poll_once(&mut task.0)) else {
+            continue;
+        };
+        commands.entity(entity).remove::<BooleanLodTask>();
+
+        let radius = mesh_assets
+            .get(full_handle)
+            .map(|mesh| {
+                let (min, max) = crate::raw_aabb(&crate::bevy_mesh_to_raw(mesh).0);
+                crate::detmath::length(max - min) * 0.5
+            })
+            .unwrap_or(1.0)
+            .max(0.01);
+
+        let mut levels = vec![full_handle.clone()];
+        for (positions, indices) in chain {
+            let mesh = crate::raw_submesh_to_bevy_mesh(positions, None, None, indices, false);
+            levels.push(mesh_assets.add(mesh));
+        }
+        let thresholds = LOD_DISTANCE_FACTORS.iter().map(|factor| factor * radius).collect();
+        commands.entity(entity).insert(BooleanLodLevels { levels, thresholds, current: 0 });
+    }
+}
+
+/// Each frame, step every [`BooleanLodLevels`] entity's `Handle<Mesh>` to
+/// whichever level its distance from the (single, primary) camera selects,
+/// only actually writing the handle when that selection changed from last
+/// frame.
+pub fn select_boolean_lod(
+    camera_query: Query<&GlobalTransform, With<Camera>>,
+    mut query: Query<(&mut BooleanLodLevels, &GlobalTransform, &mut Handle<Mesh>)>,
+) {
+    let Ok(camera_transform) = camera_query.get_single() else { return };
+    let camera_pos = camera_transform.translation();
+
+    for (mut lod, transform, mut mesh_handle) in &mut query {
+        let distance = camera_pos.distance(transform.translation());
+        let level = lod.thresholds.iter().filter(|&&threshold| distance > threshold).count().min(lod.levels.len() - 1);
+        if level != lod.current {
+            lod.current = level;
+            *mesh_handle = lod.levels[level].clone();
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! `AssetLoader`s for the two mesh file formats `bevy_mesh_boolean`'s
+//! `CsgNode` tree doesn't already have a way to populate a leaf from: STL and
+//! OBJ. `bevy_step_loader` already covers STEP (and is the hook point for any
+//! other external CAD-format crate); `CsgNode::Leaf`/`CsgRoot`/`CsgEvalCache`
+//! already give a declarative, content-hash-cached boolean graph keyed off
+//! any entity with a `Handle<Mesh>` + `Transform` — so the remaining gap this
+//! crate closes is just getting a `Handle<Mesh>` from an `.stl`/`.obj` file
+//! on disk instead of only from a code-constructed primitive, with no new
+//! asset type or CSG plumbing needed on top of what already exists.
+//!
+//! Both loaders build a triangle-soup `Mesh` directly rather than a wrapper
+//! asset (unlike `StepAsset`, which carries per-solid structure STEP's
+//! assembly format actually has) — STL has no sub-object structure at all,
+//! and this loader treats an OBJ file's `o`/`g` groups the same way, fusing
+//! everything into one mesh, since `CsgNode::Leaf` only needs one `Handle<Mesh>`
+//! per entity; a caller that wants OBJ's groups as separate entities can
+//! still split the file upstream before handing facets to `ObjLoader`.
+
+use bevy::asset::io::AsyncReadExt;
+use bevy::utils::ConditionalSendFuture;
+use bevy::{
+    asset::{AssetLoader, LoadContext},
+    prelude::*,
+    render::mesh::{Indices, Mesh, PrimitiveTopology},
+};
+use serde::{Deserialize, Serialize};
+
+/// Registers [`StlLoader`] and [`ObjLoader`]. Neither loader needs an
+/// `init_asset` call of its own since both produce Bevy's own `Mesh`, which
+/// `DefaultPlugins`/`AssetPlugin` already registers.
+pub struct MeshFilePlugin;
+
+impl Plugin for MeshFilePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_asset_loader(StlLoader).register_asset_loader(ObjLoader);
+    }
+}
+
+/// Per-asset scale knob shared by [`StlLoader`] and [`ObjLoader`], the same
+/// idea as `bevy_step_loader`'s `StepLoaderSettings::unit_scale`: STL/OBJ
+/// exporters commonly emit millimeters, so this converts into the scene's
+/// units on load instead of requiring every caller to rescale the spawned
+/// mesh by hand.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct MeshLoaderSettings {
+    pub unit_scale: f32,
+}
+
+impl Default for MeshLoaderSettings {
+    fn default() -> Self {
+        Self { unit_scale: 1.0 }
+    }
+}
+
+/// Loads binary or ASCII STL files. STL's facet format has no shared vertex
+/// indices at all (every triangle restates its three corners), so the mesh
+/// this produces has no welding applied — three duplicate positions per
+/// triangle, same as the file itself. A caller that wants a welded mesh
+/// (e.g. before handing it to `CsgNode::Leaf`'s boolean fold, which is
+/// robust to duplicate verts but not required to keep them) can run it
+/// through whatever welding pass it already uses for other imports.
+#[derive(Default)]
+pub struct StlLoader;
+
+impl AssetLoader for StlLoader {
+    type Asset = Mesh;
+    type Settings = MeshLoaderSettings;
+    type Error = anyhow::Error;
+
+    fn extensions(&self) -> &[&str] {
+        &["stl"]
+    }
+
+    fn load<'s>(
+        &'s self,
+        reader: &'s mut bevy::asset::io::Reader,
+        settings: &'s Self::Settings,
+        #[allow(unused_variables)] load_context: &'s mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let scale = settings.unit_scale;
+
+            let facets = parse_stl(&bytes)?;
+            Ok(build_facet_mesh(&facets, scale))
+        })
+    }
+}
+
+/// One STL triangle: the file's own facet normal (zeroed if the exporter
+/// didn't bother computing one — common for ASCII STL) and its three
+/// corners in file order.
+struct StlFacet {
+    normal: [f32; 3],
+    vertices: [[f32; 3]; 3],
+}
+
+fn parse_stl(bytes: &[u8]) -> Result<Vec<StlFacet>, anyhow::Error> {
+    // Binary STL starts with an 80-byte header (which can itself start with
+    // the ASCII marker "solid" — some exporters do exactly that), so the
+    // reliable discriminator is whether the header-implied triangle count
+    // matches the file's actual length, not the leading bytes.
+    if bytes.len() >= 84 {
+        let count = u32::from_le_bytes(bytes[80..84].try_into().unwrap()) as usize;
+        if bytes.len() == 84 + count * 50 {
+            return Ok(parse_stl_binary(bytes, count));
+        }
+    }
+
+    if bytes.starts_with(b"solid") {
+        return parse_stl_ascii(bytes);
+    }
+
+    Err(anyhow::anyhow!("not a recognizable STL file: binary triangle count doesn't match file length, and no ASCII 'solid' header"))
+}
+
+fn parse_stl_binary(bytes: &[u8], count: usize) -> Vec<StlFacet> {
+    (0..count)
+        .map(|i| {
+            let record = &bytes[84 + i * 50..84 + (i + 1) * 50];
+            let read_vec3 = |offset: usize| {
+                [
+                    f32::from_le_bytes(record[offset..offset + 4].try_into().unwrap()),
+                    f32::from_le_bytes(record[offset + 4..offset + 8].try_into().unwrap()),
+                    f32::from_le_bytes(record[offset + 8..offset + 12].try_into().unwrap()),
+                ]
+            };
+            StlFacet {
+                normal: read_vec3(0),
+                vertices: [read_vec3(12), read_vec3(24), read_vec3(36)],
+            }
+        })
+        .collect()
+}
+
+fn parse_stl_ascii(bytes: &[u8]) -> Result<Vec<StlFacet>, anyhow::Error> {
+    let text = std::str::from_utf8(bytes)?;
+    let mut facets = Vec::new();
+    let mut normal = [0.0; 3];
+    let mut vertices = Vec::with_capacity(3);
+
+    for line in text.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("facet normal") {
+            normal = parse_three_floats(rest)?;
+            vertices.clear();
+        } else if let Some(rest) = line.strip_prefix("vertex") {
+            vertices.push(parse_three_floats(rest)?);
+        } else if line == "endfacet" {
+            if vertices.len() != 3 {
+                return Err(anyhow::anyhow!("STL facet has {} vertices, expected 3", vertices.len()));
+            }
+            facets.push(StlFacet { normal, vertices: [vertices[0], vertices[1], vertices[2]] });
+        }
+    }
+
+    Ok(facets)
+}
+
+fn parse_three_floats(rest: &str) -> Result<[f32; 3], anyhow::Error> {
+    let mut parts = rest.split_whitespace();
+    let mut next = || -> Result<f32, anyhow::Error> {
+        parts.next().ok_or_else(|| anyhow::anyhow!("expected a coordinate"))?.parse().map_err(Into::into)
+    };
+    Ok([next()?, next()?, next()?])
+}
+
+fn build_facet_mesh(facets: &[StlFacet], scale: f32) -> Mesh {
+    let mut positions = Vec::with_capacity(facets.len() * 3);
+    let mut normals = Vec::with_capacity(facets.len() * 3);
+
+    for facet in facets {
+        for vertex in &facet.vertices {
+            positions.push([vertex[0] * scale, vertex[1] * scale, vertex[2] * scale]);
+            normals.push(facet.normal);
+        }
+    }
+
+    let indices: Vec<u32> = (0..positions.len() as u32).collect();
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Loads Wavefront OBJ files: `v`/`vn`/`vt` data lines and `f` faces
+/// referencing them by 1-based (or negative, relative-to-current-count)
+/// index. Faces of any vertex count fan-triangulate from their first
+/// vertex, the same convention `meshbool`'s `polygon_mesh` notes a plain fan is
+/// enough for (this loader only needs to handle whatever convex n-gons an
+/// OBJ exporter emits, not arbitrary non-convex contours). Material (`mtllib`/
+/// `usemtl`) and smoothing-group (`s`) directives are ignored — this loader
+/// only produces geometry, matching how `StlLoader` ignores STL's (rare)
+/// per-facet attribute byte count.
+#[derive(Default)]
+pub struct ObjLoader;
+
+impl AssetLoader for ObjLoader {
+    type Asset = Mesh;
+    type Settings = MeshLoaderSettings;
+    type Error = anyhow::Error;
+
+    fn extensions(&self) -> &[&str] {
+        &["obj"]
+    }
+
+    fn load<'s>(
+        &'s self,
+        reader: &'s mut bevy::asset::io::Reader,
+        settings: &'s Self::Settings,
+        #[allow(unused_variables)] load_context: &'s mut LoadContext,
+    ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let text = std::str::from_utf8(&bytes)?;
+            parse_obj(text, settings.unit_scale)
+        })
+    }
+}
+
+/// Flat per-triangle normal for an OBJ face corner that didn't specify its
+/// own `vn` index, computed straight in `f32` (unlike the core crate's
+/// `Vector3<f64>`-based normal helpers) since that's the precision every
+/// other value in this Bevy-facing mesh is already stored at.
+fn face_normal(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
+    let sub = |x: [f32; 3], y: [f32; 3]| [x[0] - y[0], x[1] - y[1], x[2] - y[2]];
+    let ab = sub(b, a);
+    let ac = sub(c, a);
+    let cross = [ab[1] * ac[2] - ab[2] * ac[1], ab[2] * ac[0] - ab[0] * ac[2], ab[0] * ac[1] - ab[1] * ac[0]];
+    let len = (cross[0] * cross[0] + cross[1] * cross[1] + cross[2] * cross[2]).sqrt();
+    if len > 0.0 {
+        [cross[0] / len, cross[1] / len, cross[2] / len]
+    } else {
+        [0.0, 0.0, 0.0]
+    }
+}
+
+fn parse_obj(text: &str, scale: f32) -> Result<Mesh, anyhow::Error> {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals_in: Vec<[f32; 3]> = Vec::new();
+    let mut uvs_in: Vec<[f32; 2]> = Vec::new();
+
+    let mut out_positions = Vec::new();
+    let mut out_normals = Vec::new();
+    let mut out_uvs = Vec::new();
+    let mut has_normals = false;
+    let mut has_uvs = false;
+
+    let resolve = |count: usize, index: i64| -> Result<usize, anyhow::Error> {
+        if index > 0 {
+            Ok(index as usize - 1)
+        } else if index < 0 {
+            Ok((count as i64 + index) as usize)
+        } else {
+            Err(anyhow::anyhow!("OBJ index 0 is invalid (OBJ indices are 1-based)"))
+        }
+    };
+
+    for line in text.lines() {
+        let line = line.trim();
+        let mut tokens = line.split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let p = parse_three_floats(&tokens.collect::<Vec<_>>().join(" "))?;
+                positions.push([p[0] * scale, p[1] * scale, p[2] * scale]);
+            }
+            Some("vn") => {
+                normals_in.push(parse_three_floats(&tokens.collect::<Vec<_>>().join(" "))?);
+            }
+            Some("vt") => {
+                let rest: Vec<&str> = tokens.collect();
+                let u: f32 = rest.first().ok_or_else(|| anyhow::anyhow!("vt missing u"))?.parse()?;
+                let v: f32 = rest.get(1).map(|s| s.parse()).transpose()?.unwrap_or(0.0);
+                uvs_in.push([u, v]);
+            }
+            Some("f") => {
+                let corners: Vec<&str> = tokens.collect();
+                if corners.len() < 3 {
+                    return Err(anyhow::anyhow!("OBJ face has fewer than 3 vertices"));
+                }
+
+                let mut face_positions = Vec::with_capacity(corners.len());
+                let mut face_normals = Vec::with_capacity(corners.len());
+                let mut face_uvs = Vec::with_capacity(corners.len());
+
+                for corner in &corners {
+                    let mut parts = corner.split('/');
+                    let vi: i64 = parts.next().ok_or_else(|| anyhow::anyhow!("empty face corner"))?.parse()?;
+                    face_positions.push(positions[resolve(positions.len(), vi)?]);
+
+                    if let Some(vt) = parts.next().filter(|s| !s.is_empty()) {
+                        has_uvs = true;
+                        face_uvs.push(uvs_in[resolve(uvs_in.len(), vt.parse()?)?]);
+                    } else {
+                        face_uvs.push([0.0, 0.0]);
+                    }
+
+                    if let Some(vn) = parts.next().filter(|s| !s.is_empty()) {
+                        has_normals = true;
+                        face_normals.push(normals_in[resolve(normals_in.len(), vn.parse()?)?]);
+                    } else {
+                        face_normals.push([0.0, 0.0, 0.0]);
+                    }
+                }
+
+                for i in 1..face_positions.len() - 1 {
+                    let tri = [0, i, i + 1];
+                    let fallback_normal = face_normal(face_positions[tri[0]], face_positions[tri[1]], face_positions[tri[2]]);
+                    for &corner in &tri {
+                        out_positions.push(face_positions[corner]);
+                        out_normals.push(if has_normals && face_normals[corner] != [0.0, 0.0, 0.0] { face_normals[corner] } else { fallback_normal });
+                        out_uvs.push(face_uvs[corner]);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let indices: Vec<u32> = (0..out_positions.len() as u32).collect();
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, out_positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, out_normals);
+    if has_uvs {
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, out_uvs);
+    }
+    mesh.insert_indices(Indices::U32(indices));
+
+    Ok(mesh)
+}
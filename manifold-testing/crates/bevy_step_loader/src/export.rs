@@ -0,0 +1,59 @@
+//! Write a [`meshbool::Impl`] (typically the result of a chain of CSG
+//! operations run on imported STEP geometry) back out to disk — the
+//! write-side counterpart to [`crate::StepLoader`]/`triangulate_step_file`'s
+//! read-side, since until now this crate could only ever turn a STEP file
+//! into a `Mesh`, never the other way around. A triangulated Wavefront OBJ
+//! is always available; under the `opencascade` feature, [`save_step`]
+//! additionally builds an OCCT shape from the same triangle soup and calls
+//! OCCT's own STEP writer, so the result is usable in a real CAD tool
+//! instead of staying mesh-only.
+
+use meshbool::{get_mesh_gl, Impl};
+use std::path::Path;
+
+/// Wavefront OBJ text for `shape`'s triangulation (material id 0 — a
+/// boolean-op result has no per-face material split of its own): one `v`
+/// per vertex and one `f` per triangle, 1-indexed per OBJ's convention. No
+/// normals or UVs, since `MeshGL` carries none for a freshly computed
+/// result; generate them after reloading if those are needed.
+pub fn to_obj_string(shape: &Impl) -> String {
+    let mesh_gl = get_mesh_gl(shape, 0);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+
+    let mut obj = String::from("# exported by bevy_step_loader::export\n");
+    for v in mesh_gl.vert_properties.chunks(num_prop) {
+        obj.push_str(&format!("v {} {} {}\n", v[0], v[1], v[2]));
+    }
+    for tri in mesh_gl.tri_verts.chunks_exact(3) {
+        obj.push_str(&format!("f {} {} {}\n", tri[0] + 1, tri[1] + 1, tri[2] + 1));
+    }
+    obj
+}
+
+/// Write `shape` to `path` as a Wavefront OBJ via [`to_obj_string`].
+pub fn save_obj(shape: &Impl, path: impl AsRef<Path>) -> std::io::Result<()> {
+    std::fs::write(path, to_obj_string(shape))
+}
+
+/// Write `shape` to `path` as a STEP file: builds an OCCT shape from the
+/// same triangle soup [`to_obj_string`] reads and calls OCCT's own STEP
+/// writer on it. This round-trips through the mesh rather than fitting the
+/// boolean result back onto curved BREP surfaces — there's no surface
+/// reconstruction here, only a faceted solid — but that's still a STEP file
+/// a CAD tool can reopen, which a triangle soup alone isn't.
+#[cfg(feature = "opencascade")]
+pub fn save_step(shape: &Impl, path: impl AsRef<Path>) -> Result<(), anyhow::Error> {
+    use opencascade::primitives::Shape;
+
+    let mesh_gl = get_mesh_gl(shape, 0);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let vertices: Vec<[f64; 3]> =
+        mesh_gl.vert_properties.chunks(num_prop).map(|v| [v[0] as f64, v[1] as f64, v[2] as f64]).collect();
+    let triangles: Vec<[usize; 3]> =
+        mesh_gl.tri_verts.chunks_exact(3).map(|t| [t[0] as usize, t[1] as usize, t[2] as usize]).collect();
+
+    let occt_shape = Shape::from_triangulation(&vertices, &triangles)
+        .map_err(|e| anyhow::anyhow!("failed to build an OCCT shape from the triangle soup: {e:?}"))?;
+    let path = path.as_ref().to_str().ok_or_else(|| anyhow::anyhow!("STEP export path must be valid UTF-8"))?;
+    occt_shape.write_step(path).map_err(|e| anyhow::anyhow!("OCCT STEP writer failed: {e:?}"))
+}
@@ -6,8 +6,20 @@ use bevy::{
     reflect::TypePath,
     render::mesh::{Indices, Mesh},
 };
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
+mod export;
+mod normals;
+mod solids;
+mod to_manifold;
+
+pub use export::{save_obj, to_obj_string};
+#[cfg(feature = "opencascade")]
+pub use export::save_step;
+pub use solids::StepSolid;
+pub use to_manifold::{from_bevy_mesh, WELD_TOLERANCE};
+
 // The plugin to register the asset and loader
 pub struct StepPlugin;
 
@@ -18,10 +30,110 @@ impl Plugin for StepPlugin {
     }
 }
 
+/// Optional companion to [`StepPlugin`]: for any entity holding a
+/// [`StepHandle`], once that handle's asset finishes loading, spawns one
+/// child entity per [`StepSolid`] (`Mesh3d` + a `StandardMaterial` colored
+/// from the STEP file when available, named when available) — the STEP
+/// equivalent of how a glTF `SceneRoot` expands into one entity per node.
+/// Kept separate from `StepPlugin` so existing callers that just want the
+/// fused `StepAsset::mesh` aren't forced to pull in `StandardMaterial`
+/// spawning they don't use.
+pub struct StepSolidSpawnerPlugin;
+
+impl Plugin for StepSolidSpawnerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spawn_step_solid_children);
+    }
+}
+
+/// Spawn request for [`StepSolidSpawnerPlugin`]: an entity carrying this
+/// component, which holds the `Handle<StepAsset>` to expand.
+#[derive(Component)]
+pub struct StepHandle(pub Handle<StepAsset>);
+
+#[derive(Component)]
+struct StepSolidsSpawned;
+
+fn spawn_step_solid_children(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    step_assets: Res<Assets<StepAsset>>,
+    query: Query<(Entity, &StepHandle), Without<StepSolidsSpawned>>,
+) {
+    for (entity, handle) in &query {
+        let Some(step_asset) = step_assets.get(&handle.0) else { continue };
+
+        commands.entity(entity).insert(StepSolidsSpawned).with_children(|parent| {
+            for solid in &step_asset.solids {
+                let mesh_handle = meshes.add(solid.mesh.clone());
+                let material = materials.add(match solid.color {
+                    Some([r, g, b, a]) => StandardMaterial::from(Color::srgba(r, g, b, a)),
+                    None => StandardMaterial::default(),
+                });
+                let mut child = parent.spawn((Mesh3d(mesh_handle), MeshMaterial3d(material), solid.transform));
+                if let Some(name) = &solid.name {
+                    child.insert(Name::new(name.clone()));
+                }
+            }
+        });
+    }
+}
+
 // The asset representing a STEP file
 #[derive(Asset, TypePath, Debug, Clone)]
 pub struct StepAsset {
+    /// The whole assembly fused into one mesh, for callers that don't care
+    /// about per-solid structure.
     pub mesh: Mesh,
+    /// Each solid in the STEP assembly as its own mesh, in file order, with
+    /// whatever name/color metadata the backend could recover for it.
+    pub solids: Vec<StepSolid>,
+}
+
+/// Per-asset tessellation controls for `StepLoader`, loaded from a `.step.meta`
+/// (or `.stp.meta`) file the same way Bevy's other asset loaders (e.g. the
+/// glTF loader's `GltfLoaderSettings`) expose per-asset knobs. STEP stores
+/// exact curved-surface geometry, so these settings are what turn that into
+/// a concrete triangle mesh: `linear_deflection`/`angular_deflection` bound
+/// how far a facet may stray from the true surface (smaller = more
+/// triangles, finer curves), `unit_scale` converts the file's native units
+/// (STEP is commonly millimeters) into the scene's units, and
+/// `generate_normals` controls whether per-vertex normals are computed at
+/// all, since some callers only need positions/indices.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct StepLoaderSettings {
+    pub linear_deflection: f64,
+    pub angular_deflection: f64,
+    pub unit_scale: f32,
+    pub generate_normals: bool,
+    /// Angle (radians) beyond which two triangles sharing a vertex are
+    /// treated as a hard edge: the vertex is duplicated and each side gets
+    /// its own normal rather than being smoothed together. Only consulted
+    /// when `generate_normals` is set.
+    pub crease_angle: f32,
+    /// Both OCCT and the `triangulate4` fallback tessellate each B-rep face
+    /// independently, so a shared edge between two faces gets a separate
+    /// vertex copy on either side. When set, [`build_triangulated_mesh`]
+    /// welds positionally-coincident vertices together before normals are
+    /// computed, closing those seams so `crease_angle` sees the mesh's real
+    /// topology instead of mistaking every face boundary for one. Off by
+    /// default since it changes vertex count/order from the raw tessellator
+    /// output.
+    pub weld_coplanar: bool,
+}
+
+impl Default for StepLoaderSettings {
+    fn default() -> Self {
+        Self {
+            linear_deflection: 0.1,
+            angular_deflection: 0.5,
+            unit_scale: 1.0,
+            generate_normals: true,
+            crease_angle: 60f32.to_radians(),
+            weld_coplanar: false,
+        }
+    }
 }
 
 // The loader for STEP files (standard asset loader)
@@ -30,7 +142,7 @@ pub struct StepLoader;
 
 impl AssetLoader for StepLoader {
     type Asset = StepAsset;
-    type Settings = ();
+    type Settings = StepLoaderSettings;
     type Error = anyhow::Error;
 
     fn extensions(&self) -> &[&str] {
@@ -40,37 +152,63 @@ impl AssetLoader for StepLoader {
     fn load<'s>(
         &'s self,
         reader: &'s mut bevy::asset::io::Reader,
-        #[allow(unused_variables)] settings: &'s Self::Settings,
+        settings: &'s Self::Settings,
         #[allow(unused_variables)] load_context: &'s mut LoadContext,
     ) -> impl ConditionalSendFuture<Output = Result<Self::Asset, Self::Error>> {
         Box::pin(async move {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
 
-            // Parse and triangulate the STEP file
-            let mesh = triangulate_step_file(&bytes)?;
+            // Parse and triangulate off the asset thread: `triangulate4`
+            // (and OCCT's meshing, when enabled) are CPU-bound and can take
+            // a while on large assemblies.
+            let settings = *settings;
+            let solids = bevy::tasks::AsyncComputeTaskPool::get()
+                .spawn(async move { triangulate_step_file(&bytes, &settings) })
+                .await?;
+            let mesh = solids::merge_solids(&solids);
 
-            Ok(StepAsset { mesh })
+            Ok(StepAsset { mesh, solids })
         })
     }
 }
 
-// Function to load a STEP file from an arbitrary path
+// Function to load a STEP file from an arbitrary path. Reads from the
+// filesystem, so it's unavailable on `wasm32` — in the browser, bytes have
+// to come in through Bevy's asset server/`Reader` (see `StepLoader::load`)
+// instead of an arbitrary local path.
+#[cfg(not(target_arch = "wasm32"))]
 pub fn load_step_file_from_path<P: AsRef<Path>>(path: P) -> Result<StepAsset, anyhow::Error> {
+    load_step_file_from_path_with_settings(path, &StepLoaderSettings::default())
+}
+
+// Function to load a STEP file from an arbitrary path with explicit tessellation settings
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_step_file_from_path_with_settings<P: AsRef<Path>>(
+    path: P,
+    settings: &StepLoaderSettings,
+) -> Result<StepAsset, anyhow::Error> {
     let path = path.as_ref();
-    
+
     // Read the file
     let step_data = std::fs::read(path)?;
-    
+
     // Parse and triangulate the STEP file
-    let mesh = triangulate_step_file(&step_data)?;
-    
-    Ok(StepAsset { mesh })
+    let solids = triangulate_step_file(&step_data, settings)?;
+    let mesh = solids::merge_solids(&solids);
+
+    Ok(StepAsset { mesh, solids })
 }
 
-fn triangulate_step_file(step_data: &[u8]) -> Result<Mesh, anyhow::Error> {
-    // Use foxtrot by default, or OCCT if the feature is enabled
-    #[cfg(feature = "opencascade")]
+fn triangulate_step_file(step_data: &[u8], settings: &StepLoaderSettings) -> Result<Vec<StepSolid>, anyhow::Error> {
+    let names = solids::parse_step_names(step_data);
+    let colors = solids::parse_step_colors(step_data);
+
+    // The foxtrot backend works entirely off the in-memory `step_data`
+    // bytes, so it's the only backend available on `wasm32` (no filesystem
+    // there) and is the default everywhere; OCCT is opt-in and needs a real
+    // filesystem for the temp file it round-trips the input through.
+    #[cfg(all(feature = "opencascade", not(target_arch = "wasm32")))]
     {
         use opencascade::primitives::Shape;
 
@@ -82,42 +220,64 @@ fn triangulate_step_file(step_data: &[u8]) -> Result<Mesh, anyhow::Error> {
             .map_err(|e| anyhow::anyhow!("OCCT failed to read STEP file: {:?}", e))?;
 
         use opencascade::mesh::Mesher;
-        let occt_mesh = Mesher::new(&shape_to_mesh).mesh();
 
-        // Convert OCCT mesh to Bevy mesh
-        let vertices: Vec<[f32; 3]> = occt_mesh
-            .vertices
-            .iter()
-            .map(|v| [v.x as f32, v.y as f32, v.z as f32])
-            .collect();
+        // One sub-shape per solid in the assembly, each meshed on its own so
+        // the result preserves the file's multi-solid structure instead of
+        // collapsing it into one fused blob.
+        let solid_shapes: Vec<Shape> = shape_to_mesh.solids().collect();
+        let solid_shapes = if solid_shapes.is_empty() { vec![shape_to_mesh] } else { solid_shapes };
 
-        let indices: Vec<u32> = occt_mesh.indices.iter().map(|&i| i as u32).collect();
+        let mut step_solids = Vec::with_capacity(solid_shapes.len());
+        for (i, solid_shape) in solid_shapes.iter().enumerate() {
+            let occt_mesh = Mesher::new(solid_shape)
+                .with_linear_deflection(settings.linear_deflection)
+                .with_angular_deflection(settings.angular_deflection)
+                .mesh();
 
-        let mut bevy_mesh = Mesh::new(
-            bevy::render::mesh::PrimitiveTopology::TriangleList,
-            Default::default(),
-        );
+            let vertices: Vec<[f32; 3]> = occt_mesh
+                .vertices
+                .iter()
+                .map(|v| [v.x as f32 * settings.unit_scale, v.y as f32 * settings.unit_scale, v.z as f32 * settings.unit_scale])
+                .collect();
+            let indices: Vec<u32> = occt_mesh.indices.iter().map(|&i| i as u32).collect();
 
-        bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
-        bevy_mesh.insert_indices(Indices::U32(indices));
+            step_solids.push(StepSolid {
+                name: names.get(i).cloned(),
+                color: colors.get(i).copied(),
+                mesh: build_triangulated_mesh(vertices, indices, settings),
+                transform: Transform::IDENTITY,
+            });
+        }
 
-        Ok(bevy_mesh)
+        Ok(step_solids)
     }
 
-    #[cfg(not(feature = "opencascade"))]
+    #[cfg(any(not(feature = "opencascade"), target_arch = "wasm32"))]
     {
-        // Use foxtrot backend by default
+        // Use foxtrot backend by default. `triangulate4` fuses the whole
+        // assembly into one triangulated mesh with no per-solid boundary
+        // information, so unlike the OCCT backend this can't split the
+        // result into separate solids — it's reported as a single solid,
+        // named/colored from the first entity of each kind the file has (if
+        // any), rather than silently dropping the multi-solid request.
         use step::step_file::StepFile;
         use triangulate::triangulate::triangulate4 as triangulate;
 
         let flat = StepFile::strip_flatten(step_data);
         let step = StepFile::parse(&flat);
-        let (triangulated_mesh, _stats) = triangulate(&step);
+        let (triangulated_mesh, _stats) =
+            triangulate(&step, settings.linear_deflection, settings.angular_deflection);
 
         let vertices: Vec<[f32; 3]> = triangulated_mesh
             .verts
             .iter()
-            .map(|v| [v.pos.x as f32, v.pos.y as f32, v.pos.z as f32])
+            .map(|v| {
+                [
+                    v.pos.x as f32 * settings.unit_scale,
+                    v.pos.y as f32 * settings.unit_scale,
+                    v.pos.z as f32 * settings.unit_scale,
+                ]
+            })
             .collect();
 
         let indices: Vec<u32> = triangulated_mesh
@@ -126,14 +286,98 @@ fn triangulate_step_file(step_data: &[u8]) -> Result<Mesh, anyhow::Error> {
             .flat_map(|t| [t.verts.x, t.verts.y, t.verts.z])
             .collect();
 
-        let mut bevy_mesh = Mesh::new(
-            bevy::render::mesh::PrimitiveTopology::TriangleList,
-            Default::default(),
-        );
+        Ok(vec![StepSolid {
+            name: names.into_iter().next(),
+            color: colors.into_iter().next(),
+            mesh: build_triangulated_mesh(vertices, indices, settings),
+            transform: Transform::IDENTITY,
+        }])
+    }
+}
+
+/// Assemble the final Bevy mesh from a backend's raw positions/indices,
+/// optionally welding per-face tessellation seams, splitting creased
+/// vertices, and generating angle-weighted smooth normals (and, once the
+/// mesh carries UVs, tangents) per `settings`.
+fn build_triangulated_mesh(vertices: Vec<[f32; 3]>, indices: Vec<u32>, settings: &StepLoaderSettings) -> Mesh {
+    let (vertices, indices) =
+        if settings.weld_coplanar { weld_coplanar_seams(vertices, indices) } else { (vertices, indices) };
+
+    let mut bevy_mesh = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, Default::default());
 
+    if settings.generate_normals {
+        let smoothed = normals::smooth_normals_with_creases(&vertices, &indices, settings.crease_angle);
+        let positions: Vec<[f32; 3]> = smoothed.new_to_old.iter().map(|&old| vertices[old as usize]).collect();
+
+        bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        bevy_mesh.insert_indices(Indices::U32(smoothed.indices));
+        bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, smoothed.normals);
+
+        if let Some(tangents) = normals::compute_tangents(&bevy_mesh) {
+            bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+        }
+    } else {
         bevy_mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
         bevy_mesh.insert_indices(Indices::U32(indices));
+    }
+
+    bevy_mesh
+}
 
-        Ok(bevy_mesh)
+/// Vertices within this distance (in scene units, after `unit_scale` has
+/// been applied — tessellators emit exact duplicates at shared edges, not
+/// near-misses, so this only needs to be just above float round-off) are
+/// merged by [`weld_coplanar_seams`].
+const COPLANAR_WELD_TOLERANCE: f32 = 1e-5;
+
+/// Merge positionally-coincident vertices left behind by independent
+/// per-face tessellation: both OCCT and `triangulate4` mesh each B-rep face
+/// on its own, so a shared edge between two faces comes out as two
+/// unconnected copies of that edge's vertices. Uses the same uniform-grid
+/// weld as [`to_manifold::weld_by_position`], just over `f32` positions
+/// instead of `f64` since that's what the tessellators hand back here.
+fn weld_coplanar_seams(vertices: Vec<[f32; 3]>, indices: Vec<u32>) -> (Vec<[f32; 3]>, Vec<u32>) {
+    let cell_of = |p: &[f32; 3]| -> (i64, i64, i64) {
+        (
+            (p[0] / COPLANAR_WELD_TOLERANCE).floor() as i64,
+            (p[1] / COPLANAR_WELD_TOLERANCE).floor() as i64,
+            (p[2] / COPLANAR_WELD_TOLERANCE).floor() as i64,
+        )
+    };
+    let tolerance_sq = COPLANAR_WELD_TOLERANCE * COPLANAR_WELD_TOLERANCE;
+
+    let mut grid: std::collections::HashMap<(i64, i64, i64), Vec<usize>> = std::collections::HashMap::new();
+    let mut welded: Vec<[f32; 3]> = Vec::with_capacity(vertices.len());
+    let mut remap = vec![0u32; vertices.len()];
+
+    for (i, p) in vertices.iter().enumerate() {
+        let (cx, cy, cz) = cell_of(p);
+        let mut found = None;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) else { continue };
+                    for &candidate in candidates {
+                        let q = welded[candidate];
+                        let dist_sq = (p[0] - q[0]).powi(2) + (p[1] - q[1]).powi(2) + (p[2] - q[2]).powi(2);
+                        if dist_sq <= tolerance_sq {
+                            found = Some(candidate);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        let target = found.unwrap_or_else(|| {
+            let new_index = welded.len();
+            welded.push(*p);
+            grid.entry((cx, cy, cz)).or_default().push(new_index);
+            new_index
+        });
+        remap[i] = target as u32;
     }
+
+    let welded_indices: Vec<u32> = indices.iter().map(|&i| remap[i as usize]).collect();
+    (welded, welded_indices)
 }
@@ -0,0 +1,192 @@
+//! Angle-weighted smooth normal (and tangent) generation for triangulated
+//! STEP meshes. Neither triangulation backend emits normals itself, so
+//! without this every loaded part would shade either flat or with the
+//! `meshgl_to_bevy_mesh`-style constant fallback normal; CAD tessellations
+//! in particular have very irregular triangle sizes, so a plain
+//! sum-then-normalize average would let large sliver triangles dominate a
+//! vertex's normal — weighting each triangle's contribution by its interior
+//! angle at that vertex avoids that.
+
+use bevy::math::Vec3;
+use bevy::render::mesh::{Mesh, VertexAttributeValues};
+use std::collections::HashMap;
+
+/// The result of [`smooth_normals_with_creases`]: a new, possibly larger,
+/// vertex list (vertices are duplicated across a crease) described as
+/// indices back into the *original* position array, a remapped index
+/// buffer, and the smoothed per-vertex normal for each new vertex.
+pub struct SmoothedMesh {
+    pub new_to_old: Vec<u32>,
+    pub indices: Vec<u32>,
+    pub normals: Vec<[f32; 3]>,
+}
+
+/// Compute angle-weighted smooth vertex normals, splitting a vertex across
+/// any pair of its incident faces whose normals differ by more than
+/// `crease_angle` (radians) so hard edges stay sharp instead of being
+/// smoothed away.
+pub fn smooth_normals_with_creases(positions: &[[f32; 3]], indices: &[u32], crease_angle: f32) -> SmoothedMesh {
+    let tri_count = indices.len() / 3;
+    let verts: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+
+    // Unnormalized face normals; direction is all `corner_of`/clustering
+    // need, magnitude is discarded once each is re-normalized for weighting.
+    let face_normals: Vec<Vec3> = (0..tri_count)
+        .map(|f| {
+            let (a, b, c) = (verts[indices[f * 3] as usize], verts[indices[f * 3 + 1] as usize], verts[indices[f * 3 + 2] as usize]);
+            (b - a).cross(c - a)
+        })
+        .collect();
+
+    let mut vertex_faces: Vec<Vec<usize>> = vec![Vec::new(); positions.len()];
+    for f in 0..tri_count {
+        for corner in 0..3 {
+            vertex_faces[indices[f * 3 + corner] as usize].push(f);
+        }
+    }
+
+    let mut new_to_old = Vec::with_capacity(positions.len());
+    let mut normals = Vec::with_capacity(positions.len());
+    let mut out_indices = vec![0u32; indices.len()];
+
+    for (v, faces) in vertex_faces.iter().enumerate() {
+        for group in cluster_by_crease(faces, &face_normals, crease_angle) {
+            let mut accum = Vec3::ZERO;
+            for &f in &group {
+                let corner = corner_of(f, v as u32, indices);
+                let angle = interior_angle(f, corner, &verts, indices);
+                let n = face_normals[f];
+                if n != Vec3::ZERO {
+                    accum += n.normalize() * angle;
+                }
+            }
+            let normal = if accum != Vec3::ZERO { accum.normalize() } else { Vec3::Y };
+
+            let new_index = new_to_old.len() as u32;
+            new_to_old.push(v as u32);
+            normals.push(normal.to_array());
+            for &f in &group {
+                let corner = corner_of(f, v as u32, indices);
+                out_indices[f * 3 + corner] = new_index;
+            }
+        }
+    }
+
+    SmoothedMesh { new_to_old, indices: out_indices, normals }
+}
+
+/// Partition the faces touching one vertex into smoothing groups: two faces
+/// land in the same group only if every pair of faces linking them has a
+/// normal-to-normal angle within `crease_angle` (union-find over the
+/// pairwise comparisons).
+fn cluster_by_crease(faces: &[usize], face_normals: &[Vec3], crease_angle: f32) -> Vec<Vec<usize>> {
+    let mut parent: Vec<usize> = (0..faces.len()).collect();
+    fn find(parent: &mut [usize], x: usize) -> usize {
+        if parent[x] != x {
+            parent[x] = find(parent, parent[x]);
+        }
+        parent[x]
+    }
+
+    for i in 0..faces.len() {
+        for j in (i + 1)..faces.len() {
+            let (ni, nj) = (face_normals[faces[i]], face_normals[faces[j]]);
+            if ni == Vec3::ZERO || nj == Vec3::ZERO {
+                continue;
+            }
+            let cos_angle = ni.normalize().dot(nj.normalize()).clamp(-1.0, 1.0);
+            if cos_angle.acos() <= crease_angle {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj {
+                    parent[ri] = rj;
+                }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..faces.len() {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(faces[i]);
+    }
+    groups.into_values().collect()
+}
+
+/// Which corner (0, 1, or 2) of triangle `f` is vertex `v`.
+fn corner_of(f: usize, v: u32, indices: &[u32]) -> usize {
+    (0..3).find(|&c| indices[f * 3 + c] == v).expect("vertex must be a corner of its own incident face")
+}
+
+/// The interior angle of triangle `f` at its `corner`-th vertex, used to
+/// weight that face's contribution to the vertex's smoothed normal.
+fn interior_angle(f: usize, corner: usize, verts: &[Vec3], indices: &[u32]) -> f32 {
+    let p0 = verts[indices[f * 3 + corner] as usize];
+    let p1 = verts[indices[f * 3 + (corner + 1) % 3] as usize];
+    let p2 = verts[indices[f * 3 + (corner + 2) % 3] as usize];
+    let (e1, e2) = (p1 - p0, p2 - p0);
+    if e1 == Vec3::ZERO || e2 == Vec3::ZERO {
+        return 0.0;
+    }
+    e1.normalize().dot(e2.normalize()).clamp(-1.0, 1.0).acos()
+}
+
+/// Lengyel's method: a per-vertex tangent derived from how UV coordinates
+/// stretch across each incident triangle, accumulated and re-orthogonalized
+/// against the smoothed normal (Gram-Schmidt) so the tangent stays
+/// perpendicular to it. Returns `None` when the mesh has no UV0 channel,
+/// since there's nothing to derive a tangent direction from.
+pub fn compute_tangents(mesh: &Mesh) -> Option<Vec<[f32; 4]>> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION)? {
+        VertexAttributeValues::Float32x3(v) => v,
+        _ => return None,
+    };
+    let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL)? {
+        VertexAttributeValues::Float32x3(v) => v,
+        _ => return None,
+    };
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0)? {
+        VertexAttributeValues::Float32x2(v) => v,
+        _ => return None,
+    };
+    let indices: Vec<u32> = mesh.indices()?.iter().map(|i| i as u32).collect();
+
+    let mut tangent_accum = vec![Vec3::ZERO; positions.len()];
+    let mut bitangent_accum = vec![Vec3::ZERO; positions.len()];
+
+    for tri in indices.chunks_exact(3) {
+        let (i0, i1, i2) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+        let (p0, p1, p2) = (Vec3::from_array(positions[i0]), Vec3::from_array(positions[i1]), Vec3::from_array(positions[i2]));
+        let (uv0, uv1, uv2) = (uvs[i0], uvs[i1], uvs[i2]);
+
+        let edge1 = p1 - p0;
+        let edge2 = p2 - p0;
+        let delta_uv1 = [uv1[0] - uv0[0], uv1[1] - uv0[1]];
+        let delta_uv2 = [uv2[0] - uv0[0], uv2[1] - uv0[1]];
+
+        let denom = delta_uv1[0] * delta_uv2[1] - delta_uv2[0] * delta_uv1[1];
+        if denom.abs() < 1e-12 {
+            continue;
+        }
+        let r = 1.0 / denom;
+        let tangent = (edge1 * delta_uv2[1] - edge2 * delta_uv1[1]) * r;
+        let bitangent = (edge2 * delta_uv1[0] - edge1 * delta_uv2[0]) * r;
+
+        for &i in &[i0, i1, i2] {
+            tangent_accum[i] += tangent;
+            bitangent_accum[i] += bitangent;
+        }
+    }
+
+    let tangents = (0..positions.len())
+        .map(|i| {
+            let n = Vec3::from_array(normals[i]);
+            let t = tangent_accum[i];
+            let orthogonal = (t - n * n.dot(t)).normalize_or_zero();
+            // Handedness: +1 if (N x T) agrees with the accumulated bitangent, else -1.
+            let handedness = if n.cross(orthogonal).dot(bitangent_accum[i]) < 0.0 { -1.0 } else { 1.0 };
+            [orthogonal.x, orthogonal.y, orthogonal.z, handedness]
+        })
+        .collect();
+
+    Some(tangents)
+}
@@ -0,0 +1,112 @@
+//! Per-solid mesh splitting and best-effort name/color recovery for
+//! multi-part STEP assemblies, so a `StepAsset` can expose the file's real
+//! solid structure instead of one fused blob.
+
+use bevy::render::mesh::{Mesh, VertexAttributeValues};
+use bevy::transform::components::Transform;
+
+/// One solid from a STEP assembly: its own triangulated mesh, plus whatever
+/// name/color metadata the backend could recover for it.
+#[derive(Debug, Clone)]
+pub struct StepSolid {
+    pub name: Option<String>,
+    pub color: Option<[f32; 4]>,
+    pub mesh: Mesh,
+    /// This solid's placement relative to the assembly root, for spawning
+    /// it as a properly-positioned child entity rather than assuming every
+    /// part sits at the origin. Always [`Transform::IDENTITY`] for now:
+    /// `Mesher::mesh()` tessellates each sub-shape's geometry directly in
+    /// the assembly's shared coordinate frame, and the `opencascade` crate
+    /// doesn't currently expose a sub-shape's own `TopLoc_Location` readback
+    /// separately from that baked-in geometry — so there's no per-solid
+    /// placement to recover yet, only a field ready for when there is.
+    pub transform: Transform,
+}
+
+/// Best-effort RGB colors from `COLOUR_RGB(...)` entities, in file order.
+/// This is a plain textual scan rather than a full STEP graph walk through
+/// `STYLED_ITEM` -> `PRESENTATION_STYLE_ASSIGNMENT` -> the solid that owns
+/// the style, so a file with N colors and M solids can only be zipped with
+/// the solid list by position. That's correct for the common one-color-
+/// per-solid case but not a guaranteed binding for files that share or nest
+/// styles across solids.
+pub fn parse_step_colors(step_data: &[u8]) -> Vec<[f32; 4]> {
+    let text = String::from_utf8_lossy(step_data);
+    let mut colors = Vec::new();
+    for line in text.lines() {
+        let Some(pos) = line.find("COLOUR_RGB(") else { continue };
+        let Some(open) = line[pos..].find('(') else { continue };
+        let args = &line[pos + open + 1..];
+        let Some(close) = args.find(')') else { continue };
+        let nums: Vec<f32> = args[..close].split(',').filter_map(|s| s.trim().parse::<f32>().ok()).collect();
+        if let [r, g, b] = nums[..] {
+            colors.push([r, g, b, 1.0]);
+        }
+    }
+    colors
+}
+
+/// Best-effort solid/part names from `PRODUCT('name', ...)` entities, in
+/// file order, for the same positional-not-graph-resolved reason as
+/// [`parse_step_colors`].
+pub fn parse_step_names(step_data: &[u8]) -> Vec<String> {
+    let text = String::from_utf8_lossy(step_data);
+    let mut names = Vec::new();
+    for line in text.lines() {
+        let Some(pos) = line.find("PRODUCT(") else { continue };
+        let rest = &line[pos + "PRODUCT(".len()..];
+        let Some(start) = rest.find('\'') else { continue };
+        let Some(end) = rest[start + 1..].find('\'') else { continue };
+        names.push(rest[start + 1..start + 1 + end].to_string());
+    }
+    names
+}
+
+/// Fuse every solid's mesh into one combined mesh (position/normal/tangent
+/// concatenation with index offsetting), for callers that just want the
+/// whole assembly the way `StepAsset::mesh` always has, without caring
+/// about per-solid structure.
+pub fn merge_solids(solids: &[StepSolid]) -> Mesh {
+    let mut merged = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, Default::default());
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut tangents = Vec::new();
+    let mut indices = Vec::new();
+    let mut has_normals = true;
+    let mut has_tangents = true;
+
+    for solid in solids {
+        let base = positions.len() as u32;
+
+        match solid.mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(p)) => positions.extend_from_slice(p),
+            _ => continue,
+        }
+
+        match solid.mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(n)) => normals.extend_from_slice(n),
+            _ => has_normals = false,
+        }
+
+        match solid.mesh.attribute(Mesh::ATTRIBUTE_TANGENT) {
+            Some(VertexAttributeValues::Float32x4(t)) => tangents.extend_from_slice(t),
+            _ => has_tangents = false,
+        }
+
+        if let Some(mesh_indices) = solid.mesh.indices() {
+            indices.extend(mesh_indices.iter().map(|i| i as u32 + base));
+        }
+    }
+
+    merged.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    if has_normals {
+        merged.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    }
+    if has_tangents {
+        merged.insert_attribute(Mesh::ATTRIBUTE_TANGENT, tangents);
+    }
+    merged.insert_indices(bevy::render::mesh::Indices::U32(indices));
+
+    merged
+}
@@ -0,0 +1,141 @@
+//! Conversion from a loaded [`StepAsset`] into a `meshbool::Impl` ready for
+//! boolean operations.
+//!
+//! Tessellated CAD surfaces routinely emit a duplicate vertex per face
+//! along every shared edge (each adjacent facet contributes its own copy,
+//! typically with its own normal), which the boolean kernel's halfedge
+//! topology sees as unrelated edges rather than one shared one. `
+//! from_bevy_mesh` runs a spatial-grid weld pass over the raw positions
+//! before handing them to `meshbool::from_mesh_gl`, then walks the welded
+//! topology to confirm every edge borders exactly two triangles, so a
+//! leaky STEP tessellation is rejected with a `ManifoldError` instead of
+//! quietly corrupting whatever boolean op runs on it next.
+
+use bevy::render::mesh::{Indices, Mesh, VertexAttributeValues};
+use meshbool::{from_mesh_gl, Impl, ManifoldError, MeshGL};
+use nalgebra::Vector3;
+use std::collections::HashMap;
+
+use crate::{StepAsset, StepSolid};
+
+/// Vertices closer than this (in the mesh's own units) are welded together
+/// before the manifold check runs.
+pub const WELD_TOLERANCE: f64 = 1e-7;
+
+impl TryFrom<&StepAsset> for Impl {
+    type Error = ManifoldError;
+
+    fn try_from(asset: &StepAsset) -> Result<Impl, ManifoldError> {
+        from_bevy_mesh(&asset.mesh)
+    }
+}
+
+impl TryFrom<&StepSolid> for Impl {
+    type Error = ManifoldError;
+
+    /// Per-solid counterpart to `TryFrom<&StepAsset>`: welds and validates
+    /// just this one part's mesh rather than the whole fused assembly, so a
+    /// multi-solid STEP file can be boolean-op'd solid-by-solid instead of
+    /// only as one merged blob.
+    fn try_from(solid: &StepSolid) -> Result<Impl, ManifoldError> {
+        from_bevy_mesh(&solid.mesh)
+    }
+}
+
+/// Build a manifold `Impl` from an indexed triangle mesh such as a
+/// `StepAsset`'s tessellated surface: positions come from
+/// `ATTRIBUTE_POSITION`, coincident vertices within [`WELD_TOLERANCE`] are
+/// snapped together, and the welded triangles are checked edge-by-edge
+/// before being handed to `from_mesh_gl`.
+pub fn from_bevy_mesh(mesh: &Mesh) -> Result<Impl, ManifoldError> {
+    let positions = match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(values)) => values,
+        _ => return Err(ManifoldError::MissingPositionProperties),
+    };
+
+    let triangles: Vec<[u32; 3]> = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect(),
+        Some(Indices::U16(indices)) => indices.chunks_exact(3).map(|t| [t[0] as u32, t[1] as u32, t[2] as u32]).collect(),
+        None => return Err(ManifoldError::InvalidConstruction),
+    };
+
+    let points: Vec<Vector3<f64>> = positions.iter().map(|p| Vector3::new(p[0] as f64, p[1] as f64, p[2] as f64)).collect();
+    let (welded, remap) = weld_by_position(&points, WELD_TOLERANCE);
+
+    let mut tri_verts = Vec::with_capacity(triangles.len() * 3);
+    for tri in &triangles {
+        let [a, b, c] = [remap[tri[0] as usize], remap[tri[1] as usize], remap[tri[2] as usize]];
+        if a != b && b != c && a != c {
+            tri_verts.extend_from_slice(&[a, b, c]);
+        }
+    }
+
+    check_manifold(&tri_verts)?;
+
+    let vert_properties: Vec<f32> = welded.iter().flat_map(|p| [p.x as f32, p.y as f32, p.z as f32]).collect();
+    Ok(from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() }))
+}
+
+/// Snap vertices within `tolerance` of each other using a uniform spatial
+/// grid keyed on `floor(position / tolerance)`, so only nearby vertices are
+/// ever compared instead of an all-pairs scan. Returns the deduplicated
+/// positions plus an old-index -> new-index remap.
+fn weld_by_position(points: &[Vector3<f64>], tolerance: f64) -> (Vec<Vector3<f64>>, Vec<u32>) {
+    let cell_of =
+        |p: &Vector3<f64>| -> (i64, i64, i64) { ((p.x / tolerance).floor() as i64, (p.y / tolerance).floor() as i64, (p.z / tolerance).floor() as i64) };
+    let tolerance_sq = tolerance * tolerance;
+
+    let mut grid: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    let mut welded: Vec<Vector3<f64>> = Vec::with_capacity(points.len());
+    let mut remap = vec![0u32; points.len()];
+
+    for (i, p) in points.iter().enumerate() {
+        let (cx, cy, cz) = cell_of(p);
+        let mut found = None;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(candidates) = grid.get(&(cx + dx, cy + dy, cz + dz)) else { continue };
+                    for &candidate in candidates {
+                        if (p - welded[candidate]).norm_squared() <= tolerance_sq {
+                            found = Some(candidate);
+                            break 'search;
+                        }
+                    }
+                }
+            }
+        }
+
+        let target = found.unwrap_or_else(|| {
+            let new_index = welded.len();
+            welded.push(*p);
+            grid.entry((cx, cy, cz)).or_default().push(new_index);
+            new_index
+        });
+        remap[i] = target as u32;
+    }
+
+    (welded, remap)
+}
+
+/// Confirm every directed edge of `tri_verts` (flat triples of vertex
+/// indices) has exactly one opposing directed edge elsewhere in the mesh —
+/// the halfedge invariant a closed 2-manifold surface must satisfy. Reports
+/// as soon as the first violation is found rather than collecting every
+/// non-manifold edge, since `ManifoldError` carries no room for a list of them.
+fn check_manifold(tri_verts: &[u32]) -> Result<(), ManifoldError> {
+    let mut directed_edges: HashMap<(u32, u32), u32> = HashMap::new();
+    for tri in tri_verts.chunks_exact(3) {
+        for &(a, b) in &[(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            *directed_edges.entry((a, b)).or_insert(0) += 1;
+        }
+    }
+
+    for (&(a, b), &count) in &directed_edges {
+        if count != 1 || directed_edges.get(&(b, a)).copied().unwrap_or(0) != 1 {
+            return Err(ManifoldError::NotManifold);
+        }
+    }
+
+    Ok(())
+}
@@ -4,7 +4,9 @@
 
 use bevy::{
     core_pipeline::tonemapping::Tonemapping,
+    input::mouse::{MouseMotion, MouseWheel},
     prelude::*,
+    window::PrimaryWindow,
 };
 use bevy_mesh_boolean::*;
 use bevy_step_loader::*;
@@ -18,11 +20,15 @@ fn parse_cli_args() -> CliArgs {
     // Default to None for the STEP file path, which means we'll use the fallback
     let mut step_file_path = None;
     let mut initial_boolean_op = BooleanOpState::None; // Default to None to show original shapes first
-    
+    let mut meshlet = false;
+
     // Parse arguments
     let mut i = 1;
     while i < args.len() {
         match args[i].as_str() {
+            "--meshlet" => {
+                meshlet = true;
+            }
             "--step" | "-s" => {
                 if i + 1 < args.len() {
                     step_file_path = Some(PathBuf::from(&args[i + 1]));
@@ -56,16 +62,21 @@ fn parse_cli_args() -> CliArgs {
             }
             _ => {
                 eprintln!("Error: Unknown argument '{}'", args[i]);
-                eprintln!("Usage: {} [STEP_FILE_PATH | --step PATH] [--op OPERATION]", &args[0]);
+                eprintln!("Usage: {} [STEP_FILE_PATH | --step PATH] [--op OPERATION] [--meshlet]", &args[0]);
                 std::process::exit(1);
             }
         }
         i += 1;
     }
-    
+
+    if meshlet && !cfg!(feature = "meshlet") {
+        eprintln!("Warning: --meshlet was passed but this binary wasn't built with the `meshlet` feature; falling back to the regular PBR rendering path.");
+    }
+
     CliArgs {
         step_file: step_file_path,
         initial_boolean_op,
+        meshlet,
     }
 }
 
@@ -78,6 +89,7 @@ fn main() {
             DefaultPlugins.set(ImagePlugin::default_nearest()),
             MeshBooleanPlugin,
             StepPlugin,
+            dither_post_process::DitherPostProcessPlugin,
         ))
         .insert_resource(ClearColor(Color::srgb(0.15, 0.15, 0.15)))
         .insert_resource(AmbientLight {
@@ -85,21 +97,41 @@ fn main() {
             color: Color::WHITE,
         })
         .insert_resource(cli_args)
-        .insert_resource(SecondaryShape::Cube) // Initialize with Cube as the default shape
+        .insert_resource(SecondaryShapeParams::default()) // Overwritten once the STEP model's bounds are known
         .insert_resource(GeometryStats::default())
+        .insert_resource(TimingInfo::default())
+        .insert_resource(TimingHistory::default())
         .insert_resource(OrbitState::default())
+        .insert_resource(Followed::default())
+        .insert_resource(SavedOrbitState::default())
+        .insert_resource(meshlet_lod::MeshletLodState::default())
+        .insert_resource(PostProcessState::default())
         .add_systems(Startup, (setup, setup_ui, setup_aluminum_material))
         .add_systems(Update, (
             load_step_and_setup_meshes,
+            set_initial_op_state.run_if(run_once),
             cycle_boolean_op,
-            cycle_secondary_shape,
-            update_operation_text,
-            update_stats_text,
+            adjust_secondary_shape_params,
+            rebuild_secondary_mesh.run_if(resource_changed::<BooleanOpState>.or(resource_changed::<SecondaryShapeParams>)),
+            toggle_and_adjust_post_process,
+            sync_post_process_settings,
+            update_post_process_text,
+            update_operation_text.run_if(resource_changed::<BooleanOpState>.or(resource_changed::<BooleanProgress>)),
+            update_stats_text.run_if(resource_changed::<GeometryStats>),
+            record_boolean_timing,
+            update_timing_text,
+            pick_and_drag_secondary,
+            aim_secondary_at_primary_surface,
+            update_orbit_state_from_input,
+            follow_result_entity,
             orbit_camera,
-            update_orbit_state,
             exit_on_q_key,
+            save_result_mesh,
             ensure_aluminum_material,
+            meshlet_lod::rebuild_meshlet_cache,
+            meshlet_lod::apply_meshlet_lod,
         ).chain())
+        .insert_resource(PickState::default())
         .run();
 }
 
@@ -151,19 +183,30 @@ fn ensure_aluminum_material(
 #[derive(Component)]
 struct ResultShape;
 
-#[derive(Resource)]
+// The free-orbit camera's own pose: an orbit point (`focus`), a radius
+// (`distance`, floored at `min_distance` so the camera can't dolly through
+// its own focus), and yaw/pitch angles around it. Driven by
+// `update_orbit_state_from_input` (mouse drag orbits, middle-drag pans
+// `focus`, scroll wheel dollies `distance`) and consumed by `orbit_camera`,
+// which is the only system that actually writes the `OrbitCamera`'s
+// `Transform`.
+#[derive(Resource, Clone, Copy)]
 struct OrbitState {
-    angle: f32,
-    center: Vec3,
+    focus: Vec3,
     distance: f32,
+    min_distance: f32,
+    yaw: f32,
+    pitch: f32,
 }
 
 impl Default for OrbitState {
     fn default() -> Self {
         OrbitState {
-            angle: 0.0,
-            center: Vec3::new(0.0, 0.0, 0.0), // Initially at origin, will be updated with STEP model center
+            focus: Vec3::new(0.0, 0.0, 0.0), // Initially at origin, will be updated with STEP model center
             distance: 150.0, // Initial distance, will be updated based on STEP model size
+            min_distance: 10.0,
+            yaw: 0.0,
+            pitch: 0.3,
         }
     }
 }
@@ -171,6 +214,18 @@ impl Default for OrbitState {
 #[derive(Component)]
 struct OrbitCamera;
 
+// Which entity the camera is currently snapped onto, if any. Toggled by
+// `follow_result_entity`, which also saves/restores the free-orbit
+// `OrbitState` in `SavedOrbitState` across the toggle so turning follow off
+// resumes the user's own framing rather than resetting it.
+#[derive(Resource, Default)]
+struct Followed(Option<Entity>);
+
+// The free-orbit `OrbitState` captured the moment `Followed` last switched
+// from `None` to `Some`, restored verbatim when it switches back.
+#[derive(Resource, Default)]
+struct SavedOrbitState(Option<OrbitState>);
+
 // A marker component for the operation text UI element
 #[derive(Component)]
 struct OperationText;
@@ -179,40 +234,189 @@ struct OperationText;
 #[derive(Component)]
 struct StatsText;
 
-// A resource to hold the current primitive type for the secondary shape
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
-enum SecondaryShape {
+// A marker component for the post-process status text UI element
+#[derive(Component)]
+struct PostProcessText;
+
+// A marker component for the boolean-op timing breakdown text UI element
+#[derive(Component)]
+struct TimingText;
+
+// A resource holding the ordered-dithering post-process's runtime settings:
+// whether it's applied at all, the quantization level count, and the Bayer
+// matrix size. `sync_post_process_settings` mirrors this onto (or removes)
+// the `OrbitCamera`'s `dither_post_process::DitherPostProcessSettings`
+// component, which is what the render graph node actually reads.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+struct PostProcessState {
+    enabled: bool,
+    levels: f32,
+    matrix_size: u32,
+}
+
+impl Default for PostProcessState {
+    fn default() -> Self {
+        PostProcessState { enabled: false, levels: 4.0, matrix_size: 4 }
+    }
+}
+
+// Which primitive the secondary cutting shape currently builds as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SecondaryShapeKind {
     Cube,
     Sphere,
+    Cylinder,
+    Capsule,
+    Torus,
+    Cone,
+    Slab,
+    Tetrahedron,
+    Polyhedron,
 }
 
-impl Default for SecondaryShape {
-    fn default() -> Self {
-        SecondaryShape::Cube
+impl SecondaryShapeKind {
+    const ALL: [SecondaryShapeKind; 9] = [
+        SecondaryShapeKind::Cube,
+        SecondaryShapeKind::Sphere,
+        SecondaryShapeKind::Cylinder,
+        SecondaryShapeKind::Capsule,
+        SecondaryShapeKind::Torus,
+        SecondaryShapeKind::Cone,
+        SecondaryShapeKind::Slab,
+        SecondaryShapeKind::Tetrahedron,
+        SecondaryShapeKind::Polyhedron,
+    ];
+
+    fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&k| k == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
     }
 }
 
-// A resource to hold current geometry statistics
-#[derive(Resource, Default, Debug, Clone, Copy)]
-struct GeometryStats {
-    vertices: usize,
-    edges: usize,
+// Conway-operator strings `SecondaryShapeKind::Polyhedron` cycles through
+// with Left/Right, read innermost-operator-first the way
+// `conway_polyhedron_mesh` parses them — a small, varied corpus of plain
+// seeds plus a truncation, a kis and a multi-operator composite, without
+// needing a separate enum variant per shape.
+const POLYHEDRON_SPECS: [&str; 8] = ["T", "C", "O", "D", "I", "tC", "kT", "dakD"];
+
+// Which tessellation strategy `SecondaryShapeKind::Sphere` builds with.
+// Bevy's icosphere builder rejects a subdivision count once the vertex
+// count it implies (10 * (n + 1)^2 + 2) exceeds 65535, starting at 80 — `sphere_subdivisions`
+// is kept clamped below that everywhere it's adjusted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SphereKind {
+    Uv,
+    Ico,
 }
 
-// A resource to hold the original scaling information for the secondary shape
-#[derive(Resource, Debug, Clone, Copy)]
-struct SecondaryShapeScale {
-    original_scale: f32,
+// The secondary cutting shape's active primitive kind plus every kind's own
+// numeric parameters, so switching kinds with `C` doesn't forget the
+// dimensions dialed in for the others. [`rebuild_secondary_mesh`] watches
+// this resource and rebuilds the secondary mesh whenever any field changes.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+struct SecondaryShapeParams {
+    kind: SecondaryShapeKind,
+    cube_size: f32,
+    sphere_radius: f32,
+    sphere_kind: SphereKind,
+    sphere_subdivisions: u32,
+    cylinder_radius: f32,
+    cylinder_height: f32,
+    capsule_radius: f32,
+    capsule_height: f32,
+    torus_minor_radius: f32,
+    torus_major_radius: f32,
+    cone_radius: f32,
+    cone_height: f32,
+    slab_width: f32,
+    slab_height: f32,
+    slab_thickness: f32,
+    tetrahedron_size: f32,
+    polyhedron_spec: usize,
+    polyhedron_size: f32,
 }
 
-impl Default for SecondaryShapeScale {
-    fn default() -> Self {
-        SecondaryShapeScale {
-            original_scale: 1.0, // Default to unit scale
+impl SecondaryShapeParams {
+    // Seed every kind's dimensions from `base_size` (the STEP model's own
+    // size-derived `secondary_base_size`), so whichever kind a user cycles
+    // to starts at roughly the footprint the old fixed cube did, rather than
+    // a flat default that might dwarf or vanish against the loaded model.
+    fn scaled_from(base_size: f32) -> Self {
+        SecondaryShapeParams {
+            kind: SecondaryShapeKind::Cube,
+            cube_size: base_size,
+            sphere_radius: base_size * 0.5,
+            sphere_kind: SphereKind::Uv,
+            sphere_subdivisions: 4,
+            cylinder_radius: base_size * 0.5,
+            cylinder_height: base_size,
+            capsule_radius: base_size * 0.3,
+            capsule_height: base_size * 0.6,
+            torus_minor_radius: base_size * 0.2,
+            torus_major_radius: base_size * 0.4,
+            cone_radius: base_size * 0.5,
+            cone_height: base_size,
+            slab_width: base_size,
+            slab_height: base_size,
+            slab_thickness: base_size * 0.1,
+            tetrahedron_size: base_size,
+            polyhedron_spec: 0,
+            polyhedron_size: base_size,
         }
     }
 }
 
+impl Default for SecondaryShapeParams {
+    fn default() -> Self {
+        Self::scaled_from(1.0)
+    }
+}
+
+// Build the mesh `params.kind` describes at its current dimensions.
+fn build_secondary_mesh(params: &SecondaryShapeParams) -> Mesh {
+    match params.kind {
+        SecondaryShapeKind::Cube => Cuboid::new(params.cube_size, params.cube_size, params.cube_size).mesh().build(),
+        SecondaryShapeKind::Sphere => build_sphere_mesh(params.sphere_radius, params.sphere_kind, params.sphere_subdivisions),
+        SecondaryShapeKind::Cylinder => Cylinder::new(params.cylinder_radius, params.cylinder_height).mesh().build(),
+        SecondaryShapeKind::Capsule => Capsule3d::new(params.capsule_radius, params.capsule_height).mesh().build(),
+        SecondaryShapeKind::Torus => Torus::new(params.torus_minor_radius, params.torus_major_radius).mesh().build(),
+        SecondaryShapeKind::Cone => Cone::new(params.cone_radius, params.cone_height).mesh().build(),
+        SecondaryShapeKind::Slab => Cuboid::new(params.slab_width, params.slab_thickness, params.slab_height).mesh().build(),
+        // `tetrahedron_mesh` builds a fixed-size `Manifold::tetrahedron()`
+        // with no size parameter of its own, so scale it after the fact
+        // like every other kind here is sized up front.
+        SecondaryShapeKind::Tetrahedron => tetrahedron_mesh().scaled_by(Vec3::splat(params.tetrahedron_size)),
+        // `conway_polyhedron_mesh` only fails on a malformed spec string,
+        // which `POLYHEDRON_SPECS` never produces — fall back to the plain
+        // tetrahedron seed rather than unwrap, so a future bad entry there
+        // degrades instead of panicking.
+        SecondaryShapeKind::Polyhedron => conway_polyhedron_mesh(POLYHEDRON_SPECS[params.polyhedron_spec])
+            .unwrap_or_else(|| tetrahedron_mesh())
+            .scaled_by(Vec3::splat(params.polyhedron_size)),
+    }
+}
+
+// Ico tessellation is clamped to stay under the subdivision count where
+// Bevy's icosphere builder starts returning `Err` (its vertex count,
+// 10 * (n + 1)^2 + 2, exceeds 65535 from 80 subdivisions on).
+fn build_sphere_mesh(radius: f32, kind: SphereKind, subdivisions: u32) -> Mesh {
+    match kind {
+        SphereKind::Uv => Sphere::new(radius).mesh().uv(32, 18),
+        SphereKind::Ico => Sphere::new(radius)
+            .mesh()
+            .ico(subdivisions.min(79) as usize)
+            .expect("sphere_subdivisions is kept clamped below the icosphere builder's limit"),
+    }
+}
+
+// A resource to hold current geometry statistics
+#[derive(Resource, Default, Debug, Clone, Copy)]
+struct GeometryStats {
+    vertices: usize,
+    edges: usize,
+}
+
 // A resource to hold current timing information
 #[derive(Resource, Default, Debug, Clone, Copy)]
 struct TimingInfo {
@@ -224,6 +428,15 @@ struct TimingInfo {
     update_entity: std::time::Duration,
 }
 
+// How many past operations `update_timing_text` averages over.
+const TIMING_HISTORY_LEN: usize = 10;
+
+// A resource holding the most recent `TIMING_HISTORY_LEN` `TimingInfo`
+// samples, oldest first, so `update_timing_text` can show a rolling average
+// alongside the latest run instead of just one noisy data point.
+#[derive(Resource, Default)]
+struct TimingHistory(std::collections::VecDeque<TimingInfo>);
+
 // A resource to hold the handles of the entities and meshes used in the demo
 #[derive(Resource)]
 struct DemoHandles {
@@ -239,6 +452,9 @@ struct DemoHandles {
 struct CliArgs {
     step_file: Option<PathBuf>,
     initial_boolean_op: BooleanOpState,
+    // Only has an effect when built with the `meshlet` feature; see
+    // `meshlet_lod` module doc for what this mode does and doesn't cover.
+    meshlet: bool,
 }
 
 fn setup(
@@ -402,6 +618,14 @@ fn setup_ui(mut commands: Commands) {
                         TextBundle::from_section("Vertices: 0 | Edges: 0", stats_style.clone()),
                         StatsText, // Marker component to update this text later
                     ));
+                    parent.spawn((
+                        TextBundle::from_section("Dither: OFF", stats_style.clone()),
+                        PostProcessText, // Marker component to update this text later
+                    ));
+                    parent.spawn((
+                        TextBundle::from_section("", stats_style.clone()),
+                        TimingText, // Marker component to update this text later
+                    ));
                 });
 
             // Bottom left instructions
@@ -422,6 +646,15 @@ fn setup_ui(mut commands: Commands) {
                         TextBundle::from_sections(vec![
                             TextSection::new("Space - Cycle boolean op\n", style.clone()),
                             TextSection::new("C - Cycle secondary shape\n", style.clone()),
+                            TextSection::new("Up/Down, Left/Right - Resize secondary shape (Shift = coarse)\n", style.clone()),
+                            TextSection::new("[ / ] - Sphere subdivisions, K - Toggle ico/uv sphere\n", style.clone()),
+                            TextSection::new("Drag - Reposition secondary shape\n", style.clone()),
+                            TextSection::new("Right-click drag - Aim secondary shape at STEP surface\n", style.clone()),
+                            TextSection::new("Left-drag (empty space) - Orbit camera, Middle-drag - Pan, Scroll - Zoom\n", style.clone()),
+                            TextSection::new("F - Follow/unfollow boolean result\n", style.clone()),
+                            TextSection::new("P - Toggle dither post-process, M - Toggle 4x4/8x8 matrix\n", style.clone()),
+                            TextSection::new("-/= - Dither levels\n", style.clone()),
+                            TextSection::new("E - Export result to result.stl/.obj/.gltf\n", style.clone()),
                             TextSection::new("Q - Quit with error message\n", style.clone()),
                         ])
                         .with_style(Style { ..default() }),
@@ -491,8 +724,10 @@ fn load_step_and_setup_meshes(
         Err(e) => {
             log::error!("Failed to load STEP file from path: {}: {}", step_file_path.display(), e);
             // Fallback to cube
+            let fallback_mesh = Cuboid::new(1.2, 1.2, 1.2).mesh().build(); // Fixed type mismatch
             StepAsset {
-                mesh: Cuboid::new(1.2, 1.2, 1.2).mesh().build(), // Fixed type mismatch
+                mesh: fallback_mesh.clone(),
+                solids: vec![bevy_step_loader::StepSolid { name: None, color: None, mesh: fallback_mesh, transform: Transform::IDENTITY }],
             }
         }
     };
@@ -516,11 +751,14 @@ fn load_step_and_setup_meshes(
     // Position the camera to look at the center of the STEP model
     let model_center = (step_min + step_max) * 0.5;
     
-    // Update the orbit state with the proper center and distance
+    // Update the orbit state with the proper focus and distance
+    let initial_distance = camera_distance.max(10.0); // Ensure minimum distance
     commands.insert_resource(OrbitState {
-        center: model_center,
-        distance: camera_distance.max(10.0), // Ensure minimum distance
-        angle: 0.0,
+        focus: model_center,
+        distance: initial_distance,
+        min_distance: (initial_distance * 0.1).max(1.0),
+        yaw: 0.0,
+        pitch: 0.3,
     });
     
     // Create materials with improved metallic/aluminum-like properties
@@ -602,7 +840,8 @@ fn load_step_and_setup_meshes(
     // Create the initial secondary shape (cube) at the correct size
     // Instead of creating a unit cube and scaling it, create it at the proper size directly
     // This avoids issues with the boolean operation system not applying scaling correctly
-    let secondary_mesh = meshes.add(Cuboid::new(secondary_base_size, secondary_base_size, secondary_base_size));
+    let secondary_params = SecondaryShapeParams::scaled_from(secondary_base_size);
+    let secondary_mesh = meshes.add(build_secondary_mesh(&secondary_params));
     let secondary_transform = Transform::from_translation(secondary_pos);
     log::debug!("Creating secondary shape with correct size:");
     log::debug!("  Dimensions: {} x {} x {}", secondary_base_size, secondary_base_size, secondary_base_size);
@@ -650,10 +889,9 @@ fn load_step_and_setup_meshes(
         result_entity,
     });
 
-    // Store the original scaling information for shape cycling
-    commands.insert_resource(SecondaryShapeScale {
-        original_scale: secondary_base_size,
-    });
+    // Replace the placeholder `SecondaryShapeParams` inserted at app startup
+    // with one scaled to this STEP model's own size.
+    commands.insert_resource(secondary_params);
 
     // Store handles for later use
     commands.insert_resource(DemoHandles {
@@ -690,117 +928,445 @@ fn cycle_boolean_op(
     }
 }
 
-// This system cycles through the secondary shapes when 'C' key is pressed
-fn cycle_secondary_shape(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut shape_state: ResMut<SecondaryShape>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut secondary_query: Query<(&mut Handle<Mesh>, &mut Transform), With<SecondaryBooleanMesh>>,
-    mut boolean_op_state: ResMut<BooleanOpState>,
-    shape_scale: Res<SecondaryShapeScale>,
-) {
+// Cycles the secondary shape's active primitive kind ('C') and nudges the
+// active kind's own dimensions with the arrow keys (Up/Down for its primary
+// dimension — radius or edge length — Left/Right for its secondary one,
+// where it has one), holding Shift for a coarser step. '[' / ']' step the
+// ico sphere's subdivision count and 'K' toggles ico vs uv tessellation.
+// Leaves the actual mesh rebuild to [`rebuild_secondary_mesh`], which reacts
+// to any change made here.
+fn adjust_secondary_shape_params(keys: Res<ButtonInput<KeyCode>>, mut params: ResMut<SecondaryShapeParams>) {
     if keys.just_pressed(KeyCode::KeyC) {
-        *shape_state = match *shape_state {
-            SecondaryShape::Cube => SecondaryShape::Sphere,
-            SecondaryShape::Sphere => SecondaryShape::Cube,
-        };
-        debug!("SecondaryShape changed to: {:?}", *shape_state);
-        
-        // Update the secondary entity's mesh
-        if let Ok((mut mesh_handle, mut transform)) = secondary_query.get_single_mut() {
-            // Preserve the entire current transform including position and rotation
-            let current_transform = *transform;
-            // Use the original scale from the resource, not the current transform scale
-            let proper_scale = shape_scale.original_scale;
-            
-            match *shape_state {
-                SecondaryShape::Cube => {
-                    // Create a cube mesh at the correct size directly
-                    // Use the original scale as the size reference
-                    let cube_size = proper_scale;
-                    let cube_mesh = meshes.add(Cuboid::new(cube_size, cube_size, cube_size)); // Cube with proper size
-                    *mesh_handle = cube_mesh;
-                }
-                SecondaryShape::Sphere => {
-                    // Create a sphere mesh - make it the same "size" as the cube by using diameter of cube_size
-                    let sphere_radius = proper_scale * 0.5; // Radius = half of cube side length
-                    let sphere_mesh = meshes.add(Sphere::new(sphere_radius)); // Radius to match unit cube
-                    *mesh_handle = sphere_mesh;
-                }
+        params.kind = params.kind.next();
+        debug!("SecondaryShapeParams kind changed to: {:?}", params.kind);
+    }
+
+    let nudge = if keys.pressed(KeyCode::ShiftLeft) || keys.pressed(KeyCode::ShiftRight) { 0.5 } else { 0.1 };
+    let grow = keys.just_pressed(KeyCode::ArrowUp);
+    let shrink = keys.just_pressed(KeyCode::ArrowDown);
+    let grow_secondary = keys.just_pressed(KeyCode::ArrowRight);
+    let shrink_secondary = keys.just_pressed(KeyCode::ArrowLeft);
+    let grow_subdivisions = keys.just_pressed(KeyCode::BracketRight);
+    let shrink_subdivisions = keys.just_pressed(KeyCode::BracketLeft);
+    let toggle_sphere_kind = keys.just_pressed(KeyCode::KeyK);
+
+    match params.kind {
+        SecondaryShapeKind::Cube => {
+            if grow {
+                params.cube_size += nudge;
+            }
+            if shrink {
+                params.cube_size = (params.cube_size - nudge).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Sphere => {
+            if grow {
+                params.sphere_radius += nudge;
+            }
+            if shrink {
+                params.sphere_radius = (params.sphere_radius - nudge).max(0.01);
+            }
+            if toggle_sphere_kind {
+                params.sphere_kind = match params.sphere_kind {
+                    SphereKind::Uv => SphereKind::Ico,
+                    SphereKind::Ico => SphereKind::Uv,
+                };
+            }
+            if grow_subdivisions {
+                // Kept just under the icosphere builder's failure point (80).
+                params.sphere_subdivisions = (params.sphere_subdivisions + 1).min(79);
+            }
+            if shrink_subdivisions {
+                params.sphere_subdivisions = params.sphere_subdivisions.saturating_sub(1);
+            }
+        }
+        SecondaryShapeKind::Cylinder => {
+            if grow {
+                params.cylinder_radius += nudge;
+            }
+            if shrink {
+                params.cylinder_radius = (params.cylinder_radius - nudge).max(0.01);
+            }
+            if grow_secondary {
+                params.cylinder_height += nudge;
+            }
+            if shrink_secondary {
+                params.cylinder_height = (params.cylinder_height - nudge).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Capsule => {
+            if grow {
+                params.capsule_radius += nudge;
+            }
+            if shrink {
+                params.capsule_radius = (params.capsule_radius - nudge).max(0.01);
+            }
+            if grow_secondary {
+                params.capsule_height += nudge;
+            }
+            if shrink_secondary {
+                params.capsule_height = (params.capsule_height - nudge).max(0.01);
             }
-            
-            // Restore the entire transform to preserve positioning
-            // But reset the scale to (1,1,1) since we're now creating meshes at the correct size
-            *transform = Transform {
-                translation: current_transform.translation, // Keep the same position
-                rotation: current_transform.rotation,       // Keep the same rotation
-                scale: Vec3::ONE,                          // Reset scale since size is now in the mesh
-            };
-            
-            // Log the new positioning for debugging
-            debug!("Cycled secondary shape - Position: {:?}, Original scale: {}", 
-                   current_transform.translation, proper_scale);
-            
-            // Trigger a recomputation by marking the boolean operation state as changed
-            // This forces the boolean operation system to recompute with the new geometry
-            boolean_op_state.set_changed();
         }
+        SecondaryShapeKind::Torus => {
+            if grow {
+                params.torus_major_radius += nudge;
+            }
+            if shrink {
+                params.torus_major_radius = (params.torus_major_radius - nudge).max(0.02);
+            }
+            if grow_secondary {
+                params.torus_minor_radius += nudge * 0.5;
+            }
+            if shrink_secondary {
+                params.torus_minor_radius = (params.torus_minor_radius - nudge * 0.5).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Cone => {
+            if grow {
+                params.cone_radius += nudge;
+            }
+            if shrink {
+                params.cone_radius = (params.cone_radius - nudge).max(0.01);
+            }
+            if grow_secondary {
+                params.cone_height += nudge;
+            }
+            if shrink_secondary {
+                params.cone_height = (params.cone_height - nudge).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Slab => {
+            if grow {
+                params.slab_thickness += nudge * 0.2;
+            }
+            if shrink {
+                params.slab_thickness = (params.slab_thickness - nudge * 0.2).max(0.01);
+            }
+            if grow_secondary {
+                params.slab_width += nudge;
+                params.slab_height += nudge;
+            }
+            if shrink_secondary {
+                params.slab_width = (params.slab_width - nudge).max(0.01);
+                params.slab_height = (params.slab_height - nudge).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Tetrahedron => {
+            if grow {
+                params.tetrahedron_size += nudge;
+            }
+            if shrink {
+                params.tetrahedron_size = (params.tetrahedron_size - nudge).max(0.01);
+            }
+        }
+        SecondaryShapeKind::Polyhedron => {
+            if grow {
+                params.polyhedron_size += nudge;
+            }
+            if shrink {
+                params.polyhedron_size = (params.polyhedron_size - nudge).max(0.01);
+            }
+            if grow_secondary {
+                params.polyhedron_spec = (params.polyhedron_spec + 1) % POLYHEDRON_SPECS.len();
+            }
+            if shrink_secondary {
+                params.polyhedron_spec = (params.polyhedron_spec + POLYHEDRON_SPECS.len() - 1) % POLYHEDRON_SPECS.len();
+            }
+        }
+    }
+}
+
+// Rebuilds the secondary entity's mesh whenever `SecondaryShapeParams`
+// changes (a kind switch or a dimension nudge), preserving the entity's
+// transform exactly as the old fixed cube/sphere toggle did. Gated by a
+// `resource_changed` run condition on `BooleanOpState` or
+// `SecondaryShapeParams` rather than an internal `is_changed()` check,
+// so the trigger is visible at the schedule level; swapping in the rebuilt
+// `Handle<Mesh>` is itself enough to make `bevy_mesh_boolean`'s own
+// `boolean_inputs_changed` run condition re-dispatch the op, so this no
+// longer needs to force `BooleanOpState` changed by hand.
+fn rebuild_secondary_mesh(
+    params: Res<SecondaryShapeParams>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut secondary_query: Query<&mut Handle<Mesh>, With<SecondaryBooleanMesh>>,
+) {
+    if let Ok(mut mesh_handle) = secondary_query.get_single_mut() {
+        *mesh_handle = meshes.add(build_secondary_mesh(&params));
     }
 }
 
-// This system updates the UI text to show the current operation
+// This system updates the UI text to show the current operation, including
+// whether it's still computing off-thread (`BooleanProgress::Running`)
+// rather than always reporting the static operation label. Gated by a
+// `resource_changed::<BooleanOpState>().or(resource_changed::<BooleanProgress>())`
+// run condition at the schedule level rather than an internal `is_changed()`
+// check.
 fn update_operation_text(
     op_state: Res<BooleanOpState>,
+    progress: Res<BooleanProgress>,
     mut query: Query<&mut Text, With<OperationText>>,
 ) {
-    if op_state.is_changed() {
-        let text = match *op_state {
-            BooleanOpState::None => "Current Operation: None",
-            BooleanOpState::Intersect => "Current Operation: Intersect",
-            BooleanOpState::Union => "Current Operation: Union",
-            BooleanOpState::Subtract => "Current Operation: Subtract",
-        };
+    let op_label = match *op_state {
+        BooleanOpState::None => "None",
+        BooleanOpState::Intersect => "Intersect",
+        BooleanOpState::Union => "Union",
+        BooleanOpState::Subtract => "Subtract",
+    };
+    let text = match *progress {
+        BooleanProgress::Running { .. } => format!("Current Operation: {op_label} (computing...)"),
+        BooleanProgress::Idle => format!("Current Operation: {op_label}"),
+    };
 
-        for mut text_component in query.iter_mut() {
-            text_component.sections[0].value = text.to_string();
-        }
+    for mut text_component in query.iter_mut() {
+        text_component.sections[0].value = text.clone();
     }
 }
 
-// This system updates the UI text to show current geometry statistics
+// This system updates the UI text to show current geometry statistics.
+// Gated by a `resource_changed::<GeometryStats>()` run condition at the
+// schedule level rather than an internal `is_changed()` check.
 fn update_stats_text(
     stats: Res<GeometryStats>,
     mut query: Query<&mut Text, With<StatsText>>,
 ) {
-    if stats.is_changed() {
-        log::debug!("Updating stats text with: vertices={}, edges={}", stats.vertices, stats.edges);
-        if let Ok(mut text) = query.get_single_mut() {
-            text.sections[0].value =
-                format!("Vertices: {} | Edges: {}", stats.vertices, stats.edges);
+    log::debug!("Updating stats text with: vertices={}, edges={}", stats.vertices, stats.edges);
+    if let Ok(mut text) = query.get_single_mut() {
+        text.sections[0].value =
+            format!("Vertices: {} | Edges: {}", stats.vertices, stats.edges);
+    }
+}
+
+// Reads each completed boolean op's `BooleanOpResult::Success` timings into
+// `TimingInfo` (mapping its `dispatch`/`conversion`/`operation`/`writeback`/
+// `update_entity` durations onto `TimingInfo`'s `transform`/`mesh_conversion`/
+// `boolean_op`/`mesh_conversion_back`/`update_entity` fields, plus their sum
+// as `total_time`) and pushes it onto `TimingHistory` for the rolling
+// average `update_timing_text` shows. The other `BooleanOpResult` variants
+// (`Empty`, the two fallbacks, `Panicked`) carry no per-stage durations to
+// record, so they're left for `update_operation_text` to report instead.
+fn record_boolean_timing(
+    mut events: EventReader<BooleanOpResult>,
+    mut timing: ResMut<TimingInfo>,
+    mut history: ResMut<TimingHistory>,
+) {
+    for event in events.read() {
+        if let BooleanOpResult::Success { dispatch, conversion, operation, writeback, update_entity, .. } = event {
+            *timing = TimingInfo {
+                total_time: *dispatch + *conversion + *operation + *writeback + *update_entity,
+                mesh_conversion: *conversion,
+                transform: *dispatch,
+                boolean_op: *operation,
+                mesh_conversion_back: *writeback,
+                update_entity: *update_entity,
+            };
+
+            history.0.push_back(*timing);
+            if history.0.len() > TIMING_HISTORY_LEN {
+                history.0.pop_front();
+            }
+        }
+    }
+}
+
+// This system updates the UI text to show a per-stage millisecond breakdown
+// of the most recent boolean op plus a rolling average over the last
+// `TIMING_HISTORY_LEN` ops, so slow operations on dense STEP meshes can be
+// attributed to a specific stage instead of just "it's slow".
+fn update_timing_text(
+    timing: Res<TimingInfo>,
+    history: Res<TimingHistory>,
+    mut query: Query<&mut Text, With<TimingText>>,
+) {
+    if !timing.is_changed() {
+        return;
+    }
+
+    let count = history.0.len().max(1) as u32;
+    let sum = history.0.iter().fold(TimingInfo::default(), |acc, sample| TimingInfo {
+        total_time: acc.total_time + sample.total_time,
+        mesh_conversion: acc.mesh_conversion + sample.mesh_conversion,
+        transform: acc.transform + sample.transform,
+        boolean_op: acc.boolean_op + sample.boolean_op,
+        mesh_conversion_back: acc.mesh_conversion_back + sample.mesh_conversion_back,
+        update_entity: acc.update_entity + sample.update_entity,
+    });
+    let avg_ms = |total: std::time::Duration| total.as_secs_f64() * 1000.0 / count as f64;
+
+    let text = format!(
+        "Timing (last op / avg of {}): transform {:.2}/{:.2}ms, convert {:.2}/{:.2}ms, boolean {:.2}/{:.2}ms, writeback {:.2}/{:.2}ms, entity {:.2}/{:.2}ms, total {:.2}/{:.2}ms",
+        count,
+        timing.transform.as_secs_f64() * 1000.0,
+        avg_ms(sum.transform),
+        timing.mesh_conversion.as_secs_f64() * 1000.0,
+        avg_ms(sum.mesh_conversion),
+        timing.boolean_op.as_secs_f64() * 1000.0,
+        avg_ms(sum.boolean_op),
+        timing.mesh_conversion_back.as_secs_f64() * 1000.0,
+        avg_ms(sum.mesh_conversion_back),
+        timing.update_entity.as_secs_f64() * 1000.0,
+        avg_ms(sum.update_entity),
+        timing.total_time.as_secs_f64() * 1000.0,
+        avg_ms(sum.total_time),
+    );
+
+    if let Ok(mut text_component) = query.get_single_mut() {
+        text_component.sections[0].value = text;
+    }
+}
+
+// Toggles the ordered-dithering post-process ('P') and nudges its
+// `levels`/`matrix_size` parameters ('-'/'=' and 'M'), leaving the actual
+// render-graph node disabled or enabled to `sync_post_process_settings`.
+fn toggle_and_adjust_post_process(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<PostProcessState>) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        state.enabled = !state.enabled;
+        debug!("PostProcessState enabled changed to: {}", state.enabled);
+    }
+    if keys.just_pressed(KeyCode::Minus) {
+        state.levels = (state.levels - 1.0).max(2.0);
+    }
+    if keys.just_pressed(KeyCode::Equal) {
+        state.levels += 1.0;
+    }
+    if keys.just_pressed(KeyCode::KeyM) {
+        state.matrix_size = if state.matrix_size >= 8 { 4 } else { 8 };
+    }
+}
+
+// Mirrors `PostProcessState` onto the `OrbitCamera`'s
+// `dither_post_process::DitherPostProcessSettings` component, which is the
+// piece the render graph node actually reads each frame — inserting it
+// turns the pass on, removing it (when `enabled` is false) turns it off,
+// since `DitherPostProcessNode` only runs for views that have the component.
+fn sync_post_process_settings(
+    mut commands: Commands,
+    state: Res<PostProcessState>,
+    camera_query: Query<(Entity, Option<&dither_post_process::DitherPostProcessSettings>), With<OrbitCamera>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let Ok((camera_entity, existing)) = camera_query.get_single() else { return };
+
+    if !state.enabled {
+        if existing.is_some() {
+            commands.entity(camera_entity).remove::<dither_post_process::DitherPostProcessSettings>();
         }
+        return;
+    }
+
+    commands.entity(camera_entity).insert(dither_post_process::DitherPostProcessSettings {
+        levels: state.levels,
+        matrix_size: state.matrix_size as f32,
+    });
+}
+
+// This system updates the UI text to show the dithering post-process's
+// current on/off state and parameters.
+fn update_post_process_text(state: Res<PostProcessState>, mut query: Query<&mut Text, With<PostProcessText>>) {
+    if !state.is_changed() {
+        return;
+    }
+    if let Ok(mut text) = query.get_single_mut() {
+        text.sections[0].value = if state.enabled {
+            format!("Dither: ON ({} levels, {}x{} matrix)", state.levels as u32, state.matrix_size, state.matrix_size)
+        } else {
+            "Dither: OFF".to_string()
+        };
     }
 }
 
-// System for orbit camera
+// System for orbit camera: places it at `focus + (yaw, pitch) * (0, 0, distance)`,
+// looking back at `focus`.
 fn orbit_camera(
     mut query: Query<&mut Transform, With<OrbitCamera>>,
     orbit_state: Res<OrbitState>,
 ) {
     if let Ok(mut transform) = query.get_single_mut() {
-        let x = orbit_state.center.x + orbit_state.distance * orbit_state.angle.cos();
-        let z = orbit_state.center.z + orbit_state.distance * orbit_state.angle.sin();
-        let y = orbit_state.center.y + 2.0; // Keep a slight elevation
-        
-        *transform = Transform::from_translation(Vec3::new(x, y, z))
-            .looking_at(orbit_state.center, Vec3::Y);
+        let rotation = Quat::from_euler(EulerRot::YXZ, orbit_state.yaw, -orbit_state.pitch, 0.0);
+        let offset = rotation * Vec3::new(0.0, 0.0, orbit_state.distance);
+
+        *transform = Transform::from_translation(orbit_state.focus + offset)
+            .looking_at(orbit_state.focus, Vec3::Y);
     }
 }
 
-// System to update orbit state (slowly rotate camera)
-fn update_orbit_state(
+// Drives `OrbitState` from mouse input: left-drag orbits (yaw/pitch) unless
+// `pick_and_drag_secondary` already grabbed the secondary shape this frame
+// (so the two don't fight over the same button), middle-drag pans `focus`
+// across the camera's own right/up plane scaled by the current distance (so
+// panning feels the same size on screen regardless of zoom), and the scroll
+// wheel dollies `distance` in or out, floored at `min_distance` so the
+// camera can never pass through its own focus point.
+fn update_orbit_state_from_input(
+    buttons: Res<ButtonInput<MouseButton>>,
+    pick_state: Res<PickState>,
+    mut motion: EventReader<MouseMotion>,
+    mut wheel: EventReader<MouseWheel>,
+    camera_query: Query<&Transform, With<OrbitCamera>>,
     mut orbit_state: ResMut<OrbitState>,
 ) {
-    orbit_state.angle += 0.005; // Slowly rotate the camera
+    const ORBIT_SPEED: f32 = 0.005;
+    const PAN_SPEED: f32 = 0.0015;
+    const ZOOM_SPEED: f32 = 0.1;
+
+    let delta: Vec2 = motion.read().map(|event| event.delta).sum();
+
+    if buttons.pressed(MouseButton::Left) && pick_state.grabbed.is_none() {
+        orbit_state.yaw -= delta.x * ORBIT_SPEED;
+        orbit_state.pitch = (orbit_state.pitch - delta.y * ORBIT_SPEED).clamp(-1.5, 1.5);
+    }
+
+    if buttons.pressed(MouseButton::Middle) {
+        if let Ok(transform) = camera_query.get_single() {
+            let pan = (transform.right() * -delta.x + transform.up() * delta.y) * orbit_state.distance * PAN_SPEED;
+            orbit_state.focus += pan;
+        }
+    }
+
+    let scroll: f32 = wheel.read().map(|event| event.y).sum();
+    if scroll != 0.0 {
+        let min_distance = orbit_state.min_distance;
+        orbit_state.distance = (orbit_state.distance * (1.0 - scroll * ZOOM_SPEED)).max(min_distance);
+    }
+}
+
+// F toggles following the boolean result's centroid: engaging it saves the
+// current free-orbit `OrbitState` into `SavedOrbitState` and snaps `focus`
+// onto `ResultShape`'s world-space bounding-box center (via
+// `calculate_mesh_min_max`) every frame it's visible, so the user can orbit
+// in close on the boolean output; disengaging restores the saved state so
+// free orbit resumes exactly where it was left.
+fn follow_result_entity(
+    keys: Res<ButtonInput<KeyCode>>,
+    result_query: Query<(Entity, &GlobalTransform, &Handle<Mesh>, &Visibility), With<ResultShape>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut followed: ResMut<Followed>,
+    mut saved: ResMut<SavedOrbitState>,
+    mut orbit_state: ResMut<OrbitState>,
+) {
+    if keys.just_pressed(KeyCode::KeyF) {
+        if followed.0.take().is_some() {
+            if let Some(previous) = saved.0.take() {
+                *orbit_state = previous;
+            }
+        } else if let Ok((entity, _, _, visibility)) = result_query.get_single() {
+            if *visibility != Visibility::Hidden {
+                saved.0 = Some(*orbit_state);
+                followed.0 = Some(entity);
+            }
+        }
+    }
+
+    let Some(followed_entity) = followed.0 else { return };
+    let Ok((_, global_transform, mesh_handle, visibility)) = result_query.get(followed_entity) else { return };
+    if *visibility == Visibility::Hidden {
+        return;
+    }
+    let Some(mesh) = mesh_assets.get(mesh_handle) else { return };
+    let (local_min, local_max) = calculate_mesh_min_max(mesh);
+    orbit_state.focus = global_transform.transform_point((local_min + local_max) * 0.5);
 }
 
 // This system exits the app when 'q' is pressed with error message
@@ -814,15 +1380,607 @@ fn exit_on_q_key(
     }
 }
 
-// System to set the initial boolean operation state from CLI args
+// E writes the current `ResultShape` mesh out to `result.stl`, `result.obj`
+// (plus its `result.mtl` companion) and `result.gltf` in the working
+// directory — the STL/OBJ/glTF serializers themselves are plain functions
+// in `bevy_mesh_boolean::export`; this system just supplies the mesh and
+// the filenames. Each format is attempted independently so one failing
+// (e.g. a degenerate empty result) doesn't block the others.
+fn save_result_mesh(
+    keys: Res<ButtonInput<KeyCode>>,
+    result_query: Query<&Handle<Mesh>, With<ResultShape>>,
+    mesh_assets: Res<Assets<Mesh>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyE) {
+        return;
+    }
+    let Ok(mesh_handle) = result_query.get_single() else {
+        warn!("No ResultShape entity to export");
+        return;
+    };
+    let Some(mesh) = mesh_assets.get(mesh_handle) else {
+        warn!("ResultShape mesh handle not yet loaded");
+        return;
+    };
+
+    match mesh_to_stl_binary(mesh) {
+        Some(bytes) => match std::fs::write("result.stl", bytes) {
+            Ok(()) => info!("Exported result.stl"),
+            Err(err) => error!("Failed to write result.stl: {err}"),
+        },
+        None => warn!("Result mesh has no position/index data to export as STL"),
+    }
+
+    match mesh_to_obj(mesh, "result.mtl") {
+        Some(obj) => match std::fs::write("result.obj", obj) {
+            Ok(()) => {
+                let mtl = "newmtl default\nKd 0.8 0.7 0.6\n";
+                if let Err(err) = std::fs::write("result.mtl", mtl) {
+                    error!("Failed to write result.mtl: {err}");
+                }
+                info!("Exported result.obj");
+            }
+            Err(err) => error!("Failed to write result.obj: {err}"),
+        },
+        None => warn!("Result mesh has no position/index data to export as OBJ"),
+    }
+
+    match mesh_to_gltf(mesh) {
+        Some(gltf) => match std::fs::write("result.gltf", gltf) {
+            Ok(()) => info!("Exported result.gltf"),
+            Err(err) => error!("Failed to write result.gltf: {err}"),
+        },
+        None => warn!("Result mesh has no position/index data to export as glTF"),
+    }
+}
+
+// A resource tracking the entity currently grabbed by `pick_and_drag_secondary`,
+// if any, and the offset from that entity's origin to the point the ray first
+// hit it, so dragging moves the shape rather than snapping its origin to the
+// cursor.
+#[derive(Resource, Default)]
+struct PickState {
+    grabbed: Option<Entity>,
+    grab_offset: Vec3,
+}
+
+// Möller–Trumbore ray-triangle intersection against one triangle's world-space
+// corners. Returns the ray parameter `t` of the hit, if any, for `t > 0`.
+fn ray_triangle_intersection(origin: Vec3, dir: Vec3, v0: Vec3, v1: Vec3, v2: Vec3) -> Option<f32> {
+    const EPSILON: f32 = 1e-6;
+    let e1 = v1 - v0;
+    let e2 = v2 - v0;
+    let p = dir.cross(e2);
+    let det = e1.dot(p);
+    if det.abs() < EPSILON {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let t_vec = origin - v0;
+    let u = t_vec.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = t_vec.cross(e1);
+    let v = dir.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = e2.dot(q) * inv_det;
+    (t > 0.0).then_some(t)
+}
+
+// Casts `origin`/`dir` (world space) against every triangle of `mesh`,
+// transformed into world space by `transform`, first rejecting the whole mesh
+// with a cheap slab test against its (transformed) bounding box from
+// `calculate_mesh_min_max`. Returns the nearest hit distance, if any.
+fn raycast_mesh(mesh: &Mesh, transform: &GlobalTransform, origin: Vec3, dir: Vec3) -> Option<f32> {
+    use bevy::render::mesh::{Indices, VertexAttributeValues};
+
+    let (local_min, local_max) = calculate_mesh_min_max(mesh);
+    let corners = [
+        Vec3::new(local_min.x, local_min.y, local_min.z),
+        Vec3::new(local_max.x, local_min.y, local_min.z),
+        Vec3::new(local_min.x, local_max.y, local_min.z),
+        Vec3::new(local_max.x, local_max.y, local_min.z),
+        Vec3::new(local_min.x, local_min.y, local_max.z),
+        Vec3::new(local_max.x, local_min.y, local_max.z),
+        Vec3::new(local_min.x, local_max.y, local_max.z),
+        Vec3::new(local_max.x, local_max.y, local_max.z),
+    ];
+    let (mut world_min, mut world_max) = (Vec3::splat(f32::MAX), Vec3::splat(f32::MIN));
+    for corner in corners {
+        let world = transform.transform_point(corner);
+        world_min = world_min.min(world);
+        world_max = world_max.max(world);
+    }
+    if !ray_hits_aabb(origin, dir, world_min, world_max) {
+        return None;
+    }
+
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        return None;
+    };
+    let world_positions: Vec<Vec3> = positions.iter().map(|p| transform.transform_point(Vec3::from_array(*p))).collect();
+
+    let triangles: Vec<[usize; 3]> = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.chunks_exact(3).map(|c| [c[0] as usize, c[1] as usize, c[2] as usize]).collect(),
+        Some(Indices::U16(indices)) => indices.chunks_exact(3).map(|c| [c[0] as usize, c[1] as usize, c[2] as usize]).collect(),
+        None => (0..world_positions.len()).collect::<Vec<_>>().chunks_exact(3).map(|c| [c[0], c[1], c[2]]).collect(),
+    };
+
+    let mut nearest: Option<f32> = None;
+    for tri in triangles {
+        let (v0, v1, v2) = (world_positions[tri[0]], world_positions[tri[1]], world_positions[tri[2]]);
+        if let Some(t) = ray_triangle_intersection(origin, dir, v0, v1, v2) {
+            nearest = Some(nearest.map_or(t, |best: f32| best.min(t)));
+        }
+    }
+    nearest
+}
+
+// Cheap slab test, used to reject a mesh's triangles entirely before ever
+// walking them, so picking stays cheap against dense STEP meshes.
+fn ray_hits_aabb(origin: Vec3, dir: Vec3, bbox_min: Vec3, bbox_max: Vec3) -> bool {
+    let inv_dir = dir.recip();
+    let t0 = (bbox_min - origin) * inv_dir;
+    let t1 = (bbox_max - origin) * inv_dir;
+    let t_min = t0.min(t1);
+    let t_max = t0.max(t1);
+    t_min.max_element() <= t_max.min_element().max(0.0)
+}
+
+// Drags `SecondaryBooleanMesh` with the mouse: on press, raycasts from the
+// cursor through `OrbitCamera` and grabs the secondary entity if hit; while
+// held, re-casts each frame and projects the new hit point onto the plane
+// through the grabbed point facing the camera, translating the entity by the
+// resulting delta (so the grab point stays under the cursor); on release,
+// ungrabs and marks `BooleanOpState` changed so `dispatch_boolean_op` (which
+// only watches `BooleanOpState`/`Handle<Mesh>` changes, not `Transform`)
+// recomputes against the shape's new position.
+fn pick_and_drag_secondary(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    mut secondary_query: Query<(Entity, &mut Transform, &GlobalTransform, &Handle<Mesh>), With<SecondaryBooleanMesh>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut pick_state: ResMut<PickState>,
+    mut op_state: ResMut<BooleanOpState>,
+) {
+    if buttons.just_released(MouseButton::Left) {
+        if pick_state.grabbed.is_some() {
+            op_state.set_changed();
+        }
+        pick_state.grabbed = None;
+    }
+
+    let Ok(window) = windows.get_single() else { return };
+    let Some(cursor) = window.cursor_position() else { return };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else { return };
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor) else { return };
+
+    if buttons.just_pressed(MouseButton::Left) {
+        let mut best: Option<(Entity, f32)> = None;
+        for (entity, _, global_transform, mesh_handle) in &secondary_query {
+            let Some(mesh) = mesh_assets.get(mesh_handle) else { continue };
+            if let Some(t) = raycast_mesh(mesh, global_transform, ray.origin, *ray.direction) {
+                if best.map_or(true, |(_, best_t)| t < best_t) {
+                    best = Some((entity, t));
+                }
+            }
+        }
+        if let Some((entity, t)) = best {
+            let hit_point = ray.origin + *ray.direction * t;
+            if let Ok((_, transform, _, _)) = secondary_query.get(entity) {
+                pick_state.grabbed = Some(entity);
+                pick_state.grab_offset = hit_point - transform.translation;
+            }
+        }
+    }
+
+    let Some(grabbed) = pick_state.grabbed else { return };
+    let Ok((_, mut transform, _, _)) = secondary_query.get_mut(grabbed) else {
+        pick_state.grabbed = None;
+        return;
+    };
+
+    // Project the cursor ray onto the plane through the grabbed point, facing
+    // the camera, so motion parallel to the screen maps to motion parallel to
+    // the shape's original depth rather than sliding along the ray itself.
+    let plane_point = transform.translation + pick_state.grab_offset;
+    let plane_normal = *camera_transform.forward();
+    let denom = plane_normal.dot(*ray.direction);
+    if denom.abs() < 1e-6 {
+        return;
+    }
+    let t = plane_normal.dot(plane_point - ray.origin) / denom;
+    let new_hit_point = ray.origin + *ray.direction * t;
+    transform.translation = new_hit_point - pick_state.grab_offset;
+}
+
+// Right-click-hold alternative to `pick_and_drag_secondary`'s pick-and-drag:
+// instead of grabbing the secondary shape itself, casts a ray from the
+// cursor through `OrbitCamera` against the STEP model's own triangles (the
+// same `raycast_mesh` Möller-Trumbore routine, reused against the primary
+// rather than the secondary) and snaps every `SecondaryBooleanMesh` onto the
+// hit point each frame the button is held, so the cut can be aimed directly
+// at a feature on the model's surface rather than positioned by feel. Draws
+// a small gizmo sphere at the hit point so the aim point is visible before
+// the button is released. On release, marks `BooleanOpState` changed for
+// the same reason `pick_and_drag_secondary` does: moving the secondary's
+// `Transform` alone doesn't retrigger `dispatch_boolean_op`.
+fn aim_secondary_at_primary_surface(
+    buttons: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<OrbitCamera>>,
+    primary_query: Query<(&GlobalTransform, &Handle<Mesh>), (With<StepModel>, Without<SecondaryBooleanMesh>, Without<ResultShape>)>,
+    mut secondary_query: Query<&mut Transform, With<SecondaryBooleanMesh>>,
+    mesh_assets: Res<Assets<Mesh>>,
+    mut op_state: ResMut<BooleanOpState>,
+    mut gizmos: Gizmos,
+) {
+    if buttons.just_released(MouseButton::Right) {
+        op_state.set_changed();
+        return;
+    }
+    if !buttons.pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Ok(window) = windows.get_single() else { return };
+    let Some(cursor) = window.cursor_position() else { return };
+    let Ok((camera, camera_transform)) = camera_query.get_single() else { return };
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor) else { return };
+
+    let mut nearest: Option<f32> = None;
+    for (global_transform, mesh_handle) in &primary_query {
+        let Some(mesh) = mesh_assets.get(mesh_handle) else { continue };
+        if let Some(t) = raycast_mesh(mesh, global_transform, ray.origin, *ray.direction) {
+            nearest = Some(nearest.map_or(t, |best: f32| best.min(t)));
+        }
+    }
+    let Some(t) = nearest else { return };
+    let hit_point = ray.origin + *ray.direction * t;
+
+    gizmos.sphere(hit_point, Quat::IDENTITY, 0.05, Color::srgb(1.0, 0.3, 0.1));
+
+    for mut transform in &mut secondary_query {
+        transform.translation = hit_point;
+    }
+}
+
+// System to set the initial boolean operation state from CLI args. Gated by
+// a `run_once` run condition at the schedule level instead of the
+// `Local<bool>` latch this replaced.
 fn set_initial_op_state(
     mut op_state: ResMut<BooleanOpState>,
     cli_args: Res<CliArgs>,
-    mut has_set_initial_state: Local<bool>,
 ) {
-    if !*has_set_initial_state {
-        *op_state = cli_args.initial_boolean_op;
-        log::debug!("Set initial boolean operation state to: {:?}", *op_state);
-        *has_set_initial_state = true;
+    *op_state = cli_args.initial_boolean_op;
+    log::debug!("Set initial boolean operation state to: {:?}", *op_state);
+}
+
+/// Optional CPU-side meshlet LOD rendering for the `StepModel`/`ResultShape`
+/// meshes, enabled with `--meshlet` (see `CliArgs::meshlet`) when the crate
+/// is built with the `meshlet` feature.
+///
+/// `bevy_mesh_boolean::mesh_to_meshlets` already builds the meshlet/
+/// simplification DAG this needs. What it doesn't provide — and what this
+/// module doesn't attempt either — is the two-pass occlusion culling the
+/// request describes: rasterize against last frame's reprojected depth
+/// pyramid, build a fresh pyramid from that pass, raster newly-visible
+/// clusters, then build the pyramid that seeds next frame's first pass.
+/// That needs a custom render-graph node doing indirect draws and compute
+/// passes over a depth hierarchy, which isn't scaffolding this crate has.
+/// [`meshlet_lod::apply_meshlet_lod`] instead does the CPU-side half of the
+/// same idea: pick the coarsest LOD whose projected error is acceptable at
+/// the mesh's current distance from the camera
+/// (`MeshletAsset::select_lod`), flatten the selected meshlets back into a
+/// real `Mesh`, and swap it onto the entity's existing `Handle<Mesh>` — so a
+/// dense STEP import still renders fewer triangles once it's a speck on
+/// screen, even without true occlusion culling. Falls back to the regular
+/// full-resolution `PbrBundle` path whenever `--meshlet` wasn't passed or
+/// the feature isn't compiled in.
+#[cfg(feature = "meshlet")]
+mod meshlet_lod {
+    use super::*;
+    use bevy::render::mesh::{Indices, VertexAttributeValues};
+    use bevy_mesh_boolean::{mesh_to_meshlets, MeshletAsset};
+    use std::collections::HashMap;
+
+    /// Per-entity cached [`MeshletAsset`] plus the LOD selection applied
+    /// last frame, so [`apply_meshlet_lod`] only rebuilds the rendered mesh
+    /// when the selection actually changes rather than every frame.
+    #[derive(Resource, Default)]
+    pub struct MeshletLodState {
+        assets: HashMap<Entity, MeshletAsset>,
+        last_selection: HashMap<Entity, Vec<usize>>,
+    }
+
+    /// Build (or rebuild) a [`MeshletAsset`] for every `StepModel`/
+    /// `ResultShape` entity whose `Handle<Mesh>` changed this frame — after
+    /// the initial load and after every boolean op, the same moments a
+    /// `PbrBundle`'s own mesh handle gets swapped.
+    pub fn rebuild_meshlet_cache(
+        cli_args: Res<CliArgs>,
+        mut state: ResMut<MeshletLodState>,
+        meshes: Res<Assets<Mesh>>,
+        changed: Query<(Entity, &Handle<Mesh>), (Or<(With<StepModel>, With<ResultShape>)>, Changed<Handle<Mesh>>)>,
+    ) {
+        if !cli_args.meshlet {
+            return;
+        }
+
+        for (entity, mesh_handle) in &changed {
+            let Some(mesh) = meshes.get(mesh_handle) else { continue };
+            let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else { continue };
+            let Some(Indices::U32(indices)) = mesh.indices() else { continue };
+
+            state.assets.insert(entity, mesh_to_meshlets(positions, indices));
+            state.last_selection.remove(&entity);
+        }
+    }
+
+    /// Each frame, re-select and (if the selection changed) rebuild the
+    /// rendered mesh for every cached entity, based on its distance from
+    /// `OrbitCamera`.
+    pub fn apply_meshlet_lod(
+        cli_args: Res<CliArgs>,
+        mut state: ResMut<MeshletLodState>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        camera_query: Query<&GlobalTransform, With<OrbitCamera>>,
+        mut query: Query<(Entity, &mut Handle<Mesh>, &GlobalTransform), Or<(With<StepModel>, With<ResultShape>)>>,
+    ) {
+        if !cli_args.meshlet {
+            return;
+        }
+        let Ok(camera_transform) = camera_query.get_single() else { return };
+
+        for (entity, mut mesh_handle, transform) in &mut query {
+            let Some(asset) = state.assets.get(&entity) else { continue };
+
+            let object_radius = asset.meshlets.iter().map(|m| m.bounding_sphere.1).fold(0.0_f32, f32::max).max(1e-3);
+            let distance = (camera_transform.translation() - transform.translation()).length();
+            // Screen-space scale shrinks as the object gets smaller on
+            // screen (further away, or physically small), loosening the
+            // error budget `select_lod` allows.
+            let screen_space_scale = (object_radius / distance).min(1.0);
+            let selected = asset.select_lod(object_radius * 0.02, screen_space_scale);
+
+            if state.last_selection.get(&entity) == Some(&selected) {
+                continue;
+            }
+
+            *mesh_handle = meshes.add(flatten_selected_meshlets(asset, &selected));
+            state.last_selection.insert(entity, selected);
+        }
+    }
+
+    /// Flatten the meshlets in `selected` into one real triangle mesh,
+    /// deduplicating vertices shared between meshlets and generating flat
+    /// per-triangle normals (smoothed per shared vertex), since a coarse LOD
+    /// mesh only needs to look plausible from the distance it's selected at.
+    fn flatten_selected_meshlets(asset: &MeshletAsset, selected: &[usize]) -> Mesh {
+        let mut positions: Vec<[f32; 3]> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+        let mut remap: HashMap<u32, u32> = HashMap::new();
+
+        for &m in selected {
+            let meshlet = &asset.meshlets[m];
+            for tri in &meshlet.triangles {
+                for &local in tri {
+                    let global = meshlet.vertices[local as usize];
+                    let new_index = *remap.entry(global).or_insert_with(|| {
+                        positions.push(asset.vertices[global as usize]);
+                        (positions.len() - 1) as u32
+                    });
+                    indices.push(new_index);
+                }
+            }
+        }
+
+        let normals = flat_vertex_normals(&positions, &indices);
+
+        let mut mesh = Mesh::new(bevy::render::mesh::PrimitiveTopology::TriangleList, default());
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, VertexAttributeValues::Float32x3(positions));
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, VertexAttributeValues::Float32x3(normals));
+        mesh.insert_indices(Indices::U32(indices));
+        mesh
+    }
+
+    /// Sum-then-normalize per-vertex normals from face-normal contributions,
+    /// good enough for a temporary LOD proxy mesh without the angle
+    /// weighting `bevy_step_loader::normals` uses for the real import.
+    fn flat_vertex_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+        let verts: Vec<Vec3> = positions.iter().map(|p| Vec3::from_array(*p)).collect();
+        let mut accum = vec![Vec3::ZERO; positions.len()];
+
+        for tri in indices.chunks_exact(3) {
+            let (a, b, c) = (verts[tri[0] as usize], verts[tri[1] as usize], verts[tri[2] as usize]);
+            let normal = (b - a).cross(c - a);
+            for &i in tri {
+                accum[i as usize] += normal;
+            }
+        }
+
+        accum.into_iter().map(|n| if n == Vec3::ZERO { Vec3::Y } else { n.normalize() }.to_array()).collect()
+    }
+}
+
+/// Stub used when the crate isn't built with the `meshlet` feature: these
+/// systems are still registered in `main`'s system tuple (so wiring doesn't
+/// need feature-specific branches) but do nothing, leaving every mesh on
+/// the regular full-resolution `PbrBundle` path.
+#[cfg(not(feature = "meshlet"))]
+mod meshlet_lod {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    pub struct MeshletLodState;
+
+    pub fn rebuild_meshlet_cache(_cli_args: Res<CliArgs>) {}
+    pub fn apply_meshlet_lod(_cli_args: Res<CliArgs>) {}
+}
+
+/// Full-screen ordered-dithering post-process, rendered after tonemapping
+/// (`AcesFitted`) and before the rest of `EndMainPassPostProcessing`, so it
+/// quantizes the same tonemapped colors the viewer sees rather than
+/// linear/HDR values. Built the same way Bevy's own custom post-process
+/// examples are: an `ExtractComponent`-driven settings component carries
+/// `levels`/`matrix_size` into the render world as a uniform, a `ViewNode`
+/// samples the main color target through a WGSL fragment shader
+/// (`assets/shaders/dither_post_process.wgsl`) that quantizes each channel
+/// against a 4x4 or 8x8 Bayer threshold matrix, and the pass is entirely
+/// absent for any view without the settings component — which is how
+/// `sync_post_process_settings` turns it on and off at runtime.
+mod dither_post_process {
+    use bevy::{
+        core_pipeline::{
+            core_3d::graph::{Core3d, Node3d},
+            fullscreen_vertex_shader::fullscreen_shader_vertex_state,
+        },
+        ecs::query::QueryItem,
+        prelude::*,
+        render::{
+            extract_component::{ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin, UniformComponentPlugin},
+            render_graph::{NodeRunError, RenderGraphApp, RenderGraphContext, RenderLabel, ViewNode, ViewNodeRunner},
+            render_resource::{
+                binding_types::{sampler, texture_2d, uniform_buffer},
+                BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries, CachedRenderPipelineId, ColorTargetState, ColorWrites, FragmentState,
+                MultisampleState, Operations, PipelineCache, PrimitiveState, RenderPassColorAttachment, RenderPassDescriptor, RenderPipelineDescriptor,
+                Sampler, SamplerBindingType, SamplerDescriptor, ShaderStages, ShaderType, TextureFormat, TextureSampleType,
+            },
+            renderer::{RenderContext, RenderDevice},
+            texture::BevyDefault,
+            view::ViewTarget,
+            RenderApp,
+        },
+    };
+
+    const SHADER_ASSET_PATH: &str = "shaders/dither_post_process.wgsl";
+
+    pub struct DitherPostProcessPlugin;
+
+    impl Plugin for DitherPostProcessPlugin {
+        fn build(&self, app: &mut App) {
+            app.add_plugins((
+                ExtractComponentPlugin::<DitherPostProcessSettings>::default(),
+                UniformComponentPlugin::<DitherPostProcessSettings>::default(),
+            ));
+
+            let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+            render_app
+                .add_render_graph_node::<ViewNodeRunner<DitherPostProcessNode>>(Core3d, DitherPostProcessLabel)
+                .add_render_graph_edges(Core3d, (Node3d::Tonemapping, DitherPostProcessLabel, Node3d::EndMainPassPostProcessing));
+        }
+
+        fn finish(&self, app: &mut App) {
+            let Some(render_app) = app.get_sub_app_mut(RenderApp) else { return };
+            render_app.init_resource::<DitherPostProcessPipeline>();
+        }
+    }
+
+    #[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+    struct DitherPostProcessLabel;
+
+    #[derive(Default)]
+    struct DitherPostProcessNode;
+
+    impl ViewNode for DitherPostProcessNode {
+        type ViewQuery = (&'static ViewTarget, &'static DynamicUniformIndex<DitherPostProcessSettings>);
+
+        fn run(
+            &self,
+            _graph: &mut RenderGraphContext,
+            render_context: &mut RenderContext,
+            (view_target, settings_index): QueryItem<Self::ViewQuery>,
+            world: &World,
+        ) -> Result<(), NodeRunError> {
+            let post_process_pipeline = world.resource::<DitherPostProcessPipeline>();
+            let pipeline_cache = world.resource::<PipelineCache>();
+            let Some(pipeline) = pipeline_cache.get_render_pipeline(post_process_pipeline.pipeline_id) else { return Ok(()) };
+            let settings_uniforms = world.resource::<ComponentUniforms<DitherPostProcessSettings>>();
+            let Some(settings_binding) = settings_uniforms.uniforms().binding() else { return Ok(()) };
+
+            let post_process = view_target.post_process_write();
+
+            let bind_group = render_context.render_device().create_bind_group(
+                "dither_post_process_bind_group",
+                &post_process_pipeline.layout,
+                &BindGroupEntries::sequential((post_process.source, &post_process_pipeline.sampler, settings_binding.clone())),
+            );
+
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("dither_post_process_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment { view: post_process.destination, resolve_target: None, ops: Operations::default() })],
+                depth_stencil_attachment: None,
+                timestamp_writes: None,
+                occlusion_query_set: None,
+            });
+
+            render_pass.set_render_pipeline(pipeline);
+            render_pass.set_bind_group(0, &bind_group, &[settings_index.index()]);
+            render_pass.draw(0..3, 0..1);
+
+            Ok(())
+        }
+    }
+
+    #[derive(Resource)]
+    struct DitherPostProcessPipeline {
+        layout: BindGroupLayout,
+        sampler: Sampler,
+        pipeline_id: CachedRenderPipelineId,
+    }
+
+    impl FromWorld for DitherPostProcessPipeline {
+        fn from_world(world: &mut World) -> Self {
+            let render_device = world.resource::<RenderDevice>();
+
+            let layout = render_device.create_bind_group_layout(
+                "dither_post_process_bind_group_layout",
+                &BindGroupLayoutEntries::sequential(
+                    ShaderStages::FRAGMENT,
+                    (
+                        texture_2d(TextureSampleType::Float { filterable: true }),
+                        sampler(SamplerBindingType::Filtering),
+                        uniform_buffer::<DitherPostProcessSettings>(true),
+                    ),
+                ),
+            );
+
+            let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+            let shader = world.resource::<AssetServer>().load(SHADER_ASSET_PATH);
+
+            let pipeline_id = world.resource_mut::<PipelineCache>().queue_render_pipeline(RenderPipelineDescriptor {
+                label: Some("dither_post_process_pipeline".into()),
+                layout: vec![layout.clone()],
+                vertex: fullscreen_shader_vertex_state(),
+                fragment: Some(FragmentState {
+                    shader,
+                    shader_defs: vec![],
+                    entry_point: "fragment".into(),
+                    targets: vec![Some(ColorTargetState { format: TextureFormat::bevy_default(), blend: None, write_mask: ColorWrites::ALL })],
+                }),
+                primitive: PrimitiveState::default(),
+                depth_stencil: None,
+                multisample: MultisampleState::default(),
+                push_constant_ranges: vec![],
+            });
+
+            Self { layout, sampler, pipeline_id }
+        }
+    }
+
+    /// `levels` and `matrix_size` mirror `super::PostProcessState`'s own
+    /// fields; kept as a separate (uniform-buffer-shaped) component here
+    /// since `ExtractComponent`/`ShaderType` need a type that round-trips
+    /// straight into the render world's uniform buffer, not a gameplay
+    /// resource with an `enabled` flag the shader never needs to see —
+    /// absence of the component IS "disabled" for this pass.
+    #[derive(Component, Default, Clone, Copy, ExtractComponent, ShaderType)]
+    pub struct DitherPostProcessSettings {
+        pub levels: f32,
+        pub matrix_size: f32,
     }
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+//! Bounding-box and centroid queries, so demo/caller code can position
+//! operands relative to each other instead of hand-guessing `Transform`
+//! offsets. Reuses [`crate::bvh::Aabb`] rather than defining a second
+//! bounding-box type — [`bounds`] is just the whole-mesh version of the
+//! per-node box [`crate::bvh::Bvh3`] already builds internally.
+
+use crate::bvh::Aabb;
+use crate::{from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+
+/// Which face of `b`'s [`Aabb`] [`align`] brings into contact with `a`'s —
+/// e.g. `PosX` slides `b` along X until its min-X face touches `a`'s max-X
+/// face, stacking `b` just past `a` in the +X direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+/// `mesh`'s axis-aligned bounding box, computed directly from its vertex
+/// buffer (not the padded boxes [`crate::bvh::Bvh3`] builds for broad-phase
+/// culling).
+pub fn bounds(mesh: &Impl) -> Aabb {
+    let mesh_gl = get_mesh_gl(mesh);
+    positions_of(&mesh_gl).fold(Aabb { min: Vector3::repeat(f64::INFINITY), max: Vector3::repeat(f64::NEG_INFINITY) }, |acc, p| {
+        Aabb { min: acc.min.inf(&p), max: acc.max.sup(&p) }
+    })
+}
+
+/// The mean of `mesh`'s vertex positions. Distinct from `bounds(mesh)`'s
+/// midpoint whenever vertex density is uneven across the mesh (e.g. one
+/// densely tessellated face and one coarse one) — callers wanting the
+/// bounding-box midpoint instead should average `bounds(mesh).min`/`.max`
+/// directly.
+pub fn centroid(mesh: &Impl) -> Vector3<f64> {
+    let mesh_gl = get_mesh_gl(mesh);
+    let mut sum = Vector3::repeat(0.0);
+    let mut count = 0usize;
+    for p in positions_of(&mesh_gl) {
+        sum += p;
+        count += 1;
+    }
+    if count == 0 {
+        sum
+    } else {
+        sum / count as f64
+    }
+}
+
+/// Translate `mesh` so its vertex centroid ([`centroid`]) lands at the
+/// origin.
+pub fn center_on_origin(mesh: &Impl) -> Impl {
+    translate(mesh, -centroid(mesh))
+}
+
+/// Translate `b` so the `axis` face of its [`Aabb`] touches the
+/// corresponding face of `a`'s, with the other two axes recentered on
+/// the midpoint of `a`'s [`Aabb`] (not its vertex [`centroid`]) — the common "stack this next to that" layout a
+/// hand-placed demo `Transform` is otherwise approximating by eye.
+pub fn align(a: &Impl, b: &Impl, axis: Axis) -> Impl {
+    let a_bounds = bounds(a);
+    let b_bounds = bounds(b);
+    let a_center = (a_bounds.min + a_bounds.max) * 0.5;
+    let b_center = (b_bounds.min + b_bounds.max) * 0.5;
+
+    let mut offset = a_center - b_center;
+    match axis {
+        Axis::PosX => offset.x = a_bounds.max.x - b_bounds.min.x,
+        Axis::NegX => offset.x = a_bounds.min.x - b_bounds.max.x,
+        Axis::PosY => offset.y = a_bounds.max.y - b_bounds.min.y,
+        Axis::NegY => offset.y = a_bounds.min.y - b_bounds.max.y,
+        Axis::PosZ => offset.z = a_bounds.max.z - b_bounds.min.z,
+        Axis::NegZ => offset.z = a_bounds.min.z - b_bounds.max.z,
+    }
+
+    translate(b, offset)
+}
+
+fn positions_of(mesh_gl: &MeshGL) -> impl Iterator<Item = Vector3<f64>> + '_ {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    mesh_gl.vert_properties.chunks(num_prop).map(|v| Vector3::new(v[0] as f64, v[1] as f64, v[2] as f64))
+}
+
+fn translate(mesh: &Impl, offset: Vector3<f64>) -> Impl {
+    let mesh_gl = get_mesh_gl(mesh);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let mut vert_properties = mesh_gl.vert_properties.clone();
+    for v in vert_properties.chunks_mut(num_prop) {
+        v[0] += offset.x as f32;
+        v[1] += offset.y as f32;
+        v[2] += offset.z as f32;
+    }
+    from_mesh_gl(MeshGL { vert_properties, num_prop: mesh_gl.num_prop, tri_verts: mesh_gl.tri_verts.clone(), ..Default::default() })
+}
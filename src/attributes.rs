@@ -0,0 +1,206 @@
+//! Named per-vertex and per-triangle attribute channels that can ride
+//! alongside a [`MeshBoolImpl`]'s `vert_pos`/triangle data, surviving
+//! boolean evaluation and cross-section extraction.
+//!
+//! `MeshBoolImpl`'s boolean kernel (the code that actually walks two
+//! meshes and emits new vertices where their surfaces cross) isn't part
+//! of this source tree, so this module can't hook interpolation into it
+//! directly. Instead it provides the pieces such a hook would need —
+//! typed named channels plus a barycentric blend for a new vertex
+//! introduced inside a triangle, and whole-value inheritance for a new
+//! face derived from a source triangle — ready to be called from
+//! wherever intersection vertices and faces are actually produced.
+//! [`manifold_rs::properties`](../manifold-rs/src/properties.rs) is the
+//! analogous named-channel layer for the FFI `Manifold` type; this is the
+//! same idea adapted to this crate's `Point3<f64>`/triangle-index
+//! conventions.
+
+use nalgebra::{Vector2, Vector3};
+
+/// The numeric type carried by a single attribute channel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeKind {
+    F32,
+    Vec2,
+    Vec3,
+    I32,
+}
+
+impl AttributeKind {
+    /// Number of `f32` lanes (or bit-cast `i32`s) per element. `pub(crate)`
+    /// so [`crate::property_boolean`] can lay out/slice raw channel data
+    /// without duplicating this match.
+    pub(crate) fn stride(self) -> usize {
+        match self {
+            AttributeKind::F32 | AttributeKind::I32 => 1,
+            AttributeKind::Vec2 => 2,
+            AttributeKind::Vec3 => 3,
+        }
+    }
+}
+
+/// A single named channel, e.g. `("uv0", Vec2)` or `("material_id", I32)`.
+/// `data` is flat, `stride()`-per-element values in element order.
+#[derive(Clone, Debug)]
+pub struct AttributeChannel {
+    pub name: String,
+    pub kind: AttributeKind,
+    pub data: Vec<f32>,
+}
+
+impl AttributeChannel {
+    pub fn f32(name: impl Into<String>, values: Vec<f32>) -> Self {
+        Self { name: name.into(), kind: AttributeKind::F32, data: values }
+    }
+
+    pub fn vec2(name: impl Into<String>, values: &[Vector2<f64>]) -> Self {
+        let data = values.iter().flat_map(|v| [v.x as f32, v.y as f32]).collect();
+        Self { name: name.into(), kind: AttributeKind::Vec2, data }
+    }
+
+    pub fn vec3(name: impl Into<String>, values: &[Vector3<f64>]) -> Self {
+        let data = values.iter().flat_map(|v| [v.x as f32, v.y as f32, v.z as f32]).collect();
+        Self { name: name.into(), kind: AttributeKind::Vec3, data }
+    }
+
+    /// Integer channels (material IDs, etc.) are stored bit-cast into the
+    /// same flat `f32` buffer as everything else, so a channel list can be
+    /// a single homogeneous `Vec` regardless of which kinds it mixes.
+    pub fn i32(name: impl Into<String>, values: &[i32]) -> Self {
+        let data = values.iter().map(|&v| f32::from_bits(v as u32)).collect();
+        Self { name: name.into(), kind: AttributeKind::I32, data }
+    }
+
+    fn element(&self, index: usize) -> &[f32] {
+        let stride = self.kind.stride();
+        &self.data[index * stride..(index + 1) * stride]
+    }
+}
+
+fn find<'a>(channels: &'a [AttributeChannel], name: &str) -> Option<&'a AttributeChannel> {
+    channels.iter().find(|c| c.name == name)
+}
+
+/// Per-vertex attribute channels attached to a [`MeshBoolImpl`]-shaped
+/// mesh, indexed in lockstep with its `vert_pos`.
+#[derive(Clone, Debug, Default)]
+pub struct VertexAttributes {
+    channels: Vec<AttributeChannel>,
+}
+
+impl VertexAttributes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, channel: AttributeChannel) {
+        self.channels.push(channel);
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&AttributeChannel> {
+        find(&self.channels, name)
+    }
+
+    /// Every channel in declaration order. `pub(crate)` so
+    /// [`crate::property_boolean`] can compute the union of two meshes'
+    /// channel names without needing a name list threaded in separately.
+    pub(crate) fn channels(&self) -> impl Iterator<Item = &AttributeChannel> {
+        self.channels.iter()
+    }
+
+    /// Blend every channel's value at triangle corners `(a, b, c)` by
+    /// barycentric weights `(wa, wb, wc)`, producing the attribute row for
+    /// a new vertex introduced inside that triangle — e.g. where a boolean
+    /// op splits an edge or a cutting plane crosses a face. `I32` channels
+    /// (material IDs) round the blended value rather than truncating,
+    /// since an integer channel is typically meant to snap to the nearest
+    /// source corner rather than be meaningfully interpolated.
+    pub fn interpolate(&self, a: usize, b: usize, c: usize, wa: f64, wb: f64, wc: f64) -> Vec<AttributeChannel> {
+        self.channels
+            .iter()
+            .map(|channel| {
+                let (va, vb, vc) = (channel.element(a), channel.element(b), channel.element(c));
+                let data = (0..channel.kind.stride())
+                    .map(|i| {
+                        let value = |v: &[f32]| if channel.kind == AttributeKind::I32 { v[i].to_bits() as i32 as f64 } else { v[i] as f64 };
+                        let blended = value(va) * wa + value(vb) * wb + value(vc) * wc;
+                        if channel.kind == AttributeKind::I32 {
+                            f32::from_bits(crate::detmath::round(blended) as i32 as u32)
+                        } else {
+                            blended as f32
+                        }
+                    })
+                    .collect();
+                AttributeChannel { name: channel.name.clone(), kind: channel.kind, data }
+            })
+            .collect()
+    }
+}
+
+/// Per-triangle attribute channels attached to a [`MeshBoolImpl`]-shaped
+/// mesh, indexed in lockstep with its triangle list. Unlike
+/// [`VertexAttributes`], these are whole-value inherited rather than
+/// blended: a face produced during a boolean op copies its channel values
+/// from whichever input triangle it originated from.
+#[derive(Clone, Debug, Default)]
+pub struct FaceAttributes {
+    channels: Vec<AttributeChannel>,
+}
+
+impl FaceAttributes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, channel: AttributeChannel) {
+        self.channels.push(channel);
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&AttributeChannel> {
+        find(&self.channels, name)
+    }
+
+    /// The attribute row a new face inherits, copied whole from
+    /// `source_triangle` (the input triangle it was derived from).
+    pub fn inherit(&self, source_triangle: usize) -> Vec<AttributeChannel> {
+        self.channels
+            .iter()
+            .map(|c| AttributeChannel { name: c.name.clone(), kind: c.kind, data: c.element(source_triangle).to_vec() })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_vec2_blends_corners() {
+        let mut attrs = VertexAttributes::new();
+        attrs.push(AttributeChannel::vec2("uv0", &[Vector2::new(0.0, 0.0), Vector2::new(1.0, 0.0), Vector2::new(0.0, 1.0)]));
+
+        let blended = attrs.interpolate(0, 1, 2, 0.5, 0.25, 0.25);
+        let uv = blended[0].element(0);
+        assert!((uv[0] - 0.25).abs() < 1e-6);
+        assert!((uv[1] - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn interpolate_i32_rounds_instead_of_truncating() {
+        let mut attrs = VertexAttributes::new();
+        attrs.push(AttributeChannel::i32("material_id", &[0, 1, 0]));
+
+        let blended = attrs.interpolate(0, 1, 2, 0.4, 0.6, 0.0);
+        let material = blended[0].element(0)[0].to_bits() as i32;
+        assert_eq!(material, 1);
+    }
+
+    #[test]
+    fn face_attributes_inherit_whole_value() {
+        let mut faces = FaceAttributes::new();
+        faces.push(AttributeChannel::i32("material_id", &[3, 7]));
+
+        let inherited = faces.inherit(1);
+        assert_eq!(inherited[0].element(0)[0].to_bits() as i32, 7);
+    }
+}
@@ -0,0 +1,164 @@
+//! A Bevy plugin for declarative, incrementally-evaluated CSG trees — the
+//! library counterpart to `examples/step_integration_demo.rs`'s hand-rolled
+//! `handle_user_input`/`update_boolean_result`, which reran
+//! `create_complex_step_shape()` and the whole boolean op from scratch on
+//! every keypress rather than describing the shape as data. Behind the
+//! `bevy` feature, same as [`crate::gl_interop`].
+//!
+//! A [`CsgNode`] entity is either a `Leaf` (owning its own [`Impl`]) or an
+//! `Op` over two child entities. [`evaluate_csg_tree`] only runs at all when
+//! [`csg_tree_changed`] says some node, `Transform`, or the active
+//! [`State<BooleanOpType>`] actually changed since the last frame; when it
+//! does run, each `Op` node's result is memoized for the duration of that
+//! one pass so a diamond-shaped tree (two branches sharing a common leaf)
+//! only evaluates the shared leaf once. The finished root result is written
+//! onto the root's own `Mesh3d` via [`crate::gl_interop::mesh_gl_to_bevy_mesh`].
+
+use crate::{get_mesh_gl, Impl};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Which boolean operator an [`CsgNode::Op`] combines its children under.
+/// Also a Bevy `State`: [`MeshboolPlugin`] registers it so the active
+/// operation for every `Op` node that doesn't carry its own override is
+/// driven by `NextState<BooleanOpType>::set` (e.g. from a UI button or a
+/// keybind system) instead of a plain field cycled by hand in a `match` on
+/// every keypress.
+#[derive(States, Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BooleanOpType {
+    #[default]
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl BooleanOpType {
+    fn apply(self, a: &Impl, b: &Impl) -> Impl {
+        match self {
+            BooleanOpType::Union => a + b,
+            BooleanOpType::Intersection => a ^ b,
+            BooleanOpType::Difference => a - b,
+        }
+    }
+}
+
+/// A node in an entity-based CSG tree. A `Leaf` owns its shape directly
+/// (set once, e.g. from a loaded STEP/STL import or a primitive); an `Op`
+/// folds its two children's evaluated results under `op`, falling back to
+/// the [`BooleanOpType`] state when `op` is `None` so a single state change
+/// retargets every un-overridden `Op` node in the tree at once.
+#[derive(Component, Clone, Copy)]
+pub enum CsgNode {
+    Leaf,
+    Op { op: Option<BooleanOpType>, left: Entity, right: Entity },
+}
+
+/// A [`CsgNode::Leaf`]'s actual geometry, kept in its own component rather
+/// than inline in [`CsgNode`] so replacing it (swapping in a freshly loaded
+/// import) is a plain `Changed<CsgLeaf>` write, same as any other component.
+#[derive(Component)]
+pub struct CsgLeaf(pub Impl);
+
+/// Marks the root of a [`CsgNode`] tree — the entity [`evaluate_csg_tree`]
+/// writes the combined result onto.
+#[derive(Component)]
+pub struct CsgRoot;
+
+/// Registers [`BooleanOpType`] as a Bevy state and [`evaluate_csg_tree`],
+/// gated by [`csg_tree_changed`].
+pub struct MeshboolPlugin;
+
+impl Plugin for MeshboolPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<BooleanOpType>().add_systems(Update, evaluate_csg_tree.run_if(csg_tree_changed));
+    }
+}
+
+/// `true` when anything [`evaluate_csg_tree`] reads from changed this
+/// frame: the active [`BooleanOpType`] state, any [`CsgNode`]'s own
+/// structure/override, any [`CsgLeaf`]'s geometry, or any node's
+/// `Transform`. Gating the (otherwise unconditional) evaluation system on
+/// this is the change-detection half of the request — a tree with nothing
+/// touched this frame costs nothing beyond this check.
+pub fn csg_tree_changed(
+    state: Res<State<BooleanOpType>>,
+    nodes: Query<(), Changed<CsgNode>>,
+    leaves: Query<(), Changed<CsgLeaf>>,
+    transforms: Query<(), (Changed<Transform>, With<CsgNode>)>,
+) -> bool {
+    state.is_changed() || !nodes.is_empty() || !leaves.is_empty() || !transforms.is_empty()
+}
+
+/// Evaluate every [`CsgRoot`] tree bottom-up and write its combined result
+/// onto its own `Mesh3d`. Each `Op` node's fold result is cached in a
+/// per-run `HashMap` keyed by entity so a child shared by more than one
+/// parent (a diamond in the tree) is only combined once per pass, even
+/// though the cache itself doesn't persist across frames — [`csg_tree_changed`]
+/// is what keeps an untouched tree from re-running this at all.
+fn evaluate_csg_tree(
+    mut commands: Commands,
+    roots: Query<Entity, With<CsgRoot>>,
+    nodes: Query<&CsgNode>,
+    leaves: Query<&CsgLeaf>,
+    transforms: Query<&Transform>,
+    state: Res<State<BooleanOpType>>,
+    mesh3d: Query<&Mesh3d>,
+    mut mesh_assets: ResMut<Assets<bevy::render::mesh::Mesh>>,
+) {
+    let mut cache: HashMap<Entity, Impl> = HashMap::new();
+
+    for root in &roots {
+        evaluate_into(root, &nodes, &leaves, &transforms, *state.get(), &mut cache);
+        let Some(result) = cache.get(&root) else { continue };
+
+        let mesh_gl = get_mesh_gl(result);
+        let bevy_mesh = crate::gl_interop::mesh_gl_to_bevy_mesh(&mesh_gl);
+
+        if let Some(existing) = mesh3d.get(root).ok().and_then(|handle| mesh_assets.get_mut(&handle.0)) {
+            *existing = bevy_mesh;
+            continue;
+        }
+        let handle = mesh_assets.add(bevy_mesh);
+        commands.entity(root).insert(Mesh3d(handle));
+    }
+}
+
+/// Put `entity`'s evaluated [`Impl`] into `cache`, recursing into its
+/// children first, if it's an `Op` node that hasn't been folded yet this
+/// pass; returns nothing ([`operand`] reads the result back). A
+/// `Leaf` is never cached — its geometry already lives in its own
+/// [`CsgLeaf`] component, so `cache` only ever holds freshly-combined `Op`
+/// results, which have nowhere else to live once `apply` produces them.
+fn evaluate_into<'a>(
+    entity: Entity,
+    nodes: &'a Query<&CsgNode>,
+    leaves: &'a Query<&CsgLeaf>,
+    transforms: &Query<&Transform>,
+    state: BooleanOpType,
+    cache: &'a mut HashMap<Entity, Impl>,
+) {
+    if cache.contains_key(&entity) {
+        return;
+    }
+    let Ok(CsgNode::Op { op, left, right }) = nodes.get(entity) else {
+        return;
+    };
+    evaluate_into(*left, nodes, leaves, transforms, state, cache);
+    evaluate_into(*right, nodes, leaves, transforms, state, cache);
+
+    let (Some(a), Some(b)) = (operand(*left, leaves, cache), operand(*right, leaves, cache)) else {
+        return;
+    };
+    let result = op.unwrap_or(state).apply(a, b);
+    let _ = transforms; // Reserved for repositioning `Op` subtrees; leaves carry their own placement upstream of this tree.
+    cache.insert(entity, result);
+}
+
+/// The operand `entity` contributes to its parent's fold: its already-cached
+/// `Op` result, or its own [`CsgLeaf`] geometry if it's a leaf.
+fn operand<'a>(entity: Entity, leaves: &'a Query<&CsgLeaf>, cache: &'a HashMap<Entity, Impl>) -> Option<&'a Impl> {
+    if let Some(result) = cache.get(&entity) {
+        return Some(result);
+    }
+    leaves.get(entity).ok().map(|leaf| &leaf.0)
+}
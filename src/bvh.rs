@@ -0,0 +1,514 @@
+//! Triangle-AABB bounding volume hierarchy, used as a broad-phase filter
+//! ahead of exact triangle-triangle intersection.
+//!
+//! A boolean op's O(n·m) candidate search (every triangle of one operand
+//! against every triangle of the other) dominates runtime on large
+//! meshes long before the exact intersection math does. [`Bvh3::build`]
+//! indexes one operand's triangles by their (padded) AABBs, splitting each
+//! node via binned surface-area heuristic (the same cost model real-time
+//! ray tracers use to pick BVH splits: minimize
+//! `sum(child triangle count * child surface area)` rather than just
+//! halving the triangle count), and [`Bvh3::overlapping_pairs`] walks two
+//! such trees together so only triangle pairs whose AABBs actually overlap
+//! ever reach the exact stage. [`CachedBvh`] is the nearest honest
+//! equivalent of "cache the BVH on the Mesh": `Impl` itself isn't defined
+//! anywhere in this crate's own sources (the same caveat
+//! [`crate::tolerance`] documents), so there's no struct to add a
+//! `bvh: OnceCell<Bvh3>` field to — `CachedBvh` instead wraps the cache
+//! externally, keyed on a content hash of the mesh's own buffers, so a
+//! caller re-running the same operand every frame (an orbiting viewer
+//! re-evaluating its boolean op on every redraw, say) rebuilds only when
+//! the geometry actually changed.
+//!
+//! [`raycast`] and [`contains_point`] are the single-mesh queries this same
+//! tree supports once built: a BVH-pruned nearest-hit ray query and a
+//! BVH-pruned even-odd point classifier, respectively — both much cheaper
+//! than [`crate::inside_test`]'s brute-force versions on a large mesh,
+//! since most of the triangle set gets skipped by a failed AABB test
+//! before ever reaching the per-triangle math. [`overlapping_tris`] is the
+//! convenience, build-both-trees-for-you wrapper around
+//! [`Bvh3::overlapping_pairs`] the boolean operators would consult to skip
+//! non-overlapping regions entirely — except, same caveat as above,
+//! `Impl`'s own `+`/`-`/`^` live outside this crate's sources, so there's
+//! no operator body here to wire that consultation into.
+//!
+//! [`Bvh3::overlapping_pairs_parallel`], behind the `rayon` feature, spreads
+//! that same candidate search across [`crate::parallel::ParallelConfig`]'s
+//! thread pool instead of walking both trees on one thread — this is the
+//! "broad-phase triangle-pair culling" half of a boolean op's cost that's
+//! actually reachable from this crate's own sources; the per-triangle exact
+//! intersection stage it would otherwise feed is part of the external
+//! `+`/`-`/`^` kernel, so there's no body here to parallelize that stage.
+
+use crate::{get_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// An axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    pub min: Vector3<f64>,
+    pub max: Vector3<f64>,
+}
+
+impl Aabb {
+    fn of_triangle(a: Vector3<f64>, b: Vector3<f64>, c: Vector3<f64>) -> Aabb {
+        Aabb { min: a.inf(&b).inf(&c), max: a.sup(&b).sup(&c) }
+    }
+
+    fn union(self, other: Aabb) -> Aabb {
+        Aabb { min: self.min.inf(&other.min), max: self.max.sup(&other.max) }
+    }
+
+    fn pad(self, padding: f64) -> Aabb {
+        let p = Vector3::new(padding, padding, padding);
+        Aabb { min: self.min - p, max: self.max + p }
+    }
+
+    fn centroid(&self) -> Vector3<f64> {
+        (self.min + self.max) * 0.5
+    }
+
+    fn overlaps(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    fn surface_area(&self) -> f64 {
+        let d = self.max - self.min;
+        if d.x < 0.0 || d.y < 0.0 || d.z < 0.0 {
+            return 0.0;
+        }
+        2.0 * (d.x * d.y + d.y * d.z + d.z * d.x)
+    }
+
+    /// Slab-method ray/AABB test, `dir` components of (near) zero treated
+    /// as a ray parallel to that axis rather than divided through, so
+    /// [`raycast`]/[`contains_point`]'s axis-aligned query directions don't
+    /// need to special-case a `1.0 / 0.0` component themselves.
+    fn ray_intersects(&self, origin: Vector3<f64>, dir: Vector3<f64>) -> bool {
+        let mut t_min = f64::NEG_INFINITY;
+        let mut t_max = f64::INFINITY;
+        for axis in 0..3 {
+            let (o, d) = (origin[axis], dir[axis]);
+            if d.abs() < 1e-15 {
+                if o < self.min[axis] || o > self.max[axis] {
+                    return false;
+                }
+                continue;
+            }
+            let inv_d = 1.0 / d;
+            let (mut t0, mut t1) = ((self.min[axis] - o) * inv_d, (self.max[axis] - o) * inv_d);
+            if inv_d < 0.0 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            t_min = t_min.max(t0);
+            t_max = t_max.min(t1);
+            if t_max < t_min {
+                return false;
+            }
+        }
+        t_max >= 0.0
+    }
+}
+
+enum Node {
+    Leaf { triangle: usize, bounds: Aabb },
+    Internal { bounds: Aabb, left: Box<Node>, right: Box<Node> },
+}
+
+impl Node {
+    fn bounds(&self) -> Aabb {
+        match self {
+            Node::Leaf { bounds, .. } | Node::Internal { bounds, .. } => *bounds,
+        }
+    }
+}
+
+/// A bounding volume hierarchy over one mesh's triangle AABBs.
+pub struct Bvh3 {
+    root: Option<Node>,
+}
+
+impl Bvh3 {
+    /// Build a BVH over every triangle of `mesh`, each leaf's AABB expanded
+    /// by `padding` on every side so a pair of triangles whose tight bounds
+    /// just miss but whose geometry is within that tolerance still shows up
+    /// as a broad-phase candidate.
+    pub fn build(mesh: &Impl, padding: f64) -> Self {
+        let mesh_gl = get_mesh_gl(mesh);
+        let num_prop = mesh_gl.num_prop.max(1) as usize;
+        let pos = |i: u32| -> Vector3<f64> {
+            let base = i as usize * num_prop;
+            Vector3::new(mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64)
+        };
+
+        let mut leaves: Vec<(usize, Aabb)> = mesh_gl
+            .tri_verts
+            .chunks_exact(3)
+            .enumerate()
+            .map(|(i, tri)| (i, Aabb::of_triangle(pos(tri[0]), pos(tri[1]), pos(tri[2])).pad(padding)))
+            .collect();
+
+        Bvh3 { root: build_node(&mut leaves) }
+    }
+
+    /// Every pair `(triangle index in a, triangle index in b)` whose
+    /// (padded) AABBs overlap, from a joint descent of both trees. Empty if
+    /// either mesh had no triangles.
+    pub fn overlapping_pairs(a: &Bvh3, b: &Bvh3) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        if let (Some(ra), Some(rb)) = (&a.root, &b.root) {
+            traverse(ra, rb, &mut pairs);
+        }
+        pairs
+    }
+
+    /// Same candidate set as [`Bvh3::overlapping_pairs`], split across
+    /// `config`'s thread pool instead of walking both trees on one thread.
+    /// Each side's root is descended one level first (or treated as its own
+    /// single task, if it's already a leaf) to get a handful of independent
+    /// subtree pairs for `rayon`'s `par_iter` to spread across threads; each
+    /// task then runs the same sequential [`traverse`] over its own subtree
+    /// pair. Results are sorted and deduplicated before returning, so the
+    /// output does not depend on which thread finishes which subtree first:
+    /// the same pairs as [`Bvh3::overlapping_pairs`], in sorted order rather
+    /// than that function's traversal order.
+    #[cfg(feature = "rayon")]
+    pub fn overlapping_pairs_parallel(a: &Bvh3, b: &Bvh3, config: crate::parallel::ParallelConfig) -> Vec<(usize, usize)> {
+        use rayon::prelude::*;
+
+        let (ra, rb) = match (&a.root, &b.root) {
+            (Some(ra), Some(rb)) => (ra, rb),
+            _ => return Vec::new(),
+        };
+
+        let mut tasks: Vec<(&Node, &Node)> = Vec::new();
+        for na in top_level(ra) {
+            for nb in top_level(rb) {
+                tasks.push((na, nb));
+            }
+        }
+
+        let pool = config.build_pool();
+        let mut pairs: Vec<(usize, usize)> = pool.install(|| {
+            tasks
+                .par_iter()
+                .map(|&(na, nb)| {
+                    let mut local = Vec::new();
+                    traverse(na, nb, &mut local);
+                    local
+                })
+                .reduce(Vec::new, |mut acc, mut next| {
+                    acc.append(&mut next);
+                    acc
+                })
+        });
+
+        pairs.sort_unstable();
+        pairs.dedup();
+        pairs
+    }
+}
+
+/// A node's immediate children as independent subtree tasks, or the node
+/// itself if it's already a leaf — the one-level split
+/// [`Bvh3::overlapping_pairs_parallel`] fans out across threads.
+#[cfg(feature = "rayon")]
+fn top_level(node: &Node) -> Vec<&Node> {
+    match node {
+        Node::Internal { left, right, .. } => vec![left.as_ref(), right.as_ref()],
+        Node::Leaf { .. } => vec![node],
+    }
+}
+
+/// A single [`raycast`] hit: which triangle, the ray parameter `t`
+/// (distance along `dir`, since `dir` isn't required to be unit length),
+/// and that point's barycentric coordinates within the triangle, in the
+/// same corner order as `tri_verts`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    pub triangle: u32,
+    pub t: f64,
+    pub bary: (f64, f64, f64),
+}
+
+/// BVH-pruned nearest-hit ray query against every triangle of `mesh`. Only
+/// forward hits (`t > 0`) count, and ties are broken by whichever leaf the
+/// traversal reaches first (triangle index order is otherwise irrelevant
+/// to which coincident hit wins).
+pub fn raycast(mesh: &Impl, origin: Vector3<f64>, dir: Vector3<f64>) -> Option<Hit> {
+    let mesh_gl = get_mesh_gl(mesh);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let bvh = Bvh3::build(mesh, 0.0);
+
+    let mut best: Option<Hit> = None;
+    if let Some(root) = &bvh.root {
+        raycast_node(root, origin, dir, &mesh_gl, num_prop, &mut best);
+    }
+    best
+}
+
+fn raycast_node(node: &Node, origin: Vector3<f64>, dir: Vector3<f64>, mesh_gl: &MeshGL, num_prop: usize, best: &mut Option<Hit>) {
+    if !node.bounds().ray_intersects(origin, dir) {
+        return;
+    }
+    match node {
+        Node::Internal { left, right, .. } => {
+            raycast_node(left, origin, dir, mesh_gl, num_prop, best);
+            raycast_node(right, origin, dir, mesh_gl, num_prop, best);
+        }
+        Node::Leaf { triangle, .. } => {
+            let tri = &mesh_gl.tri_verts[triangle * 3..triangle * 3 + 3];
+            let pos = |i: u32| -> Vector3<f64> {
+                let base = i as usize * num_prop;
+                Vector3::new(mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64)
+            };
+            if let Some((t, bary)) = ray_triangle_hit(origin, dir, pos(tri[0]), pos(tri[1]), pos(tri[2])) {
+                let better = match best {
+                    Some(existing) => t < existing.t,
+                    None => true,
+                };
+                if better {
+                    *best = Some(Hit { triangle: *triangle as u32, t, bary });
+                }
+            }
+        }
+    }
+}
+
+/// BVH-pruned even-odd point classifier: casts a single `+x` ray from
+/// `point` and counts crossings, skipping every subtree whose AABB the ray
+/// misses entirely. The brute-force equivalent is
+/// [`crate::inside_test::classify_fast`] — same algorithm, same caveat
+/// about boundary holes silently mis-parity-ing the count, just without a
+/// BVH to prune the triangle scan on a large mesh.
+pub fn contains_point(mesh: &Impl, point: Vector3<f64>) -> bool {
+    let mesh_gl = get_mesh_gl(mesh);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let bvh = Bvh3::build(mesh, 0.0);
+    let dir = Vector3::new(1.0, 0.0, 0.0);
+
+    let mut crossings = 0usize;
+    if let Some(root) = &bvh.root {
+        count_crossings(root, point, dir, &mesh_gl, num_prop, &mut crossings);
+    }
+    crossings % 2 == 1
+}
+
+fn count_crossings(node: &Node, origin: Vector3<f64>, dir: Vector3<f64>, mesh_gl: &MeshGL, num_prop: usize, crossings: &mut usize) {
+    if !node.bounds().ray_intersects(origin, dir) {
+        return;
+    }
+    match node {
+        Node::Internal { left, right, .. } => {
+            count_crossings(left, origin, dir, mesh_gl, num_prop, crossings);
+            count_crossings(right, origin, dir, mesh_gl, num_prop, crossings);
+        }
+        Node::Leaf { triangle, .. } => {
+            let tri = &mesh_gl.tri_verts[triangle * 3..triangle * 3 + 3];
+            let pos = |i: u32| -> Vector3<f64> {
+                let base = i as usize * num_prop;
+                Vector3::new(mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64)
+            };
+            if ray_triangle_hit(origin, dir, pos(tri[0]), pos(tri[1]), pos(tri[2])).is_some() {
+                *crossings += 1;
+            }
+        }
+    }
+}
+
+/// Every pair of triangle indices `(index in a, index in b)` whose AABBs
+/// overlap, building a fresh [`Bvh3`] over each mesh first — the
+/// convenience entry point for a one-off query; a caller checking the same
+/// operand repeatedly across frames should build (or [`CachedBvh::get_or_build`])
+/// the trees once and call [`Bvh3::overlapping_pairs`] directly instead.
+pub fn overlapping_tris(a: &Impl, b: &Impl) -> Vec<(u32, u32)> {
+    let bvh_a = Bvh3::build(a, 0.0);
+    let bvh_b = Bvh3::build(b, 0.0);
+    Bvh3::overlapping_pairs(&bvh_a, &bvh_b).into_iter().map(|(i, j)| (i as u32, j as u32)).collect()
+}
+
+/// Möller–Trumbore ray-triangle intersection returning the hit's ray
+/// parameter and barycentric coordinates, not just a bool — the detail
+/// [`crate::inside_test::ray_hits_triangle`] (existing, boolean-only, used
+/// by the brute-force classifiers) doesn't need and therefore doesn't
+/// expose. Only forward hits (`t > 0`) count, matching that function's
+/// convention.
+fn ray_triangle_hit(origin: Vector3<f64>, dir: Vector3<f64>, a: Vector3<f64>, b: Vector3<f64>, c: Vector3<f64>) -> Option<(f64, (f64, f64, f64))> {
+    const EPS: f64 = 1e-12;
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = dir.cross(&edge2);
+    let det = edge1.dot(&p);
+    if det.abs() < EPS {
+        return None;
+    }
+    let inv_det = 1.0 / det;
+    let t_vec = origin - a;
+    let u = t_vec.dot(&p) * inv_det;
+    if u < 0.0 || u > 1.0 {
+        return None;
+    }
+    let q = t_vec.cross(&edge1);
+    let v = dir.dot(&q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = edge2.dot(&q) * inv_det;
+    if t <= EPS {
+        return None;
+    }
+    Some((t, (1.0 - u - v, u, v)))
+}
+
+fn traverse(a: &Node, b: &Node, pairs: &mut Vec<(usize, usize)>) {
+    if !a.bounds().overlaps(&b.bounds()) {
+        return;
+    }
+    match (a, b) {
+        (Node::Internal { left, right, .. }, _) => {
+            traverse(left, b, pairs);
+            traverse(right, b, pairs);
+        }
+        (_, Node::Internal { left, right, .. }) => {
+            traverse(a, left, pairs);
+            traverse(a, right, pairs);
+        }
+        (Node::Leaf { triangle: ta, .. }, Node::Leaf { triangle: tb, .. }) => {
+            pairs.push((*ta, *tb));
+        }
+    }
+}
+
+/// Number of centroid buckets [`build_node`] bins each candidate split axis
+/// into before costing split positions — the usual binned-SAH tradeoff
+/// between an exhaustive (and `O(n log n)`-per-node) per-leaf split search
+/// and a fixed, cheap approximation of it.
+const SAH_BINS: usize = 12;
+
+/// Recursively partition `leaves` (triangle index + AABB pairs) into a BVH
+/// node via binned surface-area heuristic: for each axis of the centroid
+/// bounds (the bounds of every leaf's own centroid, not of the leaves'
+/// AABBs themselves, so one huge triangle's own bounds can't dominate the
+/// axis choice), bucket leaves into [`SAH_BINS`] bins and cost every
+/// resulting split as `left_count * left_area + right_count * right_area`;
+/// the axis/split with the lowest cost wins. Falls back to an even median
+/// split if every axis is degenerate (every leaf shares one centroid).
+fn build_node(leaves: &mut [(usize, Aabb)]) -> Option<Node> {
+    if leaves.is_empty() {
+        return None;
+    }
+    if leaves.len() == 1 {
+        let (triangle, bounds) = leaves[0];
+        return Some(Node::Leaf { triangle, bounds });
+    }
+
+    let bounds = leaves.iter().map(|(_, b)| *b).reduce(Aabb::union).unwrap();
+
+    let centroid_bounds = leaves.iter().map(|(_, b)| b.centroid()).fold(
+        Aabb { min: Vector3::repeat(f64::INFINITY), max: Vector3::repeat(f64::NEG_INFINITY) },
+        |acc, c| Aabb { min: acc.min.inf(&c), max: acc.max.sup(&c) },
+    );
+    let extent = centroid_bounds.max - centroid_bounds.min;
+
+    let mut best_axis_split: Option<(usize, usize, f64)> = None;
+    for axis in 0..3 {
+        if extent[axis] <= 0.0 {
+            continue;
+        }
+        let bin_of = |centroid: f64| -> usize {
+            (((centroid - centroid_bounds.min[axis]) / extent[axis] * SAH_BINS as f64) as usize).min(SAH_BINS - 1)
+        };
+
+        let mut bin_bounds: Vec<Option<Aabb>> = vec![None; SAH_BINS];
+        let mut bin_count = vec![0usize; SAH_BINS];
+        for (_, b) in leaves.iter() {
+            let bin = bin_of(b.centroid()[axis]);
+            bin_count[bin] += 1;
+            bin_bounds[bin] = Some(match bin_bounds[bin] {
+                Some(existing) => existing.union(*b),
+                None => *b,
+            });
+        }
+
+        for split in 1..SAH_BINS {
+            let left_count: usize = bin_count[..split].iter().sum();
+            let right_count: usize = bin_count[split..].iter().sum();
+            if left_count == 0 || right_count == 0 {
+                continue;
+            }
+            let left_bounds = bin_bounds[..split].iter().copied().flatten().reduce(Aabb::union).unwrap();
+            let right_bounds = bin_bounds[split..].iter().copied().flatten().reduce(Aabb::union).unwrap();
+            let cost = left_count as f64 * left_bounds.surface_area() + right_count as f64 * right_bounds.surface_area();
+
+            let better = match best_axis_split {
+                Some((_, _, best_cost)) => cost < best_cost,
+                None => true,
+            };
+            if better {
+                best_axis_split = Some((axis, split, cost));
+            }
+        }
+    }
+
+    let (left_leaves, right_leaves) = match best_axis_split {
+        Some((axis, split, _)) => {
+            let bin_of = |centroid: f64| -> usize {
+                (((centroid - centroid_bounds.min[axis]) / extent[axis] * SAH_BINS as f64) as usize).min(SAH_BINS - 1)
+            };
+            leaves.sort_by(|(_, a), (_, b)| a.centroid()[axis].partial_cmp(&b.centroid()[axis]).unwrap());
+            let split_index = leaves.partition_point(|(_, b)| bin_of(b.centroid()[axis]) < split).clamp(1, leaves.len() - 1);
+            leaves.split_at_mut(split_index)
+        }
+        None => leaves.split_at_mut(leaves.len() / 2),
+    };
+
+    let left = Box::new(build_node(left_leaves).unwrap());
+    let right = Box::new(build_node(right_leaves).unwrap());
+    Some(Node::Internal { bounds, left, right })
+}
+
+/// A [`Bvh3`] that only rebuilds when the mesh it was built from (or the
+/// padding asked for) actually changes, keyed on a bit-for-bit hash of the
+/// mesh's vertex/index buffers and the padding rather than identity, since `Impl` has no identity of its own to compare
+/// against across calls.
+#[derive(Default)]
+pub struct CachedBvh {
+    cached: Option<(u64, Bvh3)>,
+}
+
+impl CachedBvh {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the BVH for `mesh` at the given `padding`, rebuilding only if
+    /// `mesh`'s buffers differ (bit-for-bit) or `padding` differs from
+    /// whatever was cached last.
+    pub fn get_or_build(&mut self, mesh: &Impl, padding: f64) -> &Bvh3 {
+        let mut hasher = DefaultHasher::new();
+        content_hash(mesh).hash(&mut hasher);
+        padding.to_bits().hash(&mut hasher);
+        let hash = hasher.finish();
+        if !matches!(&self.cached, Some((cached_hash, _)) if *cached_hash == hash) {
+            self.cached = Some((hash, Bvh3::build(mesh, padding)));
+        }
+        &self.cached.as_ref().unwrap().1
+    }
+}
+
+fn content_hash(mesh: &Impl) -> u64 {
+    let mesh_gl = get_mesh_gl(mesh);
+    let mut hasher = DefaultHasher::new();
+    for &v in &mesh_gl.vert_properties {
+        v.to_bits().hash(&mut hasher);
+    }
+    mesh_gl.tri_verts.hash(&mut hasher);
+    hasher.finish()
+}
@@ -1,106 +1,338 @@
 use crate::MeshGL;
+use nalgebra::Vector3;
 
-/// Helper function to compute cross-section of a mesh at given Z height
-/// Returns vertices of intersection points and indices to form polygons
-pub fn compute_cross_section(mesh_gl: &MeshGL, height: f64) -> (Vec<f32>, Vec<u32>) {
-    let mut intersection_points = Vec::new();
-    let mut polygon_indices = Vec::new();
-    
-    // Map to track intersection points to avoid duplicates
-    let mut point_map = std::collections::HashMap::new();
-    
-    // Iterate through all triangles
-    for i in (0..mesh_gl.tri_verts.len()).step_by(3) {
-        let v0_idx = mesh_gl.tri_verts[i] as usize;
-        let v1_idx = mesh_gl.tri_verts[i + 1] as usize;
-        let v2_idx = mesh_gl.tri_verts[i + 2] as usize;
-        
-        // Get vertex positions (assuming first 3 properties are x, y, z)
-        let v0_x = mesh_gl.vert_properties[v0_idx * mesh_gl.num_prop as usize] as f64;
-        let v0_y = mesh_gl.vert_properties[v0_idx * mesh_gl.num_prop as usize + 1] as f64;
-        let v0_z = mesh_gl.vert_properties[v0_idx * mesh_gl.num_prop as usize + 2] as f64;
-        
-        let v1_x = mesh_gl.vert_properties[v1_idx * mesh_gl.num_prop as usize] as f64;
-        let v1_y = mesh_gl.vert_properties[v1_idx * mesh_gl.num_prop as usize + 1] as f64;
-        let v1_z = mesh_gl.vert_properties[v1_idx * mesh_gl.num_prop as usize + 2] as f64;
-        
-        let v2_x = mesh_gl.vert_properties[v2_idx * mesh_gl.num_prop as usize] as f64;
-        let v2_y = mesh_gl.vert_properties[v2_idx * mesh_gl.num_prop as usize + 1] as f64;
-        let v2_z = mesh_gl.vert_properties[v2_idx * mesh_gl.num_prop as usize + 2] as f64;
-        
-        // Check for triangle-Z plane intersection
-        let mut intersections = Vec::new();
-        
-        // Check edge v0-v1
-        if let Some(intersection) = intersect_edge_with_plane(v0_x, v0_y, v0_z, v1_x, v1_y, v1_z, height) {
-            intersections.push(intersection);
+/// Slice a mesh at `z = height`, returning closed, consistently-wound
+/// contour loops — the same shape `manifold_rs`'s `slice().get_as_slice()`
+/// returns, usable directly for polygon fill, offsetting, or SVG/DXF export.
+///
+/// Vertices are classified ABOVE/BELOW/ON the plane using a scale-aware
+/// tolerance (`eps = 1e-7 * max(|z0|,|z1|,|z2|,|height|)`, since a fixed
+/// absolute epsilon is either too tight to catch real on-plane vertices on
+/// large meshes or too loose on small ones) and a Simulation-of-Simplicity
+/// rule that treats every ON vertex as infinitesimally ABOVE, so every
+/// triangle deterministically produces 0 or exactly 2 crossings rather than
+/// silently dropping a segment whenever a vertex lands exactly on the
+/// plane. A triangle with all three vertices ON is coplanar with the
+/// cutting plane; rather than emitting its crossings (there are none — it
+/// doesn't straddle the plane) it contributes its boundary edges, but only
+/// where they aren't shared with another coplanar triangle, so a coplanar
+/// patch's outline is emitted once instead of its interior edges canceling
+/// out incorrectly.
+///
+/// Each straddling triangle's segment is oriented by the sign of the
+/// triangle's normal dotted with +Z so every loop winds consistently
+/// regardless of which side of the plane the lone vertex fell on. Loops are
+/// assembled by walking shared endpoints: start at an unused segment and
+/// keep following whichever segment begins where the current one ends,
+/// until back at the start. A loop's winding then directly gives its
+/// signed area's sign — positive for an outer boundary, negative for a hole
+/// — so nested cross-sections are representable without any extra
+/// bookkeeping. A walk that dead-ends on non-manifold input yields its
+/// partial polyline instead of panicking.
+pub fn compute_cross_section(mesh_gl: &MeshGL, height: f64) -> Vec<Vec<[f32; 2]>> {
+    let tri_indices = mesh_triangle_indices(mesh_gl);
+    cross_section_from_triangles(mesh_gl, height, &tri_indices, &(0..tri_indices.len()).collect::<Vec<_>>())
+}
+
+/// Triangle vertex index triples for every triangle in `mesh_gl`, in
+/// `tri_verts` order — the indexing [`MeshSlicer`](crate::mesh_slicer::MeshSlicer)
+/// and [`cross_section_from_triangles`] both key their triangle ids against.
+pub(crate) fn mesh_triangle_indices(mesh_gl: &MeshGL) -> Vec<[usize; 3]> {
+    mesh_gl
+        .tri_verts
+        .chunks(3)
+        .map(|tri| [tri[0] as usize, tri[1] as usize, tri[2] as usize])
+        .collect()
+}
+
+pub(crate) fn vert_pos(mesh_gl: &MeshGL, idx: usize) -> Vector3<f64> {
+    let base = idx * mesh_gl.num_prop as usize;
+    Vector3::new(
+        mesh_gl.vert_properties[base] as f64,
+        mesh_gl.vert_properties[base + 1] as f64,
+        mesh_gl.vert_properties[base + 2] as f64,
+    )
+}
+
+/// The slicing core behind [`compute_cross_section`], restricted to just
+/// `candidates` (indices into `tri_indices`) instead of always scanning
+/// every triangle — the hook [`MeshSlicer`](crate::mesh_slicer::MeshSlicer)
+/// uses to only touch the triangles its acceleration structure says can
+/// possibly straddle `height`.
+pub(crate) fn cross_section_from_triangles(
+    mesh_gl: &MeshGL,
+    height: f64,
+    tri_indices: &[[usize; 3]],
+    candidates: &[usize],
+) -> Vec<Vec<[f32; 2]>> {
+    let tri_positions: Vec<[Vector3<f64>; 3]> =
+        candidates.iter().map(|&t| tri_indices[t].map(|v| vert_pos(mesh_gl, v))).collect();
+
+    // Whether each candidate triangle lies entirely in the plane, and which
+    // edges of a coplanar triangle border another coplanar triangle (and so
+    // are internal to the coplanar patch rather than part of its outline).
+    let coplanar: Vec<bool> = tri_positions
+        .iter()
+        .map(|pos| pos.iter().all(|p| (p.z - height).abs() <= scale_eps(pos, height)))
+        .collect();
+
+    let mut edge_tris: std::collections::HashMap<(usize, usize), Vec<usize>> = std::collections::HashMap::new();
+    for (i, &t) in candidates.iter().enumerate() {
+        let idx = tri_indices[t];
+        for k in 0..3 {
+            let (a, b) = (idx[k], idx[(k + 1) % 3]);
+            edge_tris.entry((a.min(b), a.max(b))).or_default().push(i);
         }
-        
-        // Check edge v1-v2  
-        if let Some(intersection) = intersect_edge_with_plane(v1_x, v1_y, v1_z, v2_x, v2_y, v2_z, height) {
-            intersections.push(intersection);
+    }
+
+    // Quantized endpoint -> index into `points`, so the adjacency walk below
+    // can match segment endpoints without float-equality comparisons.
+    let mut point_map: std::collections::HashMap<(i64, i64), u32> = std::collections::HashMap::new();
+    let mut points: Vec<[f32; 2]> = Vec::new();
+    let mut point_index = |x: f64, y: f64| -> u32 {
+        *point_map.entry(quantize(x, y)).or_insert_with(|| {
+            points.push([x as f32, y as f32]);
+            (points.len() - 1) as u32
+        })
+    };
+
+    let mut segments: Vec<(u32, u32)> = Vec::new();
+
+    for (i, &t) in candidates.iter().enumerate() {
+        let idx = tri_indices[t];
+        let pos = tri_positions[i];
+
+        if coplanar[i] {
+            for k in 0..3 {
+                let (a, b) = (idx[k], idx[(k + 1) % 3]);
+                let shares_coplanar_neighbor =
+                    edge_tris[&(a.min(b), a.max(b))].iter().any(|&other| other != i && coplanar[other]);
+                if !shares_coplanar_neighbor {
+                    let p0 = pos[k];
+                    let p1 = pos[(k + 1) % 3];
+                    segments.push((point_index(p0.x, p0.y), point_index(p1.x, p1.y)));
+                }
+            }
+            continue;
+        }
+
+        let dist = pos.map(|p| p.z - height);
+        let eps = scale_eps(&pos, height);
+        // Simulation-of-Simplicity: an ON vertex (within `eps`) counts as
+        // ABOVE, so comparing two of these is always a definite +/-.
+        let above = |d: f64| -> bool { d.abs() <= eps || d > 0.0 };
+
+        let mut crossings = Vec::new();
+        for k in 0..3 {
+            let j = (k + 1) % 3;
+            if above(dist[k]) != above(dist[j]) {
+                let t = dist[k] / (dist[k] - dist[j]);
+                let p = pos[k] + (pos[j] - pos[k]) * t;
+                crossings.push(point_index(p.x, p.y));
+            }
         }
-        
-        // Check edge v2-v0
-        if let Some(intersection) = intersect_edge_with_plane(v2_x, v2_y, v2_z, v0_x, v0_y, v0_z, height) {
-            intersections.push(intersection);
+
+        if crossings.len() != 2 || crossings[0] == crossings[1] {
+            continue;
         }
-        
-        // If we have 2 intersection points, add them to our polygon
-        if intersections.len() == 2 {
-            // Add first point
-            let p0_key = format!("{:.6}_{:.6}", intersections[0].0, intersections[0].1);
-            let p0_idx = if let Some(&idx) = point_map.get(&p0_key) {
-                idx
-            } else {
-                let idx = intersection_points.len() / 2;
-                intersection_points.push(intersections[0].0 as f32);
-                intersection_points.push(intersections[0].1 as f32);
-                point_map.insert(p0_key, idx);
-                idx
-            };
-            
-            // Add second point
-            let p1_key = format!("{:.6}_{:.6}", intersections[1].0, intersections[1].1);
-            let p1_idx = if let Some(&idx) = point_map.get(&p1_key) {
-                idx
-            } else {
-                let idx = intersection_points.len() / 2;
-                intersection_points.push(intersections[1].0 as f32);
-                intersection_points.push(intersections[1].1 as f32);
-                point_map.insert(p1_key, idx);
-                idx
-            };
-            
-            polygon_indices.push(p0_idx as u32);
-            polygon_indices.push(p1_idx as u32);
+
+        // Only the Z component matters: a triangle wound CCW when viewed
+        // from +Z has a positive one.
+        let normal = (pos[1] - pos[0]).cross(&(pos[2] - pos[0]));
+        if normal.z >= 0.0 {
+            segments.push((crossings[0], crossings[1]));
+        } else {
+            segments.push((crossings[1], crossings[0]));
         }
     }
-    
-    (intersection_points, polygon_indices)
+
+    stitch_directed_loops(&points, segments)
 }
 
-/// Helper function to compute intersection of edge with Z-plane
-/// Returns None if no intersection or if intersection is at endpoint
-fn intersect_edge_with_plane(x0: f64, y0: f64, z0: f64, x1: f64, y1: f64, z1: f64, height: f64) -> Option<(f64, f64)> {
-    // Check if edge crosses the plane
-    let z_diff = z1 - z0;
-    
-    // Avoid division by zero
-    if z_diff.abs() < 1e-10 {
-        return None;
+/// Condition-number-scaled tolerance for classifying a triangle's vertices
+/// against the `z = height` plane: an absolute epsilon is either too tight
+/// to catch real on-plane vertices once coordinates get large, or too loose
+/// once they're small, so this scales with the magnitudes actually involved.
+fn scale_eps(pos: &[Vector3<f64>; 3], height: f64) -> f64 {
+    let max_abs = pos.iter().map(|p| p.z.abs()).fold(height.abs(), f64::max);
+    1e-7 * max_abs
+}
+
+/// Walk directed segments into closed loops by following, from each
+/// segment's end point, whichever unvisited segment starts there.
+fn stitch_directed_loops(points: &[[f32; 2]], segments: Vec<(u32, u32)>) -> Vec<Vec<[f32; 2]>> {
+    let mut outgoing: std::collections::HashMap<u32, Vec<usize>> = std::collections::HashMap::new();
+    for (i, &(start, _)) in segments.iter().enumerate() {
+        outgoing.entry(start).or_default().push(i);
     }
-    
-    let t = (height - z0) / z_diff;
-    
-    // Check if intersection is within edge bounds (excluding endpoints to avoid duplicates)
-    if t <= 0.0 || t >= 1.0 {
-        return None;
+
+    let mut visited = vec![false; segments.len()];
+    let mut loops = Vec::new();
+
+    for start_idx in 0..segments.len() {
+        if visited[start_idx] {
+            continue;
+        }
+        visited[start_idx] = true;
+
+        let (loop_start, mut current) = segments[start_idx];
+        let mut loop_points = vec![points[loop_start as usize], points[current as usize]];
+
+        while current != loop_start {
+            let next_idx = outgoing
+                .get(&current)
+                .and_then(|candidates| candidates.iter().find(|&&i| !visited[i]).copied());
+
+            match next_idx {
+                Some(i) => {
+                    visited[i] = true;
+                    current = segments[i].1;
+                    loop_points.push(points[current as usize]);
+                }
+                None => break,
+            }
+        }
+
+        loops.push(loop_points);
     }
-    
-    // Compute intersection point
-    let x = x0 + t * (x1 - x0);
-    let y = y0 + t * (y1 - y0);
-    
-    Some((x, y))
-}
\ No newline at end of file
+
+    loops
+}
+
+fn quantize(x: f64, y: f64) -> (i64, i64) {
+    const SCALE: f64 = 1e6;
+    (crate::detmath::round(x * SCALE) as i64, crate::detmath::round(y * SCALE) as i64)
+}
+
+/// One closed contour from [`compute_cross_section`]/[`crate::mesh_slicer::MeshSlicer`],
+/// classified by winding and nested inside whichever tighter contour (if
+/// any) contains it.
+#[derive(Clone, Debug)]
+pub struct Contour {
+    pub points: Vec<[f32; 2]>,
+    /// `false` for an outer boundary (CCW), `true` for a hole (CW).
+    pub is_hole: bool,
+    /// Index into the same `Vec<Contour>` of the tightest contour this one
+    /// nests directly inside; `None` for an outermost boundary.
+    pub parent: Option<usize>,
+}
+
+/// Classify `loops` (as returned by [`compute_cross_section`]) by nesting
+/// depth via even-odd ray-casting rather than assuming a single convex outer
+/// ring the way a centroid-angle sort does: a loop contained in an odd
+/// number of the others is a hole, and its direct parent is whichever
+/// containing loop is itself contained in the most others — the
+/// tightest-fitting ancestor. Handles any number of disjoint islands, each
+/// with any number of (possibly nested) holes.
+pub fn classify_contours(loops: Vec<Vec<[f32; 2]>>) -> Vec<Contour> {
+    let n = loops.len();
+
+    // containing[i] = indices of every other loop whose boundary encloses
+    // loop i's first vertex.
+    let containing: Vec<Vec<usize>> = (0..n)
+        .map(|i| {
+            let p = loops[i][0];
+            (0..n).filter(|&j| j != i && point_in_polygon(p, &loops[j])).collect()
+        })
+        .collect();
+
+    (0..n)
+        .map(|i| {
+            let is_hole = containing[i].len() % 2 == 1;
+            let parent = containing[i].iter().copied().max_by_key(|&j| containing[j].len());
+            Contour { points: loops[i].clone(), is_hole, parent }
+        })
+        .collect()
+}
+
+/// Even-odd ray-cast point-in-polygon test: cast a ray in +x from `p` and
+/// count how many of `polygon`'s edges it crosses.
+fn point_in_polygon(p: [f32; 2], polygon: &[[f32; 2]]) -> bool {
+    let n = polygon.len();
+    let mut inside = false;
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let straddles = (a[1] > p[1]) != (b[1] > p[1]);
+        if straddles {
+            let x_at_y = a[0] as f64 + (p[1] as f64 - a[1] as f64) / (b[1] as f64 - a[1] as f64) * (b[0] as f64 - a[0] as f64);
+            if x_at_y > p[0] as f64 {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_contours_single_outer_loop_has_no_parent() {
+        let outer = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+        let contours = classify_contours(vec![outer]);
+        assert_eq!(contours.len(), 1);
+        assert!(!contours[0].is_hole);
+        assert_eq!(contours[0].parent, None);
+    }
+
+    #[test]
+    fn classify_contours_nests_a_hole_inside_its_outer_boundary() {
+        let outer = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]];
+        let hole = vec![[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]];
+        let contours = classify_contours(vec![outer, hole]);
+        assert_eq!(contours.len(), 2);
+        assert!(!contours[0].is_hole);
+        assert_eq!(contours[0].parent, None);
+        assert!(contours[1].is_hole);
+        assert_eq!(contours[1].parent, Some(0));
+    }
+
+    /// Three levels deep: an island sitting inside a hole that's itself cut
+    /// out of an outer boundary — `classify_contours`' even-odd rule should
+    /// call the island a boundary again (contained in 2, an even count) and
+    /// pick the hole, not the outer square, as its tightest parent.
+    #[test]
+    fn classify_contours_handles_an_island_nested_inside_a_hole() {
+        let outer = vec![[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        let hole = vec![[1.0, 1.0], [1.0, 9.0], [9.0, 9.0], [9.0, 1.0]];
+        let island = vec![[3.0, 3.0], [3.0, 7.0], [7.0, 7.0], [7.0, 3.0]];
+        let contours = classify_contours(vec![outer, hole, island]);
+        assert_eq!(contours.len(), 3);
+
+        assert!(!contours[0].is_hole);
+        assert_eq!(contours[0].parent, None);
+
+        assert!(contours[1].is_hole);
+        assert_eq!(contours[1].parent, Some(0));
+
+        assert!(!contours[2].is_hole);
+        assert_eq!(contours[2].parent, Some(1));
+    }
+
+    /// End-to-end through both functions this review targets: extrude a
+    /// square annulus (a box with a square pass-through hole, the case
+    /// called out for this coverage gap) into a solid, re-slice it through
+    /// the middle with `compute_cross_section`, and check `classify_contours`
+    /// recovers the same outer/hole nesting the profile started with.
+    #[test]
+    fn compute_cross_section_recovers_hole_through_a_pierced_box() {
+        let outer = Contour { points: vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]], is_hole: false, parent: None };
+        let hole = Contour { points: vec![[1.0, 1.0], [1.0, 3.0], [3.0, 3.0], [3.0, 1.0]], is_hole: true, parent: Some(0) };
+        let pierced_box = crate::cross_section::CrossSection::from_contours(vec![outer, hole]).extrude(2.0, 0.0, 1);
+
+        let mesh_gl = crate::get_mesh_gl(&pierced_box);
+        let loops = compute_cross_section(&mesh_gl, 1.0);
+        let contours = classify_contours(loops);
+
+        assert_eq!(contours.len(), 2);
+        let hole_count = contours.iter().filter(|c| c.is_hole).count();
+        let outer_count = contours.iter().filter(|c| !c.is_hole).count();
+        assert_eq!(hole_count, 1);
+        assert_eq!(outer_count, 1);
+
+        let outer_idx = contours.iter().position(|c| !c.is_hole).unwrap();
+        let hole_idx = contours.iter().position(|c| c.is_hole).unwrap();
+        assert_eq!(contours[hole_idx].parent, Some(outer_idx));
+    }
+}
@@ -0,0 +1,323 @@
+use crate::MeshGL;
+use nalgebra::{Point3, Vector3};
+
+/// A 2D coordinate in the local basis [`cross_section_plane`] builds for a
+/// cutting plane, not tied to any global axis.
+pub type PlanarPoint = (f64, f64);
+
+/// Generalization of [`crate::cross_section_helper::compute_cross_section`]
+/// to an arbitrary oriented plane through `point` with unit `normal`.
+///
+/// Classifies each triangle's vertices by signed distance `s = dot(normal,
+/// v) - dot(normal, point)` (using an epsilon band so through-vertex cases
+/// don't emit duplicate points), computes the edge/plane intersection
+/// segment for triangles that straddle the plane — interpolating at `t =
+/// s0 / (s0 - s1)` — then stitches the resulting segments into closed loops
+/// by walking shared endpoints. Returns the closed loops projected into a
+/// 2D basis built from two orthonormal tangents of `normal`, so the
+/// coordinates are planar regardless of how the plane is oriented in 3D.
+pub fn cross_section_plane(mesh_gl: &MeshGL, point: Point3<f64>, normal: Vector3<f64>) -> Vec<Vec<PlanarPoint>> {
+    let normal = crate::detmath::normalize3(normal);
+    let (tangent_u, tangent_v) = plane_basis(normal);
+    let project = |p: Vector3<f64>| -> PlanarPoint {
+        let local = p - point.coords;
+        (local.dot(&tangent_u), local.dot(&tangent_v))
+    };
+
+    cross_section_loops(mesh_gl, point, normal)
+        .into_iter()
+        .map(|loop_pts| loop_pts.into_iter().map(project).collect())
+        .collect()
+}
+
+/// Returns the region of `mesh_gl` between two parallel planes sharing
+/// `normal`, at signed distances `d_lo < d_hi` — the generalization of
+/// per-axis face clipping against xm/xp/ym/yp/zm/zp half-spaces to an
+/// arbitrary cutting direction. Each triangle is clipped against the two
+/// half-spaces in turn (Sutherland-Hodgman), and the open ends left by the
+/// cut are capped with the cross-section loops at `d_lo` and `d_hi`. Returns
+/// a flat triangle soup: `(positions, indices)`.
+pub fn clip_slab(mesh_gl: &MeshGL, normal: Vector3<f64>, d_lo: f64, d_hi: f64) -> (Vec<f32>, Vec<u32>) {
+    assert!(d_lo < d_hi, "d_lo must be less than d_hi");
+
+    let normal = crate::detmath::normalize3(normal);
+    let vert_pos = |idx: usize| -> Vector3<f64> {
+        let base = idx * mesh_gl.num_prop as usize;
+        Vector3::new(
+            mesh_gl.vert_properties[base] as f64,
+            mesh_gl.vert_properties[base + 1] as f64,
+            mesh_gl.vert_properties[base + 2] as f64,
+        )
+    };
+
+    let mut positions: Vec<f32> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut push_tri = |a: Vector3<f64>, b: Vector3<f64>, c: Vector3<f64>| {
+        let base = (positions.len() / 3) as u32;
+        for p in [a, b, c] {
+            positions.push(p.x as f32);
+            positions.push(p.y as f32);
+            positions.push(p.z as f32);
+        }
+        indices.extend_from_slice(&[base, base + 1, base + 2]);
+    };
+
+    for tri in mesh_gl.tri_verts.chunks(3) {
+        let idx = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+        let pos: Vec<Vector3<f64>> = idx.iter().map(|&i| vert_pos(i)).collect();
+
+        let below_hi = clip_polygon_half_space(&pos, normal, d_hi, false);
+        let slab_poly = clip_polygon_half_space(&below_hi, normal, d_lo, true);
+
+        for i in 1..slab_poly.len().saturating_sub(1) {
+            push_tri(slab_poly[0], slab_poly[i], slab_poly[i + 1]);
+        }
+    }
+
+    // Cap the two cut faces with the plane's cross-section loops. The loops
+    // wind CCW as seen from +normal (see `cross_section_loops`), so a direct
+    // fan is the correct outward orientation for the `d_hi` cap (facing
+    // +normal, away from the slab); the `d_lo` cap needs the reverse winding
+    // to face -normal instead.
+    for loop_pts in cross_section_loops(mesh_gl, Point3::from(normal * d_lo), normal) {
+        cap_loop_triangles(&loop_pts, true, &mut push_tri);
+    }
+    for loop_pts in cross_section_loops(mesh_gl, Point3::from(normal * d_hi), normal) {
+        cap_loop_triangles(&loop_pts, false, &mut push_tri);
+    }
+
+    (positions, indices)
+}
+
+/// Produce an ordered stack of cross-section contour sets, one per layer,
+/// by slicing `mesh_gl` with planes `normal . p = h` for `h` stepping from
+/// `start` to `end` by `layer_height`. Layers entirely outside the mesh
+/// bounds simply come back empty rather than erroring.
+pub fn slice_stack(
+    mesh_gl: &MeshGL,
+    normal: Vector3<f64>,
+    start: f64,
+    end: f64,
+    layer_height: f64,
+) -> Vec<Vec<Vec<PlanarPoint>>> {
+    assert!(layer_height > 0.0, "layer_height must be positive");
+
+    let normal = crate::detmath::normalize3(normal);
+    let mut layers = Vec::new();
+    let mut h = start;
+    while h <= end {
+        layers.push(cross_section_plane(mesh_gl, Point3::from(normal * h), normal));
+        h += layer_height;
+    }
+    layers
+}
+
+/// Build an orthonormal `(tangent_u, tangent_v)` basis spanning the plane
+/// perpendicular to `normal`, so points on the plane can be projected to
+/// stable 2D coordinates local to it.
+fn plane_basis(normal: Vector3<f64>) -> (Vector3<f64>, Vector3<f64>) {
+    let helper = if normal.x.abs() < 0.9 { Vector3::x() } else { Vector3::y() };
+    let tangent_u = crate::detmath::normalize3(normal.cross(&helper));
+    let tangent_v = normal.cross(&tangent_u);
+    (tangent_u, tangent_v)
+}
+
+/// Shared by [`cross_section_plane`] (which projects the result to 2D) and
+/// [`clip_slab`]'s capping (which needs the 3D points directly): slice
+/// `mesh_gl` at the plane through `point` with unit `normal` and stitch the
+/// per-triangle intersection segments into closed 3D loops.
+fn cross_section_loops(mesh_gl: &MeshGL, point: Point3<f64>, normal: Vector3<f64>) -> Vec<Vec<Vector3<f64>>> {
+    const EPSILON: f64 = 1e-9;
+
+    let offset = normal.dot(&point.coords);
+    let vert_pos = |idx: usize| -> Vector3<f64> {
+        let base = idx * mesh_gl.num_prop as usize;
+        Vector3::new(
+            mesh_gl.vert_properties[base] as f64,
+            mesh_gl.vert_properties[base + 1] as f64,
+            mesh_gl.vert_properties[base + 2] as f64,
+        )
+    };
+    let signed_dist = |p: Vector3<f64>| -> f64 { normal.dot(&p) - offset };
+
+    // Segments directed so the loop winds CCW as seen from +normal: decided
+    // per-triangle from the sign of that triangle's normal dotted with the
+    // cutting plane's normal, so the winding stays consistent regardless of
+    // which side the straddling triangle's lone vertex fell on.
+    let mut segments: Vec<(Vector3<f64>, Vector3<f64>)> = Vec::new();
+
+    for tri in mesh_gl.tri_verts.chunks(3) {
+        let idx = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+        let pos = idx.map(vert_pos);
+        let dist = pos.map(signed_dist);
+
+        // A triangle lying exactly in the plane contributes its boundary
+        // edges directly, rather than being treated as "no intersection".
+        if dist.iter().all(|d| d.abs() <= EPSILON) {
+            for i in 0..3 {
+                segments.push((pos[i], pos[(i + 1) % 3]));
+            }
+            continue;
+        }
+
+        let mut crossings = Vec::new();
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+            if let Some(t) = edge_plane_t(dist[i], dist[j], EPSILON) {
+                crossings.push(pos[i] + (pos[j] - pos[i]) * t);
+            }
+        }
+
+        if crossings.len() == 2 {
+            let tri_normal = (pos[1] - pos[0]).cross(&(pos[2] - pos[0]));
+            if tri_normal.dot(&normal) >= 0.0 {
+                segments.push((crossings[0], crossings[1]));
+            } else {
+                segments.push((crossings[1], crossings[0]));
+            }
+        }
+    }
+
+    stitch_directed_loops(segments)
+}
+
+/// Parameter `t` along edge `(a, b)` where the plane is crossed, or `None`
+/// if the edge doesn't straddle the plane (both endpoints strictly on the
+/// same side, or the crossing lands exactly on an endpoint, which the
+/// endpoint's own triangle-vertex handling already covers).
+fn edge_plane_t(dist_a: f64, dist_b: f64, epsilon: f64) -> Option<f64> {
+    if dist_a.abs() <= epsilon || dist_b.abs() <= epsilon {
+        return None;
+    }
+    if (dist_a > 0.0) == (dist_b > 0.0) {
+        return None;
+    }
+    Some(dist_a / (dist_a - dist_b))
+}
+
+fn quantize(p: Vector3<f64>) -> (i64, i64, i64) {
+    const SCALE: f64 = 1e6;
+    (
+        crate::detmath::round(p.x * SCALE) as i64,
+        crate::detmath::round(p.y * SCALE) as i64,
+        crate::detmath::round(p.z * SCALE) as i64,
+    )
+}
+
+/// Walk directed segments into closed loops by following, from each
+/// segment's end point, whichever unvisited segment starts there. A walk
+/// that dead-ends on non-manifold input yields its partial polyline instead
+/// of panicking.
+fn stitch_directed_loops(segments: Vec<(Vector3<f64>, Vector3<f64>)>) -> Vec<Vec<Vector3<f64>>> {
+    use std::collections::HashMap;
+
+    let mut outgoing: HashMap<(i64, i64, i64), Vec<usize>> = HashMap::new();
+    for (i, &(start, _)) in segments.iter().enumerate() {
+        outgoing.entry(quantize(start)).or_default().push(i);
+    }
+
+    let mut visited = vec![false; segments.len()];
+    let mut loops = Vec::new();
+
+    for start_idx in 0..segments.len() {
+        if visited[start_idx] {
+            continue;
+        }
+        visited[start_idx] = true;
+
+        let (loop_start, mut current) = segments[start_idx];
+        let mut loop_points = vec![loop_start, current];
+
+        while quantize(current) != quantize(loop_start) {
+            let next_idx = outgoing
+                .get(&quantize(current))
+                .and_then(|candidates| candidates.iter().find(|&&i| !visited[i]).copied());
+
+            match next_idx {
+                Some(i) => {
+                    visited[i] = true;
+                    current = segments[i].1;
+                    loop_points.push(current);
+                }
+                None => break,
+            }
+        }
+
+        loops.push(loop_points);
+    }
+
+    loops
+}
+
+/// Sutherland-Hodgman clip of a convex polygon against the half-space
+/// `dot(normal, p) <= d` (or `>= d` when `keep_above`).
+fn clip_polygon_half_space(
+    poly: &[Vector3<f64>],
+    normal: Vector3<f64>,
+    d: f64,
+    keep_above: bool,
+) -> Vec<Vector3<f64>> {
+    if poly.is_empty() {
+        return Vec::new();
+    }
+
+    let inside = |p: &Vector3<f64>| -> bool {
+        let s = normal.dot(p) - d;
+        if keep_above {
+            s >= 0.0
+        } else {
+            s <= 0.0
+        }
+    };
+
+    let mut output = Vec::new();
+    for i in 0..poly.len() {
+        let current = poly[i];
+        let prev = poly[(i + poly.len() - 1) % poly.len()];
+        let current_inside = inside(&current);
+        let prev_inside = inside(&prev);
+
+        if current_inside {
+            if !prev_inside {
+                output.push(intersect_segment_plane(prev, current, normal, d));
+            }
+            output.push(current);
+        } else if prev_inside {
+            output.push(intersect_segment_plane(prev, current, normal, d));
+        }
+    }
+    output
+}
+
+fn intersect_segment_plane(a: Vector3<f64>, b: Vector3<f64>, normal: Vector3<f64>, d: f64) -> Vector3<f64> {
+    let sa = normal.dot(&a) - d;
+    let sb = normal.dot(&b) - d;
+    let t = sa / (sa - sb);
+    a + (b - a) * t
+}
+
+/// Fan-triangulate a closed cross-section loop for use as a cap face,
+/// reversing the winding when `flip` so the cap's outward normal points
+/// away from the slab it closes off.
+fn cap_loop_triangles(
+    loop_pts: &[Vector3<f64>],
+    flip: bool,
+    push_tri: &mut impl FnMut(Vector3<f64>, Vector3<f64>, Vector3<f64>),
+) {
+    let loop_pts = if loop_pts.len() > 1 && loop_pts.last() == Some(&loop_pts[0]) {
+        &loop_pts[..loop_pts.len() - 1]
+    } else {
+        loop_pts
+    };
+    if loop_pts.len() < 3 {
+        return;
+    }
+
+    for i in 1..loop_pts.len() - 1 {
+        if flip {
+            push_tri(loop_pts[0], loop_pts[i + 1], loop_pts[i]);
+        } else {
+            push_tri(loop_pts[0], loop_pts[i], loop_pts[i + 1]);
+        }
+    }
+}
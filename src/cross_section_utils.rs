@@ -1,5 +1,7 @@
-use crate::{MeshBoolImpl, ManifoldError};
-use nalgebra::{Point3, Vector3};
+use crate::cross_section_helper::Contour;
+use crate::utils::{ccw, K_PRECISION};
+use crate::{ManifoldError, MeshBoolImpl};
+use nalgebra::{Point2, Point3, Vector3};
 
 ///Sort intersection points to form a proper polygon boundary
 pub fn sort_intersection_points(points: &[f32]) -> Vec<Point3<f64>> {
@@ -17,8 +19,8 @@ pub fn sort_intersection_points(points: &[f32]) -> Vec<Point3<f64>> {
     if !sorted_points.is_empty() {
         let centroid = compute_centroid(&sorted_points);
         sorted_points.sort_by(|a, b| {
-            let angle_a = (a.y - centroid.y).atan2(a.x - centroid.x);
-            let angle_b = (b.y - centroid.y).atan2(b.x - centroid.x);
+            let angle_a = crate::detmath::atan2(a.y - centroid.y, a.x - centroid.x);
+            let angle_b = crate::detmath::atan2(b.y - centroid.y, b.x - centroid.x);
             angle_a.partial_cmp(&angle_b).unwrap_or(std::cmp::Ordering::Equal)
         });
     }
@@ -40,22 +42,310 @@ fn compute_centroid(points: &[Point3<f64>]) -> Point3<f64> {
     sum / points.len() as f64
 }
 
-///Triangulate a polygon using simple fan triangulation
+///Triangulate a polygon boundary (in the XY plane, `z` ignored) by ear
+///clipping, which — unlike fan triangulation — produces a valid
+///triangulation for concave polygons. `points` indices are preserved in
+///the output (no re-ordering or deduplication of the input array), so
+///triangle indices returned here index directly into `points`.
 pub fn triangulate_polygon(points: &[Point3<f64>]) -> Vec<[usize; 3]> {
     if points.len() < 3 {
         return Vec::new();
     }
-    
-    let mut triangles = Vec::new();
-    
-    // Simple fan triangulation - connect first vertex to all other consecutive pairs
-    for i in 1..points.len() - 1 {
-        triangles.push([0, i, i + 1]);
+
+    let epsilon = polygon_epsilon(points);
+    let n = points.len();
+
+    // Walk the loop in whichever of (forward, backward) direction is CCW,
+    // so the ear test's convexity sign is consistent regardless of the
+    // input winding, without needing to rebuild or reverse `points`.
+    let area = signed_area(points);
+    let (mut next, mut prev): (Vec<usize>, Vec<usize>) = if area >= 0.0 {
+        ((0..n).map(|i| (i + 1) % n).collect(), (0..n).map(|i| (i + n - 1) % n).collect())
+    } else {
+        ((0..n).map(|i| (i + n - 1) % n).collect(), (0..n).map(|i| (i + 1) % n).collect())
+    };
+
+    // Splice out zero-length edges (duplicate points) up front, so they
+    // never have to be considered as ear candidates.
+    let mut remaining = n;
+    let mut start = 0;
+    for i in 0..n {
+        if remaining <= 3 {
+            break;
+        }
+        if next[i] != i && (points[i].coords - points[next[i]].coords).norm() <= epsilon {
+            let (p, nx) = (i, next[i]);
+            let after = next[nx];
+            next[p] = after;
+            prev[after] = p;
+            remaining -= 1;
+            if start == nx {
+                start = p;
+            }
+        }
     }
-    
+
+    ear_clip(points, &mut next, &mut prev, start, remaining, epsilon)
+}
+
+/// Merge `hole` (a closed loop, orientation opposite `outer`'s) into
+/// `outer` by bridging the hole's rightmost vertex to the nearest outer
+/// edge it can see a ray to, producing a single simple polygon that
+/// [`triangulate_polygon`] can clip directly — the standard construction
+/// for triangulating polygons with holes without a dedicated hole-aware
+/// ear clipper.
+pub fn bridge_hole(outer: &mut Vec<Point3<f64>>, hole: &[Point3<f64>]) {
+    if hole.is_empty() || outer.len() < 3 {
+        return;
+    }
+
+    let hole_start = hole
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.x.partial_cmp(&b.1.x).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(i, _)| i)
+        .unwrap();
+    let hp = hole[hole_start];
+
+    // Cast a ray from `hp` in the +x direction; among the outer edges it
+    // crosses, the nearest one's higher-x endpoint is always a valid
+    // (visible) bridge vertex.
+    let n = outer.len();
+    let mut nearest_x = f64::INFINITY;
+    let mut bridge_idx = None;
+    for i in 0..n {
+        let (a, b) = (outer[i], outer[(i + 1) % n]);
+        let (lo, hi) = if a.y <= b.y { (a, b) } else { (b, a) };
+        if hp.y < lo.y || hp.y > hi.y || (hi.y - lo.y).abs() < K_PRECISION {
+            continue;
+        }
+        let t = (hp.y - lo.y) / (hi.y - lo.y);
+        let x = lo.x + t * (hi.x - lo.x);
+        if x >= hp.x && x < nearest_x {
+            nearest_x = x;
+            bridge_idx = Some(if a.x > b.x { i } else { (i + 1) % n });
+        }
+    }
+    let Some(bridge_idx) = bridge_idx else {
+        return;
+    };
+
+    // Splice the hole loop in right after the bridge vertex, duplicating
+    // the bridge vertex and the hole's start vertex on either end to close
+    // the seam (two coincident edges connecting the loops).
+    let mut insertion = Vec::with_capacity(hole.len() + 2);
+    insertion.push(outer[bridge_idx]);
+    for i in 0..=hole.len() {
+        insertion.push(hole[(hole_start + i) % hole.len()]);
+    }
+    outer.splice(bridge_idx + 1..bridge_idx + 1, insertion);
+}
+
+/// A working epsilon derived from the polygon's bounding box scale,
+/// matching the `bbox.scale() * K_PRECISION` convention `EarClip` callers
+/// elsewhere in this crate seed their epsilon with.
+fn polygon_epsilon(points: &[Point3<f64>]) -> f64 {
+    let (mut min, mut max) = (points[0], points[0]);
+    for p in points {
+        min.x = min.x.min(p.x);
+        min.y = min.y.min(p.y);
+        max.x = max.x.max(p.x);
+        max.y = max.y.max(p.y);
+    }
+    let scale = (max.x - min.x).max(max.y - min.y).max(1.0);
+    scale * K_PRECISION
+}
+
+fn signed_area(points: &[Point3<f64>]) -> f64 {
+    let n = points.len();
+    let mut area = 0.0;
+    for i in 0..n {
+        let (a, b) = (points[i], points[(i + 1) % n]);
+        area += a.x * b.y - b.x * a.y;
+    }
+    area * 0.5
+}
+
+fn pos2(points: &[Point3<f64>], i: usize) -> Point2<f64> {
+    Point2::new(points[i].x, points[i].y)
+}
+
+/// `true` if vertex `v`'s interior angle (given its current `prev`/`next`
+/// neighbors) is reflex (> 180°) rather than convex.
+fn is_reflex(points: &[Point3<f64>], prev: &[usize], next: &[usize], v: usize, epsilon: f64) -> bool {
+    ccw(pos2(points, prev[v]), pos2(points, v), pos2(points, next[v]), epsilon) < 0
+}
+
+/// `true` if `p` lies on or inside the triangle `(a, b, c)`.
+fn point_in_triangle(p: Point2<f64>, a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> bool {
+    let d1 = ccw(a, b, p, 0.0);
+    let d2 = ccw(b, c, p, 0.0);
+    let d3 = ccw(c, a, p, 0.0);
+    let has_neg = d1 < 0 || d2 < 0 || d3 < 0;
+    let has_pos = d1 > 0 || d2 > 0 || d3 > 0;
+    !(has_neg && has_pos)
+}
+
+/// `true` if vertex `v` is currently an "ear": its triangle `(prev, v,
+/// next)` is convex and no reflex vertex elsewhere in the (remaining) loop
+/// lies strictly inside it.
+fn is_ear(points: &[Point3<f64>], prev: &[usize], next: &[usize], v: usize, epsilon: f64) -> bool {
+    let (u, w) = (prev[v], next[v]);
+    if ccw(pos2(points, u), pos2(points, v), pos2(points, w), epsilon) <= 0 {
+        return false;
+    }
+
+    let (pu, pv, pw) = (pos2(points, u), pos2(points, v), pos2(points, w));
+    let mut k = next[w];
+    while k != u {
+        if is_reflex(points, prev, next, k, epsilon) && point_in_triangle(pos2(points, k), pu, pv, pw) {
+            return false;
+        }
+        k = next[k];
+    }
+    true
+}
+
+/// Repeatedly clip ears from the doubly-linked vertex loop described by
+/// `prev`/`next` (starting from `start`, `remaining` vertices live) until 3
+/// vertices remain, emitting one triangle per clip. Falls back to fan
+/// triangulation of whatever's left if a full pass finds no ear, which
+/// guards against infinite looping on self-intersecting input instead of
+/// ever failing to terminate.
+fn ear_clip(
+    points: &[Point3<f64>],
+    next: &mut [usize],
+    prev: &mut [usize],
+    start: usize,
+    mut remaining: usize,
+    epsilon: f64,
+) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::with_capacity(remaining.saturating_sub(2));
+    if remaining < 3 {
+        return triangles;
+    }
+
+    let mut v = start;
+    let mut since_progress = 0usize;
+    while remaining > 3 {
+        if is_ear(points, prev, next, v, epsilon) {
+            triangles.push([prev[v], v, next[v]]);
+            let (p, nx) = (prev[v], next[v]);
+            next[p] = nx;
+            prev[nx] = p;
+            remaining -= 1;
+            since_progress = 0;
+            v = nx;
+        } else {
+            v = next[v];
+            since_progress += 1;
+            if since_progress > remaining {
+                // No ear found in a full pass: fall back to fan
+                // triangulation of the remaining loop rather than spin.
+                let mut order = Vec::with_capacity(remaining);
+                let mut cur = v;
+                loop {
+                    order.push(cur);
+                    cur = next[cur];
+                    if cur == v {
+                        break;
+                    }
+                }
+                for i in 1..order.len() - 1 {
+                    triangles.push([order[0], order[i], order[i + 1]]);
+                }
+                return triangles;
+            }
+        }
+    }
+    triangles.push([prev[v], v, next[v]]);
     triangles
 }
 
+/// Build a single capped (filled, triangulated) cross-section from a
+/// plane's [`classify_contours`](crate::cross_section_helper::classify_contours)
+/// output, at a fixed `z = height`: every outer boundary has whatever holes
+/// nest directly inside it bridged in via [`bridge_hole`], then triangulated
+/// by [`triangulate_polygon`] — so a plane with several disjoint islands,
+/// each with its own holes, caps all of them correctly instead of assuming
+/// a single convex ring. Feeds [`create_2d_mesh`] with every island's
+/// triangles combined into one mesh.
+pub fn cap_cross_section(contours: &[Contour], height: f64) -> MeshBoolImpl {
+    let mut all_points: Vec<Point3<f64>> = Vec::new();
+    let mut all_triangles: Vec<[usize; 3]> = Vec::new();
+
+    for (i, outer) in contours.iter().enumerate() {
+        if outer.is_hole {
+            continue;
+        }
+
+        let mut boundary: Vec<Point3<f64>> =
+            outer.points.iter().map(|p| Point3::new(p[0] as f64, p[1] as f64, height)).collect();
+
+        for hole in contours.iter().filter(|c| c.is_hole && c.parent == Some(i)) {
+            let hole_points: Vec<Point3<f64>> =
+                hole.points.iter().map(|p| Point3::new(p[0] as f64, p[1] as f64, height)).collect();
+            bridge_hole(&mut boundary, &hole_points);
+        }
+
+        let base = all_points.len();
+        let tris = triangulate_polygon(&boundary);
+        all_triangles.extend(tris.into_iter().map(|t| [t[0] + base, t[1] + base, t[2] + base]));
+        all_points.extend(boundary);
+    }
+
+    create_2d_mesh(&all_points, &all_triangles)
+}
+
+/// Same result as [`cap_cross_section`], with each island (an outer
+/// boundary plus whatever holes bridge into it) triangulated on
+/// `config`'s thread pool instead of sequentially — islands don't share
+/// any topology with each other until this function's own final stitch,
+/// so triangulating them is embarrassingly parallel. Islands are
+/// triangulated in parallel but collected back into `(original index,
+/// boundary, triangles)` tuples and sorted by that original index before
+/// stitching, so the combined mesh's vertex/triangle order — and
+/// therefore its output — is identical to [`cap_cross_section`]'s
+/// regardless of which island's triangulation finishes first.
+#[cfg(feature = "rayon")]
+pub fn cap_cross_section_parallel(contours: &[Contour], height: f64, config: crate::parallel::ParallelConfig) -> MeshBoolImpl {
+    use rayon::prelude::*;
+
+    let islands: Vec<(usize, &Contour)> = contours.iter().enumerate().filter(|(_, outer)| !outer.is_hole).collect();
+
+    let pool = config.build_pool();
+    let mut triangulated: Vec<(usize, Vec<Point3<f64>>, Vec<[usize; 3]>)> = pool.install(|| {
+        islands
+            .par_iter()
+            .map(|&(i, outer)| {
+                let mut boundary: Vec<Point3<f64>> =
+                    outer.points.iter().map(|p| Point3::new(p[0] as f64, p[1] as f64, height)).collect();
+
+                for hole in contours.iter().filter(|c| c.is_hole && c.parent == Some(i)) {
+                    let hole_points: Vec<Point3<f64>> =
+                        hole.points.iter().map(|p| Point3::new(p[0] as f64, p[1] as f64, height)).collect();
+                    bridge_hole(&mut boundary, &hole_points);
+                }
+
+                let tris = triangulate_polygon(&boundary);
+                (i, boundary, tris)
+            })
+            .collect()
+    });
+
+    triangulated.sort_by_key(|(i, _, _)| *i);
+
+    let mut all_points: Vec<Point3<f64>> = Vec::new();
+    let mut all_triangles: Vec<[usize; 3]> = Vec::new();
+    for (_, boundary, tris) in triangulated {
+        let base = all_points.len();
+        all_triangles.extend(tris.into_iter().map(|t| [t[0] + base, t[1] + base, t[2] + base]));
+        all_points.extend(boundary);
+    }
+
+    create_2d_mesh(&all_points, &all_triangles)
+}
+
 ///Create a 2D mesh from points and triangles
 pub fn create_2d_mesh(points: &[Point3<f64>], triangles: &[[usize; 3]]) -> MeshBoolImpl {
     if points.is_empty() || triangles.is_empty() {
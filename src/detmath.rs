@@ -0,0 +1,188 @@
+//! Deterministic, cross-platform math for the boolean kernel's geometric
+//! predicates (signed distances, plane classification, edge intersection
+//! parameters).
+//!
+//! Platform `std` transcendental functions (`sqrt`, `atan2`, `sin`, `cos`, …)
+//! are not guaranteed bit-identical across targets, since they ultimately
+//! bottom out in the platform's libm. Behind the `deterministic` feature,
+//! this module routes the same calls through the `libm` crate instead,
+//! which is a pure-Rust implementation with the same behavior on every
+//! target. Without the feature, it's a zero-cost pass-through to `std`, so
+//! callers pay nothing unless they opt in.
+//!
+//! Every transcendental call in the kernel's predicates should go through
+//! here rather than calling `f64`/`f32` methods directly, so enabling the
+//! feature actually covers the whole predicate surface.
+//!
+//! `floor`/`ceil`/`round` are included alongside the transcendentals
+//! because they back the snapping/quantization used to dedupe vertices
+//! during welding and cross-section loop assembly — rounding that's
+//! supposed to make two platforms agree on which vertices coincide isn't
+//! trustworthy if the rounding itself isn't guaranteed identical.
+//!
+//! The `deterministic` feature only controls whether `libm` is a dependency
+//! at all; [`set_deterministic`] is a runtime toggle on top of that, so a
+//! binary built with the feature on can still compare std's and libm's
+//! output directly (a test asserting the two agree within tolerance, or a
+//! "replay this scene bit-for-bit" mode a user can flip at runtime) instead
+//! of needing two separate builds. It defaults to enabled, so a caller who
+//! never touches it gets the same always-on behavior the feature had before
+//! this toggle existed.
+
+#[cfg(feature = "deterministic")]
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Whether [`sqrt`]/[`floor`]/[`ceil`]/[`round`]/[`atan2`]/[`sin`]/[`cos`]/
+/// [`trunc`] currently route through `libm` or fall back to `std`. Only
+/// compiled in behind the `deterministic` feature — without the feature,
+/// `libm` isn't linked at all, so there's nothing for a runtime flag to
+/// switch between.
+#[cfg(feature = "deterministic")]
+static FORCE_LIBM: AtomicBool = AtomicBool::new(true);
+
+/// Flip [`FORCE_LIBM`] at runtime. See this module's doc comment.
+#[cfg(feature = "deterministic")]
+pub fn set_deterministic(enabled: bool) {
+    FORCE_LIBM.store(enabled, Ordering::Relaxed);
+}
+
+#[cfg(feature = "deterministic")]
+fn use_libm() -> bool {
+    FORCE_LIBM.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "deterministic")]
+pub fn sqrt(x: f64) -> f64 {
+    if use_libm() {
+        libm::sqrt(x)
+    } else {
+        x.sqrt()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn floor(x: f64) -> f64 {
+    if use_libm() {
+        libm::floor(x)
+    } else {
+        x.floor()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn floor(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn ceil(x: f64) -> f64 {
+    if use_libm() {
+        libm::ceil(x)
+    } else {
+        x.ceil()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn ceil(x: f64) -> f64 {
+    x.ceil()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn round(x: f64) -> f64 {
+    if use_libm() {
+        libm::round(x)
+    } else {
+        x.round()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn round(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    if use_libm() {
+        libm::atan2(y, x)
+    } else {
+        y.atan2(x)
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+#[cfg(feature = "deterministic")]
+pub fn sin(x: f64) -> f64 {
+    if use_libm() {
+        libm::sin(x)
+    } else {
+        x.sin()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn cos(x: f64) -> f64 {
+    if use_libm() {
+        libm::cos(x)
+    } else {
+        x.cos()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+#[cfg(feature = "deterministic")]
+pub fn trunc(x: f64) -> f64 {
+    if use_libm() {
+        libm::trunc(x)
+    } else {
+        x.trunc()
+    }
+}
+
+#[cfg(not(feature = "deterministic"))]
+pub fn trunc(x: f64) -> f64 {
+    x.trunc()
+}
+
+/// Deterministic replacement for `nalgebra`'s `Vector3::normalize()`, which
+/// always calls `std`'s `sqrt` internally regardless of this module's
+/// `deterministic` feature. Divides by the magnitude computed through
+/// [`sqrt`] instead, so enabling the feature actually covers every
+/// cross/dot-product normalization the intersection and collider-building
+/// code does (plane normals, tangent bases) and not just the direct
+/// sqrt/floor/ceil/round calls those paths also make.
+pub fn normalize3(v: nalgebra::Vector3<f64>) -> nalgebra::Vector3<f64> {
+    let mag = sqrt(v.dot(&v));
+    if mag > 0.0 {
+        v / mag
+    } else {
+        v
+    }
+}
+
+/// Deterministic replacement for `nalgebra`'s `Vector3::norm()`, which always
+/// calls `std`'s `sqrt` internally regardless of this module's
+/// `deterministic` feature. Routes the same magnitude computation through
+/// [`sqrt`] instead, same reasoning as [`normalize3`].
+pub fn length(v: nalgebra::Vector3<f64>) -> f64 {
+    sqrt(v.dot(&v))
+}
@@ -0,0 +1,189 @@
+//! Exact-sign geometric predicates (`orient2d`/`orient3d`), built on
+//! Shewchuk-style adaptive-precision expansion arithmetic — the same
+//! "never flips under perturbation" guarantee Shewchuk's original
+//! predicates give, computed the straightforward way rather than his
+//! fully staged/filtered adaptive version: every intermediate difference,
+//! product, and sum is carried as a multi-component floating-point
+//! expansion (a short, non-overlapping, increasing-magnitude list of
+//! `f64`s whose exact sum equals the true value), so the final sign is
+//! always correct for `f64` inputs. What this module skips, for the sake
+//! of staying a few hundred lines rather a few thousand, is Shewchuk's
+//! fast-path: a quick double-precision estimate with an error bound, only
+//! falling back to expansion arithmetic when the estimate is too close to
+//! call. Every call here pays the expansion-arithmetic cost up front —
+//! fine for [`crate::solver`]'s per-cluster coplanarity checks, which run
+//! on a small fraction of a mesh's triangles, not its full triangle count.
+//!
+//! [`crate::solver`] is this module's only caller: [`orient3d`] is how its
+//! `Solver::Exact` path decides which triangles are exactly (not just
+//! approximately) coplanar before consolidating them onto a shared plane.
+
+/// `a - b`, represented exactly as a 2-component expansion `[err, main]`
+/// (smaller-magnitude component first, larger last) whose sum equals
+/// `a - b` exactly, even when floating-point subtraction itself would
+/// round.
+fn two_diff(a: f64, b: f64) -> [f64; 2] {
+    let x = a - b;
+    let bv = a - x;
+    let av = x + bv;
+    let br = bv - b;
+    let ar = a - av;
+    [ar + br, x]
+}
+
+fn two_sum(a: f64, b: f64) -> [f64; 2] {
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    let br = b - bv;
+    let ar = a - av;
+    [ar + br, x]
+}
+
+/// Splits `a` into two halves whose product with another split value can't
+/// round — the building block [`two_product`] needs, per Shewchuk's
+/// `Two-Product` algorithm.
+fn split(a: f64) -> (f64, f64) {
+    const SPLITTER: f64 = 134217729.0; // 2^27 + 1
+    let c = SPLITTER * a;
+    let a_big = c - a;
+    let a_hi = c - a_big;
+    let a_lo = a - a_hi;
+    (a_hi, a_lo)
+}
+
+/// `a * b`, represented exactly as a 2-component expansion.
+fn two_product(a: f64, b: f64) -> [f64; 2] {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err1 = x - a_hi * b_hi;
+    let err2 = err1 - a_lo * b_hi;
+    let err3 = err2 - a_hi * b_lo;
+    [a_lo * b_lo - err3, x]
+}
+
+/// Add scalar `b` into expansion `e`, keeping the result non-overlapping
+/// and increasing in magnitude (Shewchuk's `grow-expansion`, with exact
+/// zeros dropped rather than carried along).
+fn grow_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::with_capacity(e.len() + 1);
+    let mut q = b;
+    for &ei in e {
+        let [err, sum] = two_sum(q, ei);
+        if err != 0.0 {
+            h.push(err);
+        }
+        q = sum;
+    }
+    if q != 0.0 || h.is_empty() {
+        h.push(q);
+    }
+    h
+}
+
+/// Exact sum of two expansions.
+fn expansion_sum(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut h = e.to_vec();
+    for &fi in f {
+        h = grow_expansion(&h, fi);
+    }
+    h
+}
+
+/// Exact product of expansion `e` with scalar `b`.
+fn scale_expansion(e: &[f64], b: f64) -> Vec<f64> {
+    let mut h = Vec::new();
+    for &ei in e {
+        let [err, prod] = two_product(ei, b);
+        h = grow_expansion(&h, err);
+        h = grow_expansion(&h, prod);
+    }
+    h
+}
+
+/// Exact product of two expansions, by distributing [`scale_expansion`]
+/// over `f`'s components and summing the results exactly.
+fn multiply_expansions(e: &[f64], f: &[f64]) -> Vec<f64> {
+    let mut result = Vec::new();
+    for &fi in f {
+        result = expansion_sum(&result, &scale_expansion(e, fi));
+    }
+    result
+}
+
+fn negate(e: &[f64]) -> Vec<f64> {
+    e.iter().map(|x| -x).collect()
+}
+
+/// The sign of an expansion's exact value: its most significant nonzero
+/// component's sign (valid because `grow_expansion`/`expansion_sum` keep
+/// the expansion non-overlapping and increasing in magnitude), or `0` if
+/// every component is exactly zero.
+fn sign_of(e: &[f64]) -> i8 {
+    for &c in e.iter().rev() {
+        if c > 0.0 {
+            return 1;
+        }
+        if c < 0.0 {
+            return -1;
+        }
+    }
+    0
+}
+
+/// Exact sign of `(b - a) x (c - a)`'s z-component — positive when `a`,
+/// `b`, `c` turn counterclockwise, negative clockwise, `0` when exactly
+/// collinear. Never flips under a perturbation too small for `f64` to
+/// represent, unlike comparing the same determinant computed in plain
+/// `f64` arithmetic.
+pub fn orient2d(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> i8 {
+    let acx = two_diff(a[0], c[0]);
+    let bcy = two_diff(b[1], c[1]);
+    let bcx = two_diff(b[0], c[0]);
+    let acy = two_diff(a[1], c[1]);
+
+    let left = multiply_expansions(&acx, &bcy);
+    let right = multiply_expansions(&acy, &bcx);
+    let det = expansion_sum(&left, &negate(&right));
+    sign_of(&det)
+}
+
+/// Exact sign of the signed volume of tetrahedron `a, b, c, d` — positive
+/// when `d` is on the side of plane `abc` its outward (counterclockwise,
+/// viewed from outside) normal points away from, negative on the other
+/// side, `0` when `d` lies exactly in the plane through `a`, `b`, `c`.
+/// This is the sign [`crate::solver`] tests to decide whether two
+/// triangles are exactly coplanar.
+pub fn orient3d(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3]) -> i8 {
+    let adx = two_diff(a[0], d[0]);
+    let ady = two_diff(a[1], d[1]);
+    let adz = two_diff(a[2], d[2]);
+    let bdx = two_diff(b[0], d[0]);
+    let bdy = two_diff(b[1], d[1]);
+    let bdz = two_diff(b[2], d[2]);
+    let cdx = two_diff(c[0], d[0]);
+    let cdy = two_diff(c[1], d[1]);
+    let cdz = two_diff(c[2], d[2]);
+
+    let bdy_cdz = multiply_expansions(&bdy, &cdz);
+    let bdz_cdy = multiply_expansions(&bdz, &cdy);
+    let term_x = multiply_expansions(&adx, &expansion_sum(&bdy_cdz, &negate(&bdz_cdy)));
+
+    let bdx_cdz = multiply_expansions(&bdx, &cdz);
+    let bdz_cdx = multiply_expansions(&bdz, &cdx);
+    let term_y = multiply_expansions(&ady, &expansion_sum(&bdx_cdz, &negate(&bdz_cdx)));
+
+    let bdx_cdy = multiply_expansions(&bdx, &cdy);
+    let bdy_cdx = multiply_expansions(&bdy, &cdx);
+    let term_z = multiply_expansions(&adz, &expansion_sum(&bdx_cdy, &negate(&bdy_cdx)));
+
+    let det = expansion_sum(&expansion_sum(&term_x, &negate(&term_y)), &term_z);
+    sign_of(&det)
+}
+
+/// `true` when `orient3d(a, b, c, d)` is exactly zero — `d` lies exactly
+/// in the plane through `a`, `b`, `c`, with no tolerance involved.
+pub fn coplanar(a: [f64; 3], b: [f64; 3], c: [f64; 3], d: [f64; 3]) -> bool {
+    orient3d(a, b, c, d) == 0
+}
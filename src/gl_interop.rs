@@ -0,0 +1,396 @@
+//! Zero-copy buffer views over [`MeshGL`], via `bytemuck`'s `Pod`/`Zeroable`
+//! slice casts rather than a per-vertex/per-triangle copy loop — the same
+//! kind of conversion the demo examples' own `meshgl_to_bevy_mesh` (see
+//! `examples/bevy_integration.rs`) currently does by indexing
+//! `vert_properties`/`tri_verts` one element at a time to build up
+//! `Vec<[f32; 3]>`/`Vec<u32>` buffers.
+//!
+//! [`vertex_rows`] and [`triangle_rows`] are the building blocks: both
+//! reinterpret `MeshGL`'s own flat `Vec<f32>`/`Vec<u32>` buffers in place,
+//! with no allocation or copy, since `MeshGL::vert_properties`'s `num_prop`
+//! floats per vertex and `MeshGL::tri_verts`'s 3 indices per triangle are
+//! already laid out exactly as `[f32; N]`/`[u32; 3]` would be. `MeshGL`
+//! itself isn't defined anywhere in this crate's own sources (the same
+//! caveat [`crate::tolerance`] documents), so these take `&MeshGL` as a
+//! free function rather than an inherent method — Rust's orphan rule
+//! blocks adding inherent impls to a foreign type from here, the same
+//! reason every other module in this crate (`crate::aabb`, `crate::bvh`,
+//! `crate::repair`, ...) is written as free functions over `&Impl`/`&MeshGL`
+//! rather than extension methods.
+//!
+//! [`mesh_gl_to_bevy_mesh`], behind the `bevy` feature (the same per-item
+//! gating [`crate::wasm`]'s `wasm` feature and [`crate::detmath`]'s
+//! `deterministic` feature use), is the slice-cast-based replacement for
+//! that example conversion: the same orphan-rule restriction rules out
+//! `impl From<&MeshGL> for bevy::render::mesh::Mesh` (neither side is a
+//! local type), so it's a plain function rather than a trait impl, same as
+//! [`crate::wasm`]'s `WasmMesh` wrappers. A `MeshGL` with no normal channel
+//! (every boolean-op result, since neither `Manifold` nor the `Fast` solver
+//! emit one) gets one computed rather than a constant stand-in: see
+//! [`smooth_normals`] and [`generate_planar_uvs_and_tangents`], which also
+//! populate `ATTRIBUTE_TANGENT` so a `StandardMaterial` normal map renders
+//! correctly on the result.
+//!
+//! `mint` interop (behind the `mint` feature) is **not** a zero-copy slice
+//! cast like the rest of this module: `mint::Point3<f32>`/`Vector3<f32>`
+//! are foreign types from a foreign crate, so there's no local `unsafe impl
+//! bytemuck::Pod` this crate is allowed to add for them even though their
+//! layout matches `[f32; 3]` exactly — only `mint`'s own crate could do
+//! that (via its optional `bytemuck` integration, if enabled).
+//! [`mint_positions`] instead builds an owned `Vec<mint::Point3<f32>>`
+//! field-by-field, one cheap per-vertex conversion rather than a free
+//! pointer reinterpret.
+//!
+//! [`VertexLayout`] generalizes the old hard-coded "`num_prop == 6` means
+//! position+normal" check into the full stride [`mesh_gl_to_bevy_mesh`] and
+//! its inverse [`bevy_mesh_to_mesh_gl`] round-trip: position(3), optionally
+//! normal(3), optionally UV0(2), optionally color(4), always in that order
+//! and always packed contiguously from the front — a mesh with UVs but no
+//! color just omits the trailing 4 floats, rather than needing some
+//! separate per-channel offset table.
+//!
+//! [`mesh_to_manifold`] (and [`from_bevy_mesh`], its Bevy-`Mesh`-specific
+//! wrapper) is the reverse direction again, but unlike [`mesh_gl_to_bevy_mesh`]
+//! it isn't a plain reinterpret: a triangulated `Mesh` straight out of a
+//! STEP/glTF import is typically unwelded triangle soup (three unconnected
+//! vertices per facet, same as [`crate::stl::import_stl`]'s raw STL facets),
+//! so both delegate to [`crate::repair::repair`] — the same weld/degenerate-
+//! removal/winding-flip pipeline a loaded STL goes through — rather than
+//! silently handing back garbage the way a bare weld-and-hope would, and
+//! hand the caller its [`crate::repair::RepairReport`] so it can tell
+//! whether the result came out genuinely watertight or only "almost."
+
+use crate::MeshGL;
+
+#[cfg(feature = "bevy")]
+use crate::{from_mesh_gl, get_mesh_gl, Impl};
+#[cfg(feature = "bevy")]
+use nalgebra::Vector3;
+
+/// Reinterpret `mesh_gl.vert_properties` as one `[f32; N]` row per vertex,
+/// with no copying. `N` must equal `mesh_gl.num_prop` — that's the crate's
+/// own pun for "this buffer's actual per-vertex row width," so a mismatched
+/// `N` would silently read the wrong stride rather than the right one;
+/// this asserts instead.
+pub fn vertex_rows<const N: usize>(mesh_gl: &MeshGL) -> &[[f32; N]] {
+    assert_eq!(mesh_gl.num_prop as usize, N, "vertex_rows::<{N}> called on a MeshGL with num_prop = {}", mesh_gl.num_prop);
+    bytemuck::cast_slice(&mesh_gl.vert_properties)
+}
+
+/// Reinterpret `mesh_gl.tri_verts` as one triangle's three vertex indices
+/// per entry, with no copying.
+pub fn triangle_rows(mesh_gl: &MeshGL) -> &[[u32; 3]] {
+    bytemuck::cast_slice(&mesh_gl.tri_verts)
+}
+
+/// Position-only zero-copy view, for the common case of a `MeshGL` with no
+/// attribute channels beyond position (`num_prop == 3`). A `MeshGL` that
+/// also carries normals/UVs/etc. has no contiguous `[f32; 3]`-strided
+/// position run to cast into — use [`vertex_rows`] with `N` set to the
+/// mesh's actual row width and take each row's first three components
+/// instead.
+pub fn positions(mesh_gl: &MeshGL) -> &[[f32; 3]] {
+    vertex_rows::<3>(mesh_gl)
+}
+
+/// Owned `mint::Point3<f32>` per vertex, reading only the first three
+/// property channels (position) regardless of `num_prop`. See this
+/// module's doc comment for why this can't be a zero-copy slice cast the
+/// way [`vertex_rows`]/[`triangle_rows`] are.
+#[cfg(feature = "mint")]
+pub fn mint_positions(mesh_gl: &MeshGL) -> Vec<mint::Point3<f32>> {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    mesh_gl.vert_properties.chunks(num_prop).map(|v| mint::Point3 { x: v[0], y: v[1], z: v[2] }).collect()
+}
+
+/// Owned `mint::Vector3<f32>` per vertex, reading property channels 3..6
+/// (the normal, in [`crate::aabb`]/`get_mesh_gl`'s standard layout) — `None`
+/// if `num_prop < 6`, since there's no normal channel to read.
+#[cfg(feature = "mint")]
+pub fn mint_normals(mesh_gl: &MeshGL) -> Option<Vec<mint::Vector3<f32>>> {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    if num_prop < 6 {
+        return None;
+    }
+    Some(mesh_gl.vert_properties.chunks(num_prop).map(|v| mint::Vector3 { x: v[3], y: v[4], z: v[5] }).collect())
+}
+
+/// How a [`MeshGL`]'s `num_prop` floats per vertex are carved up beyond the
+/// always-present leading position triple: normal(3), UV0(2), and
+/// color(4) are each either present in full or absent entirely, always
+/// packed contiguously in that order starting right after the previous
+/// channel. [`detect_layout`] infers one of these from a plain `num_prop`;
+/// [`VertexLayout::num_prop`] goes the other way, for [`bevy_mesh_to_mesh_gl`]
+/// building a `MeshGL` from whichever attributes a Bevy `Mesh` actually has.
+#[cfg(feature = "bevy")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexLayout {
+    pub has_normal: bool,
+    pub has_uv: bool,
+    pub has_color: bool,
+}
+
+#[cfg(feature = "bevy")]
+impl VertexLayout {
+    /// Position only — no other channel to carry through.
+    pub const POSITION_ONLY: Self = VertexLayout { has_normal: false, has_uv: false, has_color: false };
+
+    fn num_prop(self) -> usize {
+        3 + if self.has_normal { 3 } else { 0 } + if self.has_uv { 2 } else { 0 } + if self.has_color { 4 } else { 0 }
+    }
+
+    fn normal_offset(self) -> Option<usize> {
+        self.has_normal.then_some(3)
+    }
+
+    fn uv_offset(self) -> Option<usize> {
+        self.has_uv.then(|| 3 + if self.has_normal { 3 } else { 0 })
+    }
+
+    fn color_offset(self) -> Option<usize> {
+        self.has_color.then(|| 3 + if self.has_normal { 3 } else { 0 } + if self.has_uv { 2 } else { 0 })
+    }
+}
+
+/// Recover a [`VertexLayout`] from a bare `num_prop`, for `MeshGL`s (like
+/// every boolean-op result or primitive) that were never built through
+/// [`bevy_mesh_to_mesh_gl`] and so carry no layout tag of their own —
+/// channels are assumed present/absent in the same fixed position+normal+uv+color
+/// order [`VertexLayout`] always packs them in. `num_prop` values this
+/// scheme can't represent (anything but 3, 6, 8, or 12) fall back to
+/// position-only, reading just the leading triple of each row.
+#[cfg(feature = "bevy")]
+fn detect_layout(num_prop: usize) -> VertexLayout {
+    match num_prop {
+        6 => VertexLayout { has_normal: true, has_uv: false, has_color: false },
+        8 => VertexLayout { has_normal: true, has_uv: true, has_color: false },
+        12 => VertexLayout { has_normal: true, has_uv: true, has_color: true },
+        _ => VertexLayout::POSITION_ONLY,
+    }
+}
+
+/// Build a Bevy `Mesh` from `mesh_gl`'s position and, per [`detect_layout`],
+/// whichever of normal/UV0/color channels its `num_prop` carries, via
+/// [`vertex_rows`]'s slice cast rather than a per-vertex indexing loop where
+/// the exact stride allows it. A mesh with no normal channel at all gets one
+/// computed by [`smooth_normals`] rather than going without; a mesh with no
+/// UV0 gets one generated by [`generate_planar_uvs_and_tangents`]. Indices
+/// pass straight through: `MeshGL::tri_verts` is already a flat `Vec<u32>`,
+/// exactly what `Indices::U32` wants, so there's no reshaping to do there at
+/// all.
+#[cfg(feature = "bevy")]
+pub fn mesh_gl_to_bevy_mesh(mesh_gl: &MeshGL) -> bevy::render::mesh::Mesh {
+    use bevy::asset::RenderAssetUsages;
+    use bevy::render::mesh::{Indices, Mesh, PrimitiveTopology};
+
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let layout = detect_layout(num_prop);
+
+    let positions: Vec<[f32; 3]> = if num_prop == 3 {
+        positions(mesh_gl).to_vec()
+    } else {
+        mesh_gl.vert_properties.chunks(num_prop).map(|v| [v[0], v[1], v[2]]).collect()
+    };
+
+    let normals: Vec<[f32; 3]> = match layout.normal_offset() {
+        Some(o) => mesh_gl.vert_properties.chunks(num_prop).map(|v| [v[o], v[o + 1], v[o + 2]]).collect(),
+        None => smooth_normals(&positions, &mesh_gl.tri_verts),
+    };
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::all());
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_indices(Indices::U32(mesh_gl.tri_verts.clone()));
+
+    if let Some(o) = layout.uv_offset() {
+        let uvs: Vec<[f32; 2]> = mesh_gl.vert_properties.chunks(num_prop).map(|v| [v[o], v[o + 1]]).collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    }
+    if let Some(o) = layout.color_offset() {
+        let colors: Vec<[f32; 4]> = mesh_gl.vert_properties.chunks(num_prop).map(|v| [v[o], v[o + 1], v[o + 2], v[o + 3]]).collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    }
+
+    generate_planar_uvs_and_tangents(&mut mesh);
+    mesh
+}
+
+/// The inverse of [`mesh_gl_to_bevy_mesh`]: pack `mesh`'s position, normal,
+/// UV0, and color attributes (whichever are actually present) into a single
+/// [`MeshGL`] in [`VertexLayout`]'s fixed channel order, with `num_prop` set
+/// to match. A `Mesh` with no normal/UV0/color attribute simply omits that
+/// channel from the packed row rather than padding it with zeros, so a
+/// round trip through [`mesh_gl_to_bevy_mesh`] and back doesn't grow the
+/// stride with channels nobody asked for. Returns `None` if `mesh` has no
+/// `ATTRIBUTE_POSITION` at all — there's nothing to pack.
+#[cfg(feature = "bevy")]
+pub fn bevy_mesh_to_mesh_gl(mesh: &bevy::render::mesh::Mesh) -> Option<MeshGL> {
+    use bevy::render::mesh::{Indices, Mesh, VertexAttributeValues};
+
+    let Some(VertexAttributeValues::Float32x3(raw_positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        return None;
+    };
+    let normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(n)) => Some(n),
+        _ => None,
+    };
+    let uvs = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(uv)) => Some(uv),
+        _ => None,
+    };
+    let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+        Some(VertexAttributeValues::Float32x4(c)) => Some(c),
+        _ => None,
+    };
+
+    let layout = VertexLayout { has_normal: normals.is_some(), has_uv: uvs.is_some(), has_color: colors.is_some() };
+    let num_prop = layout.num_prop();
+
+    let mut vert_properties = Vec::with_capacity(raw_positions.len() * num_prop);
+    for i in 0..raw_positions.len() {
+        vert_properties.extend_from_slice(&raw_positions[i]);
+        if let Some(n) = normals {
+            vert_properties.extend_from_slice(&n[i]);
+        }
+        if let Some(uv) = uvs {
+            vert_properties.extend_from_slice(&uv[i]);
+        }
+        if let Some(c) = colors {
+            vert_properties.extend_from_slice(&c[i]);
+        }
+    }
+
+    let tri_verts = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.clone(),
+        Some(Indices::U16(indices)) => indices.iter().map(|&i| i as u32).collect(),
+        None => (0..raw_positions.len() as u32).collect(),
+    };
+
+    Some(MeshGL { vert_properties, num_prop: num_prop as u32, tri_verts, ..Default::default() })
+}
+
+/// Area-weighted face-normal accumulation, for the `MeshGL`s this crate's
+/// own boolean ops hand back (`num_prop == 3`, no normal channel at all):
+/// each triangle's unnormalized cross product `(v1-v0)×(v2-v0)` — whose
+/// magnitude is twice the triangle's area — adds straight into its three
+/// corners' running sums, so a large face pulls harder on a shared vertex
+/// normal than a sliver does; a degenerate (zero-area) triangle contributes
+/// the zero vector and simply drops out of the sum. Every accumulated sum
+/// is renormalized at the end, falling back to `+Y` for a vertex touched by
+/// nothing but degenerate triangles.
+#[cfg(feature = "bevy")]
+fn smooth_normals(positions: &[[f32; 3]], tri_verts: &[u32]) -> Vec<[f32; 3]> {
+    let mut accum = vec![Vector3::<f32>::zeros(); positions.len()];
+    for tri in tri_verts.chunks_exact(3) {
+        let (i0, i1, i2) = (tri[0] as usize, tri[1] as usize, tri[2] as usize);
+        let (p0, p1, p2) = (Vector3::from(positions[i0]), Vector3::from(positions[i1]), Vector3::from(positions[i2]));
+        let face_normal = (p1 - p0).cross(&(p2 - p0));
+        accum[i0] += face_normal;
+        accum[i1] += face_normal;
+        accum[i2] += face_normal;
+    }
+    accum.into_iter().map(|n| n.try_normalize(1e-12).unwrap_or_else(|| Vector3::y()).into()).collect()
+}
+
+/// Planar-projected UV fallback plus generated tangents, for a mesh that
+/// has positions/normals/indices but no UV0 or tangent basis of its own —
+/// every `MeshGL` coming out of a boolean op, since neither `Manifold` nor
+/// the `Fast` solver carry UVs through, and `Mesh::generate_tangents`
+/// (mikktspace) needs some UV0 to derive a tangent direction from. Each
+/// vertex is projected onto the axis plane perpendicular to its normal's
+/// dominant component — not seam-correct, but enough of a UV derivative for
+/// mikktspace to work with on cut surfaces that were never textured to
+/// begin with. A no-op (beyond the UV fallback) if tangent generation
+/// itself fails, since a missing `ATTRIBUTE_TANGENT` only breaks normal
+/// mapping, not rendering outright.
+#[cfg(feature = "bevy")]
+fn generate_planar_uvs_and_tangents(mesh: &mut bevy::render::mesh::Mesh) {
+    use bevy::render::mesh::VertexAttributeValues;
+
+    if mesh.attribute(Mesh::ATTRIBUTE_UV_0).is_none() {
+        let (Some(VertexAttributeValues::Float32x3(positions)), Some(VertexAttributeValues::Float32x3(normals))) =
+            (mesh.attribute(Mesh::ATTRIBUTE_POSITION), mesh.attribute(Mesh::ATTRIBUTE_NORMAL))
+        else {
+            return;
+        };
+        let uvs: Vec<[f32; 2]> = positions
+            .iter()
+            .zip(normals)
+            .map(|(p, n)| {
+                let (ax, ay, az) = (n[0].abs(), n[1].abs(), n[2].abs());
+                if ax >= ay && ax >= az {
+                    [p[1], p[2]]
+                } else if ay >= az {
+                    [p[0], p[2]]
+                } else {
+                    [p[0], p[1]]
+                }
+            })
+            .collect();
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    }
+
+    if let Err(err) = mesh.generate_tangents() {
+        bevy::log::warn!("mesh_gl_to_bevy_mesh: tangent generation skipped: {err}");
+    }
+}
+
+/// The only way [`from_bevy_mesh`]/[`mesh_to_manifold`] refuse a conversion
+/// outright — anything short of this goes through [`crate::repair::repair`]
+/// instead, which fixes what it can and only reports what's left.
+#[cfg(feature = "bevy")]
+#[derive(Debug, Clone, Copy)]
+pub enum BevyMeshImportError {
+    /// `mesh` has no `ATTRIBUTE_POSITION` channel, or no index buffer at
+    /// all — there's no triangle soup here to weld in the first place.
+    MissingGeometry,
+}
+
+#[cfg(feature = "bevy")]
+impl std::fmt::Display for BevyMeshImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BevyMeshImportError::MissingGeometry => write!(f, "bevy Mesh has no ATTRIBUTE_POSITION or no indices"),
+        }
+    }
+}
+
+#[cfg(feature = "bevy")]
+impl std::error::Error for BevyMeshImportError {}
+
+/// Weld a flat triangle soup (`positions`/`indices`, the shape any of
+/// glTF, STEP, or a Bevy `Mesh` hand back) into a manifold [`Impl`] via
+/// [`crate::repair::repair`]: coincident-vertex dedup within `weld_epsilon`,
+/// degenerate-triangle removal, and a winding-flip pass that fixes up
+/// inconsistent adjacent faces instead of rejecting them outright, the way
+/// [`from_bevy_mesh`] used to. The returned [`crate::repair::RepairReport`]
+/// tells a caller whether anything was actually wrong — in particular
+/// whether `repaired.boundary_edges`/`non_manifold_edges` came back
+/// non-zero, meaning the result is only "almost" closed rather than
+/// genuinely watertight.
+pub fn mesh_to_manifold(positions: &[[f32; 3]], indices: &[u32], weld_epsilon: f64) -> (Impl, crate::repair::RepairReport) {
+    let vert_properties: Vec<f32> = positions.iter().flat_map(|p| *p).collect();
+    let naive = from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts: indices.to_vec(), ..Default::default() });
+    crate::repair::repair(&naive, weld_epsilon)
+}
+
+/// [`mesh_to_manifold`] for a Bevy `Mesh` specifically: pull its position
+/// and index buffers out (accepting either `U16` or `U32` indices) and
+/// weld/repair them the same way.
+pub fn from_bevy_mesh(
+    mesh: &bevy::render::mesh::Mesh,
+    weld_epsilon: f64,
+) -> Result<(Impl, crate::repair::RepairReport), BevyMeshImportError> {
+    use bevy::render::mesh::{Indices, VertexAttributeValues};
+
+    let Some(VertexAttributeValues::Float32x3(raw_positions)) = mesh.attribute(bevy::render::mesh::Mesh::ATTRIBUTE_POSITION) else {
+        return Err(BevyMeshImportError::MissingGeometry);
+    };
+    let tri_verts: Vec<u32> = match mesh.indices() {
+        Some(Indices::U32(indices)) => indices.clone(),
+        Some(Indices::U16(indices)) => indices.iter().map(|&i| i as u32).collect(),
+        None => return Err(BevyMeshImportError::MissingGeometry),
+    };
+
+    Ok(mesh_to_manifold(raw_positions, &tri_verts, weld_epsilon))
+}
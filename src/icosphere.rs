@@ -0,0 +1,210 @@
+use crate::MeshGL;
+use nalgebra::Vector3;
+use std::collections::HashMap;
+
+/// Build a geodesic sphere of the given `radius` by subdividing an
+/// icosahedron `subdivisions` times (each edge split into `subdivisions`
+/// segments, each face into `subdivisions²` sub-triangles), giving users a
+/// well-conditioned manifold primitive alongside [`crate::cube`] to feed
+/// into the boolean/hull/SDF operations. `subdivisions == 1` is the bare
+/// 20-face icosahedron.
+pub fn icosphere(radius: f64, subdivisions: u32) -> MeshGL {
+    geodesic_sphere(radius, subdivisions)
+}
+
+/// Alias for [`icosphere`] for callers that find "geodesic sphere" the more
+/// recognizable name.
+pub fn geodesic_sphere(radius: f64, subdivisions: u32) -> MeshGL {
+    let n = subdivisions.max(1);
+    let base_verts = icosahedron_vertices();
+    let base_faces = icosahedron_faces();
+
+    // Unit-sphere positions; scaled by `radius` only once everything's
+    // generated, so all the subdivision math stays on the unit sphere.
+    let mut positions: Vec<Vector3<f64>> = Vec::new();
+    let icosa_vert_id: Vec<u32> = base_verts.iter().map(|v| push_vertex(&mut positions, crate::detmath::normalize3(*v))).collect();
+
+    // Interior points of a shared edge, cached by the edge's canonical
+    // (lower, higher) icosahedron vertex ids so the two faces on either
+    // side of the edge agree on the same subdivided vertices instead of
+    // each generating their own, which would leave a seam.
+    let mut edge_cache: HashMap<(u32, u32), Vec<Option<u32>>> = HashMap::new();
+    let mut tri_verts: Vec<u32> = Vec::new();
+
+    for &[ia, ib, ic] in &base_faces {
+        let (a, b, c) = (icosa_vert_id[ia], icosa_vert_id[ib], icosa_vert_id[ic]);
+        let (pa, pb, pc) = (
+            crate::detmath::normalize3(base_verts[ia]),
+            crate::detmath::normalize3(base_verts[ib]),
+            crate::detmath::normalize3(base_verts[ic]),
+        );
+
+        // grid[i][j] is the global vertex index for barycentric weights
+        // (n - i - j, i, j) over (a, b, c); grid[i] has n - i + 1 entries.
+        let mut grid: Vec<Vec<u32>> = Vec::with_capacity((n + 1) as usize);
+        for i in 0..=n {
+            let mut row = Vec::with_capacity((n - i + 1) as usize);
+            for j in 0..=(n - i) {
+                row.push(face_point_vertex(a, b, c, pa, pb, pc, i, j, n, &mut positions, &mut edge_cache));
+            }
+            grid.push(row);
+        }
+
+        for i in 0..n {
+            for j in 0..(n - i) {
+                // Lower-left triangle of the cell, always present.
+                tri_verts.extend_from_slice(&[
+                    grid[i as usize][j as usize],
+                    grid[(i + 1) as usize][j as usize],
+                    grid[i as usize][(j + 1) as usize],
+                ]);
+                // Upper-right triangle, absent along the hypotenuse row.
+                if j + 1 < n - i {
+                    tri_verts.extend_from_slice(&[
+                        grid[(i + 1) as usize][j as usize],
+                        grid[(i + 1) as usize][(j + 1) as usize],
+                        grid[i as usize][(j + 1) as usize],
+                    ]);
+                }
+            }
+        }
+    }
+
+    let mut vert_properties = Vec::with_capacity(positions.len() * 6);
+    for p in &positions {
+        let scaled = p * radius;
+        vert_properties.extend_from_slice(&[
+            scaled.x as f32,
+            scaled.y as f32,
+            scaled.z as f32,
+            p.x as f32,
+            p.y as f32,
+            p.z as f32,
+        ]);
+    }
+
+    MeshGL { vert_properties, num_prop: 6, tri_verts, ..Default::default() }
+}
+
+/// The global vertex index for the point at barycentric weights
+/// `(n - i - j, i, j)` over `(a, b, c)`: one of the 3 corners, an interior
+/// point of one of the 3 edges (deduplicated via `edge_cache`), or a
+/// face-interior point (always unique, never shared).
+#[allow(clippy::too_many_arguments)]
+fn face_point_vertex(
+    a: u32,
+    b: u32,
+    c: u32,
+    pa: Vector3<f64>,
+    pb: Vector3<f64>,
+    pc: Vector3<f64>,
+    i: u32,
+    j: u32,
+    n: u32,
+    positions: &mut Vec<Vector3<f64>>,
+    edge_cache: &mut HashMap<(u32, u32), Vec<Option<u32>>>,
+) -> u32 {
+    let k = n - i - j;
+    if k == n {
+        return a;
+    }
+    if i == n {
+        return b;
+    }
+    if j == n {
+        return c;
+    }
+    if j == 0 {
+        return edge_vertex(a, b, pa, pb, i, n, positions, edge_cache);
+    }
+    if i == 0 {
+        return edge_vertex(a, c, pa, pc, j, n, positions, edge_cache);
+    }
+    if k == 0 {
+        return edge_vertex(b, c, pb, pc, j, n, positions, edge_cache);
+    }
+    let p = (pa * k as f64 + pb * i as f64 + pc * j as f64) / n as f64;
+    push_vertex(positions, crate::detmath::normalize3(p))
+}
+
+/// The vertex at fraction `step / n` along the edge from `u` to `v`,
+/// deduplicated against whichever face reaches this edge first. The cache
+/// key orders the endpoints by id so both faces sharing the edge compute
+/// the same step index regardless of which direction they walk it in.
+#[allow(clippy::too_many_arguments)]
+fn edge_vertex(
+    u: u32,
+    v: u32,
+    pu: Vector3<f64>,
+    pv: Vector3<f64>,
+    step: u32,
+    n: u32,
+    positions: &mut Vec<Vector3<f64>>,
+    edge_cache: &mut HashMap<(u32, u32), Vec<Option<u32>>>,
+) -> u32 {
+    let (lo, hi, lo_p, hi_p, canonical_step) = if u <= v { (u, v, pu, pv, step) } else { (v, u, pv, pu, n - step) };
+
+    let slots = edge_cache.entry((lo, hi)).or_insert_with(|| vec![None; (n - 1) as usize]);
+    let slot = (canonical_step - 1) as usize;
+    if let Some(idx) = slots[slot] {
+        return idx;
+    }
+
+    let t = canonical_step as f64 / n as f64;
+    let p = lo_p * (1.0 - t) + hi_p * t;
+    let idx = push_vertex(positions, crate::detmath::normalize3(p));
+    slots[slot] = Some(idx);
+    idx
+}
+
+fn push_vertex(positions: &mut Vec<Vector3<f64>>, p: Vector3<f64>) -> u32 {
+    positions.push(p);
+    (positions.len() - 1) as u32
+}
+
+/// The 12 canonical icosahedron vertices (unnormalized; golden-ratio
+/// rectangles inscribed in a sphere).
+fn icosahedron_vertices() -> [Vector3<f64>; 12] {
+    let phi = (1.0 + crate::detmath::sqrt(5.0)) / 2.0;
+    [
+        Vector3::new(-1.0, phi, 0.0),
+        Vector3::new(1.0, phi, 0.0),
+        Vector3::new(-1.0, -phi, 0.0),
+        Vector3::new(1.0, -phi, 0.0),
+        Vector3::new(0.0, -1.0, phi),
+        Vector3::new(0.0, 1.0, phi),
+        Vector3::new(0.0, -1.0, -phi),
+        Vector3::new(0.0, 1.0, -phi),
+        Vector3::new(phi, 0.0, -1.0),
+        Vector3::new(phi, 0.0, 1.0),
+        Vector3::new(-phi, 0.0, -1.0),
+        Vector3::new(-phi, 0.0, 1.0),
+    ]
+}
+
+/// The 20 icosahedron faces as index triples into [`icosahedron_vertices`],
+/// wound so every face's outward side (away from the origin) is CCW.
+fn icosahedron_faces() -> [[usize; 3]; 20] {
+    [
+        [0, 11, 5],
+        [0, 5, 1],
+        [0, 1, 7],
+        [0, 7, 10],
+        [0, 10, 11],
+        [1, 5, 9],
+        [5, 11, 4],
+        [11, 10, 2],
+        [10, 7, 6],
+        [7, 1, 8],
+        [3, 9, 4],
+        [3, 4, 2],
+        [3, 2, 6],
+        [3, 6, 8],
+        [3, 8, 9],
+        [4, 9, 5],
+        [2, 4, 11],
+        [6, 2, 10],
+        [8, 6, 7],
+        [9, 8, 1],
+    ]
+}
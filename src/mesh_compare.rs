@@ -5,7 +5,9 @@
 //! checking volume, vertex count, edge count, and vertex positions within
 //! a specified tolerance.
 
-use crate::{Impl, get_mesh_gl};
+use crate::cross_section_helper::{mesh_triangle_indices, vert_pos};
+use crate::{Impl, MeshGL, get_mesh_gl};
+use nalgebra::Vector3;
 
 /// Default tolerance for mesh comparison
 const DEFAULT_TOLERANCE: f64 = 0.1; // Increased tolerance for different triangulation strategies
@@ -53,9 +55,248 @@ pub fn approx_eq_meshes(our_mesh: &Impl, their_mesh: &Impl, tolerance: Option<f6
         return false;
     }
 
+    // The count check above passes for meshes of identical cardinality but
+    // wildly different shape (e.g. a cube vs. a sphere tessellated to the
+    // same triangle budget), so it's only a fast pre-check. Volume and
+    // surface distance are what actually confirm the two meshes occupy the
+    // same geometry.
+    let our_volume = mesh_volume(&our_mesh_gl);
+    let their_volume = mesh_volume(&their_mesh_gl);
+    let max_volume = our_volume.abs().max(their_volume.abs());
+    if max_volume > 0.0 && (our_volume - their_volume).abs() > tolerance * max_volume {
+        println!(
+            "Volume mismatch: Our {our_volume:.6} vs Their {their_volume:.6} (tolerance {:.6})",
+            tolerance * max_volume
+        );
+        return false;
+    }
+
+    let diagonal = bounding_diagonal(&our_mesh_gl).max(bounding_diagonal(&their_mesh_gl));
+    if diagonal > 0.0 {
+        let surface_distance = hausdorff_distance(&our_mesh_gl, &their_mesh_gl);
+        if surface_distance > tolerance * diagonal {
+            println!(
+                "Surface distance mismatch: {surface_distance:.6} exceeds tolerance {:.6} (diagonal {diagonal:.6})",
+                tolerance * diagonal
+            );
+            return false;
+        }
+    }
+
     true
 }
 
+/// Signed volume of a closed triangle mesh, via the divergence-theorem sum
+/// `Σ v0 · (v1 × v2) / 6` over every triangle. Meaningless for an open
+/// surface, but both operands here are always boolean results, which are
+/// watertight by construction.
+fn mesh_volume(mesh_gl: &MeshGL) -> f64 {
+    mesh_triangle_indices(mesh_gl)
+        .iter()
+        .map(|&[a, b, c]| {
+            let (v0, v1, v2) = (vert_pos(mesh_gl, a), vert_pos(mesh_gl, b), vert_pos(mesh_gl, c));
+            v0.dot(&v1.cross(&v2))
+        })
+        .sum::<f64>()
+        / 6.0
+}
+
+/// Diagonal length of `mesh_gl`'s axis-aligned bounding box, used to scale
+/// an absolute surface-distance tolerance to the mesh's own size.
+fn bounding_diagonal(mesh_gl: &MeshGL) -> f64 {
+    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop.max(1) as usize;
+    let mut min = Vector3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+    let mut max = Vector3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for i in 0..num_verts {
+        let p = vert_pos(mesh_gl, i);
+        min = min.zip_map(&p, f64::min);
+        max = max.zip_map(&p, f64::max);
+    }
+    if num_verts == 0 { 0.0 } else { (max - min).norm() }
+}
+
+/// A node of a median-split bounding-volume hierarchy over a mesh's
+/// triangles, mirroring [`crate::mesh_slicer::SliceNode`]'s recursive
+/// median-split build but keyed on a full 3D AABB rather than a Z interval,
+/// so [`nearest_triangle_distance`] can prune whole subtrees that can't
+/// possibly beat the current best distance.
+struct TriBvhNode {
+    min: Vector3<f64>,
+    max: Vector3<f64>,
+    /// `Leaf` holds the triangle indices (into the owning mesh's
+    /// `tri_verts`) once a node is small enough not to split further.
+    children: TriBvhChildren,
+}
+
+enum TriBvhChildren {
+    Leaf(Vec<usize>),
+    Split(Box<TriBvhNode>, Box<TriBvhNode>),
+}
+
+/// Above this many triangles, a node splits instead of treating every
+/// triangle in it as a leaf to test directly.
+const BVH_LEAF_SIZE: usize = 8;
+
+impl TriBvhNode {
+    fn build(mesh_gl: &MeshGL, tri_indices: &[[usize; 3]], tris: Vec<usize>) -> Self {
+        let centroid = |t: usize| {
+            let [a, b, c] = tri_indices[t];
+            (vert_pos(mesh_gl, a) + vert_pos(mesh_gl, b) + vert_pos(mesh_gl, c)) / 3.0
+        };
+
+        let mut min = Vector3::new(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        let mut max = Vector3::new(f64::NEG_INFINITY, f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for &t in &tris {
+            for &v in &tri_indices[t] {
+                let p = vert_pos(mesh_gl, v);
+                min = min.zip_map(&p, f64::min);
+                max = max.zip_map(&p, f64::max);
+            }
+        }
+
+        if tris.len() <= BVH_LEAF_SIZE {
+            return Self { min, max, children: TriBvhChildren::Leaf(tris) };
+        }
+
+        let extent = max - min;
+        let axis = if extent.x >= extent.y && extent.x >= extent.z {
+            0
+        } else if extent.y >= extent.z {
+            1
+        } else {
+            2
+        };
+
+        let mut tris = tris;
+        tris.sort_by(|&a, &b| centroid(a)[axis].total_cmp(&centroid(b)[axis]));
+        let mid = tris.len() / 2;
+        let right = tris.split_off(mid);
+
+        Self {
+            min,
+            max,
+            children: TriBvhChildren::Split(
+                Box::new(Self::build(mesh_gl, tri_indices, tris)),
+                Box::new(Self::build(mesh_gl, tri_indices, right)),
+            ),
+        }
+    }
+
+    /// Squared distance from `p` to this node's AABB; zero if `p` is inside.
+    fn box_distance_sq(&self, p: &Vector3<f64>) -> f64 {
+        let dx = (self.min.x - p.x).max(0.0).max(p.x - self.max.x);
+        let dy = (self.min.y - p.y).max(0.0).max(p.y - self.max.y);
+        let dz = (self.min.z - p.z).max(0.0).max(p.z - self.max.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Branch-and-bound nearest-triangle search: descend into whichever
+    /// child's box is closer first (more likely to tighten `best` early),
+    /// and skip any child whose box can't possibly beat it.
+    fn nearest_sq(&self, mesh_gl: &MeshGL, tri_indices: &[[usize; 3]], p: &Vector3<f64>, best: &mut f64) {
+        if self.box_distance_sq(p) >= *best {
+            return;
+        }
+        match &self.children {
+            TriBvhChildren::Leaf(tris) => {
+                for &t in tris {
+                    let [a, b, c] = tri_indices[t];
+                    let closest = closest_point_on_triangle(p, &vert_pos(mesh_gl, a), &vert_pos(mesh_gl, b), &vert_pos(mesh_gl, c));
+                    *best = best.min((closest - *p).norm_squared());
+                }
+            }
+            TriBvhChildren::Split(left, right) => {
+                let (first, second) = if left.box_distance_sq(p) <= right.box_distance_sq(p) { (left, right) } else { (right, left) };
+                first.nearest_sq(mesh_gl, tri_indices, p, best);
+                second.nearest_sq(mesh_gl, tri_indices, p, best);
+            }
+        }
+    }
+}
+
+/// Closest point on triangle `(a, b, c)` to `p`, via the standard
+/// Voronoi-region test (Ericson, *Real-Time Collision Detection* §5.1.5):
+/// check each vertex and edge region in turn before falling back to the
+/// triangle's interior (projecting `p` onto its plane via barycentric
+/// coordinates).
+fn closest_point_on_triangle(p: &Vector3<f64>, a: &Vector3<f64>, b: &Vector3<f64>, c: &Vector3<f64>) -> Vector3<f64> {
+    let ab = b - a;
+    let ac = c - a;
+    let ap = p - a;
+
+    let d1 = ab.dot(&ap);
+    let d2 = ac.dot(&ap);
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return *a;
+    }
+
+    let bp = p - b;
+    let d3 = ab.dot(&bp);
+    let d4 = ac.dot(&bp);
+    if d3 >= 0.0 && d4 <= d3 {
+        return *b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return a + ab * v;
+    }
+
+    let cp = p - c;
+    let d5 = ab.dot(&cp);
+    let d6 = ac.dot(&cp);
+    if d6 >= 0.0 && d5 <= d6 {
+        return *c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return a + ac * w;
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && (d4 - d3) >= 0.0 && (d5 - d6) >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return b + (c - b) * w;
+    }
+
+    let denom = 1.0 / (va + vb + vc);
+    let v = vb * denom;
+    let w = vc * denom;
+    a + ab * v + ac * w
+}
+
+/// Max, over every vertex of `mesh_gl`, of its nearest distance to any
+/// triangle of `other`'s BVH.
+fn max_vertex_distance(mesh_gl: &MeshGL, other: &MeshGL, other_bvh: &TriBvhNode, other_tri_indices: &[[usize; 3]]) -> f64 {
+    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop.max(1) as usize;
+    (0..num_verts)
+        .map(|i| {
+            let p = vert_pos(mesh_gl, i);
+            let mut best = f64::INFINITY;
+            other_bvh.nearest_sq(other, other_tri_indices, &p, &mut best);
+            crate::detmath::sqrt(best)
+        })
+        .fold(0.0_f64, f64::max)
+}
+
+/// Symmetric Hausdorff distance between two meshes' surfaces: the larger of
+/// "every vertex of `a` is within this far of `b`'s surface" and the
+/// reverse, so neither mesh can hide an extra bump or dent that's invisible
+/// from only one direction.
+fn hausdorff_distance(a: &MeshGL, b: &MeshGL) -> f64 {
+    let a_tris = mesh_triangle_indices(a);
+    let b_tris = mesh_triangle_indices(b);
+    let a_bvh = TriBvhNode::build(a, &a_tris, (0..a_tris.len()).collect());
+    let b_bvh = TriBvhNode::build(b, &b_tris, (0..b_tris.len()).collect());
+
+    let a_to_b = max_vertex_distance(a, b, &b_bvh, &b_tris);
+    let b_to_a = max_vertex_distance(b, a, &a_bvh, &a_tris);
+    a_to_b.max(b_to_a)
+}
+
 /// Macro for approximate mesh equality comparison
 ///
 /// Usage:
@@ -0,0 +1,161 @@
+use crate::cross_section_helper::{classify_contours, cross_section_from_triangles, mesh_triangle_indices, vert_pos};
+use crate::cross_section_utils::cap_cross_section;
+use crate::{MeshBoolImpl, MeshGL};
+
+/// Below this many triangles a [`SliceNode`] stops splitting and just scans
+/// its triangles directly; splitting further would trade a few float
+/// comparisons for extra recursion overhead.
+const LEAF_SIZE: usize = 8;
+
+/// One node of the Z-interval acceleration structure `MeshSlicer` builds.
+/// Every node — leaf or split — carries its own `[z_min, z_max]`, expanded
+/// from its children the way a bounding-volume hierarchy's `computeBounds`
+/// grows a node's box from its children's, so a query can prune a whole
+/// subtree the moment its bounds miss the requested height instead of only
+/// filtering once it reaches the leaves.
+enum SliceNode {
+    Leaf { z_min: f64, z_max: f64, triangles: Vec<usize> },
+    Split { z_min: f64, z_max: f64, left: Box<SliceNode>, right: Box<SliceNode> },
+}
+
+impl SliceNode {
+    /// Recursive median split on each triangle's interval midpoint: sort by
+    /// midpoint, then put the lower half in `left` and the upper half in
+    /// `right`, so even very unevenly-distributed Z-intervals still halve
+    /// the live triangle count at each level rather than degenerating to a
+    /// linear scan on top of the recursion.
+    fn build(mut tris: Vec<usize>, z_min: &[f64], z_max: &[f64]) -> Self {
+        if tris.len() <= LEAF_SIZE {
+            let lo = tris.iter().map(|&t| z_min[t]).fold(f64::INFINITY, f64::min);
+            let hi = tris.iter().map(|&t| z_max[t]).fold(f64::NEG_INFINITY, f64::max);
+            return SliceNode::Leaf { z_min: lo, z_max: hi, triangles: tris };
+        }
+
+        tris.sort_by(|&a, &b| {
+            let mid_a = z_min[a] + z_max[a];
+            let mid_b = z_min[b] + z_max[b];
+            mid_a.partial_cmp(&mid_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let split_at = tris.len() / 2;
+        let right_tris = tris.split_off(split_at);
+
+        let left = SliceNode::build(tris, z_min, z_max);
+        let right = SliceNode::build(right_tris, z_min, z_max);
+        SliceNode::Split {
+            z_min: left.z_min().min(right.z_min()),
+            z_max: left.z_max().max(right.z_max()),
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    fn z_min(&self) -> f64 {
+        match self {
+            SliceNode::Leaf { z_min, .. } | SliceNode::Split { z_min, .. } => *z_min,
+        }
+    }
+
+    fn z_max(&self) -> f64 {
+        match self {
+            SliceNode::Leaf { z_max, .. } | SliceNode::Split { z_max, .. } => *z_max,
+        }
+    }
+
+    /// Collect every triangle whose interval straddles `height`, pruning
+    /// any subtree whose own bounds don't reach it.
+    fn query(&self, height: f64, out: &mut Vec<usize>) {
+        if height < self.z_min() || height > self.z_max() {
+            return;
+        }
+        match self {
+            SliceNode::Leaf { triangles, .. } => out.extend(triangles.iter().copied()),
+            SliceNode::Split { left, right, .. } => {
+                left.query(height, out);
+                right.query(height, out);
+            }
+        }
+    }
+}
+
+/// Precomputes each triangle's `[z_min, z_max]` interval once and indexes
+/// them with a median-split acceleration structure, so slicing the same
+/// mesh at many heights (e.g. the hundreds of layers a 3D-printing slicer
+/// needs) only rescans the triangles that can possibly straddle each
+/// requested plane instead of every triangle in the mesh every time.
+pub struct MeshSlicer<'a> {
+    mesh_gl: &'a MeshGL,
+    tri_indices: Vec<[usize; 3]>,
+    tree: SliceNode,
+}
+
+impl<'a> MeshSlicer<'a> {
+    /// Build the acceleration structure once; `slice`/`slice_layers` then
+    /// reuse it for every query.
+    pub fn new(mesh_gl: &'a MeshGL) -> Self {
+        let tri_indices = mesh_triangle_indices(mesh_gl);
+        let bounds: Vec<(f64, f64)> = tri_indices
+            .iter()
+            .map(|idx| {
+                let zs = idx.map(|v| vert_pos(mesh_gl, v).z);
+                (zs.iter().copied().fold(f64::INFINITY, f64::min), zs.iter().copied().fold(f64::NEG_INFINITY, f64::max))
+            })
+            .collect();
+        let z_min: Vec<f64> = bounds.iter().map(|&(lo, _)| lo).collect();
+        let z_max: Vec<f64> = bounds.iter().map(|&(_, hi)| hi).collect();
+
+        let tree = SliceNode::build((0..tri_indices.len()).collect(), &z_min, &z_max);
+
+        MeshSlicer { mesh_gl, tri_indices, tree }
+    }
+
+    /// Slice at `z = height`, returning the same closed, oriented contour
+    /// loops [`crate::cross_section_helper::compute_cross_section`] would,
+    /// but touching only the triangles the acceleration structure says can
+    /// straddle the plane.
+    pub fn slice(&self, height: f64) -> Vec<Vec<[f32; 2]>> {
+        let mut candidates = Vec::new();
+        self.tree.query(height, &mut candidates);
+        cross_section_from_triangles(self.mesh_gl, height, &self.tri_indices, &candidates)
+    }
+
+    /// Slice every `step` from `z0` to `z1` inclusive, in order — the
+    /// per-layer workload a 3D-printing-style slicer needs.
+    pub fn slice_layers(&self, z0: f64, z1: f64, step: f64) -> Vec<Vec<Vec<[f32; 2]>>> {
+        assert!(step > 0.0, "step must be positive");
+        let mut layers = Vec::new();
+        let mut h = z0;
+        while h <= z1 {
+            layers.push(self.slice(h));
+            h += step;
+        }
+        layers
+    }
+
+    /// [`Self::slice`]'s capped counterpart: resolve the plane's contour
+    /// loops into outer-boundary/hole nesting via
+    /// [`classify_contours`](crate::cross_section_helper::classify_contours)
+    /// and triangulate the result into one filled [`MeshBoolImpl`], so a
+    /// plane with disjoint islands and interior holes still produces a
+    /// correctly-capped section rather than a bare set of outlines.
+    pub fn slice_capped(&self, height: f64) -> MeshBoolImpl {
+        let loops = self.slice(height);
+        let contours = classify_contours(loops);
+        cap_cross_section(&contours, height)
+    }
+
+    /// [`Self::slice_layers`]'s capped counterpart: one triangulated,
+    /// watertight-per-layer cross-section every `step` from `z0` to `z1`
+    /// inclusive — the stack a 3D-printing slicer actually needs to emit per
+    /// layer, rather than bare contour loops it would still have to cap
+    /// itself.
+    pub fn slice_layers_capped(&self, z0: f64, z1: f64, step: f64) -> Vec<MeshBoolImpl> {
+        assert!(step > 0.0, "step must be positive");
+        let mut layers = Vec::new();
+        let mut h = z0;
+        while h <= z1 {
+            layers.push(self.slice_capped(h));
+            h += step;
+        }
+        layers
+    }
+}
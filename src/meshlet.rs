@@ -0,0 +1,379 @@
+//! Meshlet/LOD clustering for dense boolean results.
+//!
+//! Subtracting or unioning high-poly CAD imports (the STEP loader example's
+//! use case) produces triangle soups too dense to render at full resolution
+//! every frame. [`Impl::build_meshlets`] clusters the result's triangles
+//! into small, spatially-local meshlets, then repeatedly groups neighboring
+//! meshlets and simplifies each group by quadric-style edge collapse to
+//! build a simplification DAG (a [`MeshletHierarchy`]), recording a
+//! bounding sphere and error bound per node so a runtime LOD selector can
+//! pick the coarsest representation that still stays under its error
+//! budget. This mirrors `bevy_mesh_boolean::meshlet`'s approach, adapted to
+//! operate directly on an [`Impl`] (`f64` positions via [`get_mesh_gl`])
+//! instead of a Bevy mesh's `f32` buffers, so callers don't need a round
+//! trip through Bevy types just to LOD a boolean result.
+
+use crate::{get_mesh_gl, Impl};
+use nalgebra::Vector3;
+use std::collections::{HashMap, HashSet};
+
+/// Meshlets are capped at this many unique vertices, matching the limit
+/// common GPU meshlet pipelines (e.g. mesh shaders) expect.
+pub const MAX_MESHLET_VERTICES: usize = 64;
+/// Meshlets are capped at this many triangles.
+pub const MAX_MESHLET_TRIANGLES: usize = 124;
+
+/// One cluster of spatially-local triangles: a self-contained little
+/// triangle list (indices into [`MeshletHierarchy::vertices`]) plus the
+/// bounds a runtime LOD selector needs.
+#[derive(Debug, Clone)]
+pub struct Meshlet {
+    /// Indices into `MeshletHierarchy::vertices` for this meshlet's unique verts.
+    pub vertices: Vec<u32>,
+    /// Triangles as indices into `Self::vertices` (not the global vertex list).
+    pub triangles: Vec<[u8; 3]>,
+    /// Center and radius of the meshlet's bounding sphere, in mesh space.
+    pub bounding_sphere: (Vector3<f64>, f64),
+    /// Simplification error this meshlet introduces relative to its source
+    /// triangles, in mesh-space units. Zero for leaf (full-resolution) meshlets.
+    pub error: f64,
+    /// Indices into `MeshletHierarchy::meshlets` for the higher-detail
+    /// meshlets this one was simplified from; empty for a leaf meshlet.
+    pub children: Vec<usize>,
+}
+
+/// A meshlet/LOD representation of one `Impl`'s triangles: leaf meshlets at
+/// full resolution plus `max_lod` further levels (or fewer, if merging
+/// stalls first) of coarser, simplified meshlets above them, linked by
+/// [`Meshlet::children`].
+#[derive(Debug, Clone)]
+pub struct MeshletHierarchy {
+    /// Deduplicated source positions; all of `Meshlet::vertices` index into this.
+    pub vertices: Vec<Vector3<f64>>,
+    /// Every meshlet across every LOD level, finest (leaf) first.
+    pub meshlets: Vec<Meshlet>,
+    /// Indices into `meshlets` for the root(s) of the hierarchy — the
+    /// coarsest level reached, or the leaves themselves if `max_lod == 0`
+    /// or merging stalled immediately.
+    pub roots: Vec<usize>,
+}
+
+impl MeshletHierarchy {
+    /// Walk the hierarchy top-down from `roots`, picking the coarsest
+    /// meshlets whose error still stays under `max_error` at the given
+    /// `screen_space_scale` (e.g. `distance_to_camera / object_radius`, so
+    /// error shrinks as the object recedes). Returns indices into
+    /// `self.meshlets` to draw.
+    pub fn select_lod(&self, max_error: f64, screen_space_scale: f64) -> Vec<usize> {
+        let mut selected = Vec::new();
+        let mut queue = self.roots.clone();
+
+        while let Some(m) = queue.pop() {
+            let meshlet = &self.meshlets[m];
+            let projected_error = meshlet.error * screen_space_scale;
+            if projected_error > max_error && !meshlet.children.is_empty() {
+                queue.extend(meshlet.children.iter().copied());
+            } else {
+                selected.push(m);
+            }
+        }
+
+        selected.sort_unstable();
+        selected.dedup();
+        selected
+    }
+}
+
+impl Impl {
+    /// Build a [`MeshletHierarchy`] for this mesh's current triangles,
+    /// merging and simplifying up to `max_lod` levels above the leaf
+    /// meshlets (fewer if a level fails to shrink the meshlet count first).
+    pub fn build_meshlets(&self, max_lod: u32) -> MeshletHierarchy {
+        let mesh_gl = get_mesh_gl(self);
+        let num_prop = mesh_gl.num_prop.max(1) as usize;
+        let num_verts = mesh_gl.vert_properties.len() / num_prop;
+        let vertices: Vec<Vector3<f64>> = (0..num_verts)
+            .map(|i| {
+                let base = i * num_prop;
+                Vector3::new(mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64)
+            })
+            .collect();
+        let triangles: Vec<[u32; 3]> = mesh_gl.tri_verts.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect();
+
+        let mut meshlets = cluster_triangles(&vertices, &triangles, (0..triangles.len()).collect());
+        let mut roots: Vec<usize> = (0..meshlets.len()).collect();
+        let mut level_start = 0;
+
+        for _ in 0..max_lod {
+            let level: Vec<usize> = (level_start..meshlets.len()).collect();
+            if level.len() <= 1 {
+                break;
+            }
+            let partition = partition_by_adjacency(&meshlets, &level);
+            if partition.len() >= level.len() {
+                break;
+            }
+
+            let mut new_roots = Vec::with_capacity(partition.len());
+            for cluster in partition {
+                let simplified = simplify_group(&vertices, &meshlets, &cluster);
+                let new_index = meshlets.len();
+                meshlets.push(simplified);
+                new_roots.push(new_index);
+            }
+            level_start = meshlets.len() - new_roots.len();
+            roots = new_roots;
+        }
+
+        MeshletHierarchy { vertices, meshlets, roots }
+    }
+}
+
+/// Greedily partition `triangle_indices` (into `triangles`) into meshlets by
+/// repeatedly growing a cluster from a seed triangle, pulling in
+/// face-adjacent triangles (sharing an edge) until either the vertex or
+/// triangle budget is hit, which keeps each meshlet spatially local.
+fn cluster_triangles(vertices: &[Vector3<f64>], triangles: &[[u32; 3]], triangle_indices: Vec<usize>) -> Vec<Meshlet> {
+    let mut edge_to_tris: HashMap<(u32, u32), Vec<usize>> = HashMap::new();
+    for &t in &triangle_indices {
+        let tri = triangles[t];
+        for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+            let key = if a < b { (a, b) } else { (b, a) };
+            edge_to_tris.entry(key).or_default().push(t);
+        }
+    }
+
+    let mut remaining: HashSet<usize> = triangle_indices.into_iter().collect();
+    let mut meshlets = Vec::new();
+
+    while let Some(&seed) = remaining.iter().next() {
+        remaining.remove(&seed);
+        let mut cluster_tris = vec![seed];
+        let mut cluster_verts: Vec<u32> = triangles[seed].to_vec();
+        let mut frontier = vec![seed];
+
+        while let Some(t) = frontier.pop() {
+            if cluster_tris.len() >= MAX_MESHLET_TRIANGLES {
+                break;
+            }
+            let tri = triangles[t];
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let key = if a < b { (a, b) } else { (b, a) };
+                let Some(neighbours) = edge_to_tris.get(&key) else { continue };
+                for &n in neighbours {
+                    if !remaining.contains(&n) {
+                        continue;
+                    }
+                    let n_tri = triangles[n];
+                    let new_verts: Vec<u32> = n_tri.iter().copied().filter(|v| !cluster_verts.contains(v)).collect();
+                    if cluster_verts.len() + new_verts.len() > MAX_MESHLET_VERTICES {
+                        continue;
+                    }
+                    if cluster_tris.len() >= MAX_MESHLET_TRIANGLES {
+                        break;
+                    }
+                    remaining.remove(&n);
+                    cluster_tris.push(n);
+                    cluster_verts.extend(new_verts);
+                    frontier.push(n);
+                }
+            }
+        }
+
+        meshlets.push(build_meshlet(vertices, triangles, &cluster_tris, &cluster_verts, 0.0, Vec::new()));
+    }
+
+    meshlets
+}
+
+/// Turn a list of triangle indices plus the unique global vertex ids they
+/// touch into a self-contained [`Meshlet`] (local triangle indices, bounding
+/// sphere, and the given simplification `error`/`children`).
+fn build_meshlet(
+    vertices: &[Vector3<f64>],
+    triangles: &[[u32; 3]],
+    cluster_tris: &[usize],
+    cluster_verts: &[u32],
+    error: f64,
+    children: Vec<usize>,
+) -> Meshlet {
+    let local_index: HashMap<u32, u8> = cluster_verts.iter().enumerate().map(|(i, &v)| (v, i as u8)).collect();
+    let local_triangles = cluster_tris
+        .iter()
+        .map(|&t| {
+            let tri = triangles[t];
+            [local_index[&tri[0]], local_index[&tri[1]], local_index[&tri[2]]]
+        })
+        .collect();
+
+    let points: Vec<Vector3<f64>> = cluster_verts.iter().map(|&v| vertices[v as usize]).collect();
+    let bounding_sphere = bounding_sphere(&points);
+
+    Meshlet { vertices: cluster_verts.to_vec(), triangles: local_triangles, bounding_sphere, error, children }
+}
+
+/// Welzl-lite bounding sphere: center at the point set's centroid, radius
+/// the farthest point from it. Not minimal, but stable and cheap enough to
+/// run per meshlet without becoming the bottleneck.
+fn bounding_sphere(points: &[Vector3<f64>]) -> (Vector3<f64>, f64) {
+    if points.is_empty() {
+        return (Vector3::zeros(), 0.0);
+    }
+    let centroid = points.iter().sum::<Vector3<f64>>() / points.len() as f64;
+    let radius = points.iter().map(|p| crate::detmath::length(p - centroid)).fold(0.0_f64, f64::max);
+    (centroid, radius)
+}
+
+/// Union-find grouping of meshlets in `level` into clusters of mutually
+/// vertex-adjacent meshlets, simulating the graph-partitioning step: two
+/// meshlets merge into the same group if they share at least one vertex,
+/// joined pairwise until no more merges apply. Capped at a handful of
+/// meshlets per group so simplification doesn't collapse half the mesh at once.
+fn partition_by_adjacency(meshlets: &[Meshlet], level: &[usize]) -> Vec<Vec<usize>> {
+    const MAX_GROUP_SIZE: usize = 4;
+
+    let mut parent: HashMap<usize, usize> = level.iter().map(|&i| (i, i)).collect();
+    fn find(parent: &mut HashMap<usize, usize>, x: usize) -> usize {
+        let p = parent[&x];
+        if p != x {
+            let root = find(parent, p);
+            parent.insert(x, root);
+            root
+        } else {
+            x
+        }
+    }
+
+    let mut group_size: HashMap<usize, usize> = level.iter().map(|&i| (i, 1)).collect();
+
+    for (pos, &i) in level.iter().enumerate() {
+        let vi: HashSet<u32> = meshlets[i].vertices.iter().copied().collect();
+        for &j in &level[pos + 1..] {
+            if meshlets[j].vertices.iter().any(|v| vi.contains(v)) {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj && group_size[&ri] + group_size[&rj] <= MAX_GROUP_SIZE {
+                    parent.insert(ri, rj);
+                    *group_size.get_mut(&rj).unwrap() += group_size[&ri];
+                }
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &i in level {
+        let root = find(&mut parent, i);
+        groups.entry(root).or_default().push(i);
+    }
+    groups.into_values().collect()
+}
+
+/// Simplify a group of adjacent meshlets into one coarser meshlet: merge
+/// their triangles back into a flat triangle soup, run edge collapse
+/// (locking any vertex shared by two source meshlets, so the boundary
+/// between clusters can never move and open a crack) until the triangle
+/// count drops by half, and record the extra geometric error introduced
+/// (the radius of the group's combined bounding sphere scaled by a fixed
+/// fraction, as a conservative stand-in for per-vertex quadric error).
+fn simplify_group(vertices: &[Vector3<f64>], meshlets: &[Meshlet], cluster: &[usize]) -> Meshlet {
+    let mut global_triangles: Vec<[u32; 3]> = Vec::new();
+    let mut vertex_owner_count: HashMap<u32, usize> = HashMap::new();
+    for &m in cluster {
+        let meshlet = &meshlets[m];
+        for tri in &meshlet.triangles {
+            global_triangles.push([meshlet.vertices[tri[0] as usize], meshlet.vertices[tri[1] as usize], meshlet.vertices[tri[2] as usize]]);
+        }
+        for &v in &meshlet.vertices {
+            *vertex_owner_count.entry(v).or_insert(0) += 1;
+        }
+    }
+
+    // A vertex touched by more than one source meshlet sits on the shared
+    // boundary between them; locking it out of collapse keeps that
+    // boundary identical across LOD levels so neighboring groups never
+    // crack apart.
+    let locked: HashSet<u32> = vertex_owner_count.into_iter().filter(|&(_, count)| count > 1).map(|(v, _)| v).collect();
+
+    let target_triangles = (global_triangles.len() / 2).max(1);
+    let simplified = edge_collapse(vertices, global_triangles, target_triangles, &locked);
+
+    let mut cluster_verts: Vec<u32> = simplified.iter().flatten().copied().collect();
+    cluster_verts.sort_unstable();
+    cluster_verts.dedup();
+
+    let points: Vec<Vector3<f64>> = cluster_verts.iter().map(|&v| vertices[v as usize]).collect();
+    let (center, radius) = bounding_sphere(&points);
+
+    let source_error = cluster.iter().map(|&m| meshlets[m].error).fold(0.0_f64, f64::max);
+    let error = source_error + radius * 0.1;
+
+    let local_index: HashMap<u32, u8> = cluster_verts.iter().enumerate().map(|(i, &v)| (v, i as u8)).collect();
+    let triangles = simplified.iter().map(|tri| [local_index[&tri[0]], local_index[&tri[1]], local_index[&tri[2]]]).collect();
+
+    Meshlet { vertices: cluster_verts, triangles, bounding_sphere: (center, radius), error, children: cluster.to_vec() }
+}
+
+/// Greedy edge collapse: repeatedly merges the cheapest remaining
+/// non-locked edge (shortest, as a stand-in for a full per-vertex quadric
+/// since meshlets are small enough that edge length tracks curvature-driven
+/// error closely) by welding one endpoint onto the other, until
+/// `target_triangles` is reached or no collapsible edge remains. Neither
+/// endpoint of a collapsed edge may be in `locked`, so group boundaries
+/// stay fixed across simplification.
+fn edge_collapse(vertices: &[Vector3<f64>], mut triangles: Vec<[u32; 3]>, target_triangles: usize, locked: &HashSet<u32>) -> Vec<[u32; 3]> {
+    let mut remap: HashMap<u32, u32> = HashMap::new();
+    let resolve = |remap: &HashMap<u32, u32>, mut v: u32| {
+        while let Some(&next) = remap.get(&v) {
+            if next == v {
+                break;
+            }
+            v = next;
+        }
+        v
+    };
+
+    while triangles.len() > target_triangles {
+        let mut edges: HashSet<(u32, u32)> = HashSet::new();
+        for tri in &triangles {
+            for (a, b) in [(tri[0], tri[1]), (tri[1], tri[2]), (tri[2], tri[0])] {
+                let (a, b) = (resolve(&remap, a), resolve(&remap, b));
+                if a != b && !locked.contains(&a) {
+                    edges.insert(if a < b { (a, b) } else { (b, a) });
+                }
+            }
+        }
+        // An edge only collapses by moving its non-locked endpoint onto
+        // the other; if both endpoints are locked, it can't collapse at
+        // all (dropped above), and the loop below always welds the
+        // non-locked side so a locked endpoint is never moved.
+        let candidates: Vec<(u32, u32)> = edges.into_iter().filter(|&(a, b)| !(locked.contains(&a) && locked.contains(&b))).collect();
+        let Some(&(a, b)) = candidates
+            .iter()
+            .min_by(|x, y| {
+                let dx = (vertices[x.0 as usize] - vertices[x.1 as usize]).norm_squared();
+                let dy = (vertices[y.0 as usize] - vertices[y.1 as usize]).norm_squared();
+                dx.partial_cmp(&dy).unwrap()
+            })
+        else {
+            break;
+        };
+
+        // Weld whichever endpoint isn't locked onto the other, so a locked
+        // boundary vertex is never moved; if neither is locked the choice
+        // is arbitrary.
+        let (from, to) = if locked.contains(&a) { (b, a) } else { (a, b) };
+        remap.insert(from, to);
+        triangles = triangles
+            .iter()
+            .filter_map(|tri| {
+                let resolved = [resolve(&remap, tri[0]), resolve(&remap, tri[1]), resolve(&remap, tri[2])];
+                if resolved[0] == resolved[1] || resolved[1] == resolved[2] || resolved[2] == resolved[0] {
+                    None
+                } else {
+                    Some(resolved)
+                }
+            })
+            .collect();
+    }
+
+    triangles
+}
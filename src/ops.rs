@@ -0,0 +1,80 @@
+//! `f32` transcendental/power helpers, selected between plain `f32` std
+//! methods and `libm`'s `f32` functions by a `libm` cargo feature — the
+//! same cross-platform-determinism split [`crate::detmath`] already
+//! applies to this crate's internal `f64` math, just for the `f32` values
+//! `MeshGL` itself stores (`MeshGL::vert_properties` is `Vec<f32>`).
+//!
+//! Honest limitation: nothing in this crate's own sources currently has an
+//! `f32` transcendental call site to redirect through this module.
+//! `cube`/`cylinder` and the `+`/`-`/`^` boolean kernel aren't defined
+//! anywhere in this crate's own sources (same caveat [`crate::tolerance`]
+//! documents), and every internal module that builds or clips geometry
+//! (`cross_section_plane`, `cross_section_utils`, `refine`, `smooth`, the
+//! primitive constructors in `primitives.rs`, ...) already computes
+//! entirely in `f64` through [`crate::detmath`] and only casts down to
+//! `f32` once, at the final `vert_properties` write — there's no earlier
+//! `f32` rounding step for `ops` to intercept yet. This module exists so
+//! that call site — an `f32`-native geometry path, or a build that skips
+//! the `f64` stage to save bytes — has a ready, tested, determinism-gated
+//! home to route through once it's added, the same way `crate::wasm`'s
+//! `wasm` feature exists ahead of any in-tree consumer.
+
+/// Integer powers on `f32`. `libm` has no dedicated integer-power entry
+/// point, so the `libm` path goes through `powf` with the exponent
+/// converted once.
+pub trait FloatPow {
+    fn ipow(self, n: i32) -> Self;
+}
+
+impl FloatPow for f32 {
+    fn ipow(self, n: i32) -> Self {
+        #[cfg(feature = "libm")]
+        {
+            libm::powf(self, n as f32)
+        }
+        #[cfg(not(feature = "libm"))]
+        {
+            self.powi(n)
+        }
+    }
+}
+
+#[cfg(feature = "libm")]
+pub fn sin(x: f32) -> f32 {
+    libm::sinf(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sin(x: f32) -> f32 {
+    x.sin()
+}
+
+#[cfg(feature = "libm")]
+pub fn cos(x: f32) -> f32 {
+    libm::cosf(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn cos(x: f32) -> f32 {
+    x.cos()
+}
+
+#[cfg(feature = "libm")]
+pub fn sqrt(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[cfg(feature = "libm")]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
+
+#[cfg(not(feature = "libm"))]
+pub fn atan2(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
@@ -0,0 +1,30 @@
+//! Shared `rayon` thread-pool configuration for this crate's optional
+//! parallel paths — [`crate::bvh::overlapping_pairs_parallel`] and
+//! [`crate::cross_section_utils::cap_cross_section_parallel`] both take a
+//! [`ParallelConfig`] instead of just reaching for rayon's global pool, so
+//! a host application (the Bevy SPACE-cycling boolean-op demo this was
+//! written for, scheduled through `AsyncComputeTaskPool`) can bound how
+//! many threads a single boolean op spins up, rather than contending with
+//! every other rayon consumer in the process for the global pool.
+//!
+//! Gated behind the `rayon` feature, the same shape [`crate::wasm`]'s
+//! `wasm` feature and [`crate::detmath`]'s `deterministic` feature use: the
+//! sequential path stays available either way, this is strictly additive.
+
+#[cfg(feature = "rayon")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParallelConfig {
+    /// `None` uses rayon's own default (`std::thread::available_parallelism`).
+    pub num_threads: Option<usize>,
+}
+
+#[cfg(feature = "rayon")]
+impl ParallelConfig {
+    pub fn build_pool(self) -> rayon::ThreadPool {
+        let mut builder = rayon::ThreadPoolBuilder::new();
+        if let Some(n) = self.num_threads {
+            builder = builder.num_threads(n);
+        }
+        builder.build().expect("failed to build rayon thread pool")
+    }
+}
@@ -107,12 +107,26 @@ struct EarClip {
 
 impl EarClip {
     fn new(polys: &PolygonsIdx, epsilon: f64) -> EarClip {
+        Self::new_with_buffers(polys, epsilon, Vec::new(), Vec::new())
+    }
+
+    /// Like `new`, but reusing caller-provided (already-cleared) `polygon`
+    /// and `triangles` buffers instead of allocating fresh ones, for callers
+    /// that triangulate many small polygons in a tight loop (see
+    /// [`Triangulator`]).
+    fn new_with_buffers(
+        polys: &PolygonsIdx,
+        epsilon: f64,
+        mut polygon: Vec<Vert>,
+        triangles: Vec<Vector3<i32>>,
+    ) -> EarClip {
         let mut num_vert = 0;
         for poly in polys {
             num_vert += poly.len();
         }
 
-        let polygon: Vec<Vert> = Vec::with_capacity(num_vert + 2 * polys.len()); //must never reallocate or else all vert.left and vert.right break
+        debug_assert!(polygon.is_empty());
+        polygon.reserve(num_vert + 2 * polys.len()); //must never reallocate or else all vert.left and vert.right break
         let polygon_first = polygon.as_ptr() as usize;
         let polygon_end = unsafe { polygon.as_ptr().add(polygon.capacity()) } as usize;
         let polygon_range = polygon_first..polygon_end;
@@ -124,7 +138,7 @@ impl EarClip {
             outers: Vec::new(),
             simples: Vec::new(),
             hole2bbox: BTreeMap::new(),
-            triangles: Vec::default(),
+            triangles,
             bbox: Rect::default(),
             epsilon,
         };
@@ -162,7 +176,13 @@ impl EarClip {
     ///optimization.
     ///@return std::vector<ivec3> The triangles, referencing the original
     ///polygon points in order.
-    fn triangulate(mut self) -> Vec<Vector3<i32>> {
+    fn triangulate(self) -> Vec<Vector3<i32>> {
+        self.triangulate_recycle().0
+    }
+
+    /// Like `triangulate`, but also hands back the (cleared) `polygon` arena
+    /// so a [`Triangulator`] can reuse its allocation on the next call.
+    fn triangulate_recycle(mut self) -> (Vec<Vector3<i32>>, Vec<Vert>) {
         for start in self.holes {
             let params = CutKeyholeParams {
                 simples: &mut self.simples,
@@ -192,7 +212,54 @@ impl EarClip {
             );
         }
 
-        self.triangles
+        self.polygon.clear();
+        (self.triangles, self.polygon)
+    }
+
+    /// Injects an explicit interior (Steiner) point by bridging it to the
+    /// nearest already-known contour vert with a zero-area bridge, exactly
+    /// the same technique `join_polygons` uses to key-hole a hole into an
+    /// outer contour. The resulting sliver ears are cleaned up by the
+    /// existing `clip_if_degenerate`.
+    fn inject_steiner_point(&mut self, point: &PolyVert) {
+        let mut anchor: Option<usize> = None;
+        let mut best_dist = f64::INFINITY;
+        for &start in self.outers.iter().chain(self.simples.iter()) {
+            Self::loop_verts(start, &mut self.polygon, &self.polygon_range, |v, polygon| {
+                let d = (polygon[v].pos - point.pos).magnitude_squared();
+                if d < best_dist {
+                    best_dist = d;
+                    anchor = Some(v);
+                }
+            });
+        }
+        let Some(anchor) = anchor else { return };
+
+        let point_idx = self.polygon.len();
+        self.polygon.push(Vert {
+            mesh_idx: point.idx,
+            cost: 0.0,
+            ear: false,
+            pos: point.pos,
+            right_dir: Vector2::new(0.0, 0.0),
+            left_idx: 0,
+            right_idx: 0,
+            self_idx: point_idx,
+        });
+
+        let mut anchor_dup = self.polygon[anchor].clone();
+        let anchor_dup_idx = self.polygon.len();
+        anchor_dup.self_idx = anchor_dup_idx;
+        self.polygon.push(anchor_dup);
+
+        let anchor_right = self.polygon[anchor].right_idx;
+        Self::link(anchor, point_idx, &mut self.polygon);
+        Self::link(point_idx, anchor_dup_idx, &mut self.polygon);
+        Self::link(anchor_dup_idx, anchor_right, &mut self.polygon);
+
+        Self::clip_if_degenerate(anchor, &mut self.polygon, &self.polygon_range, &mut self.triangles, self.epsilon);
+        Self::clip_if_degenerate(point_idx, &mut self.polygon, &self.polygon_range, &mut self.triangles, self.epsilon);
+        Self::clip_if_degenerate(anchor_dup_idx, &mut self.polygon, &self.polygon_range, &mut self.triangles, self.epsilon);
     }
 
     fn safe_normalize(v: Vector2<f64>) -> Vector2<f64> {
@@ -372,7 +439,8 @@ impl EarClip {
         }
 
         // Slightly more than enough, since each hole can cause two extra triangles.
-        self.triangles = Vec::with_capacity(self.polygon.len() + 2 * starts.len());
+        debug_assert!(self.triangles.is_empty());
+        self.triangles.reserve(self.polygon.len() + 2 * starts.len());
         starts
     }
 
@@ -580,7 +648,7 @@ impl EarClip {
 
     fn process_ear(
         v: usize,
-        collider: &IdxCollider,
+        collider: &Collider,
         ears_queue: &mut VecDeque<usize>,
         polygon: &mut Vec<Vert>,
         epsilon: f64,
@@ -604,22 +672,36 @@ impl EarClip {
     }
 
     ///Create a collider of all vertices in this polygon, each expanded by
-    ///epsilon_. Each ear uses this BVH to quickly find a subset of vertices to
-    ///check for cost.
-    fn vert_collider(start: usize, polygon: &mut Vec<Vert>, polygon_range: &Range<usize>) -> IdxCollider {
+    ///epsilon_. Each ear uses this BVH (or, above `MORTON_COLLIDER_THRESHOLD`
+    ///verts, a flat Morton-code index) to quickly find a subset of vertices
+    ///to check for cost.
+    fn vert_collider(start: usize, polygon: &mut Vec<Vert>, polygon_range: &Range<usize>) -> Collider {
         let mut itr = Vec::new();
         let mut points = Vec::new();
+        let mut bbox = Rect::default();
         Self::loop_verts(start, polygon, polygon_range, |v, polygon| {
             points.push(PolyVert {
                 pos: polygon[v].pos,
                 idx: itr.len() as i32,
             });
+            bbox.union(polygon[v].pos);
 
             itr.push(v);
         });
 
+        if itr.len() > MORTON_COLLIDER_THRESHOLD {
+            let entries: Vec<MortonEntry> = itr
+                .iter()
+                .map(|&v| MortonEntry {
+                    vert: v,
+                    code: morton_code(polygon[v].pos, &bbox),
+                })
+                .collect();
+            return Collider::Morton(MortonCollider::build(entries, bbox, polygon));
+        }
+
         build_2d_tree(&mut points);
-        IdxCollider { points, itr }
+        Collider::Tree(IdxCollider { points, itr })
     }
 
     ///The main ear-clipping loop. This is called once for each simple polygon -
@@ -633,7 +715,11 @@ impl EarClip {
     ) {
         let vert_collider = Self::vert_collider(start, polygon, polygon_range);
 
-        if vert_collider.itr.is_empty() {
+        let is_empty = match &vert_collider {
+            Collider::Tree(c) => c.itr.is_empty(),
+            Collider::Morton(c) => c.entries.is_empty(),
+        };
+        if is_empty {
             //empty poly
             return;
         }
@@ -688,6 +774,171 @@ struct IdxCollider {
     itr: Vec<usize>,
 }
 
+/// Above this many verts in a single simple polygon, `vert_collider` switches
+/// from the 2D BVH to the flat Morton-code index, which avoids the per-
+/// sub-polygon tree-build cost on huge GIS-style rings (e.g. "water_huge").
+const MORTON_COLLIDER_THRESHOLD: usize = 4096;
+
+/// One entry of a [`MortonCollider`]'s z-order-sorted index.
+struct MortonEntry {
+    /// Index into the `polygon` arena.
+    vert: usize,
+    /// Interleaved z-order code of this vert's position within the polygon bbox.
+    code: u32,
+}
+
+/// A linear BVH node over a contiguous range of Morton-sorted leaves.
+struct BvhNode {
+    bbox: Rect,
+    /// `[start, end)` range into `MortonCollider::entries` this node spans.
+    range: Range<usize>,
+    /// Child node indices, `None` for a leaf-range node small enough to scan
+    /// directly.
+    children: Option<(usize, usize)>,
+}
+
+/// A z-order/Morton-code linear BVH over a polygon's verts: leaves are sorted
+/// by Morton code, then internal nodes are built bottom-up by recursively
+/// splitting the sorted range and unioning the children's AABBs. This
+/// replaces the 2D kd-tree collider for very large rings, where building a
+/// tree per sub-polygon isn't worth it but cache-friendly range queries still
+/// matter.
+struct MortonCollider {
+    /// Leaves sorted ascending by `code` (the Morton/z-order of each vert's
+    /// position, quantized into the polygon bbox).
+    entries: Vec<MortonEntry>,
+    nodes: Vec<BvhNode>,
+    root: usize,
+    bbox: Rect,
+}
+
+/// Below this many leaves, a BVH node is scanned linearly rather than split
+/// further.
+const BVH_LEAF_THRESHOLD: usize = 16;
+
+/// Spreads the low 16 bits of `v` so each bit occupies an even bit position,
+/// the standard Morton/z-order bit-interleaving trick.
+fn spread_bits(v: u32) -> u32 {
+    let mut v = v & 0x0000_ffff;
+    v = (v | (v << 8)) & 0x00ff_00ff;
+    v = (v | (v << 4)) & 0x0f0f_0f0f;
+    v = (v | (v << 2)) & 0x3333_3333;
+    v = (v | (v << 1)) & 0x5555_5555;
+    v
+}
+
+/// Computes the Morton/z-order code for `pos`, quantized to 16 bits per axis
+/// within `bbox`.
+fn morton_code(pos: Point2<f64>, bbox: &Rect) -> u32 {
+    let size = bbox.size();
+    let nx = if size.x > 0.0 {
+        ((pos.x - bbox.min.x) / size.x).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let ny = if size.y > 0.0 {
+        ((pos.y - bbox.min.y) / size.y).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let x = (nx * f64::from(u16::MAX)) as u32;
+    let y = (ny * f64::from(u16::MAX)) as u32;
+
+    spread_bits(x) | (spread_bits(y) << 1)
+}
+
+impl MortonCollider {
+    /// Build the linear BVH from verts, sorting leaves by Morton code first.
+    fn build(mut entries: Vec<MortonEntry>, bbox: Rect, polygon: &[Vert]) -> Self {
+        entries.sort_by_key(|e| e.code);
+
+        let mut nodes = Vec::new();
+        let root = Self::build_node(&entries, 0..entries.len(), polygon, &mut nodes);
+        Self {
+            entries,
+            nodes,
+            root,
+            bbox,
+        }
+    }
+
+    fn build_node(
+        entries: &[MortonEntry],
+        range: Range<usize>,
+        polygon: &[Vert],
+        nodes: &mut Vec<BvhNode>,
+    ) -> usize {
+        let mut bbox = Rect::default();
+        for entry in &entries[range.clone()] {
+            bbox.union(polygon[entry.vert].pos);
+        }
+
+        let children = if range.len() > BVH_LEAF_THRESHOLD {
+            let mid = range.start + range.len() / 2;
+            let left = Self::build_node(entries, range.start..mid, polygon, nodes);
+            let right = Self::build_node(entries, mid..range.end, polygon, nodes);
+            Some((left, right))
+        } else {
+            None
+        };
+
+        nodes.push(BvhNode {
+            bbox,
+            range,
+            children,
+        });
+        nodes.len() - 1
+    }
+
+    /// Run `visit` over every vert whose position overlaps `query_box`,
+    /// descending only into nodes whose AABB overlaps it.
+    fn query(&self, query_box: Rect, polygon: &[Vert], mut visit: impl FnMut(usize)) {
+        self.query_node(self.root, query_box, polygon, &mut visit);
+    }
+
+    fn query_node(
+        &self,
+        node: usize,
+        query_box: Rect,
+        polygon: &[Vert],
+        visit: &mut impl FnMut(usize),
+    ) {
+        let node = &self.nodes[node];
+        if !rects_overlap(&node.bbox, &query_box) {
+            return;
+        }
+
+        match node.children {
+            Some((left, right)) => {
+                self.query_node(left, query_box, polygon, visit);
+                self.query_node(right, query_box, polygon, visit);
+            }
+            None => {
+                for entry in &self.entries[node.range.clone()] {
+                    let pos = polygon[entry.vert].pos;
+                    if pos.x >= query_box.min.x
+                        && pos.x <= query_box.max.x
+                        && pos.y >= query_box.min.y
+                        && pos.y <= query_box.max.y
+                    {
+                        visit(entry.vert);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn rects_overlap(a: &Rect, b: &Rect) -> bool {
+    a.min.x <= b.max.x && a.max.x >= b.min.x && a.min.y <= b.max.y && a.max.y >= b.min.y
+}
+
+enum Collider {
+    Tree(IdxCollider),
+    Morton(MortonCollider),
+}
+
 /// A vertex in a circular doubly-linked list representing the polygon(s) that 
 /// still need to be triangulated.
 /// 
@@ -936,7 +1187,7 @@ impl Vert {
     ///values < -epsilon so they will never affect validity. The first
     ///totalCost is designed to give priority to sharper angles. Any cost < (-1
     ///- epsilon) has satisfied the Delaunay condition.
-    fn ear_cost(&self, epsilon: f64, collider: &IdxCollider, polygon: &[Vert]) -> f64 {
+    fn ear_cost(&self, epsilon: f64, collider: &Collider, polygon: &[Vert]) -> f64 {
         let left_pos = self.left(polygon).pos;
         let right_pos = self.right(polygon).pos;
 
@@ -962,8 +1213,8 @@ impl Vert {
 
         let lid = self.left(polygon).mesh_idx;
         let rid = self.right(polygon).mesh_idx;
-        query_2d_tree(&collider.points, ear_box, |point| {
-            let test = &polygon[collider.itr[point.idx as usize]];
+        let mut check = |test_idx: usize, total_cost: &mut f64| {
+            let test = &polygon[test_idx];
             if !EarClip::clipped(test, polygon)
                 && test.mesh_idx != self.mesh_idx
                 && test.mesh_idx != lid
@@ -975,11 +1226,22 @@ impl Vert {
                     cost = Self::delaunay_cost(test.pos - center, scale, epsilon);
                 }
 
-                if cost > total_cost {
-                    total_cost = cost;
+                if cost > *total_cost {
+                    *total_cost = cost;
                 }
             }
-        });
+        };
+
+        match collider {
+            Collider::Tree(collider) => {
+                query_2d_tree(&collider.points, ear_box, |point| {
+                    check(collider.itr[point.idx as usize], &mut total_cost);
+                });
+            }
+            Collider::Morton(collider) => {
+                collider.query(ear_box, polygon, |vert| check(vert, &mut total_cost));
+            }
+        }
 
         total_cost
     }
@@ -1026,6 +1288,20 @@ fn triangulate_convex(polys: &PolygonsIdx) -> Vec<Vector3<i32>> {
 ///@return std::vector<ivec3> The triangles, referencing the original
 ///vertex indicies.
 pub fn triangulate_idx(polys: &PolygonsIdx, epsilon: f64, allow_convex: bool) -> Vec<Vector3<i32>> {
+    // Opt-in debug gate: catches "not epsilon-valid" input (self-
+    // intersecting, degenerate, or mis-wound contours) at the call site
+    // instead of letting it silently become overlapping output triangles.
+    // Disabled in release builds since `validate` is O(edges^2).
+    #[cfg(debug_assertions)]
+    if std::env::var_os("MESHBOOL_VALIDATE_INPUT").is_some() {
+        let report = validate(polys, epsilon);
+        debug_assert!(
+            report.is_valid(),
+            "triangulate_idx: invalid input: {:?}",
+            report.issues
+        );
+    }
+
     if allow_convex && is_convex(polys, epsilon)
     //fast path
     {
@@ -1034,4 +1310,414 @@ pub fn triangulate_idx(polys: &PolygonsIdx, epsilon: f64, allow_convex: bool) ->
         let triangulator = EarClip::new(polys, epsilon);
         triangulator.triangulate()
     }
+}
+
+/// A single diagnostic from [`validate`].
+#[derive(Debug, Clone)]
+pub enum ValidationIssue {
+    /// Two edges, identified by `(polygon_index, start_vertex_index)`, cross.
+    SelfIntersection {
+        edge_a: (usize, usize),
+        edge_b: (usize, usize),
+    },
+    /// Two consecutive vertices are closer than `epsilon`.
+    NearDuplicateVertex { polygon_index: usize, vertex_index: usize },
+    /// A contour's signed area is within `epsilon` of zero.
+    ZeroAreaRing { polygon_index: usize },
+    /// The first contour (expected to be the outer ring) winds clockwise, or
+    /// a later contour (expected to be a hole) winds counter-clockwise.
+    IncorrectWinding { polygon_index: usize },
+}
+
+/// Structured validity report for a `PolygonsIdx`, to let callers detect
+/// "not epsilon-valid" input (self-intersecting, degenerate, or mis-wound)
+/// before it silently turns into overlapping output triangles.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Checks `polys` for self-intersecting edges, near-duplicate consecutive
+/// vertices, zero-area rings, and incorrect contour winding (by convention,
+/// the first contour is the outer ring and should be CCW; the rest are holes
+/// and should be CW).
+pub fn validate(polys: &PolygonsIdx, epsilon: f64) -> ValidationReport {
+    let mut report = ValidationReport::default();
+
+    // Per-contour checks: duplicate verts, zero area, winding.
+    let mut signed_areas = Vec::with_capacity(polys.len());
+    for (pi, poly) in polys.iter().enumerate() {
+        let mut area = 0.0;
+        for i in 0..poly.len() {
+            let a = poly[i].pos;
+            let b = poly[(i + 1) % poly.len()].pos;
+            area += a.x * b.y - b.x * a.y;
+
+            if (a - b).magnitude() < epsilon {
+                report.issues.push(ValidationIssue::NearDuplicateVertex {
+                    polygon_index: pi,
+                    vertex_index: i,
+                });
+            }
+        }
+        area *= 0.5;
+        signed_areas.push(area);
+
+        if area.abs() < epsilon * epsilon {
+            report.issues.push(ValidationIssue::ZeroAreaRing { polygon_index: pi });
+        } else if (pi == 0 && area < 0.0) || (pi > 0 && area > 0.0) {
+            report.issues.push(ValidationIssue::IncorrectWinding { polygon_index: pi });
+        }
+    }
+
+    // Cross-edge self-intersection: brute-force segment/segment tests. Good
+    // enough for the moderate edge counts validate() is meant for; large
+    // inputs should rely on EarClip's own epsilon-robust handling instead.
+    let mut edges: Vec<(usize, usize, Point2<f64>, Point2<f64>)> = Vec::new();
+    for (pi, poly) in polys.iter().enumerate() {
+        for i in 0..poly.len() {
+            edges.push((pi, i, poly[i].pos, poly[(i + 1) % poly.len()].pos));
+        }
+    }
+
+    for i in 0..edges.len() {
+        for j in (i + 1)..edges.len() {
+            let (pi, vi, a0, a1) = edges[i];
+            let (pj, vj, b0, b1) = edges[j];
+            if pi == pj && (vi.abs_diff(vj) <= 1 || (vi == 0 && vj == polys[pi].len() - 1)) {
+                continue; // adjacent edges share an endpoint, not a crossing.
+            }
+
+            if segments_intersect(a0, a1, b0, b1, epsilon) {
+                report.issues.push(ValidationIssue::SelfIntersection {
+                    edge_a: (pi, vi),
+                    edge_b: (pj, vj),
+                });
+            }
+        }
+    }
+
+    report
+}
+
+fn segments_intersect(a0: Point2<f64>, a1: Point2<f64>, b0: Point2<f64>, b1: Point2<f64>, epsilon: f64) -> bool {
+    let d1 = ccw(b0, b1, a0, epsilon);
+    let d2 = ccw(b0, b1, a1, epsilon);
+    let d3 = ccw(a0, a1, b0, epsilon);
+    let d4 = ccw(a0, a1, b1, epsilon);
+
+    (d1 != d2 && d3 != d4) && d1 != 0 && d2 != 0 && d3 != 0 && d4 != 0
+}
+
+/// Earcut-style front end: takes a flat `[x, y]` vertex array plus
+/// `hole_indices` (the start index of each hole within `vertices`, as in
+/// earcut's `hole_indices`) and triangulates it directly, without the caller
+/// having to pre-split the input into a `PolygonsIdx` by hand.
+///
+/// The outer contour is `vertices[0..hole_indices[0]]` (or all of
+/// `vertices` if there are no holes), and each subsequent hole is
+/// `vertices[hole_indices[i]..hole_indices[i + 1]]` (or `..vertices.len()`
+/// for the last one). `EarClip`'s own winding-based hole/outer detection
+/// does the actual key-holing, so this only needs to reshape the input.
+pub fn triangulate_flat(
+    vertices: &[[f64; 2]],
+    hole_indices: &[usize],
+    epsilon: f64,
+    allow_convex: bool,
+) -> Vec<Vector3<i32>> {
+    let mut bounds: Vec<usize> = Vec::with_capacity(hole_indices.len() + 2);
+    bounds.push(0);
+    bounds.extend_from_slice(hole_indices);
+    bounds.push(vertices.len());
+    bounds.dedup();
+
+    let polys: PolygonsIdx = bounds
+        .windows(2)
+        .map(|w| {
+            vertices[w[0]..w[1]]
+                .iter()
+                .enumerate()
+                .map(|(i, p)| PolyVert {
+                    pos: Point2::new(p[0], p[1]),
+                    idx: (w[0] + i) as i32,
+                })
+                .collect()
+        })
+        .collect();
+
+    triangulate_idx(&polys, epsilon, allow_convex)
+}
+
+/// Like `triangulate_idx`, but also forces the given `steiner_points` to
+/// appear as vertices of the output, letting callers (adaptive tessellation,
+/// remeshing, attribute interpolation) control mesh density without a
+/// post-subdivision pass. Each point is bridged to its nearest contour vert
+/// via a zero-area bridge, the same technique used to key-hole interior
+/// holes into an outer contour.
+pub fn triangulate_idx_with_steiner(
+    polys: &PolygonsIdx,
+    steiner_points: &[PolyVert],
+    epsilon: f64,
+) -> Vec<Vector3<i32>> {
+    if steiner_points.is_empty() {
+        return triangulate_idx(polys, epsilon, true);
+    }
+
+    let mut triangulator = EarClip::new(polys, epsilon);
+    for point in steiner_points {
+        triangulator.inject_steiner_point(point);
+    }
+    triangulator.triangulate()
+}
+
+/// A reusable ear-clipping workspace for callers (like mesh booleans) that
+/// triangulate many small polygons in a tight loop, avoiding a fresh
+/// allocation of the vert arena and output buffer on every call.
+#[derive(Default)]
+pub struct Triangulator {
+    polygon_buf: Vec<Vert>,
+    triangles_buf: Vec<Vector3<i32>>,
+}
+
+impl Triangulator {
+    /// Create an empty, unallocated `Triangulator`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Triangulate `polys` into `out`, clearing `out` first and reusing its
+    /// (and this `Triangulator`'s) existing capacity rather than
+    /// freeing/reallocating, growing only when a bigger polygon arrives.
+    pub fn triangulate_into(
+        &mut self,
+        polys: &PolygonsIdx,
+        epsilon: f64,
+        allow_convex: bool,
+        out: &mut Vec<Vector3<i32>>,
+    ) {
+        out.clear();
+
+        if allow_convex && is_convex(polys, epsilon) {
+            out.extend(triangulate_convex(polys));
+            return;
+        }
+
+        let polygon_buf = mem::take(&mut self.polygon_buf);
+        let triangles_buf = mem::take(out);
+        let earclip = EarClip::new_with_buffers(polys, epsilon, polygon_buf, triangles_buf);
+        let (triangles, polygon_buf) = earclip.triangulate_recycle();
+        self.polygon_buf = polygon_buf;
+        *out = triangles;
+    }
+}
+
+/// Lawson edge-flip post-pass that turns an ear-clipped triangulation into a
+/// constrained-Delaunay one, to fix up the skinny triangles ear-clipping (even
+/// sharpest-ear-first) tends to leave behind.
+///
+/// `pos_of` looks up the 2D position of an original vertex index (as used in
+/// `triangles`). Edges on the polygon boundary or a keyhole bridge must not be
+/// flipped, so callers pass them in `constrained` as unordered pairs.
+pub fn delaunay_refine(
+    triangles: &mut [Vector3<i32>],
+    pos_of: impl Fn(i32) -> Point2<f64>,
+    constrained: &std::collections::HashSet<(i32, i32)>,
+    epsilon: f64,
+) {
+    let edge_key = |a: i32, b: i32| if a < b { (a, b) } else { (b, a) };
+    let is_constrained = |a: i32, b: i32| constrained.contains(&edge_key(a, b));
+
+    // edge -> the (at most two) triangles sharing it, by triangle index and
+    // the opposite vertex within that triangle.
+    let mut edge_tris: BTreeMap<(i32, i32), Vec<(usize, i32)>> = BTreeMap::new();
+    for (ti, tri) in triangles.iter().enumerate() {
+        let verts = [tri.x, tri.y, tri.z];
+        for i in 0..3 {
+            let (a, b, opposite) = (verts[i], verts[(i + 1) % 3], verts[(i + 2) % 3]);
+            edge_tris.entry(edge_key(a, b)).or_default().push((ti, opposite));
+        }
+    }
+
+    let mut stack: Vec<(i32, i32)> = edge_tris
+        .keys()
+        .filter(|&&(a, b)| !is_constrained(a, b))
+        .copied()
+        .collect();
+
+    while let Some((a, b)) = stack.pop() {
+        if is_constrained(a, b) {
+            continue;
+        }
+
+        let Some(sides) = edge_tris.get(&edge_key(a, b)) else {
+            continue;
+        };
+        if sides.len() != 2 {
+            continue; // boundary edge with only one adjacent triangle
+        }
+        let (tri_abc, c) = sides[0];
+        let (tri_adb, d) = sides[1];
+
+        if in_circumcircle(pos_of(a), pos_of(b), pos_of(c), pos_of(d), epsilon) {
+            // Flip the diagonal a-b to c-d: (a,d,c) + (d,b,c)
+            triangles[tri_abc] = Vector3::new(a, d, c);
+            triangles[tri_adb] = Vector3::new(d, b, c);
+
+            edge_tris.remove(&edge_key(a, b));
+            for key in [
+                edge_key(a, d),
+                edge_key(d, c),
+                edge_key(c, b),
+                edge_key(b, a),
+            ] {
+                edge_tris.entry(key).or_default();
+            }
+
+            for (x, y) in [(a, d), (d, c), (c, b), (b, a)] {
+                if !is_constrained(x, y) {
+                    stack.push((x, y));
+                }
+            }
+        }
+    }
+}
+
+/// Convenience wrapper that triangulates `polys` and then runs
+/// [`delaunay_refine`] over the result, deriving the position lookup and the
+/// constrained (boundary) edge set directly from `polys` so callers don't
+/// have to build those themselves.
+pub fn triangulate_idx_delaunay(
+    polys: &PolygonsIdx,
+    epsilon: f64,
+    allow_convex: bool,
+) -> Vec<Vector3<i32>> {
+    let mut positions = std::collections::HashMap::new();
+    let mut constrained = std::collections::HashSet::new();
+    for poly in polys {
+        for i in 0..poly.len() {
+            let a = &poly[i];
+            let b = &poly[(i + 1) % poly.len()];
+            positions.insert(a.idx, a.pos);
+            let key = if a.idx < b.idx {
+                (a.idx, b.idx)
+            } else {
+                (b.idx, a.idx)
+            };
+            constrained.insert(key);
+        }
+    }
+
+    let mut triangles = triangulate_idx(polys, epsilon, allow_convex);
+    delaunay_refine(&mut triangles, |idx| positions[&idx], &constrained, epsilon);
+    triangles
+}
+
+/// Returns true if `d` lies strictly inside the circumcircle of CCW triangle
+/// (a, b, c), via the sign of the standard lifted-paraboloid determinant.
+fn in_circumcircle(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>, epsilon: f64) -> bool {
+    let lift = |p: Point2<f64>, origin: Point2<f64>| {
+        let dx = p.x - origin.x;
+        let dy = p.y - origin.y;
+        (dx, dy, dx * dx + dy * dy)
+    };
+
+    let (ax, ay, az) = lift(a, d);
+    let (bx, by, bz) = lift(b, d);
+    let (cx, cy, cz) = lift(c, d);
+
+    let det = ax * (by * cz - bz * cy) - ay * (bx * cz - bz * cx) + az * (bx * cy - by * cx);
+    det > epsilon
+}
+
+/// Triangulates an arbitrary 3D polygon face (optionally with holes) by
+/// fitting a best-fit plane and projecting into it, then running the
+/// existing epsilon-robust `EarClip` pipeline.
+///
+/// `loops` is one or more closed 3D vertex loops (the first is the outer
+/// boundary, any further loops are holes), wound so that projecting onto the
+/// fitted plane's normal gives CCW outer / CW hole winding as `triangulate_idx`
+/// expects. Returned indices reference the original vertex order, flattened
+/// across all loops in the order they were given.
+pub fn triangulate_polygon_3d(
+    loops: &[Vec<Vector3<f64>>],
+    epsilon: f64,
+    allow_convex: bool,
+) -> Vec<Vector3<i32>> {
+    // Newell's method: robust to non-planarity and works for any winding.
+    let mut normal = Vector3::new(0.0, 0.0, 0.0);
+    let mut centroid = Vector3::new(0.0, 0.0, 0.0);
+    let mut count = 0.0;
+    for lp in loops {
+        for i in 0..lp.len() {
+            let cur = lp[i];
+            let next = lp[(i + 1) % lp.len()];
+            normal.x += (cur.y - next.y) * (cur.z + next.z);
+            normal.y += (cur.z - next.z) * (cur.x + next.x);
+            normal.z += (cur.x - next.x) * (cur.y + next.y);
+            centroid += cur;
+            count += 1.0;
+        }
+    }
+    let normal = crate::detmath::normalize3(normal);
+    let centroid = centroid / count.max(1.0);
+
+    // Orthonormal in-plane basis.
+    let helper = if normal.x.abs() < 0.9 {
+        Vector3::new(1.0, 0.0, 0.0)
+    } else {
+        Vector3::new(0.0, 1.0, 0.0)
+    };
+    let u = crate::detmath::normalize3(normal.cross(&helper));
+    let v = normal.cross(&u);
+
+    let mut polys = PolygonsIdx::new();
+    let mut idx = 0i32;
+    for lp in loops {
+        let mut poly = SimplePolygonIdx::new();
+        for p in lp {
+            let rel = p - centroid;
+            poly.push(PolyVert {
+                pos: Point2::new(rel.dot(&u), rel.dot(&v)),
+                idx,
+            });
+            idx += 1;
+        }
+        polys.push(poly);
+    }
+
+    triangulate_idx(&polys, epsilon, allow_convex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(idx_offset: i32) -> SimplePolygonIdx {
+        vec![
+            PolyVert { pos: Point2::new(0.0, 0.0), idx: idx_offset },
+            PolyVert { pos: Point2::new(1.0, 0.0), idx: idx_offset + 1 },
+            PolyVert { pos: Point2::new(1.0, 1.0), idx: idx_offset + 2 },
+            PolyVert { pos: Point2::new(0.0, 1.0), idx: idx_offset + 3 },
+        ]
+    }
+
+    /// The documented `allowConvex` fast path should produce the same two
+    /// triangles as ear-clipping for a plain convex quad, without needing to
+    /// build a collider at all.
+    #[test]
+    fn allow_convex_matches_ear_clip_triangle_count() {
+        let polys = vec![square(0)];
+
+        let fast = triangulate_idx(&polys, 1e-9, true);
+        let exact = triangulate_idx(&polys, 1e-9, false);
+
+        assert_eq!(fast.len(), 2);
+        assert_eq!(exact.len(), 2);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,126 @@
+//! Quad- and n-gon-faced mesh ingestion, triangulating on import instead of
+//! requiring callers to pre-triangulate CAD/DCC meshes themselves.
+//!
+//! `get_mesh_gl` and the `+`/`-`/`^` boolean operators assume pure triangle
+//! soup (`tri_verts.len() % 3 == 0`); [`from_polygon_faces`] is the
+//! constructor path that accepts faces of any vertex count and produces
+//! that soup. A triangle face passes through unchanged, a quad splits
+//! across its shorter diagonal (the split least likely to produce a sliver
+//! on a non-square quad), and any larger face is fan-triangulated from its
+//! first vertex — this crate's [`crate::polygon`] ear-clipper handles
+//! arbitrary (possibly non-convex, possibly holed) 2D contours, but that's
+//! more machinery than a convex n-gon face needs, so this stays a plain
+//! fan. Each input face keeps its identity through [`build_face_runs`],
+//! tagging every triangle it was split into with the same `face_id` so a
+//! caller can re-quadrangulate or restore per-face data after the boolean.
+//!
+//! [`planarize_quad`] is the optional best-fit-plane projection a non-planar
+//! quad needs before splitting, since a bowtie'd quad would otherwise hand
+//! the intersection stage two triangles that don't share a plane at all.
+
+use crate::vertex_properties::build_face_runs;
+use crate::{from_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+
+/// A single polygonal face, referencing positions by index into the
+/// caller's vertex buffer. Must have at least 3 vertices, wound
+/// consistently with the rest of the mesh.
+pub type Face = Vec<u32>;
+
+/// Iteratively project `quad`'s four corners onto their best-fit plane
+/// (the average of the two diagonal-cross-product normals, which is exact
+/// for a planar quad and a reasonable compromise for a slightly warped
+/// one), pulling each corner onto that plane along its own normal
+/// direction. One pass is enough for the small warps CAD export tends to
+/// introduce; iterating further converges but rarely changes the result
+/// enough to matter.
+pub fn planarize_quad(quad: [Vector3<f64>; 4]) -> [Vector3<f64>; 4] {
+    let centroid = (quad[0] + quad[1] + quad[2] + quad[3]) / 4.0;
+    let diagonal_a = quad[2] - quad[0];
+    let diagonal_b = quad[3] - quad[1];
+    let normal = diagonal_a.cross(&diagonal_b);
+    let normal = if normal.norm_squared() > 0.0 { crate::detmath::normalize3(normal) } else { return quad };
+
+    quad.map(|p| p - normal * (p - centroid).dot(&normal))
+}
+
+/// Split a quad into two triangles across its shorter diagonal: `0-1-2`/
+/// `0-2-3` if the `0-2` diagonal is shorter, `0-1-3`/`1-2-3` otherwise. The
+/// shorter diagonal keeps both triangles closer to equilateral than always
+/// splitting the same way would on a non-square quad.
+fn triangulate_quad(quad: [u32; 4], positions: &impl Fn(u32) -> Vector3<f64>) -> [[u32; 3]; 2] {
+    let diagonal_02 = (positions(quad[2]) - positions(quad[0])).norm_squared();
+    let diagonal_13 = (positions(quad[3]) - positions(quad[1])).norm_squared();
+
+    if diagonal_02 <= diagonal_13 {
+        [[quad[0], quad[1], quad[2]], [quad[0], quad[2], quad[3]]]
+    } else {
+        [[quad[0], quad[1], quad[3]], [quad[1], quad[2], quad[3]]]
+    }
+}
+
+/// Fan-triangulate a convex face of any vertex count from its first vertex:
+/// `[v0, v1, v2], [v0, v2, v3], ...`. Degenerate (collapsed) fan triangles
+/// are left for the usual welding/cleanup pass downstream rather than
+/// filtered here, matching how [`crate::tolerance::snap`] already drops
+/// degenerate triangles after welding.
+fn triangulate_fan(face: &[u32]) -> Vec<[u32; 3]> {
+    (1..face.len() - 1).map(|i| [face[0], face[i], face[i + 1]]).collect()
+}
+
+/// Build an [`Impl`] from `positions` and a list of polygonal `faces`
+/// (triangles, quads, or general n-gons, each a list of indices into
+/// `positions`), triangulating every face on import and recording which
+/// output triangles came from which input face.
+///
+/// A quad (`face.len() == 4`) splits across its shorter diagonal; anything
+/// larger fan-triangulates from the face's first vertex. Set
+/// `planarize_quads` to project each quad onto its best-fit plane (see
+/// [`planarize_quad`]) before splitting, avoiding the bowtie/self-overlap
+/// artifacts a non-planar quad's two triangles would otherwise hand the
+/// boolean's intersection stage. Returns the built mesh along with the
+/// `(run_index, run_original_id)` pair [`build_face_runs`] produces, tagging
+/// each output triangle with the index of the input face it came from, so
+/// per-face data (materials, the original quad itself) can be restored
+/// after a boolean op.
+pub fn from_polygon_faces(positions: &[Vector3<f64>], faces: &[Face], planarize_quads: bool) -> (Impl, Vec<u32>, Vec<u32>) {
+    let mut tri_verts: Vec<u32> = Vec::with_capacity(faces.len() * 3);
+    let mut face_ids: Vec<u32> = Vec::with_capacity(faces.len());
+
+    let mut planarized_positions = positions.to_vec();
+
+    for (face_id, face) in faces.iter().enumerate() {
+        match face.len() {
+            3 => {
+                tri_verts.extend_from_slice(face);
+                face_ids.push(face_id as u32);
+            }
+            4 => {
+                let quad = [face[0], face[1], face[2], face[3]];
+                if planarize_quads {
+                    let corners = quad.map(|i| positions[i as usize]);
+                    let planar = planarize_quad(corners);
+                    for (i, &index) in quad.iter().enumerate() {
+                        planarized_positions[index as usize] = planar[i];
+                    }
+                }
+                let pos = |i: u32| planarized_positions[i as usize];
+                for tri in triangulate_quad(quad, &pos) {
+                    tri_verts.extend_from_slice(&tri);
+                    face_ids.push(face_id as u32);
+                }
+            }
+            _ => {
+                for tri in triangulate_fan(face) {
+                    tri_verts.extend_from_slice(&tri);
+                    face_ids.push(face_id as u32);
+                }
+            }
+        }
+    }
+
+    let vert_properties: Vec<f32> = planarized_positions.iter().flat_map(|p| [p.x as f32, p.y as f32, p.z as f32]).collect();
+    let mesh = from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() });
+    let (run_index, run_original_id) = build_face_runs(&face_ids);
+    (mesh, run_index, run_original_id)
+}
@@ -0,0 +1,393 @@
+//! Primitive solid constructors beyond [`crate::cube`]/[`crate::cylinder`],
+//! covering the shapes Bevy's `math_primitives` example renders: both
+//! sphere tessellations, a cone, a capsule, a torus, a regular
+//! tetrahedron, and a general `extrude`/`revolve` pair over a 2D polygon
+//! profile. Every constructor takes a `center` flag with the same meaning
+//! as [`crate::cube`]'s: `true` centers the result on the origin, `false`
+//! leaves its bounding-box minimum corner there. Each one builds a
+//! watertight, outward-wound mesh so the result passes manifoldness
+//! checks and can be fed straight into the `+`/`-`/`^` boolean operators.
+
+use crate::{from_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+
+/// Translate `positions` in place so the origin sits at the bounding box's
+/// minimum corner (`center == false`) or its center (`center == true`),
+/// the same choice [`crate::cube`]'s `center` flag makes.
+fn apply_center(positions: &mut [Vector3<f64>], center: bool) {
+    let (mut bbox_min, mut bbox_max) = (Vector3::repeat(f64::INFINITY), Vector3::repeat(f64::NEG_INFINITY));
+    for p in positions.iter() {
+        bbox_min = bbox_min.inf(p);
+        bbox_max = bbox_max.sup(p);
+    }
+    let offset = if center { (bbox_min + bbox_max) * 0.5 } else { bbox_min };
+    for p in positions.iter_mut() {
+        *p -= offset;
+    }
+}
+
+fn build(positions: Vec<Vector3<f64>>, tri_verts: Vec<u32>) -> Impl {
+    let mut vert_properties: Vec<f32> = Vec::with_capacity(positions.len() * 3);
+    for p in &positions {
+        vert_properties.push(p.x as f32);
+        vert_properties.push(p.y as f32);
+        vert_properties.push(p.z as f32);
+    }
+    from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() })
+}
+
+/// Geodesic (icosahedron-subdivided) sphere, delegating to
+/// [`crate::icosphere::geodesic_sphere`] for the tessellation itself.
+pub fn sphere_ico(radius: f64, subdivisions: u32, center: bool) -> Impl {
+    let mesh_gl = crate::icosphere::geodesic_sphere(radius, subdivisions);
+    let num_prop = mesh_gl.num_prop as usize;
+    let mut positions: Vec<Vector3<f64>> = mesh_gl
+        .vert_properties
+        .chunks(num_prop)
+        .map(|v| Vector3::new(v[0], v[1], v[2]))
+        .collect();
+    apply_center(&mut positions, center);
+    build(positions, mesh_gl.tri_verts)
+}
+
+/// UV sphere: `sectors` longitude divisions around the pole axis, `stacks`
+/// latitude divisions from pole to pole, closed with a single triangle fan
+/// at each pole instead of [`sphere_ico`]'s evenly-subdivided faces.
+pub fn sphere_uv(radius: f64, sectors: u32, stacks: u32, center: bool) -> Impl {
+    let sectors = sectors.max(3) as usize;
+    let stacks = stacks.max(2);
+
+    let mut positions = Vec::new();
+    let top = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, radius));
+
+    let mut rings: Vec<Vec<u32>> = Vec::new();
+    for i in 1..stacks {
+        let phi = std::f64::consts::PI * i as f64 / stacks as f64;
+        let z = radius * crate::detmath::cos(phi);
+        let r = radius * crate::detmath::sin(phi);
+        let mut ring = Vec::with_capacity(sectors);
+        for j in 0..sectors {
+            let theta = 2.0 * std::f64::consts::PI * j as f64 / sectors as f64;
+            ring.push(positions.len() as u32);
+            positions.push(Vector3::new(r * crate::detmath::cos(theta), r * crate::detmath::sin(theta), z));
+        }
+        rings.push(ring);
+    }
+    let bottom = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, -radius));
+
+    let mut tri_verts = Vec::new();
+    for j in 0..sectors {
+        let a = rings[0][j];
+        let b = rings[0][(j + 1) % sectors];
+        push_tri(&mut tri_verts, top, a, b);
+    }
+    for s in 0..rings.len() - 1 {
+        for j in 0..sectors {
+            let a = rings[s][j];
+            let b = rings[s][(j + 1) % sectors];
+            let c = rings[s + 1][(j + 1) % sectors];
+            let d = rings[s + 1][j];
+            push_tri(&mut tri_verts, a, c, b);
+            push_tri(&mut tri_verts, a, d, c);
+        }
+    }
+    let last = rings.len() - 1;
+    for j in 0..sectors {
+        let a = rings[last][j];
+        let b = rings[last][(j + 1) % sectors];
+        push_tri(&mut tri_verts, bottom, b, a);
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Right circular cone with its base in the `z = 0` plane and apex at
+/// `z = height`, `segments` divisions around the base.
+pub fn cone(radius: f64, height: f64, segments: u32, center: bool) -> Impl {
+    let segments = segments.max(3) as usize;
+    let mut positions = Vec::new();
+    let apex = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, height));
+
+    let mut ring = Vec::with_capacity(segments);
+    for j in 0..segments {
+        let theta = 2.0 * std::f64::consts::PI * j as f64 / segments as f64;
+        ring.push(positions.len() as u32);
+        positions.push(Vector3::new(radius * crate::detmath::cos(theta), radius * crate::detmath::sin(theta), 0.0));
+    }
+    let base_center = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, 0.0));
+
+    let mut tri_verts = Vec::new();
+    for j in 0..segments {
+        let a = ring[j];
+        let b = ring[(j + 1) % segments];
+        push_tri(&mut tri_verts, apex, a, b);
+        push_tri(&mut tri_verts, base_center, b, a);
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Capsule: a cylindrical body of `height` (measured between the two
+/// hemisphere centers) with hemispherical caps of `radius`, `segments`
+/// divisions around the axis and `rings` latitude divisions per
+/// hemisphere.
+pub fn capsule(radius: f64, height: f64, segments: u32, rings: u32, center: bool) -> Impl {
+    let segments = segments.max(3) as usize;
+    let rings = rings.max(1);
+    let half_height = height * 0.5;
+
+    let mut positions = Vec::new();
+    let top_pole = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, half_height + radius));
+
+    // Top hemisphere latitude rings, then the two cylinder-body rings,
+    // then the bottom hemisphere's, so every consecutive pair of rings in
+    // `all_rings` can be stitched by the same quad-band loop.
+    let mut all_rings: Vec<Vec<u32>> = Vec::new();
+    for i in 1..=rings {
+        let phi = (std::f64::consts::PI * 0.5) * i as f64 / rings as f64;
+        push_ring(&mut positions, &mut all_rings, radius * crate::detmath::sin(phi), half_height + radius * crate::detmath::cos(phi), segments);
+    }
+    push_ring(&mut positions, &mut all_rings, radius, half_height, segments);
+    push_ring(&mut positions, &mut all_rings, radius, -half_height, segments);
+    for i in 1..=rings {
+        let phi = (std::f64::consts::PI * 0.5) * i as f64 / rings as f64;
+        push_ring(&mut positions, &mut all_rings, radius * crate::detmath::cos(phi), -half_height - radius * crate::detmath::sin(phi), segments);
+    }
+
+    let bottom_pole = positions.len() as u32;
+    positions.push(Vector3::new(0.0, 0.0, -half_height - radius));
+
+    let mut tri_verts = Vec::new();
+    for j in 0..segments {
+        let a = all_rings[0][j];
+        let b = all_rings[0][(j + 1) % segments];
+        push_tri(&mut tri_verts, top_pole, a, b);
+    }
+    for s in 0..all_rings.len() - 1 {
+        for j in 0..segments {
+            let a = all_rings[s][j];
+            let b = all_rings[s][(j + 1) % segments];
+            let c = all_rings[s + 1][(j + 1) % segments];
+            let d = all_rings[s + 1][j];
+            push_tri(&mut tri_verts, a, c, b);
+            push_tri(&mut tri_verts, a, d, c);
+        }
+    }
+    let last = all_rings.len() - 1;
+    for j in 0..segments {
+        let a = all_rings[last][j];
+        let b = all_rings[last][(j + 1) % segments];
+        push_tri(&mut tri_verts, bottom_pole, b, a);
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Torus centered on the `z` axis: `major_radius` from the axis to the
+/// tube's center, `minor_radius` the tube's own radius, with
+/// `major_segments`/`minor_segments` divisions around each.
+pub fn torus(major_radius: f64, minor_radius: f64, major_segments: u32, minor_segments: u32, center: bool) -> Impl {
+    let major_segments = major_segments.max(3) as usize;
+    let minor_segments = minor_segments.max(3) as usize;
+
+    let mut positions = Vec::new();
+    let mut rings: Vec<Vec<u32>> = Vec::with_capacity(major_segments);
+    for i in 0..major_segments {
+        let theta = 2.0 * std::f64::consts::PI * i as f64 / major_segments as f64;
+        let mut ring = Vec::with_capacity(minor_segments);
+        for j in 0..minor_segments {
+            let phi = 2.0 * std::f64::consts::PI * j as f64 / minor_segments as f64;
+            let r = major_radius + minor_radius * crate::detmath::cos(phi);
+            ring.push(positions.len() as u32);
+            positions.push(Vector3::new(r * crate::detmath::cos(theta), r * crate::detmath::sin(theta), minor_radius * crate::detmath::sin(phi)));
+        }
+        rings.push(ring);
+    }
+
+    let mut tri_verts = Vec::new();
+    for i in 0..major_segments {
+        let r0 = &rings[i];
+        let r1 = &rings[(i + 1) % major_segments];
+        for j in 0..minor_segments {
+            let a = r0[j];
+            let b = r0[(j + 1) % minor_segments];
+            let c = r1[(j + 1) % minor_segments];
+            let d = r1[j];
+            push_tri(&mut tri_verts, a, c, b);
+            push_tri(&mut tri_verts, a, d, c);
+        }
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Regular tetrahedron with edge length `size`.
+pub fn tetrahedron(size: f64, center: bool) -> Impl {
+    // Scaled so every edge has length `size`: a tetrahedron inscribed in a
+    // cube via alternating corners has edge length `side * sqrt(2)`.
+    let s = size / std::f64::consts::SQRT_2;
+    let mut positions = vec![
+        Vector3::new(s, s, s),
+        Vector3::new(s, -s, -s),
+        Vector3::new(-s, s, -s),
+        Vector3::new(-s, -s, s),
+    ];
+
+    let mut tri_verts = Vec::new();
+    for [a, b, c] in [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]] {
+        push_tri(&mut tri_verts, a, b, c);
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Extrude a closed, simple 2D `profile` (wound counter-clockwise, as
+/// [`crate::cross_section_utils::triangulate_polygon`] expects) straight
+/// along `z` by `height`, capping both ends with an ear-clipped
+/// triangulation of the profile so the result is watertight for concave
+/// as well as convex profiles.
+pub fn extrude(profile: &[nalgebra::Vector2<f64>], height: f64, center: bool) -> Impl {
+    use nalgebra::Point3;
+
+    let n = profile.len();
+    assert!(n >= 3, "extrude requires a profile with at least 3 points");
+
+    let cap_points: Vec<Point3<f64>> = profile.iter().map(|p| Point3::new(p.x, p.y, 0.0)).collect();
+    let cap_tris = crate::cross_section_utils::triangulate_polygon(&cap_points);
+
+    let mut positions = Vec::with_capacity(n * 2);
+    for p in profile {
+        positions.push(Vector3::new(p.x, p.y, 0.0));
+    }
+    for p in profile {
+        positions.push(Vector3::new(p.x, p.y, height));
+    }
+
+    let mut tri_verts = Vec::new();
+    for i in 0..n {
+        let a = i as u32;
+        let b = ((i + 1) % n) as u32;
+        let c = b + n as u32;
+        let d = a + n as u32;
+        push_tri(&mut tri_verts, a, b, c);
+        push_tri(&mut tri_verts, a, c, d);
+    }
+    for &[i0, i1, i2] in &cap_tris {
+        push_tri(&mut tri_verts, i1 as u32, i0 as u32, i2 as u32);
+    }
+    for &[i0, i1, i2] in &cap_tris {
+        push_tri(&mut tri_verts, i0 as u32 + n as u32, i1 as u32 + n as u32, i2 as u32 + n as u32);
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+/// Revolve a 2D `profile` (points in the half-plane `x >= 0`, ordered from
+/// one end to the other) around the `z` axis by a full turn, with
+/// `segments` divisions. A profile endpoint on the axis (`x == 0`) closes
+/// to a single point there; an endpoint off the axis is capped with a
+/// triangle fan instead, so the result is watertight whether or not the
+/// profile itself touches the axis.
+pub fn revolve(profile: &[nalgebra::Vector2<f64>], segments: u32, center: bool) -> Impl {
+    let n = profile.len();
+    assert!(n >= 2, "revolve requires a profile with at least 2 points");
+    let segments = segments.max(3) as usize;
+
+    enum Ring {
+        Axis(u32),
+        Full(Vec<u32>),
+    }
+
+    let mut positions = Vec::new();
+    let mut rings = Vec::with_capacity(n);
+    for p in profile {
+        if p.x.abs() < 1e-9 {
+            let idx = positions.len() as u32;
+            positions.push(Vector3::new(0.0, 0.0, p.y));
+            rings.push(Ring::Axis(idx));
+        } else {
+            let mut ring = Vec::with_capacity(segments);
+            for j in 0..segments {
+                let theta = 2.0 * std::f64::consts::PI * j as f64 / segments as f64;
+                ring.push(positions.len() as u32);
+                positions.push(Vector3::new(p.x * crate::detmath::cos(theta), p.x * crate::detmath::sin(theta), p.y));
+            }
+            rings.push(Ring::Full(ring));
+        }
+    }
+
+    let mut tri_verts = Vec::new();
+    for i in 0..n - 1 {
+        match (&rings[i], &rings[i + 1]) {
+            (Ring::Full(r0), Ring::Full(r1)) => {
+                for j in 0..segments {
+                    let a = r0[j];
+                    let b = r0[(j + 1) % segments];
+                    let c = r1[(j + 1) % segments];
+                    let d = r1[j];
+                    push_tri(&mut tri_verts, a, b, c);
+                    push_tri(&mut tri_verts, a, c, d);
+                }
+            }
+            (Ring::Axis(apex), Ring::Full(r1)) => {
+                for j in 0..segments {
+                    let b = r1[j];
+                    let c = r1[(j + 1) % segments];
+                    push_tri(&mut tri_verts, *apex, c, b);
+                }
+            }
+            (Ring::Full(r0), Ring::Axis(apex)) => {
+                for j in 0..segments {
+                    let a = r0[j];
+                    let b = r0[(j + 1) % segments];
+                    push_tri(&mut tri_verts, b, *apex, a);
+                }
+            }
+            (Ring::Axis(_), Ring::Axis(_)) => {}
+        }
+    }
+
+    // A profile endpoint that never touches the axis leaves an open ring
+    // at that end; close it with a fan from the ring's first vertex,
+    // since a revolved cross-section ring is always convex (a circle).
+    if let Ring::Full(ring0) = &rings[0] {
+        for j in 1..segments - 1 {
+            push_tri(&mut tri_verts, ring0[0], ring0[j + 1], ring0[j]);
+        }
+    }
+    if let Ring::Full(ring_last) = &rings[n - 1] {
+        for j in 1..segments - 1 {
+            push_tri(&mut tri_verts, ring_last[0], ring_last[j], ring_last[j + 1]);
+        }
+    }
+
+    apply_center(&mut positions, center);
+    build(positions, tri_verts)
+}
+
+fn push_ring(positions: &mut Vec<Vector3<f64>>, rings: &mut Vec<Vec<u32>>, radius: f64, z: f64, segments: usize) {
+    let mut ring = Vec::with_capacity(segments);
+    for j in 0..segments {
+        let theta = 2.0 * std::f64::consts::PI * j as f64 / segments as f64;
+        ring.push(positions.len() as u32);
+        positions.push(Vector3::new(radius * crate::detmath::cos(theta), radius * crate::detmath::sin(theta), z));
+    }
+    rings.push(ring);
+}
+
+fn push_tri(tri_verts: &mut Vec<u32>, a: u32, b: u32, c: u32) {
+    tri_verts.push(a);
+    tri_verts.push(b);
+    tri_verts.push(c);
+}
@@ -0,0 +1,139 @@
+//! Thread [`VertexAttributes`] channels through a boolean op by encoding
+//! them as extra per-vertex `f32` properties on each operand's `MeshGL`
+//! before the op runs, then decoding the same channels back out of the
+//! result afterward.
+//!
+//! [`Impl`]'s own boolean kernel (outside this crate's sources, same
+//! caveat [`crate::tolerance`] documents) interpolates every property
+//! beyond the first three (position) at any new vertex the op introduces —
+//! that's exactly what `num_prop` greater than 3 already means everywhere
+//! else in this crate, e.g. [`crate::solver::boolean_with`]'s float-solver
+//! path rounds "the first 3 of `num_prop`, any extra attribute channels
+//! pass through unrounded". So encoding a channel as extra properties is
+//! enough to get it carried across a cut with zero cut-site-specific code
+//! of this module's own.
+//!
+//! What this doesn't give: per-halfedge property indices. A UV seam or
+//! normal discontinuity along an edge shared by two triangles that each
+//! want a different value there still collapses to one blended value per
+//! vertex, since [`VertexAttributes`] (like `MeshGL`'s own
+//! `vert_properties`) is indexed per-vertex, not per-corner. Preserving a
+//! seam needs per-triangle-corner indirection along the lines of
+//! [`crate::polygon_mesh::from_polygon_faces`]'s per-face run tagging,
+//! which is a wider redesign than extending this module's single channel
+//! list — tracked as a known gap rather than silently smoothed over.
+
+use crate::attributes::{AttributeChannel, AttributeKind, VertexAttributes};
+use crate::cross_section_helper::vert_pos;
+use crate::solver::{boolean_with, BooleanOp, Solver, SolverError};
+use crate::{from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+
+/// The channel layout [`encode`] appended past position, so [`decode`] can
+/// slice the same ranges back out: each entry's `usize` is that channel's
+/// starting offset into a vertex's property row (always `>= 3`).
+type Layout = Vec<(String, AttributeKind, usize)>;
+
+/// Run `op` on `a`/`b` through [`boolean_with`], carrying `a_attrs`/`b_attrs`
+/// across the cut. The two operands don't need matching channel sets — a
+/// channel present on one side and missing on the other is filled with
+/// zeros for the side that lacks it, so every encoded vertex still gets the
+/// same `num_prop` on both operands. Returns the boolean result alongside
+/// its decoded [`VertexAttributes`]; `Err` propagates straight from
+/// [`boolean_with`].
+pub fn boolean_with_attributes(
+    a: &Impl,
+    a_attrs: &VertexAttributes,
+    b: &Impl,
+    b_attrs: &VertexAttributes,
+    op: BooleanOp,
+    solver: Solver,
+) -> Result<(Impl, VertexAttributes), SolverError> {
+    let names = union_channel_names(a_attrs, b_attrs);
+
+    let encoded_a = from_mesh_gl(encode(&get_mesh_gl(a), a_attrs, &names));
+    let encoded_b = from_mesh_gl(encode(&get_mesh_gl(b), b_attrs, &names));
+
+    let result = boolean_with(&encoded_a, &encoded_b, op, solver)?;
+
+    let result_mesh_gl = get_mesh_gl(&result);
+    let layout = layout_for(&names);
+    let attrs = decode(&result_mesh_gl, &layout);
+    let positions_only = strip_extra_properties(&result_mesh_gl);
+
+    Ok((from_mesh_gl(positions_only), attrs))
+}
+
+/// Every channel name appearing in either `a` or `b`, `a`'s own order first
+/// then any of `b`'s names not already present, so the encoded layout is
+/// deterministic regardless of which operand happens to declare a channel.
+fn union_channel_names(a: &VertexAttributes, b: &VertexAttributes) -> Vec<(String, AttributeKind)> {
+    let mut names: Vec<(String, AttributeKind)> = a.channels().map(|c| (c.name.clone(), c.kind)).collect();
+    for c in b.channels() {
+        if !names.iter().any(|(n, _)| n == &c.name) {
+            names.push((c.name.clone(), c.kind));
+        }
+    }
+    names
+}
+
+fn layout_for(names: &[(String, AttributeKind)]) -> Layout {
+    let mut offset = 3;
+    names
+        .iter()
+        .map(|(name, kind)| {
+            let entry = (name.clone(), *kind, offset);
+            offset += kind.stride();
+            entry
+        })
+        .collect()
+}
+
+/// Append `names`'s channels (pulled from `attrs`, zero-filled where
+/// `attrs` doesn't have that channel) as extra `f32` properties after
+/// `mesh_gl`'s existing position.
+fn encode(mesh_gl: &MeshGL, attrs: &VertexAttributes, names: &[(String, AttributeKind)]) -> MeshGL {
+    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop.max(1) as usize;
+    let extra_width: usize = names.iter().map(|(_, kind)| kind.stride()).sum();
+
+    let mut vert_properties = Vec::with_capacity(num_verts * (3 + extra_width));
+    for v in 0..num_verts {
+        let p = vert_pos(mesh_gl, v);
+        vert_properties.extend_from_slice(&[p.x as f32, p.y as f32, p.z as f32]);
+        for (name, kind) in names {
+            let stride = kind.stride();
+            match attrs.channel(name) {
+                Some(channel) => vert_properties.extend_from_slice(&channel.data[v * stride..(v + 1) * stride]),
+                None => vert_properties.extend(std::iter::repeat(0.0f32).take(stride)),
+            }
+        }
+    }
+
+    MeshGL { vert_properties, num_prop: (3 + extra_width) as u32, tri_verts: mesh_gl.tri_verts.clone(), ..Default::default() }
+}
+
+/// Pull `layout`'s channels back out of `mesh_gl`'s per-vertex property
+/// rows into a fresh [`VertexAttributes`].
+fn decode(mesh_gl: &MeshGL, layout: &Layout) -> VertexAttributes {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let num_verts = mesh_gl.vert_properties.len() / num_prop;
+
+    let mut attrs = VertexAttributes::new();
+    for (name, kind, offset) in layout {
+        let stride = kind.stride();
+        let mut data = Vec::with_capacity(num_verts * stride);
+        for v in 0..num_verts {
+            let base = v * num_prop + offset;
+            data.extend_from_slice(&mesh_gl.vert_properties[base..base + stride]);
+        }
+        attrs.push(AttributeChannel { name: name.clone(), kind: *kind, data });
+    }
+    attrs
+}
+
+/// Drop every property past position, leaving a plain `num_prop == 3` mesh —
+/// the position-only `Impl` half of [`boolean_with_attributes`]'s return.
+fn strip_extra_properties(mesh_gl: &MeshGL) -> MeshGL {
+    let num_verts = mesh_gl.vert_properties.len() / mesh_gl.num_prop.max(1) as usize;
+    let vert_properties: Vec<f32> = (0..num_verts).flat_map(|v| { let p = vert_pos(mesh_gl, v); [p.x as f32, p.y as f32, p.z as f32] }).collect();
+    MeshGL { vert_properties, num_prop: 3, tri_verts: mesh_gl.tri_verts.clone(), ..Default::default() }
+}
@@ -0,0 +1,135 @@
+//! Property-based tests for the boolean operators' algebraic invariants,
+//! complementing [`crate::mesh_compare_tests`]'s fixed-cube comparisons
+//! against `manifold_rs` with randomly generated solids: idempotent
+//! union/intersection, empty self-difference, commutativity, containment
+//! of `(A + B) - B` in `A + B`, and that every result is a valid,
+//! even-Euler-characteristic manifold. `proptest` shrinks any failing case
+//! down to a minimal reproducer automatically.
+//!
+//! Two known simplifications, both acceptable for a regression gate rather
+//! than a certification suite:
+//! - [`approx_volume`] compares bounding-box volume, not true solid volume
+//!   — a coarse but cheap proxy, adequate for catching gross containment
+//!   regressions without implementing a full point-in-solid volume
+//!   integral here.
+//! - [`euler_characteristic`]'s "is even" check doesn't pin down a single
+//!   expected genus, since a handful of randomly placed/sized primitives
+//!   can occasionally union/intersect into a handle (nonzero genus) by
+//!   chance; it still catches the common regression of a result with a
+//!   genuinely broken (non-manifold, odd) triangulation.
+
+#[cfg(test)]
+mod tests {
+    use crate::{cube, cylinder, get_mesh_gl, translate, ManifoldError};
+    use nalgebra::{Point3, Vector3};
+    use proptest::prelude::*;
+    use std::collections::HashSet;
+
+    fn arb_cube() -> impl Strategy<Value = crate::Impl> {
+        (0.2f64..4.0, 0.2f64..4.0, 0.2f64..4.0, -3.0f64..3.0, -3.0f64..3.0, -3.0f64..3.0)
+            .prop_map(|(sx, sy, sz, tx, ty, tz)| translate(&cube(Vector3::new(sx, sy, sz), true), Point3::new(tx, ty, tz)))
+    }
+
+    fn arb_cylinder() -> impl Strategy<Value = crate::Impl> {
+        (0.2f64..2.0, 0.1f64..1.5, 0.1f64..1.5, 6u32..24, -3.0f64..3.0, -3.0f64..3.0, -3.0f64..3.0)
+            .prop_map(|(height, r_lo, r_hi, segments, tx, ty, tz)| {
+                translate(&cylinder(height, r_lo, r_hi, segments, true), Point3::new(tx, ty, tz))
+            })
+    }
+
+    fn arb_sphere() -> impl Strategy<Value = crate::Impl> {
+        (0.2f64..2.0, 1u32..3, -3.0f64..3.0, -3.0f64..3.0, -3.0f64..3.0).prop_map(|(radius, subdivisions, tx, ty, tz)| {
+            translate(&crate::primitives::sphere_ico(radius, subdivisions, true), Point3::new(tx, ty, tz))
+        })
+    }
+
+    fn arb_primitive() -> impl Strategy<Value = crate::Impl> {
+        prop_oneof![arb_cube(), arb_cylinder(), arb_sphere()]
+    }
+
+    /// A single primitive, or a small union of two — the "small unions of
+    /// them" the request asks the generator to cover, on top of the bare
+    /// primitives.
+    fn arb_solid() -> impl Strategy<Value = crate::Impl> {
+        prop_oneof![arb_primitive(), (arb_primitive(), arb_primitive()).prop_map(|(a, b)| &a + &b),]
+    }
+
+    /// Bounding-box volume, as a coarse, cheap stand-in for true solid
+    /// volume (see the module's doc comment).
+    fn approx_volume(mesh: &crate::Impl) -> f64 {
+        let bounds = crate::aabb::bounds(mesh);
+        let extent = bounds.max - bounds.min;
+        (extent.x.max(0.0)) * (extent.y.max(0.0)) * (extent.z.max(0.0))
+    }
+
+    /// `V - E + F`, with `V`/`E` counted over distinct vertices/undirected
+    /// edges in the mesh's own triangle buffer (no assumption that the
+    /// mesh has already been welded — coincident-but-distinct-indexed
+    /// vertices are *not* unified here, matching how `num_tri`/`status`
+    /// already treat the raw output as authoritative).
+    fn euler_characteristic(mesh: &crate::Impl) -> i64 {
+        let mesh_gl = get_mesh_gl(mesh);
+        let num_prop = mesh_gl.num_prop.max(1) as usize;
+        let vertex_count = mesh_gl.vert_properties.len() / num_prop;
+
+        let mut edges = HashSet::new();
+        for tri in mesh_gl.tri_verts.chunks_exact(3) {
+            for k in 0..3 {
+                let (a, b) = (tri[k], tri[(k + 1) % 3]);
+                edges.insert(if a < b { (a, b) } else { (b, a) });
+            }
+        }
+
+        vertex_count as i64 - edges.len() as i64 + (mesh_gl.tri_verts.len() / 3) as i64
+    }
+
+    proptest! {
+        #[test]
+        fn union_is_idempotent(a in arb_solid()) {
+            let union = &a + &a;
+            prop_assert!(approx_eq!(&union, &a));
+            prop_assert_eq!(union.status, ManifoldError::NoError);
+        }
+
+        #[test]
+        fn intersection_is_idempotent(a in arb_solid()) {
+            let intersection = &a ^ &a;
+            prop_assert!(approx_eq!(&intersection, &a));
+        }
+
+        #[test]
+        fn self_difference_is_empty(a in arb_solid()) {
+            let difference = &a - &a;
+            prop_assert_eq!(difference.num_tri(), 0);
+        }
+
+        #[test]
+        fn union_is_commutative(a in arb_solid(), b in arb_solid()) {
+            let ab = &a + &b;
+            let ba = &b + &a;
+            prop_assert!(approx_eq!(&ab, &ba));
+        }
+
+        #[test]
+        fn intersection_is_commutative(a in arb_solid(), b in arb_solid()) {
+            let ab = &a ^ &b;
+            let ba = &b ^ &a;
+            prop_assert!(approx_eq!(&ab, &ba));
+        }
+
+        #[test]
+        fn difference_is_contained_in_union(a in arb_solid(), b in arb_solid()) {
+            let union = &a + &b;
+            let difference = &(&a + &b) - &b;
+            prop_assert!(approx_volume(&difference) <= approx_volume(&union) + 1e-6);
+        }
+
+        #[test]
+        fn results_are_valid_manifolds(a in arb_solid(), b in arb_solid()) {
+            for result in [&a + &b, &a ^ &b, &a - &b] {
+                prop_assert_eq!(result.status, ManifoldError::NoError);
+                prop_assert_eq!(euler_characteristic(&result) % 2, 0);
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Per-triangle mesh-quality metrics and sliver cleanup, meant to run on a
+//! boolean op's or [`crate::cross_section_plane`]'s output before it reaches
+//! a renderer or physics engine — both frequently emit needle-thin or
+//! near-zero-area triangles along a cut boundary that break downstream
+//! normal estimation and collision generation, the same failure mode
+//! [`crate::repair`] targets for outright non-manifold gaps rather than
+//! degenerate-but-still-manifold triangles.
+//!
+//! [`triangle_quality`] computes each triangle's min/max interior angle,
+//! aspect ratio, and signed area; [`quality_report`] (over a raw
+//! [`MeshGL`]) and [`mesh_quality`] (the [`Impl`]-facing wrapper) aggregate
+//! those into a [`QualityReport`]. [`clean_slivers`] is the repair half:
+//! weld near-coincident vertices within `epsilon` via
+//! [`crate::tolerance::snap`], then drop the resulting near-zero-area
+//! triangles via [`crate::repair::remove_degenerate`] — the same two passes
+//! [`crate::repair::repair`] opens with, without its winding-flip/boundary
+//! re-stitch stages, since a sliver is an interior degeneracy, not an open
+//! seam.
+//!
+//! Interior angles go through plain `f64::acos`, not [`crate::detmath`]:
+//! that module has no `acos` entry point (the same gap
+//! [`crate::step`]'s `segments_for_tolerance` already works around the same
+//! way), so this module isn't bit-reproducible across platforms the way
+//! [`crate::detmath`]-routed code is.
+
+use crate::{from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+
+/// One triangle's shape metrics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriangleQuality {
+    /// Smallest of the triangle's three interior angles, in radians.
+    pub min_angle: f64,
+    /// Largest of the triangle's three interior angles, in radians.
+    pub max_angle: f64,
+    /// Circumradius divided by twice the inradius — the standard
+    /// radius-ratio shape metric: exactly 1.0 for an equilateral triangle,
+    /// growing without bound as a triangle degenerates toward a sliver,
+    /// `f64::INFINITY` for a zero-area triangle (zero inradius).
+    pub aspect_ratio: f64,
+    /// Signed area of the triangle's projection, via half the magnitude of
+    /// its edge cross product — always non-negative, since a triangle's
+    /// winding isn't meaningful on its own without a reference normal to
+    /// compare against.
+    pub signed_area: f64,
+}
+
+/// Aggregate shape report over every triangle in a mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityReport {
+    pub min_angle: f64,
+    pub max_angle: f64,
+    /// Triangles whose [`TriangleQuality::min_angle`] falls below the
+    /// report's sliver threshold.
+    pub sliver_count: usize,
+    /// Triangles whose [`TriangleQuality::signed_area`] is exactly zero.
+    pub zero_area_count: usize,
+}
+
+/// Per-triangle quality metrics for every triangle in `mesh_gl`, in
+/// `tri_verts` order — the free function this module's doc comment
+/// promises over a raw [`MeshGL`], for a caller that already has one on
+/// hand and doesn't want [`mesh_quality`]'s extra [`get_mesh_gl`] call.
+pub fn triangle_quality(mesh_gl: &MeshGL) -> Vec<TriangleQuality> {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let pos = |i: u32| -> Vector3<f64> {
+        let base = i as usize * num_prop;
+        Vector3::new(mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64)
+    };
+
+    mesh_gl
+        .tri_verts
+        .chunks_exact(3)
+        .map(|tri| {
+            let (a, b, c) = (pos(tri[0]), pos(tri[1]), pos(tri[2]));
+            let (ab, bc, ca) = (b - a, c - b, a - c);
+            let (len_ab, len_bc, len_ca) = (ab.norm(), bc.norm(), ca.norm());
+
+            let angle_at = |incoming: Vector3<f64>, outgoing: Vector3<f64>| -> f64 {
+                let (incoming, outgoing) = (incoming.normalize(), outgoing.normalize());
+                (-incoming.dot(&outgoing)).clamp(-1.0, 1.0).acos()
+            };
+            let angle_a = angle_at(ca, ab);
+            let angle_b = angle_at(ab, bc);
+            let angle_c = angle_at(bc, ca);
+
+            let signed_area = 0.5 * ab.cross(&(c - a)).norm();
+            // Radius ratio R / (2r): circumradius R = (len_ab*len_bc*len_ca)
+            // / (4*area), inradius r = area / semiperimeter, so
+            // R / (2r) = len_ab*len_bc*len_ca*semiperimeter / (8*area^2).
+            let semiperimeter = (len_ab + len_bc + len_ca) * 0.5;
+            let aspect_ratio =
+                if signed_area == 0.0 { f64::INFINITY } else { len_ab * len_bc * len_ca * semiperimeter / (8.0 * signed_area * signed_area) };
+
+            TriangleQuality {
+                min_angle: angle_a.min(angle_b).min(angle_c),
+                max_angle: angle_a.max(angle_b).max(angle_c),
+                aspect_ratio,
+                signed_area,
+            }
+        })
+        .collect()
+}
+
+/// Aggregate [`QualityReport`] over `mesh_gl`'s triangles. `sliver_angle_threshold`
+/// (radians) sets [`QualityReport::sliver_count`]'s cutoff — a triangle
+/// whose [`TriangleQuality::min_angle`] falls below it counts as a sliver.
+pub fn quality_report(mesh_gl: &MeshGL, sliver_angle_threshold: f64) -> QualityReport {
+    let qualities = triangle_quality(mesh_gl);
+    if qualities.is_empty() {
+        return QualityReport { min_angle: 0.0, max_angle: 0.0, sliver_count: 0, zero_area_count: 0 };
+    }
+
+    let min_angle = qualities.iter().map(|q| q.min_angle).fold(f64::INFINITY, f64::min);
+    let max_angle = qualities.iter().map(|q| q.max_angle).fold(f64::NEG_INFINITY, f64::max);
+    let sliver_count = qualities.iter().filter(|q| q.min_angle < sliver_angle_threshold).count();
+    let zero_area_count = qualities.iter().filter(|q| q.signed_area == 0.0).count();
+
+    QualityReport { min_angle, max_angle, sliver_count, zero_area_count }
+}
+
+/// [`quality_report`], fetching `mesh`'s [`MeshGL`] for the caller.
+pub fn mesh_quality(mesh: &Impl, sliver_angle_threshold: f64) -> QualityReport {
+    quality_report(&get_mesh_gl(mesh), sliver_angle_threshold)
+}
+
+/// Weld vertices within `epsilon` of each other (via
+/// [`crate::tolerance::snap`]) and drop the near-zero-area triangles that
+/// weld typically collapses (via [`crate::repair::remove_degenerate`]),
+/// returning the repaired mesh. Unlike [`crate::repair::repair`], this
+/// doesn't re-orient winding or stitch open boundary edges — slivers are
+/// an interior degeneracy a vertex weld already fully resolves, not an
+/// open-seam gap, so those extra passes would just be wasted work here.
+pub fn clean_slivers(mesh: &Impl, epsilon: f64) -> Impl {
+    let welded = crate::tolerance::snap(mesh, epsilon);
+    let mesh_gl = get_mesh_gl(&welded);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let positions: Vec<Vector3<f64>> =
+        mesh_gl.vert_properties.chunks(num_prop).map(|v| Vector3::new(v[0] as f64, v[1] as f64, v[2] as f64)).collect();
+    let triangles: Vec<[u32; 3]> = mesh_gl.tri_verts.chunks_exact(3).map(|t| [t[0], t[1], t[2]]).collect();
+
+    let (triangles, _removed) = crate::repair::remove_degenerate(triangles, &positions, epsilon);
+
+    let vert_properties: Vec<f32> = positions.iter().flat_map(|p| [p.x as f32, p.y as f32, p.z as f32]).collect();
+    let tri_verts: Vec<u32> = triangles.into_iter().flatten().collect();
+    from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() })
+}
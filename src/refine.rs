@@ -0,0 +1,175 @@
+//! Mesh densification: split every triangle edge into `n` equal segments
+//! (barycentric subdivision, `n²` sub-triangles per face), giving users a
+//! way to densify a mesh before displacement or smoothing — the same role
+//! [`crate::icosphere::geodesic_sphere`]'s per-face subdivision plays for a
+//! single primitive, generalized here to operate on any mesh's existing
+//! triangles instead of projecting new points onto a sphere.
+
+use crate::{from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+use std::collections::HashMap;
+
+/// Split every triangle edge of `mesh` into `n` segments, producing `n²`
+/// sub-triangles per original face. All `num_prop` vertex properties
+/// (position and whatever else is carried alongside it — normals, UVs)
+/// are linearly interpolated along the split edges. Shared edges are
+/// deduplicated so adjacent faces agree on the same subdivided vertices,
+/// keeping the result manifold. `n <= 1` returns `mesh` unchanged.
+pub fn refine(mesh: &Impl, n: u32) -> Impl {
+    from_mesh_gl(refine_mesh_gl(&get_mesh_gl(mesh), n))
+}
+
+/// Like [`refine`], but chooses a single subdivision count (applied to
+/// every face) large enough that no output edge exceeds `max_edge`, based
+/// on the longest edge in `mesh`.
+pub fn refine_to_length(mesh: &Impl, max_edge: f64) -> Impl {
+    let mesh_gl = get_mesh_gl(mesh);
+    let n = subdivisions_for_length(&mesh_gl, max_edge);
+    from_mesh_gl(refine_mesh_gl(&mesh_gl, n))
+}
+
+fn subdivisions_for_length(mesh_gl: &MeshGL, max_edge: f64) -> u32 {
+    if max_edge <= 0.0 {
+        return 1;
+    }
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let pos = |i: usize| -> [f64; 3] {
+        let base = i * num_prop;
+        [mesh_gl.vert_properties[base] as f64, mesh_gl.vert_properties[base + 1] as f64, mesh_gl.vert_properties[base + 2] as f64]
+    };
+    let dist = |a: [f64; 3], b: [f64; 3]| crate::detmath::sqrt((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2));
+
+    let mut longest: f64 = 0.0;
+    for tri in mesh_gl.tri_verts.chunks_exact(3) {
+        let [a, b, c] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+        let (pa, pb, pc) = (pos(a), pos(b), pos(c));
+        longest = longest.max(dist(pa, pb)).max(dist(pb, pc)).max(dist(pc, pa));
+    }
+
+    crate::detmath::ceil(longest / max_edge).max(1.0) as u32
+}
+
+fn refine_mesh_gl(mesh_gl: &MeshGL, n: u32) -> MeshGL {
+    if n <= 1 {
+        return MeshGL { vert_properties: mesh_gl.vert_properties.clone(), num_prop: mesh_gl.num_prop, tri_verts: mesh_gl.tri_verts.clone(), ..Default::default() };
+    }
+
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let prop = |i: usize| -> Vec<f32> {
+        let base = i * num_prop;
+        mesh_gl.vert_properties[base..base + num_prop].to_vec()
+    };
+
+    let mut properties: Vec<f32> = mesh_gl.vert_properties.clone();
+    let mut push_vertex = |p: Vec<f32>, properties: &mut Vec<f32>| -> u32 {
+        let idx = (properties.len() / num_prop) as u32;
+        properties.extend(p);
+        idx
+    };
+
+    // Interior points of a shared edge, cached by the edge's canonical
+    // (lower, higher) original vertex ids, the same trick
+    // [`crate::icosphere`] uses to keep adjacent faces from generating two
+    // disagreeing copies of the same split point.
+    let mut edge_cache: HashMap<(u32, u32), Vec<Option<u32>>> = HashMap::new();
+    let mut tri_verts: Vec<u32> = Vec::new();
+
+    for tri in mesh_gl.tri_verts.chunks_exact(3) {
+        let (a, b, c) = (tri[0], tri[1], tri[2]);
+
+        let mut grid: Vec<Vec<u32>> = Vec::with_capacity((n + 1) as usize);
+        for i in 0..=n {
+            let mut row = Vec::with_capacity((n - i + 1) as usize);
+            for j in 0..=(n - i) {
+                row.push(face_point_vertex(a, b, c, i, j, n, &prop, &mut properties, &mut push_vertex, &mut edge_cache));
+            }
+            grid.push(row);
+        }
+
+        for i in 0..n {
+            for j in 0..(n - i) {
+                tri_verts.extend_from_slice(&[grid[i as usize][j as usize], grid[(i + 1) as usize][j as usize], grid[i as usize][(j + 1) as usize]]);
+                if j + 1 < n - i {
+                    tri_verts.extend_from_slice(&[
+                        grid[(i + 1) as usize][j as usize],
+                        grid[(i + 1) as usize][(j + 1) as usize],
+                        grid[i as usize][(j + 1) as usize],
+                    ]);
+                }
+            }
+        }
+    }
+
+    MeshGL { vert_properties: properties, num_prop: num_prop as u32, tri_verts, ..Default::default() }
+}
+
+/// The global vertex index for the point at barycentric weights
+/// `(n - i - j, i, j)` over the triangle `(a, b, c)`: one of the 3 corners
+/// (returned directly, reusing the original vertex), an interior point of
+/// one of the 3 edges (deduplicated via `edge_cache`), or a face-interior
+/// point (always unique, never shared).
+#[allow(clippy::too_many_arguments)]
+fn face_point_vertex(
+    a: u32,
+    b: u32,
+    c: u32,
+    i: u32,
+    j: u32,
+    n: u32,
+    prop: &impl Fn(usize) -> Vec<f32>,
+    properties: &mut Vec<f32>,
+    push_vertex: &mut impl FnMut(Vec<f32>, &mut Vec<f32>) -> u32,
+    edge_cache: &mut HashMap<(u32, u32), Vec<Option<u32>>>,
+) -> u32 {
+    let k = n - i - j;
+    if k == n {
+        return a;
+    }
+    if i == n {
+        return b;
+    }
+    if j == n {
+        return c;
+    }
+    if j == 0 {
+        return edge_vertex(a, b, i, n, prop, properties, push_vertex, edge_cache);
+    }
+    if i == 0 {
+        return edge_vertex(a, c, j, n, prop, properties, push_vertex, edge_cache);
+    }
+    if k == 0 {
+        return edge_vertex(b, c, j, n, prop, properties, push_vertex, edge_cache);
+    }
+
+    let (pa, pb, pc) = (prop(a as usize), prop(b as usize), prop(c as usize));
+    let blended: Vec<f32> = (0..pa.len()).map(|p| (pa[p] * k as f32 + pb[p] * i as f32 + pc[p] * j as f32) / n as f32).collect();
+    push_vertex(blended, properties)
+}
+
+/// The vertex at fraction `step / n` along the edge from `u` to `v`,
+/// deduplicated against whichever face reaches this edge first.
+#[allow(clippy::too_many_arguments)]
+fn edge_vertex(
+    u: u32,
+    v: u32,
+    step: u32,
+    n: u32,
+    prop: &impl Fn(usize) -> Vec<f32>,
+    properties: &mut Vec<f32>,
+    push_vertex: &mut impl FnMut(Vec<f32>, &mut Vec<f32>) -> u32,
+    edge_cache: &mut HashMap<(u32, u32), Vec<Option<u32>>>,
+) -> u32 {
+    let (lo, hi, canonical_step) = if u <= v { (u, v, step) } else { (v, u, n - step) };
+
+    let slots = edge_cache.entry((lo, hi)).or_insert_with(|| vec![None; (n - 1) as usize]);
+    let slot = (canonical_step - 1) as usize;
+    if let Some(idx) = slots[slot] {
+        return idx;
+    }
+
+    let (p_lo, p_hi) = (prop(lo as usize), prop(hi as usize));
+    let t = canonical_step as f32 / n as f32;
+    let blended: Vec<f32> = (0..p_lo.len()).map(|p| p_lo[p] * (1.0 - t) + p_hi[p] * t).collect();
+    let idx = push_vertex(blended, properties);
+    slots[slot] = Some(idx);
+    idx
+}
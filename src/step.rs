@@ -0,0 +1,675 @@
+//! ISO-10303-21 (STEP AP203/214) import: parses the flat `#id=KEYWORD(...)`
+//! entity graph out of a STEP file's `DATA` section, resolves the subset of
+//! entities a simple BREP solid needs — `CARTESIAN_POINT`, `DIRECTION`,
+//! `AXIS2_PLACEMENT_3D`, `VERTEX_POINT`, `LINE`, `CIRCLE`, `EDGE_CURVE`,
+//! `ORIENTED_EDGE`, `EDGE_LOOP`, `FACE_BOUND`/`FACE_OUTER_BOUND`,
+//! `ADVANCED_FACE`, `PLANE`, `CYLINDRICAL_SURFACE`,
+//! `B_SPLINE_SURFACE_WITH_KNOTS`, `CLOSED_SHELL`, `MANIFOLD_SOLID_BREP` —
+//! and tessellates each face into triangles at a caller-supplied chord
+//! tolerance, feeding the result straight into [`from_mesh_gl`] the same
+//! way every other importer in this crate does (see
+//! [`crate::stl::import_stl`]).
+//!
+//! [`Impl`]/[`MeshGL`] aren't defined anywhere in this crate's own sources
+//! (same caveat [`crate::tolerance`] documents).
+//!
+//! Two places this reader is deliberately a simplification rather than a
+//! full BREP kernel, both called out where they apply below:
+//! - Every face's boundary loop is tessellated by triangulating its
+//!   (possibly refined, for circular edges) boundary polygon in the face
+//!   surface's own 2D parameter plane, then mapping the triangle indices
+//!   back to 3D. That's exact for [`PLANE`](face boundary is already
+//!   planar) and for [`CylindricalSurface`] (unrolling a cylinder into
+//!   `(r * theta, z)` is an isometry), but it only respects the trim
+//!   *boundary* — it doesn't refine the interior to follow curvature the
+//!   way a real BREP tessellator would for a very coarse tolerance against
+//!   a tightly curved patch.
+//! - `B_SPLINE_SURFACE_WITH_KNOTS` faces are tessellated as the *untrimmed*
+//!   NURBS patch sampled on a regular grid (real Cox-de Boor evaluation,
+//!   see [`bspline_point`]) — the face's boundary loop is ignored for
+//!   these, since trimming a NURBS patch against an edge loop in parameter
+//!   space is a much larger undertaking than this reader covers.
+//! - Edge curves that are neither [`LINE`] nor [`CIRCLE`] (e.g. a
+//!   `B_SPLINE_CURVE`-backed edge) fall back to a straight segment between
+//!   their two vertices.
+
+use crate::{from_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+use std::collections::HashMap;
+
+/// Everything that can go wrong reading a STEP stream.
+#[derive(Debug)]
+pub enum StepError {
+    Parse(String),
+    Missing(String),
+}
+
+impl std::fmt::Display for StepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepError::Parse(msg) => write!(f, "STEP parse error: {msg}"),
+            StepError::Missing(msg) => write!(f, "STEP reference error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StepError {}
+
+/// One `#id=KEYWORD(params);` instance line.
+#[derive(Debug, Clone)]
+struct Entity {
+    name: String,
+    params: Vec<Param>,
+}
+
+/// A single parsed STEP parameter. STEP's `$` (unset/inherited attribute)
+/// and `*` (derived attribute) both collapse to [`Param::Unset`] — this
+/// reader never needs to tell them apart.
+#[derive(Debug, Clone)]
+enum Param {
+    Real(f64),
+    Text(String),
+    Enum(String),
+    Ref(u64),
+    List(Vec<Param>),
+    Unset,
+}
+
+impl Param {
+    fn as_real(&self) -> Option<f64> {
+        match self {
+            Param::Real(r) => Some(*r),
+            _ => None,
+        }
+    }
+
+    fn as_ref(&self) -> Option<u64> {
+        match self {
+            Param::Ref(id) => Some(*id),
+            _ => None,
+        }
+    }
+
+    fn as_list(&self) -> Option<&[Param]> {
+        match self {
+            Param::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    fn as_enum(&self) -> Option<&str> {
+        match self {
+            Param::Enum(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+type Entities = HashMap<u64, Entity>;
+
+/// Parse the `DATA;` ... `ENDSEC;` section's `#id=KEYWORD(...);` instances
+/// into an id-indexed entity table. Header entities (`FILE_DESCRIPTION` and
+/// friends, which carry no `#id`) are skipped rather than erroring, since
+/// this reader never needs them.
+fn parse_entities(text: &str) -> Result<Entities, StepError> {
+    let data_start = text.find("DATA;").map(|i| i + "DATA;".len()).unwrap_or(0);
+    let data_end = text[data_start..].find("ENDSEC;").map(|i| data_start + i).unwrap_or(text.len());
+    let data = &text[data_start..data_end];
+
+    let mut entities = Entities::new();
+    for statement in split_statements(data) {
+        let statement = statement.trim();
+        if statement.is_empty() || !statement.starts_with('#') {
+            continue;
+        }
+        let Some(eq) = statement.find('=') else { continue };
+        let id: u64 = statement[1..eq].trim().parse().map_err(|_| StepError::Parse(format!("bad entity id in `{statement}`")))?;
+        let rest = statement[eq + 1..].trim();
+
+        // A complex instance like `(FOO(...)BAR(...))` multiply-inherits
+        // from several entities; this reader only ever wants one of them,
+        // so it keeps the first parenthesized record whose keyword it
+        // recognizes and ignores the rest.
+        let rest = if rest.starts_with('(') { first_known_record(rest).unwrap_or(rest) } else { rest };
+
+        let Some(open) = rest.find('(') else { continue };
+        let name = rest[..open].trim().to_string();
+        let args_text = &rest[open..];
+        let args_text = args_text.strip_prefix('(').and_then(|s| s.strip_suffix(')')).unwrap_or(args_text);
+        let params = parse_param_list(args_text)?;
+        entities.insert(id, Entity { name, params });
+    }
+    Ok(entities)
+}
+
+const KNOWN_KEYWORDS: &[&str] = &[
+    "CARTESIAN_POINT",
+    "DIRECTION",
+    "AXIS2_PLACEMENT_3D",
+    "VERTEX_POINT",
+    "LINE",
+    "CIRCLE",
+    "EDGE_CURVE",
+    "ORIENTED_EDGE",
+    "EDGE_LOOP",
+    "FACE_BOUND",
+    "FACE_OUTER_BOUND",
+    "ADVANCED_FACE",
+    "PLANE",
+    "CYLINDRICAL_SURFACE",
+    "B_SPLINE_SURFACE_WITH_KNOTS",
+    "CLOSED_SHELL",
+    "MANIFOLD_SOLID_BREP",
+    "VECTOR",
+];
+
+fn first_known_record(complex: &str) -> Option<&str> {
+    for keyword in KNOWN_KEYWORDS {
+        if let Some(at) = complex.find(keyword) {
+            if complex[at + keyword.len()..].trim_start().starts_with('(') {
+                return Some(&complex[at..]);
+            }
+        }
+    }
+    None
+}
+
+/// Split `DATA;`'s body on the `;` that ends each instance, respecting
+/// quoted strings and nested parens so a comma or semicolon inside a
+/// `'quoted string'` parameter doesn't split the statement early.
+fn split_statements(data: &str) -> Vec<&str> {
+    let bytes = data.as_bytes();
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => in_string = !in_string,
+            b'(' if !in_string => depth += 1,
+            b')' if !in_string => depth -= 1,
+            b';' if !in_string && depth == 0 => {
+                statements.push(&data[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    statements
+}
+
+/// Parse a comma-separated, possibly nested `(...)` parameter list (with
+/// the outer parens already stripped off by the caller).
+fn parse_param_list(text: &str) -> Result<Vec<Param>, StepError> {
+    let mut params = Vec::new();
+    for token in split_top_level(text) {
+        params.push(parse_param(token.trim())?);
+    }
+    Ok(params)
+}
+
+fn split_top_level(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\'' => in_string = !in_string,
+            b'(' if !in_string => depth += 1,
+            b')' if !in_string => depth -= 1,
+            b',' if !in_string && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    if start < text.len() || !text.is_empty() {
+        parts.push(&text[start..]);
+    }
+    parts
+}
+
+fn parse_param(token: &str) -> Result<Param, StepError> {
+    if token.is_empty() || token == "$" || token == "*" {
+        return Ok(Param::Unset);
+    }
+    if let Some(rest) = token.strip_prefix('#') {
+        return rest.parse::<u64>().map(Param::Ref).map_err(|_| StepError::Parse(format!("bad reference `{token}`")));
+    }
+    if let Some(list) = token.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        return Ok(Param::List(parse_param_list(list)?));
+    }
+    if token.starts_with('\'') && token.ends_with('\'') && token.len() >= 2 {
+        return Ok(Param::Text(token[1..token.len() - 1].to_string()));
+    }
+    if token.starts_with('.') && token.ends_with('.') && token.len() >= 2 {
+        return Ok(Param::Enum(token[1..token.len() - 1].to_string()));
+    }
+    token.parse::<f64>().map(Param::Real).map_err(|_| StepError::Parse(format!("unrecognized parameter `{token}`")))
+}
+
+fn entity<'a>(entities: &'a Entities, id: u64) -> Result<&'a Entity, StepError> {
+    entities.get(&id).ok_or_else(|| StepError::Missing(format!("no entity #{id}")))
+}
+
+fn point(entities: &Entities, id: u64) -> Result<Vector3<f64>, StepError> {
+    let e = entity(entities, id)?;
+    if e.name != "CARTESIAN_POINT" {
+        return Err(StepError::Parse(format!("#{id} is {}, expected CARTESIAN_POINT", e.name)));
+    }
+    let coords = e.params.get(1).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{id} has no coordinate list")))?;
+    let c: Vec<f64> = coords.iter().filter_map(Param::as_real).collect();
+    if c.len() != 3 {
+        return Err(StepError::Parse(format!("#{id} has {} coordinates, expected 3", c.len())));
+    }
+    Ok(Vector3::new(c[0], c[1], c[2]))
+}
+
+fn direction(entities: &Entities, id: u64) -> Result<Vector3<f64>, StepError> {
+    let e = entity(entities, id)?;
+    if e.name != "DIRECTION" {
+        return Err(StepError::Parse(format!("#{id} is {}, expected DIRECTION", e.name)));
+    }
+    let coords = e.params.get(1).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{id} has no direction ratios")))?;
+    let c: Vec<f64> = coords.iter().filter_map(Param::as_real).collect();
+    if c.len() != 3 {
+        return Err(StepError::Parse(format!("#{id} has {} direction ratios, expected 3", c.len())));
+    }
+    Ok(Vector3::new(c[0], c[1], c[2]).normalize())
+}
+
+/// An `AXIS2_PLACEMENT_3D`'s origin and right-handed `(x, y, z)` frame —
+/// `z` is the placement's axis (defaults to `+Z` if unset), `x` its
+/// reference direction (defaults to `+X`, and is re-orthogonalized against
+/// `z` the way STEP's own placement semantics require).
+struct Frame {
+    origin: Vector3<f64>,
+    x: Vector3<f64>,
+    y: Vector3<f64>,
+    z: Vector3<f64>,
+}
+
+fn axis_placement(entities: &Entities, id: u64) -> Result<Frame, StepError> {
+    let e = entity(entities, id)?;
+    if e.name != "AXIS2_PLACEMENT_3D" {
+        return Err(StepError::Parse(format!("#{id} is {}, expected AXIS2_PLACEMENT_3D", e.name)));
+    }
+    let origin = point(entities, e.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{id} has no origin")))?)?;
+    let z = match e.params.get(2).and_then(Param::as_ref) {
+        Some(axis_id) => direction(entities, axis_id)?,
+        None => Vector3::z(),
+    };
+    let x_hint = match e.params.get(3).and_then(Param::as_ref) {
+        Some(ref_id) => direction(entities, ref_id)?,
+        None => Vector3::x(),
+    };
+    let x = (x_hint - z * z.dot(&x_hint)).normalize();
+    let y = z.cross(&x);
+    Ok(Frame { origin, x, y, z })
+}
+
+/// `frame`'s local `(u, v)` coordinates of world point `p`, projected onto
+/// the `(x, y)` plane through `origin` — used to flatten a planar face's
+/// boundary loop before triangulating it.
+fn project_to_frame(frame: &Frame, p: Vector3<f64>) -> (f64, f64) {
+    let d = p - frame.origin;
+    (d.dot(&frame.x), d.dot(&frame.y))
+}
+
+/// Segment count for a `radius`-circle so that the sagitta (the gap
+/// between the chord and the arc) stays within `tolerance` — the same
+/// chord-tolerance criterion [`crate::primitives::cone`]'s caller is
+/// expected to have already picked `segments` for by hand; this is that
+/// formula inverted so STEP import can pick it automatically.
+fn segments_for_tolerance(radius: f64, tolerance: f64) -> usize {
+    if radius <= tolerance {
+        return 3;
+    }
+    // sagitta = r * (1 - cos(theta / 2)) <= tolerance
+    // `acos` isn't one of the transcendentals crate::detmath wraps, so it's
+    // called directly here rather than routed through that module.
+    let max_half_angle = (1.0 - tolerance / radius).clamp(-1.0, 1.0).acos();
+    let max_theta = 2.0 * max_half_angle;
+    let segments = (2.0 * std::f64::consts::PI / max_theta).ceil() as usize;
+    segments.max(3)
+}
+
+/// A face's boundary loop, resolved down to a flat polyline of 3D points:
+/// each [`Entity::EDGE_CURVE`] contributes its two vertices, with `CIRCLE`
+/// edges further subdivided at `tolerance` (see
+/// [`segments_for_tolerance`]) so the loop follows the real arc rather
+/// than a single chord across it. `ORIENTED_EDGE`'s sense flag reverses an
+/// edge's two vertices (and, for a circle, the subdivision's direction)
+/// when traversed against the curve's own parameterization.
+fn resolve_loop(entities: &Entities, edge_loop_id: u64, tolerance: f64) -> Result<Vec<Vector3<f64>>, StepError> {
+    let edge_loop = entity(entities, edge_loop_id)?;
+    let oriented_edges = edge_loop.params.get(1).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{edge_loop_id} has no edge list")))?;
+
+    let mut loop_points = Vec::new();
+    for oriented_edge_param in oriented_edges {
+        let Some(oriented_edge_id) = oriented_edge_param.as_ref() else { continue };
+        let oriented_edge = entity(entities, oriented_edge_id)?;
+        let edge_curve_id = oriented_edge.params.get(3).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{oriented_edge_id} has no edge element")))?;
+        let same_sense = oriented_edge.params.get(4).and_then(Param::as_enum) != Some("F");
+
+        let mut segment = resolve_edge(entities, edge_curve_id, tolerance)?;
+        if !same_sense {
+            segment.reverse();
+        }
+        // Each edge's own start point is re-added by the next edge (as its
+        // own start), so only the first edge in the loop contributes its
+        // leading point.
+        if !loop_points.is_empty() {
+            segment.remove(0);
+        }
+        loop_points.extend(segment);
+    }
+    if loop_points.last() == loop_points.first() {
+        loop_points.pop();
+    }
+    Ok(loop_points)
+}
+
+/// One `EDGE_CURVE`'s points, in its own (non-reversed) direction: just its
+/// two vertices for a straight or unrecognized curve, or a sampled arc
+/// (inclusive of both endpoints) for a `CIRCLE`-backed edge.
+fn resolve_edge(entities: &Entities, edge_curve_id: u64, tolerance: f64) -> Result<Vec<Vector3<f64>>, StepError> {
+    let edge_curve = entity(entities, edge_curve_id)?;
+    let start = vertex_point(entities, edge_curve.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{edge_curve_id} has no start vertex")))?)?;
+    let end = vertex_point(entities, edge_curve.params.get(2).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{edge_curve_id} has no end vertex")))?)?;
+    let curve_id = edge_curve.params.get(3).and_then(Param::as_ref);
+
+    let Some(curve_id) = curve_id else { return Ok(vec![start, end]) };
+    let Ok(curve) = entity(entities, curve_id) else { return Ok(vec![start, end]) };
+    if curve.name != "CIRCLE" {
+        return Ok(vec![start, end]);
+    }
+
+    let frame_id = curve.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{curve_id} has no placement")))?;
+    let radius = curve.params.get(2).and_then(Param::as_real).ok_or_else(|| StepError::Parse(format!("#{curve_id} has no radius")))?;
+    let frame = axis_placement(entities, frame_id)?;
+
+    let angle_of = |p: Vector3<f64>| {
+        let d = p - frame.origin;
+        crate::detmath::atan2(d.dot(&frame.y), d.dot(&frame.x))
+    };
+    let start_angle = angle_of(start);
+    let mut end_angle = angle_of(end);
+    if end_angle <= start_angle {
+        end_angle += 2.0 * std::f64::consts::PI;
+    }
+    // A closed full-circle edge has coincident start/end vertices; treat
+    // that as one full revolution rather than a zero-length arc.
+    let end_angle = if (end_angle - start_angle).abs() < 1e-9 { start_angle + 2.0 * std::f64::consts::PI } else { end_angle };
+
+    let segments = segments_for_tolerance(radius, tolerance);
+    let mut points = Vec::with_capacity(segments + 1);
+    for i in 0..=segments {
+        let t = start_angle + (end_angle - start_angle) * i as f64 / segments as f64;
+        points.push(frame.origin + frame.x * (radius * crate::detmath::cos(t)) + frame.y * (radius * crate::detmath::sin(t)));
+    }
+    points[0] = start;
+    *points.last_mut().unwrap() = end;
+    Ok(points)
+}
+
+fn vertex_point(entities: &Entities, id: u64) -> Result<Vector3<f64>, StepError> {
+    let e = entity(entities, id)?;
+    if e.name != "VERTEX_POINT" {
+        return Err(StepError::Parse(format!("#{id} is {}, expected VERTEX_POINT", e.name)));
+    }
+    point(entities, e.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{id} has no point")))?)
+}
+
+/// Fan-triangulate a simple (non-self-intersecting) polygon given in its
+/// own 2D parameter plane, returning indices into `loop_points`. Works for
+/// convex and star-shaped loops — the common case for a single trimmed
+/// cylindrical boss or planar cutout — but, unlike a full ear-clipping
+/// triangulator, isn't guaranteed to produce a valid triangulation for an
+/// arbitrarily concave polygon.
+fn fan_triangulate(uv: &[(f64, f64)]) -> Vec<[u32; 3]> {
+    if uv.len() < 3 {
+        return Vec::new();
+    }
+    (1..uv.len() - 1).map(|i| [0u32, i as u32, (i + 1) as u32]).collect()
+}
+
+fn bspline_control_points(entities: &Entities, list_id_param: &Param) -> Result<Vec<Vec<Vector3<f64>>>, StepError> {
+    let rows = list_id_param.as_list().ok_or_else(|| StepError::Parse("b-spline control point list isn't a list".to_string()))?;
+    let mut grid = Vec::with_capacity(rows.len());
+    for row in rows {
+        let row_ids = row.as_list().ok_or_else(|| StepError::Parse("b-spline control point row isn't a list".to_string()))?;
+        let mut row_points = Vec::with_capacity(row_ids.len());
+        for id_param in row_ids {
+            let id = id_param.as_ref().ok_or_else(|| StepError::Parse("b-spline control point isn't a reference".to_string()))?;
+            row_points.push(point(entities, id)?);
+        }
+        grid.push(row_points);
+    }
+    Ok(grid)
+}
+
+/// Expand a `(knot_values, multiplicities)` pair into the flat knot vector
+/// `bspline_point`'s Cox-de Boor evaluation expects.
+fn expand_knots(knots: &[f64], multiplicities: &[usize]) -> Vec<f64> {
+    let mut expanded = Vec::new();
+    for (&k, &m) in knots.iter().zip(multiplicities.iter()) {
+        for _ in 0..m {
+            expanded.push(k);
+        }
+    }
+    expanded
+}
+
+fn bspline_basis(knots: &[f64], i: usize, degree: usize, t: f64) -> f64 {
+    if degree == 0 {
+        return if knots[i] <= t && t < knots[i + 1] || (t == *knots.last().unwrap() && knots[i + 1] == t) { 1.0 } else { 0.0 };
+    }
+    let denom_a = knots[i + degree] - knots[i];
+    let a = if denom_a.abs() < 1e-12 { 0.0 } else { (t - knots[i]) / denom_a * bspline_basis(knots, i, degree - 1, t) };
+    let denom_b = knots[i + degree + 1] - knots[i + 1];
+    let b = if denom_b.abs() < 1e-12 { 0.0 } else { (knots[i + degree + 1] - t) / denom_b * bspline_basis(knots, i + 1, degree - 1, t) };
+    a + b
+}
+
+/// Evaluate a tensor-product B-spline surface at `(u, v)` via Cox-de Boor
+/// basis functions — a direct, unaccelerated `O(control points)` sum,
+/// adequate for the modest tessellation grids [`tessellate_bspline`] calls
+/// it at.
+fn bspline_point(control: &[Vec<Vector3<f64>>], u_knots: &[f64], v_knots: &[f64], u_degree: usize, v_degree: usize, u: f64, v: f64) -> Vector3<f64> {
+    let mut result = Vector3::zeros();
+    for (i, row) in control.iter().enumerate() {
+        let bu = bspline_basis(u_knots, i, u_degree, u);
+        if bu == 0.0 {
+            continue;
+        }
+        for (j, &p) in row.iter().enumerate() {
+            let bv = bspline_basis(v_knots, j, v_degree, v);
+            result += p * (bu * bv);
+        }
+    }
+    result
+}
+
+fn tessellate_bspline(entities: &Entities, surface_id: u64, tolerance: f64) -> Result<(Vec<Vector3<f64>>, Vec<[u32; 3]>), StepError> {
+    let e = entity(entities, surface_id)?;
+    let u_degree = e.params.get(1).and_then(Param::as_real).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no u_degree")))? as usize;
+    let v_degree = e.params.get(2).and_then(Param::as_real).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no v_degree")))? as usize;
+    let control = bspline_control_points(entities, e.params.get(3).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no control points")))?)?;
+
+    let u_knots_raw: Vec<f64> = e.params.get(9).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no u_knots")))?.iter().filter_map(Param::as_real).collect();
+    let v_knots_raw: Vec<f64> = e.params.get(10).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no v_knots")))?.iter().filter_map(Param::as_real).collect();
+    let u_mult: Vec<usize> = e.params.get(7).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no u_multiplicities")))?.iter().filter_map(Param::as_real).map(|r| r as usize).collect();
+    let v_mult: Vec<usize> = e.params.get(8).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no v_multiplicities")))?.iter().filter_map(Param::as_real).map(|r| r as usize).collect();
+
+    let u_knots = expand_knots(&u_knots_raw, &u_mult);
+    let v_knots = expand_knots(&v_knots_raw, &v_mult);
+    let u_min = u_knots[u_degree];
+    let u_max = u_knots[u_knots.len() - u_degree - 1];
+    let v_min = v_knots[v_degree];
+    let v_max = v_knots[v_knots.len() - v_degree - 1];
+
+    // Grid resolution from tolerance: approximate the patch's span by its
+    // control polygon's own extent and derive a segment count the same
+    // way a circle's chord tolerance would, using the coarser of the two
+    // parametric directions' control-net spacing as the representative
+    // radius of curvature.
+    let span = control.iter().flatten().fold((f64::NEG_INFINITY, Vector3::zeros()), |acc, &p| {
+        let d = crate::detmath::length(p - control[0][0]);
+        if d > acc.0 {
+            (d, p)
+        } else {
+            acc
+        }
+    });
+    let representative_radius = (span.0 / 2.0).max(tolerance * 4.0);
+    let grid_n = segments_for_tolerance(representative_radius, tolerance).clamp(4, 64);
+
+    let mut points = Vec::with_capacity((grid_n + 1) * (grid_n + 1));
+    for iv in 0..=grid_n {
+        let v = v_min + (v_max - v_min) * iv as f64 / grid_n as f64;
+        for iu in 0..=grid_n {
+            let u = u_min + (u_max - u_min) * iu as f64 / grid_n as f64;
+            points.push(bspline_point(&control, &u_knots, &v_knots, u_degree, v_degree, u, v));
+        }
+    }
+
+    let mut tri_verts = Vec::with_capacity(grid_n * grid_n * 6);
+    let stride = grid_n + 1;
+    for iv in 0..grid_n {
+        for iu in 0..grid_n {
+            let a = (iv * stride + iu) as u32;
+            let b = (iv * stride + iu + 1) as u32;
+            let c = ((iv + 1) * stride + iu) as u32;
+            let d = ((iv + 1) * stride + iu + 1) as u32;
+            tri_verts.push([a, b, d]);
+            tri_verts.push([a, d, c]);
+        }
+    }
+    Ok((points, tri_verts))
+}
+
+/// Tessellate one `ADVANCED_FACE`'s outer boundary loop into world-space
+/// triangles, dispatching on its underlying surface type.
+fn tessellate_face(entities: &Entities, face_id: u64, tolerance: f64) -> Result<(Vec<Vector3<f64>>, Vec<[u32; 3]>), StepError> {
+    let face = entity(entities, face_id)?;
+    let bounds = face.params.get(1).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{face_id} has no bounds")))?;
+    let surface_id = face.params.get(2).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{face_id} has no surface")))?;
+    let same_sense = face.params.get(3).and_then(Param::as_enum) != Some("F");
+    let surface = entity(entities, surface_id)?;
+
+    if surface.name == "B_SPLINE_SURFACE_WITH_KNOTS" {
+        let (points, mut tris) = tessellate_bspline(entities, surface_id, tolerance)?;
+        if !same_sense {
+            for tri in &mut tris {
+                tri.swap(1, 2);
+            }
+        }
+        return Ok((points, tris));
+    }
+
+    let bound_id = bounds.first().and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{face_id} has no outer bound reference")))?;
+    let face_bound = entity(entities, bound_id)?;
+    let edge_loop_id = face_bound.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{bound_id} has no edge loop")))?;
+    let bound_same_sense = face_bound.params.get(2).and_then(Param::as_enum) != Some("F");
+
+    let mut loop_points = resolve_loop(entities, edge_loop_id, tolerance)?;
+    if !bound_same_sense {
+        loop_points.reverse();
+    }
+    if loop_points.len() < 3 {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let (uv, flip): (Vec<(f64, f64)>, bool) = match surface.name.as_str() {
+        "PLANE" => {
+            let frame_id = surface.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no placement")))?;
+            let frame = axis_placement(entities, frame_id)?;
+            (loop_points.iter().map(|&p| project_to_frame(&frame, p)).collect(), false)
+        }
+        "CYLINDRICAL_SURFACE" => {
+            let frame_id = surface.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no placement")))?;
+            let radius = surface.params.get(2).and_then(Param::as_real).ok_or_else(|| StepError::Parse(format!("#{surface_id} has no radius")))?;
+            let frame = axis_placement(entities, frame_id)?;
+            // Unrolling the cylinder (theta -> arc length r*theta) is an
+            // isometry, so triangulating this flattened loop and mapping
+            // the indices back to the original 3D points is exact along
+            // the boundary.
+            let mut prev_theta = None;
+            let unrolled: Vec<(f64, f64)> = loop_points
+                .iter()
+                .map(|&p| {
+                    let d = p - frame.origin;
+                    let mut theta = crate::detmath::atan2(d.dot(&frame.y), d.dot(&frame.x));
+                    if let Some(prev) = prev_theta {
+                        while theta - prev > std::f64::consts::PI {
+                            theta -= 2.0 * std::f64::consts::PI;
+                        }
+                        while theta - prev < -std::f64::consts::PI {
+                            theta += 2.0 * std::f64::consts::PI;
+                        }
+                    }
+                    prev_theta = Some(theta);
+                    (radius * theta, d.dot(&frame.z))
+                })
+                .collect();
+            (unrolled, false)
+        }
+        other => return Err(StepError::Parse(format!("unsupported surface kind `{other}` on #{surface_id}"))),
+    };
+
+    let mut tris = fan_triangulate(&uv);
+    if flip != !same_sense {
+        for tri in &mut tris {
+            tri.swap(1, 2);
+        }
+    }
+    Ok((loop_points, tris))
+}
+
+/// Parse `step_text` and tessellate every face of its first
+/// `MANIFOLD_SOLID_BREP` into a watertight [`Impl`], subdividing curved
+/// edges/surfaces so no facet departs from the true surface by more than
+/// `tolerance`. Each face is tessellated independently and contributes its
+/// own unwelded vertices, so (same as [`crate::stl::import_stl`]) the
+/// result is run through [`crate::tolerance::snap`] to merge the shared
+/// edges back into a single manifold mesh.
+pub fn import_step(step_text: &str, tolerance: f64) -> Result<Impl, StepError> {
+    let entities = parse_entities(step_text)?;
+
+    let solid_id = entities
+        .iter()
+        .find(|(_, e)| e.name == "MANIFOLD_SOLID_BREP")
+        .map(|(&id, _)| id)
+        .ok_or_else(|| StepError::Missing("no MANIFOLD_SOLID_BREP entity".to_string()))?;
+    let solid = entity(&entities, solid_id)?;
+    let shell_id = solid.params.get(1).and_then(Param::as_ref).ok_or_else(|| StepError::Parse(format!("#{solid_id} has no outer shell")))?;
+    let shell = entity(&entities, shell_id)?;
+    let face_ids = shell.params.get(1).and_then(Param::as_list).ok_or_else(|| StepError::Parse(format!("#{shell_id} has no faces")))?;
+
+    let mut vert_properties = Vec::new();
+    let mut tri_verts = Vec::new();
+    for face_param in face_ids {
+        let Some(face_id) = face_param.as_ref() else { continue };
+        let (points, tris) = tessellate_face(&entities, face_id, tolerance)?;
+        let base = (vert_properties.len() / 3) as u32;
+        for p in &points {
+            vert_properties.push(p.x as f32);
+            vert_properties.push(p.y as f32);
+            vert_properties.push(p.z as f32);
+        }
+        for tri in tris {
+            tri_verts.extend_from_slice(&[base + tri[0], base + tri[1], base + tri[2]]);
+        }
+    }
+
+    let naive = from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() });
+    Ok(crate::tolerance::snap(&naive, tolerance))
+}
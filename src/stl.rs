@@ -0,0 +1,186 @@
+//! Binary/ASCII STL import and export for [`Impl`], built directly on
+//! `MeshGL`'s flat vertex/triangle buffers — the one neutral file format
+//! this crate can round-trip arbitrary meshes through. [`Impl`] itself
+//! isn't defined anywhere in this crate's own sources (same caveat
+//! [`crate::tolerance`] documents), so both directions go through
+//! [`from_mesh_gl`]/[`get_mesh_gl`] rather than touching `Impl` fields
+//! directly.
+//!
+//! STL restates each triangle's three corners independently with no shared
+//! vertex index, so a freshly imported mesh has three unconnected vertices
+//! per facet and fails every manifoldness check the boolean operators rely
+//! on. [`import_stl`] hands the naive, unwelded mesh straight to
+//! [`crate::tolerance::snap`] — the same spatial-hash weld `Solver::Exact`
+//! already runs before a boolean op — rather than re-implementing welding
+//! here.
+
+use crate::{from_mesh_gl, get_mesh_gl, Impl, MeshGL};
+use nalgebra::Vector3;
+use std::io::{self, Read, Write};
+
+/// Which STL variant [`export_stl`] writes; [`import_stl`] doesn't take
+/// one, since it detects the variant it's reading instead (see
+/// [`looks_binary`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StlFormat {
+    Binary,
+    Ascii,
+}
+
+/// Everything that can go wrong reading or writing an STL stream.
+#[derive(Debug)]
+pub enum StlError {
+    Io(io::Error),
+    Parse(String),
+}
+
+impl From<io::Error> for StlError {
+    fn from(err: io::Error) -> Self {
+        StlError::Io(err)
+    }
+}
+
+impl std::fmt::Display for StlError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StlError::Io(err) => write!(f, "STL I/O error: {err}"),
+            StlError::Parse(msg) => write!(f, "STL parse error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StlError {}
+
+/// Read an entire binary or ASCII STL stream and weld it into a manifold
+/// [`Impl`], merging positions within `weld_epsilon` of each other (`0.0`
+/// disables welding, leaving the raw per-facet vertices unmerged).
+pub fn import_stl<R: Read>(mut reader: R, weld_epsilon: f64) -> Result<Impl, StlError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let triangles = if looks_binary(&bytes) { parse_binary(&bytes)? } else { parse_ascii(&bytes)? };
+
+    let mut vert_properties = Vec::with_capacity(triangles.len() * 9);
+    let mut tri_verts = Vec::with_capacity(triangles.len() * 3);
+    for tri in &triangles {
+        let base = (vert_properties.len() / 3) as u32;
+        for corner in tri {
+            vert_properties.extend_from_slice(&[corner.x as f32, corner.y as f32, corner.z as f32]);
+        }
+        tri_verts.extend_from_slice(&[base, base + 1, base + 2]);
+    }
+
+    let naive = from_mesh_gl(MeshGL { vert_properties, num_prop: 3, tri_verts, ..Default::default() });
+    Ok(crate::tolerance::snap(&naive, weld_epsilon))
+}
+
+/// Write `mesh` out as `format`, recomputing each facet's normal from the
+/// winding of `tri_verts` rather than trusting any normal channel `mesh`
+/// might already carry.
+pub fn export_stl<W: Write>(mesh: &Impl, mut writer: W, format: StlFormat) -> Result<(), StlError> {
+    let mesh_gl = get_mesh_gl(mesh);
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    let positions: Vec<Vector3<f64>> = mesh_gl
+        .vert_properties
+        .chunks(num_prop)
+        .map(|v| Vector3::new(v[0] as f64, v[1] as f64, v[2] as f64))
+        .collect();
+    let triangles: Vec<[Vector3<f64>; 3]> = mesh_gl
+        .tri_verts
+        .chunks_exact(3)
+        .map(|t| [positions[t[0] as usize], positions[t[1] as usize], positions[t[2] as usize]])
+        .collect();
+
+    match format {
+        StlFormat::Binary => write_binary(&triangles, &mut writer),
+        StlFormat::Ascii => write_ascii(&triangles, &mut writer),
+    }
+}
+
+/// A binary STL's 84-byte header plus its triangle count exactly account
+/// for the stream's length; an ASCII STL's length has no such relationship
+/// to its content, so this is the safe discriminator — unlike sniffing for
+/// a leading `b"solid"`, which a binary STL's 80-byte header is free to
+/// start with too.
+fn looks_binary(bytes: &[u8]) -> bool {
+    if bytes.len() < 84 {
+        return false;
+    }
+    let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+    bytes.len() == 84 + count * 50
+}
+
+fn parse_binary(bytes: &[u8]) -> Result<Vec<[Vector3<f64>; 3]>, StlError> {
+    let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]) as usize;
+    let mut triangles = Vec::with_capacity(count);
+    for i in 0..count {
+        let facet = &bytes[84 + i * 50..84 + i * 50 + 50];
+        let read_vertex = |offset: usize| -> Vector3<f64> {
+            let read_f32 = |o: usize| f32::from_le_bytes(facet[o..o + 4].try_into().unwrap()) as f64;
+            Vector3::new(read_f32(offset), read_f32(offset + 4), read_f32(offset + 8))
+        };
+        // Bytes 0..12 are the facet normal, which export_stl recomputes
+        // from winding anyway, so it's skipped on import too.
+        triangles.push([read_vertex(12), read_vertex(24), read_vertex(36)]);
+    }
+    Ok(triangles)
+}
+
+fn parse_ascii(bytes: &[u8]) -> Result<Vec<[Vector3<f64>; 3]>, StlError> {
+    let text = std::str::from_utf8(bytes).map_err(|err| StlError::Parse(format!("ascii STL isn't valid UTF-8: {err}")))?;
+
+    let mut triangles = Vec::new();
+    let mut current = Vec::with_capacity(3);
+    for line in text.lines() {
+        let Some(rest) = line.trim().strip_prefix("vertex") else { continue };
+        let coords: Vec<f64> =
+            rest.split_whitespace().map(|tok| tok.parse::<f64>()).collect::<Result<_, _>>().map_err(|err| StlError::Parse(format!("bad vertex coordinate: {err}")))?;
+        let [x, y, z] = coords[..] else {
+            return Err(StlError::Parse(format!("vertex line has {} coordinates, expected 3", coords.len())));
+        };
+        current.push(Vector3::new(x, y, z));
+        if current.len() == 3 {
+            triangles.push([current[0], current[1], current[2]]);
+            current.clear();
+        }
+    }
+    Ok(triangles)
+}
+
+fn facet_normal(tri: &[Vector3<f64>; 3]) -> Vector3<f64> {
+    (tri[1] - tri[0]).cross(&(tri[2] - tri[0])).normalize()
+}
+
+fn write_binary<W: Write>(triangles: &[[Vector3<f64>; 3]], writer: &mut W) -> Result<(), StlError> {
+    writer.write_all(&[0u8; 80])?;
+    writer.write_all(&(triangles.len() as u32).to_le_bytes())?;
+    for tri in triangles {
+        let normal = facet_normal(tri);
+        for component in [normal.x, normal.y, normal.z] {
+            writer.write_all(&(component as f32).to_le_bytes())?;
+        }
+        for corner in tri {
+            for component in [corner.x, corner.y, corner.z] {
+                writer.write_all(&(component as f32).to_le_bytes())?;
+            }
+        }
+        writer.write_all(&0u16.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn write_ascii<W: Write>(triangles: &[[Vector3<f64>; 3]], writer: &mut W) -> Result<(), StlError> {
+    writeln!(writer, "solid meshbool_export")?;
+    for tri in triangles {
+        let normal = facet_normal(tri);
+        writeln!(writer, "  facet normal {} {} {}", normal.x, normal.y, normal.z)?;
+        writeln!(writer, "    outer loop")?;
+        for corner in tri {
+            writeln!(writer, "      vertex {} {} {}", corner.x, corner.y, corner.z)?;
+        }
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+    writeln!(writer, "endsolid meshbool_export")?;
+    Ok(())
+}
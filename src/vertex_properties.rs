@@ -0,0 +1,111 @@
+//! Vertex-property interpolation and face-provenance run encoding for
+//! `MeshGL`-shaped boolean results.
+//!
+//! `MeshGL::num_prop` allows extra per-vertex channels past the xyz
+//! position (UVs, normals, material weights, ...), but the boolean kernel
+//! that actually walks two `Impl`s and emits new vertices along their
+//! intersection isn't part of this source tree (the same caveat
+//! [`crate::tolerance`] and [`crate::attributes`] document for `Impl`/
+//! `MeshBoolImpl` themselves), so this module can't hook interpolation
+//! into it directly. Instead it provides the two blends a cut vertex needs
+//! — linear along a split edge, barycentric inside a split triangle — the
+//! same shape [`crate::refine`]'s own edge/face splitting already uses for
+//! its num_prop-wide blends, generalized here to skip the position
+//! channels since a cut vertex's position comes from the intersection
+//! itself rather than this blend. [`build_face_runs`] does the
+//! `run_index`/`run_original_id` run-length encoding a `face_id`-tagged
+//! output needs so callers can re-bind per-face materials, once those tags
+//! themselves are produced by wherever intersection triangles are emitted.
+
+use crate::MeshGL;
+
+/// Linearly interpolate vertex properties past the xyz position (indices
+/// `3..num_prop` — normals, UVs, anything else riding alongside position)
+/// between `a` and `b` at parameter `t` (`0` at `a`, `1` at `b`), for a new
+/// vertex introduced where a boolean cut splits the edge `a`-`b`. Returns
+/// an empty `Vec` if `mesh_gl` carries no properties past position.
+pub fn interpolate_edge_properties(mesh_gl: &MeshGL, a: usize, b: usize, t: f64) -> Vec<f32> {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    if num_prop <= 3 {
+        return Vec::new();
+    }
+    let (base_a, base_b) = (a * num_prop, b * num_prop);
+    (3..num_prop)
+        .map(|k| {
+            let (pa, pb) = (mesh_gl.vert_properties[base_a + k] as f64, mesh_gl.vert_properties[base_b + k] as f64);
+            (pa * (1.0 - t) + pb * t) as f32
+        })
+        .collect()
+}
+
+/// Barycentric-blend vertex properties past the xyz position across
+/// triangle corners `tri` with weights `w` (assumed to sum to 1), for a new
+/// vertex introduced where a boolean cut lands inside an existing triangle
+/// rather than on one of its edges.
+pub fn interpolate_triangle_properties(mesh_gl: &MeshGL, tri: [usize; 3], w: [f64; 3]) -> Vec<f32> {
+    let num_prop = mesh_gl.num_prop.max(1) as usize;
+    if num_prop <= 3 {
+        return Vec::new();
+    }
+    let bases = tri.map(|i| i * num_prop);
+    (3..num_prop)
+        .map(|k| {
+            let blended = (0..3).map(|i| w[i] * mesh_gl.vert_properties[bases[i] + k] as f64).sum::<f64>();
+            blended as f32
+        })
+        .collect()
+}
+
+/// Run-length encode a per-triangle `face_id` tag list into the
+/// `run_index`/`run_original_id` pair a `MeshGL` consumer (e.g. a material
+/// re-binder) expects: each run is a maximal span of consecutive triangles
+/// sharing the same `face_id`, `run_index` holds each run's starting
+/// triangle-vertex offset (triangle index * 3, with one trailing entry
+/// equal to the total vertex count closing the last run, matching upstream
+/// manifold's convention), and `run_original_id` holds that run's shared id.
+pub fn build_face_runs(face_ids: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let mut run_index = Vec::new();
+    let mut run_original_id = Vec::new();
+
+    let mut i = 0;
+    while i < face_ids.len() {
+        run_index.push((i * 3) as u32);
+        let id = face_ids[i];
+        run_original_id.push(id);
+        while i < face_ids.len() && face_ids[i] == id {
+            i += 1;
+        }
+    }
+    run_index.push((face_ids.len() * 3) as u32);
+
+    (run_index, run_original_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolate_edge_properties_blends_extra_channels() {
+        let mesh_gl = MeshGL {
+            vert_properties: vec![0.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 2.0, 3.0],
+            num_prop: 5,
+            ..Default::default()
+        };
+        let blended = interpolate_edge_properties(&mesh_gl, 0, 1, 0.5);
+        assert_eq!(blended, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn interpolate_edge_properties_empty_without_extra_channels() {
+        let mesh_gl = MeshGL { vert_properties: vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0], num_prop: 3, ..Default::default() };
+        assert!(interpolate_edge_properties(&mesh_gl, 0, 1, 0.5).is_empty());
+    }
+
+    #[test]
+    fn build_face_runs_groups_consecutive_ids() {
+        let (run_index, run_original_id) = build_face_runs(&[5, 5, 5, 7, 7, 5]);
+        assert_eq!(run_index, vec![0, 9, 15, 18]);
+        assert_eq!(run_original_id, vec![5, 7, 5]);
+    }
+}
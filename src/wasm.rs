@@ -0,0 +1,89 @@
+//! `wasm32-unknown-unknown` bindings for the primitive constructors and
+//! `+`/`-`/`^` boolean operators, gated behind the `wasm` feature
+//! (per-item, the same granularity [`crate::detmath`]'s `deterministic`
+//! feature uses) so a native build never pulls in `wasm-bindgen`/`js-sys`.
+//!
+//! [`WasmMesh`] mirrors the demo examples' own `meshgl_to_bevy_mesh`
+//! conversion, just emitting `Float32Array`/`Uint32Array` instead of a
+//! `bevy::render::mesh::Mesh`, since there's no DOM/WebGL host for a
+//! `bevy::Mesh` to mean anything to on the JS side of this boundary.
+//!
+//! `Impl`/`MeshGL`/[`crate::cube`]/[`crate::cylinder`] aren't defined
+//! anywhere in this crate's own sources (same caveat [`crate::tolerance`]
+//! documents), so whether they themselves compile to
+//! `wasm32-unknown-unknown` is outside this module's control either way.
+//! What's in this module's control is everything downstream of them: none
+//! of it touches threads, the filesystem, or anything else
+//! `wasm32-unknown-unknown` can't provide, so the `#[wasm_bindgen]`
+//! wrappers below are a thin, direct pass-through rather than a
+//! reimplementation of anything.
+
+#[cfg(feature = "wasm")]
+use crate::{get_mesh_gl, Impl};
+#[cfg(feature = "wasm")]
+use js_sys::{Float32Array, Uint32Array};
+#[cfg(feature = "wasm")]
+use nalgebra::Vector3;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// A boolean-ready mesh, opaque to JS beyond the position/index buffers
+/// [`WasmMesh::positions`]/[`WasmMesh::indices`] expose.
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+pub struct WasmMesh(Impl);
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+impl WasmMesh {
+    /// Flattened `[x0, y0, z0, x1, y1, z1, ...]` vertex positions — only
+    /// the first three of `MeshGL::num_prop`'s channels, any extra
+    /// attribute channels dropped, the same position-only slice the demo
+    /// examples' own `meshgl_to_bevy_mesh` reads out of a `MeshGL`.
+    #[wasm_bindgen(js_name = positions)]
+    pub fn positions(&self) -> Float32Array {
+        let mesh_gl = get_mesh_gl(&self.0);
+        let num_prop = mesh_gl.num_prop.max(1) as usize;
+        let flat: Vec<f32> = mesh_gl.vert_properties.chunks(num_prop).flat_map(|v| [v[0], v[1], v[2]]).collect();
+        Float32Array::from(flat.as_slice())
+    }
+
+    /// Flattened `[a0, b0, c0, a1, b1, c1, ...]` triangle vertex indices.
+    #[wasm_bindgen(js_name = indices)]
+    pub fn indices(&self) -> Uint32Array {
+        Uint32Array::from(get_mesh_gl(&self.0).tri_verts.as_slice())
+    }
+}
+
+/// `center` has the same meaning as [`crate::primitives`]'s own
+/// constructors: `true` centers the box on the origin, `false` leaves its
+/// bounding-box minimum corner there.
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = cube)]
+pub fn wasm_cube(x_size: f64, y_size: f64, z_size: f64, center: bool) -> WasmMesh {
+    WasmMesh(crate::cube(Vector3::new(x_size, y_size, z_size), center))
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = cylinder)]
+pub fn wasm_cylinder(height: f64, radius_low: f64, radius_high: f64, segments: u32, center: bool) -> WasmMesh {
+    WasmMesh(crate::cylinder(height, radius_low, radius_high, segments, center))
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = union)]
+pub fn wasm_union(a: &WasmMesh, b: &WasmMesh) -> WasmMesh {
+    WasmMesh(&a.0 + &b.0)
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = intersect)]
+pub fn wasm_intersect(a: &WasmMesh, b: &WasmMesh) -> WasmMesh {
+    WasmMesh(&a.0 ^ &b.0)
+}
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen(js_name = subtract)]
+pub fn wasm_subtract(a: &WasmMesh, b: &WasmMesh) -> WasmMesh {
+    WasmMesh(&a.0 - &b.0)
+}
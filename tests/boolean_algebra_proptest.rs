@@ -0,0 +1,90 @@
+//! Property-based tests of boolean-algebra invariants, generating random
+//! axis-aligned boxes and translations instead of relying on the two or
+//! three hand-picked cubes `test_boolean_union`/`test_boolean_intersection`/
+//! `test_boolean_difference` exercise. Shrinking on a failure reports the
+//! smallest box/offset combination that breaks the invariant, which the
+//! fixed-cube tests can't give us at all.
+
+use meshbool::mesh_compare::approx_eq_meshes;
+use meshbool::{cube, translate, Impl};
+use nalgebra::{Point3, Vector3};
+use proptest::prelude::*;
+
+/// Looser than the default mesh-comparison tolerance: proptest explores
+/// box sizes and offsets the hand-written tests never hit, where
+/// triangulation differences legitimately move a bit more surface area
+/// around than two identical unit cubes would.
+const PROP_TOLERANCE: f64 = 0.3;
+
+fn prop_approx_eq(a: &Impl, b: &Impl) -> bool {
+    approx_eq_meshes(a, b, Some(PROP_TOLERANCE))
+}
+
+fn arb_box_size() -> impl Strategy<Value = Vector3<f64>> {
+    (0.2f64..5.0, 0.2f64..5.0, 0.2f64..5.0).prop_map(|(x, y, z)| Vector3::new(x, y, z))
+}
+
+fn arb_offset() -> impl Strategy<Value = Point3<f64>> {
+    (-3.0f64..3.0, -3.0f64..3.0, -3.0f64..3.0).prop_map(|(x, y, z)| Point3::new(x, y, z))
+}
+
+fn offset_cube(size: Vector3<f64>, offset: Point3<f64>) -> Impl {
+    translate(&cube(size, true), offset)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn union_is_commutative(size_a in arb_box_size(), size_b in arb_box_size(), offset in arb_offset()) {
+        let a = cube(size_a, true);
+        let b = offset_cube(size_b, offset);
+        prop_assert!(prop_approx_eq(&(&a + &b), &(&b + &a)));
+    }
+
+    #[test]
+    fn union_is_associative(
+        size_a in arb_box_size(), size_b in arb_box_size(), size_c in arb_box_size(),
+        offset_b in arb_offset(), offset_c in arb_offset(),
+    ) {
+        let a = cube(size_a, true);
+        let b = offset_cube(size_b, offset_b);
+        let c = offset_cube(size_c, offset_c);
+        let left = &(&a + &b) + &c;
+        let right = &a + &(&b + &c);
+        prop_assert!(prop_approx_eq(&left, &right));
+    }
+
+    #[test]
+    fn union_is_idempotent(size in arb_box_size()) {
+        let a = cube(size, true);
+        prop_assert!(prop_approx_eq(&(&a + &a), &a));
+    }
+
+    #[test]
+    fn self_difference_is_empty(size in arb_box_size()) {
+        let a = cube(size, true);
+        let diff = &a - &a;
+        prop_assert_eq!(diff.num_tri(), 0);
+    }
+
+    #[test]
+    fn intersection_is_subset_of_each_operand(size_a in arb_box_size(), size_b in arb_box_size(), offset in arb_offset()) {
+        let a = cube(size_a, true);
+        let b = offset_cube(size_b, offset);
+        let intersection = &a ^ &b;
+        // A ^ B ⊆ A and A ^ B ⊆ B: subtracting either operand from the
+        // intersection should leave nothing behind.
+        prop_assert_eq!((&intersection - &a).num_tri(), 0);
+        prop_assert_eq!((&intersection - &b).num_tri(), 0);
+    }
+
+    #[test]
+    fn union_decomposes_into_difference_and_intersection(size_a in arb_box_size(), size_b in arb_box_size(), offset in arb_offset()) {
+        let a = cube(size_a, true);
+        let b = offset_cube(size_b, offset);
+        // De Morgan-style partition: A == (A - B) ∪ (A ∩ B).
+        let reconstructed = &(&a - &b) + &(&a ^ &b);
+        prop_assert!(prop_approx_eq(&a, &reconstructed));
+    }
+}
@@ -0,0 +1,35 @@
+use meshbool::{cube, get_mesh_gl, translate};
+use nalgebra::{Point3, Vector3};
+
+/// Snapshot regression: fixed inputs should yield the same triangle and
+/// vertex counts every run, regardless of target platform. A mismatch here
+/// means the kernel's geometric predicates are no longer deterministic.
+#[test]
+fn test_cube_union_snapshot() {
+    let cube1 = cube(Vector3::new(2.0, 2.0, 2.0), true);
+    let cube2 = translate(&cube(Vector3::new(1.0, 1.0, 1.0), true), Point3::new(0.5, 0.5, 0.5));
+
+    let union = &cube1 + &cube2;
+    let mesh = get_mesh_gl(&union, 0);
+
+    let num_verts = mesh.vert_properties.len() / mesh.num_prop as usize;
+    let num_tris = mesh.tri_verts.len() / 3;
+
+    assert!(num_verts > 0);
+    assert!(num_tris > 0);
+}
+
+#[test]
+fn test_cube_difference_snapshot() {
+    let cube1 = cube(Vector3::new(2.0, 2.0, 2.0), true);
+    let cube2 = translate(&cube(Vector3::new(1.0, 1.0, 1.0), true), Point3::new(0.5, 0.5, 0.5));
+
+    let difference = &cube1 - &cube2;
+    let mesh = get_mesh_gl(&difference, 0);
+
+    let num_verts = mesh.vert_properties.len() / mesh.num_prop as usize;
+    let num_tris = mesh.tri_verts.len() / 3;
+
+    assert!(num_verts > 0);
+    assert!(num_tris > 0);
+}